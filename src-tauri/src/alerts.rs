@@ -0,0 +1,154 @@
+use crate::process::{self, LogEvent};
+use crate::projects::project_name;
+use crate::system::ProcessMetrics;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Instant;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_notification::NotificationExt;
+
+lazy_static::lazy_static! {
+    // Per PID and rule index: when usage went over the threshold, and whether it already alerted
+    static ref OVER: Mutex<HashMap<(u32, usize), (Instant, bool)>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAlertEvent {
+    pub pid: u32,
+    pub path: String,
+    /// "memory" or "cpu"
+    pub metric: String,
+    /// MB or percent, from the sample that triggered the alert
+    pub value: f32,
+    pub threshold: f32,
+    pub duration_secs: u64,
+    /// "warn" or "restart"
+    pub action: String,
+}
+
+fn amount(metric: &str, value: f32) -> String {
+    if metric == "cpu" {
+        format!("{:.0}%", value)
+    } else {
+        format!("{:.0} MB", value)
+    }
+}
+
+/// e.g. "CPU at 180% for over 300s (threshold 150%)"
+fn describe(alert: &ResourceAlertEvent) -> String {
+    format!(
+        "{} at {} for over {}s (threshold {})",
+        if alert.metric == "cpu" { "CPU" } else { "Memory" },
+        amount(&alert.metric, alert.value),
+        alert.duration_secs,
+        amount(&alert.metric, alert.threshold)
+    )
+}
+
+/// Compare a metrics sample against each project's alert rules; a rule fires once its
+/// threshold has been exceeded for the whole duration, and again only after usage drops back.
+/// Memory rules skip `over_cap` (PIDs over their memory limit), so a breach isn't warned about
+/// or restarted twice.
+pub(crate) fn check(app: &AppHandle, metrics: &[ProcessMetrics], over_cap: &HashSet<u32>) {
+    let Ok(config) = crate::config::load_config_internal(app) else {
+        return;
+    };
+    let mut fired = Vec::new();
+    {
+        let Ok(mut over) = OVER.lock() else {
+            return;
+        };
+        over.retain(|(pid, _), _| metrics.iter().any(|m| m.pid == *pid));
+
+        for sample in metrics {
+            let Some(path) = &sample.path else {
+                continue;
+            };
+            for (index, rule) in config.project_settings(path).resource_alerts.into_iter().enumerate() {
+                let value = match rule.metric.as_str() {
+                    "cpu" => sample.cpu_percent,
+                    "memory" if !over_cap.contains(&sample.pid) => sample.rss_mb,
+                    _ => continue,
+                };
+                let key = (sample.pid, index);
+                if value <= rule.threshold {
+                    over.remove(&key);
+                    continue;
+                }
+                let (since, alerted) = over.entry(key).or_insert((Instant::now(), false));
+                if *alerted || since.elapsed().as_secs() < rule.duration_secs {
+                    continue;
+                }
+                *alerted = true;
+                let alert = ResourceAlertEvent {
+                    pid: sample.pid,
+                    path: path.clone(),
+                    metric: rule.metric,
+                    value,
+                    threshold: rule.threshold,
+                    duration_secs: rule.duration_secs,
+                    action: if rule.action == "restart" { "restart" } else { "warn" }.to_string(),
+                };
+                fired.push((alert, rule.notify));
+            }
+        }
+    }
+
+    for (alert, notify_user) in fired {
+        if notify_user {
+            notify(app, &alert);
+        }
+        act(app, alert);
+    }
+}
+
+fn notify(app: &AppHandle, alert: &ResourceAlertEvent) {
+    let shown = app
+        .notification()
+        .builder()
+        .title(format!("{} is using a lot of resources", project_name(&alert.path)))
+        .body(format!(
+            "{}{}",
+            describe(alert),
+            if alert.action == "restart" { ", restarting" } else { "" }
+        ))
+        .show();
+    if let Err(e) = shown {
+        println!("[alerts] Failed to show notification: {}", e);
+    }
+}
+
+fn act(app: &AppHandle, alert: ResourceAlertEvent) {
+    println!(
+        "[alerts] PID {} ({}): {} ({})",
+        alert.pid,
+        alert.path,
+        describe(&alert),
+        alert.action
+    );
+    process::emit_log(
+        app,
+        LogEvent {
+            path: alert.path.clone(),
+            level: "warn".to_string(),
+            message: format!(
+                "[alerts] {}{}",
+                describe(&alert),
+                if alert.action == "restart" { ", restarting" } else { "" }
+            ),
+            ..Default::default()
+        },
+    );
+    let _ = app.emit("resource-alert", alert.clone());
+
+    if alert.action == "restart" {
+        let (app, pid) = (app.clone(), alert.pid);
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = process::restart_process_internal(&app, pid).await {
+                println!("[alerts] Failed to restart PID {}: {}", pid, e);
+            }
+        });
+    }
+}
@@ -0,0 +1,175 @@
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+const COLOR_NAMES: [&str; 8] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+lazy_static::lazy_static! {
+    // CSI/OSC escape sequences
+    static ref ANSI_REGEX: Regex = Regex::new(r"\x1b\[[0-?]*[ -/]*[@-~]|\x1b\][^\x07\x1b]*(?:\x07|\x1b\\)|\x1b[@-Z\\-_]").unwrap();
+    // Log handling per project path ("strip", "spans" or "keep"), set on each launch
+    static ref MODES: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+}
+
+/// A run of log text in one style
+#[derive(Debug, Clone, Serialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AnsiSpan {
+    pub text: String,
+    /// Color name ("red", "brightBlue") or "#rrggbb"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fg: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bg: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub bold: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub dim: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub italic: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub underline: bool,
+}
+
+impl AnsiSpan {
+    fn is_plain(&self) -> bool {
+        *self
+            == AnsiSpan {
+                text: self.text.clone(),
+                ..Default::default()
+            }
+    }
+}
+
+/// Remove every escape sequence
+pub(crate) fn strip(text: &str) -> String {
+    ANSI_REGEX.replace_all(text, "").to_string()
+}
+
+/// Remember a project's ansi_mode for the launch; called once per launch
+pub(crate) fn load(app: &AppHandle, cwd: &str) {
+    let mode = crate::config::load_config_internal(app)
+        .ok()
+        .and_then(|c| c.project_settings(cwd).ansi_mode)
+        .unwrap_or_else(|| "strip".to_string());
+    if let Ok(mut modes) = MODES.lock() {
+        modes.insert(cwd.to_string(), mode);
+    }
+}
+
+/// Apply the project's ansi_mode to a log line: the message to log, plus styled spans in
+/// "spans" mode when the line has any styling
+pub(crate) fn process(cwd: &str, line: String) -> (String, Vec<AnsiSpan>) {
+    if !line.contains('\x1b') {
+        return (line, Vec::new());
+    }
+    let mode = MODES
+        .lock()
+        .ok()
+        .and_then(|m| m.get(cwd).cloned())
+        .unwrap_or_default();
+    match mode.as_str() {
+        "keep" => (line, Vec::new()),
+        "spans" => {
+            let spans = parse_spans(&line);
+            let plain: String = spans.iter().map(|s| s.text.as_str()).collect();
+            if spans.iter().all(|s| s.is_plain()) {
+                (plain, Vec::new())
+            } else {
+                (plain, spans)
+            }
+        }
+        _ => (strip(&line), Vec::new()),
+    }
+}
+
+/// xterm 256-color palette entry
+fn palette(index: u8) -> String {
+    match index {
+        0..=7 => COLOR_NAMES[index as usize].to_string(),
+        8..=15 => bright(COLOR_NAMES[index as usize - 8]),
+        16..=231 => {
+            let i = index - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            format!("#{:02x}{:02x}{:02x}", level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            format!("#{:02x}{:02x}{:02x}", gray, gray, gray)
+        }
+    }
+}
+
+fn bright(name: &str) -> String {
+    let mut chars = name.chars();
+    let first = chars.next().map(|c| c.to_ascii_uppercase()).unwrap_or_default();
+    format!("bright{}{}", first, chars.as_str())
+}
+
+/// Extended color after a 38/48: "5;n" (palette) or "2;r;g;b" (truecolor)
+fn extended_color(codes: &mut impl Iterator<Item = u16>) -> Option<String> {
+    match codes.next()? {
+        5 => codes.next().map(|n| palette(n.min(255) as u8)),
+        2 => {
+            let mut channel = || codes.next().map(|v| v.min(255) as u8);
+            let (r, g, b) = (channel()?, channel()?, channel()?);
+            Some(format!("#{:02x}{:02x}{:02x}", r, g, b))
+        }
+        _ => None,
+    }
+}
+
+/// Update a style from SGR parameters ("1;31" in ESC[1;31m)
+fn apply_sgr(style: &mut AnsiSpan, params: &str) {
+    let mut codes = params.split(';').map(|c| c.parse::<u16>().unwrap_or(0));
+    while let Some(code) = codes.next() {
+        match code {
+            0 => *style = AnsiSpan::default(),
+            1 => style.bold = true,
+            2 => style.dim = true,
+            3 => style.italic = true,
+            4 => style.underline = true,
+            22 => (style.bold, style.dim) = (false, false),
+            23 => style.italic = false,
+            24 => style.underline = false,
+            30..=37 => style.fg = Some(COLOR_NAMES[code as usize - 30].to_string()),
+            38 => style.fg = extended_color(&mut codes),
+            39 => style.fg = None,
+            40..=47 => style.bg = Some(COLOR_NAMES[code as usize - 40].to_string()),
+            48 => style.bg = extended_color(&mut codes),
+            49 => style.bg = None,
+            90..=97 => style.fg = Some(bright(COLOR_NAMES[code as usize - 90])),
+            100..=107 => style.bg = Some(bright(COLOR_NAMES[code as usize - 100])),
+            _ => {}
+        }
+    }
+}
+
+/// Split a line into styled runs; escape sequences other than colors/attributes are dropped
+fn parse_spans(line: &str) -> Vec<AnsiSpan> {
+    fn push(style: &AnsiSpan, text: &str, spans: &mut Vec<AnsiSpan>) {
+        if !text.is_empty() {
+            spans.push(AnsiSpan {
+                text: text.to_string(),
+                ..style.clone()
+            });
+        }
+    }
+
+    let mut spans = Vec::new();
+    let mut style = AnsiSpan::default();
+
+    let mut last = 0;
+    for m in ANSI_REGEX.find_iter(line) {
+        push(&style, &line[last..m.start()], &mut spans);
+        last = m.end();
+        let sequence = m.as_str();
+        if let Some(params) = sequence.strip_prefix("\x1b[").and_then(|s| s.strip_suffix('m')) {
+            apply_sgr(&mut style, params);
+        }
+    }
+    push(&style, &line[last..], &mut spans);
+    spans
+}
@@ -0,0 +1,321 @@
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+use tauri::AppHandle;
+use tauri_plugin_opener::OpenerExt;
+
+/// Prefix of throwaway profile folders in the temp directory
+const PROFILE_PREFIX: &str = "devlaunch-profile-";
+
+// Throwaway profiles of browsers still open, removed when the browser or DevLaunch exits
+static TEMP_PROFILES: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+#[derive(Clone, Copy, PartialEq)]
+enum Engine {
+    Chromium,
+    Firefox,
+    Safari,
+}
+
+struct BrowserSpec {
+    id: &'static str,
+    name: &'static str,
+    engine: Engine,
+    /// Private mode flag; None when the browser can't be started private from the command line
+    private_flag: Option<&'static str>,
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+    mac_app: &'static str,
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    linux_bins: &'static [&'static str],
+    /// Relative to Program Files / Program Files (x86) / LocalAppData
+    #[cfg_attr(not(windows), allow(dead_code))]
+    windows_exe: &'static str,
+}
+
+const BROWSERS: &[BrowserSpec] = &[
+    BrowserSpec {
+        id: "chrome",
+        name: "Google Chrome",
+        engine: Engine::Chromium,
+        private_flag: Some("--incognito"),
+        mac_app: "Google Chrome",
+        linux_bins: &["google-chrome", "google-chrome-stable"],
+        windows_exe: r"Google\Chrome\Application\chrome.exe",
+    },
+    BrowserSpec {
+        id: "chromium",
+        name: "Chromium",
+        engine: Engine::Chromium,
+        private_flag: Some("--incognito"),
+        mac_app: "Chromium",
+        linux_bins: &["chromium", "chromium-browser"],
+        windows_exe: r"Chromium\Application\chrome.exe",
+    },
+    BrowserSpec {
+        id: "firefox",
+        name: "Firefox",
+        engine: Engine::Firefox,
+        private_flag: Some("-private-window"),
+        mac_app: "Firefox",
+        linux_bins: &["firefox"],
+        windows_exe: r"Mozilla Firefox\firefox.exe",
+    },
+    BrowserSpec {
+        id: "safari",
+        name: "Safari",
+        engine: Engine::Safari,
+        private_flag: None,
+        mac_app: "Safari",
+        linux_bins: &[],
+        windows_exe: "",
+    },
+    BrowserSpec {
+        id: "arc",
+        name: "Arc",
+        engine: Engine::Chromium,
+        private_flag: None,
+        mac_app: "Arc",
+        linux_bins: &[],
+        windows_exe: "",
+    },
+    BrowserSpec {
+        id: "edge",
+        name: "Microsoft Edge",
+        engine: Engine::Chromium,
+        private_flag: Some("--inprivate"),
+        mac_app: "Microsoft Edge",
+        linux_bins: &["microsoft-edge", "microsoft-edge-stable"],
+        windows_exe: r"Microsoft\Edge\Application\msedge.exe",
+    },
+    BrowserSpec {
+        id: "brave",
+        name: "Brave",
+        engine: Engine::Chromium,
+        private_flag: Some("--incognito"),
+        mac_app: "Brave Browser",
+        linux_bins: &["brave-browser", "brave"],
+        windows_exe: r"BraveSoftware\Brave-Browser\Application\brave.exe",
+    },
+];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserInfo {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    pub supports_private: bool,
+    pub supports_clean_profile: bool,
+}
+
+/// Where a browser is installed, if it is
+#[allow(unused_variables)]
+fn locate(app: &AppHandle, spec: &BrowserSpec) -> Option<PathBuf> {
+    #[cfg(target_os = "macos")]
+    {
+        let home = std::env::var("HOME").unwrap_or_default();
+        [PathBuf::from("/Applications"), PathBuf::from(home).join("Applications")]
+            .into_iter()
+            .map(|dir| dir.join(format!("{}.app", spec.mac_app)))
+            .find(|p| p.exists())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        // GUI launches get a minimal PATH; search the login shell's
+        let path = crate::shell_env::spawn_env(app)
+            .get("PATH")
+            .cloned()
+            .or_else(|| std::env::var("PATH").ok())
+            .unwrap_or_default();
+        spec.linux_bins.iter().find_map(|bin| {
+            std::env::split_paths(&path)
+                .map(|dir| dir.join(bin))
+                .find(|p| p.is_file())
+        })
+    }
+
+    #[cfg(windows)]
+    {
+        if spec.windows_exe.is_empty() {
+            return None;
+        }
+        ["ProgramFiles", "ProgramFiles(x86)", "LOCALAPPDATA"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .map(|dir| PathBuf::from(dir).join(spec.windows_exe))
+            .find(|p| p.is_file())
+    }
+}
+
+/// Installed browsers DevLaunch can launch directly
+#[tauri::command]
+pub fn list_browsers(app: AppHandle) -> Vec<BrowserInfo> {
+    BROWSERS
+        .iter()
+        .filter_map(|spec| {
+            let path = locate(&app, spec)?;
+            Some(BrowserInfo {
+                id: spec.id.to_string(),
+                name: spec.name.to_string(),
+                path: path.to_string_lossy().to_string(),
+                supports_private: spec.private_flag.is_some(),
+                supports_clean_profile: matches!(spec.engine, Engine::Chromium | Engine::Firefox)
+                    && spec.id != "arc",
+            })
+        })
+        .collect()
+}
+
+/// Fresh throwaway profile directory, so no cookies or sessions carry over
+fn temp_profile(browser: &str) -> Result<PathBuf, String> {
+    let name = format!("{}{}-{}", PROFILE_PREFIX, browser, uuid::Uuid::new_v4().simple());
+    let dir = std::env::temp_dir().join(name);
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create profile folder: {}", e))?;
+    if let Ok(mut profiles) = TEMP_PROFILES.lock() {
+        profiles.push(dir.clone());
+    }
+    Ok(dir)
+}
+
+fn remove_profile(dir: &Path) {
+    if let Err(e) = std::fs::remove_dir_all(dir) {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            println!("[browsers] Failed to remove profile {}: {}", dir.display(), e);
+        }
+    }
+    if let Ok(mut profiles) = TEMP_PROFILES.lock() {
+        profiles.retain(|p| p != dir);
+    }
+}
+
+/// Remove the throwaway profiles of browsers that are still open (on exit), and any a
+/// crashed session left behind
+pub(crate) fn remove_temp_profiles() {
+    let open = TEMP_PROFILES.lock().map(|p| p.clone()).unwrap_or_default();
+    for dir in open {
+        remove_profile(&dir);
+    }
+    let Ok(entries) = std::fs::read_dir(std::env::temp_dir()) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_name().to_string_lossy().starts_with(PROFILE_PREFIX) {
+            remove_profile(&entry.path());
+        }
+    }
+}
+
+/// Launch arguments, plus the throwaway profile they point at when `clean_profile` is set
+fn browser_args(
+    spec: &BrowserSpec,
+    private: bool,
+    clean_profile: bool,
+) -> Result<(Vec<String>, Option<PathBuf>), String> {
+    let mut args = Vec::new();
+    if private {
+        let flag = spec
+            .private_flag
+            .ok_or_else(|| format!("{} can't be opened in private mode from DevLaunch", spec.name))?;
+        args.push(flag.to_string());
+    }
+    if !clean_profile {
+        return Ok((args, None));
+    }
+    if !matches!(spec.engine, Engine::Chromium | Engine::Firefox) || spec.id == "arc" {
+        return Err(format!("{} doesn't support a separate profile", spec.name));
+    }
+    let profile = temp_profile(spec.id)?;
+    let dir = profile.to_string_lossy().to_string();
+    if spec.engine == Engine::Firefox {
+        args.extend(["-no-remote".to_string(), "-profile".to_string(), dir]);
+    } else {
+        args.push(format!("--user-data-dir={}", dir));
+        args.push("--no-first-run".to_string());
+        args.push("--no-default-browser-check".to_string());
+    }
+    Ok((args, Some(profile)))
+}
+
+/// Open a URL in a specific browser, optionally in private mode or a throwaway profile.
+/// Without `browser`, the OS default browser is used.
+#[tauri::command]
+pub fn open_url(
+    app: AppHandle,
+    url: String,
+    browser: Option<String>,
+    incognito: Option<bool>,
+    clean_profile: Option<bool>,
+) -> Result<(), String> {
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err(format!("Not a web URL: {}", url));
+    }
+    let private = incognito.unwrap_or(false);
+    let clean_profile = clean_profile.unwrap_or(false);
+
+    let Some(id) = browser else {
+        if private || clean_profile {
+            return Err("Choose a browser to open a private window or clean profile".to_string());
+        }
+        return app
+            .opener()
+            .open_url(&url, None::<&str>)
+            .map_err(|e| format!("Failed to open {}: {}", url, e));
+    };
+
+    let spec = BROWSERS
+        .iter()
+        .find(|b| b.id == id)
+        .ok_or_else(|| format!("Unknown browser: {}", id))?;
+    let path = locate(&app, spec).ok_or_else(|| format!("{} is not installed", spec.name))?;
+    let (args, profile) = browser_args(spec, private, clean_profile)?;
+
+    #[cfg(target_os = "macos")]
+    let mut cmd = {
+        let mut cmd = Command::new("open");
+        // -n starts a new instance, the only way to pass flags to a browser that's already running
+        if !args.is_empty() {
+            cmd.arg("-n");
+        }
+        // -W waits for the browser to quit, so its throwaway profile can be removed then
+        if profile.is_some() {
+            cmd.arg("-W");
+        }
+        cmd.arg("-a").arg(&path);
+        if args.is_empty() {
+            cmd.arg(&url);
+        } else {
+            cmd.arg("--args").args(&args).arg(&url);
+        }
+        cmd
+    };
+    #[cfg(not(target_os = "macos"))]
+    let mut cmd = {
+        let mut cmd = Command::new(&path);
+        cmd.args(&args).arg(&url);
+        cmd
+    };
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            if let Some(profile) = &profile {
+                remove_profile(profile);
+            }
+            return Err(format!("Failed to launch {}: {}", spec.name, e));
+        }
+    };
+    println!(
+        "[browsers] Opened {} in {} (private: {}, clean profile: {})",
+        url, spec.name, private, clean_profile
+    );
+    // Reap the launcher (or browser) whenever it exits, then drop its throwaway profile
+    std::thread::spawn(move || {
+        let _ = child.wait();
+        if let Some(profile) = profile {
+            remove_profile(&profile);
+        }
+    });
+    Ok(())
+}
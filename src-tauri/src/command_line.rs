@@ -0,0 +1,194 @@
+/// A shell-style command line split into words, with any leading `KEY=value` assignments
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandLine {
+    pub env: Vec<(String, String)>,
+    pub command: String,
+    pub args: Vec<String>,
+    /// Uses syntax only a shell can run (pipes, redirects, `&&`, `$VAR`, globs, `~`)
+    pub needs_shell: bool,
+}
+
+/// Unquoted characters that mean the line is a shell script rather than a plain command
+const SHELL_SYNTAX: &[char] = &['|', '&', ';', '<', '>', '(', ')', '$', '`', '*', '?'];
+
+fn is_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().map(|c| c.is_ascii_alphabetic() || c == '_').unwrap_or(false)
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Split a command line into words the way a POSIX shell would: single quotes are literal,
+/// double quotes allow `\"`, `\\`, `\$` and `` \` `` escapes, and a backslash outside quotes
+/// escapes the next character
+fn split_words(line: &str) -> Result<(Vec<String>, bool), String> {
+    let mut words = Vec::new();
+    let mut needs_shell = false;
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => word.push(c),
+                        None => return Err("Unbalanced single quote".to_string()),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\' | '$' | '`')) => word.push(c),
+                            // Line continuation
+                            Some('\n') => {}
+                            Some(c) => {
+                                word.push('\\');
+                                word.push(c);
+                            }
+                            None => return Err("Unbalanced double quote".to_string()),
+                        },
+                        Some(c) => {
+                            needs_shell |= c == '$' || c == '`';
+                            word.push(c);
+                        }
+                        None => return Err("Unbalanced double quote".to_string()),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some(c) => {
+                    in_word = true;
+                    word.push(c);
+                }
+                None => return Err("Trailing backslash".to_string()),
+            },
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            c => {
+                needs_shell |= SHELL_SYNTAX.contains(&c) || (c == '~' && !in_word);
+                in_word = true;
+                word.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    Ok((words, needs_shell))
+}
+
+/// Parse a command line like `NODE_ENV=test npm run dev -- --port 4000`
+pub fn parse(line: &str) -> Result<CommandLine, String> {
+    let (words, needs_shell) = split_words(line)?;
+    let mut words = words.into_iter().peekable();
+
+    let mut env = Vec::new();
+    while let Some((key, value)) = words.peek().and_then(|w| w.split_once('=')) {
+        if !is_env_name(key) {
+            break;
+        }
+        env.push((key.to_string(), value.to_string()));
+        words.next();
+    }
+    let command = words.next().ok_or_else(|| {
+        if env.is_empty() {
+            "Command line is empty".to_string()
+        } else {
+            "Command line only sets variables; add the command to run".to_string()
+        }
+    })?;
+    Ok(CommandLine {
+        env,
+        command,
+        args: words.collect(),
+        needs_shell,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(line: &str) -> Vec<String> {
+        split_words(line).unwrap().0
+    }
+
+    #[test]
+    fn splits_on_whitespace() {
+        assert_eq!(words("  npm   run dev "), ["npm", "run", "dev"]);
+    }
+
+    #[test]
+    fn single_quotes_are_literal() {
+        assert_eq!(words(r#"echo 'a "b" \n $HOME'"#), ["echo", r#"a "b" \n $HOME"#]);
+        assert!(!split_words("echo '$HOME'").unwrap().1);
+    }
+
+    #[test]
+    fn double_quotes_keep_spaces_and_handle_escapes() {
+        assert_eq!(words(r#"echo "a b" "say \"hi\"" "c:\dir""#), ["echo", "a b", r#"say "hi""#, r"c:\dir"]);
+    }
+
+    #[test]
+    fn quotes_join_adjacent_text() {
+        assert_eq!(words(r#"--name="my app"'!'"#), ["--name=my app!"]);
+        assert_eq!(words(r#"echo """#), ["echo", ""]);
+    }
+
+    #[test]
+    fn backslash_escapes_outside_quotes() {
+        assert_eq!(words(r"touch my\ file"), ["touch", "my file"]);
+        assert_eq!(words("npm run \\\n dev"), ["npm", "run", "dev"]);
+    }
+
+    #[test]
+    fn unbalanced_quotes_are_errors() {
+        assert_eq!(split_words("echo 'oops").unwrap_err(), "Unbalanced single quote");
+        assert_eq!(split_words(r#"echo "oops"#).unwrap_err(), "Unbalanced double quote");
+        assert_eq!(split_words(r#"echo "oops\"#).unwrap_err(), "Unbalanced double quote");
+        assert_eq!(split_words(r"echo oops\").unwrap_err(), "Trailing backslash");
+    }
+
+    #[test]
+    fn detects_shell_syntax() {
+        for line in ["a | b", "a && b", "a > out", "echo $HOME", r#"echo "$HOME""#, "ls *.rs", "cd ~"] {
+            assert!(split_words(line).unwrap().1, "{}", line);
+        }
+        for line in ["npm run dev", "echo 'a|b'", r"echo a\|b", "git log a~1"] {
+            assert!(!split_words(line).unwrap().1, "{}", line);
+        }
+    }
+
+    #[test]
+    fn parses_leading_env_assignments() {
+        let line = parse("NODE_ENV=test PORT=\"4000\" npm run dev -- --flag=x").unwrap();
+        assert_eq!(line.env, [("NODE_ENV".to_string(), "test".to_string()), ("PORT".to_string(), "4000".to_string())]);
+        assert_eq!(line.command, "npm");
+        assert_eq!(line.args, ["run", "dev", "--", "--flag=x"]);
+    }
+
+    #[test]
+    fn only_valid_names_are_env_assignments() {
+        let line = parse("1X=2 cmd").unwrap();
+        assert!(line.env.is_empty());
+        assert_eq!(line.command, "1X=2");
+    }
+
+    #[test]
+    fn rejects_empty_lines() {
+        assert_eq!(parse("   ").unwrap_err(), "Command line is empty");
+        assert!(parse("A=1").unwrap_err().contains("only sets variables"));
+    }
+}
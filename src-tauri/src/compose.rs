@@ -0,0 +1,309 @@
+use crate::process;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::process::Command;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+/// Default number of lines `compose_logs` returns per service
+const DEFAULT_LOG_TAIL: u32 = 200;
+
+lazy_static::lazy_static! {
+    // Stacks started by compose_up, per project path
+    static ref STACKS: Mutex<HashMap<String, Stack>> = Mutex::new(HashMap::new());
+}
+
+/// The services a compose_up started and the managed process standing in for them
+#[derive(Debug, Clone)]
+struct Stack {
+    pid: u32,
+    /// Empty for the whole stack
+    services: Vec<String>,
+    detached: bool,
+}
+
+impl Stack {
+    fn covers(&self, service: &str) -> bool {
+        self.services.is_empty() || self.services.iter().any(|s| s == service)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComposePort {
+    pub host_port: Option<u16>,
+    pub container_port: u16,
+    pub protocol: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComposeContainer {
+    pub name: String,
+    pub service: String,
+    pub image: String,
+    /// "running", "exited", "restarting", "created", ...
+    pub state: String,
+    /// Human-readable status, e.g. "Up 2 minutes (healthy)"
+    pub status: String,
+    /// "healthy", "unhealthy", "starting" or empty without a healthcheck
+    pub health: String,
+    pub ports: Vec<ComposePort>,
+    /// PID of the managed process (in the process view) attached to this stack, if any
+    pub managed_pid: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComposeUpResult {
+    /// Managed process streaming the stack's output (`up` itself, or `logs -f` when detached)
+    pub pid: u32,
+    pub detached: bool,
+    pub containers: Vec<ComposeContainer>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComposeLogsResult {
+    pub lines: Vec<String>,
+    /// Managed process following the logs, when `follow` was set
+    pub pid: Option<u32>,
+}
+
+/// `docker compose --ansi never <args>` in the project directory, returning stdout
+fn run_compose_blocking(app: &AppHandle, path: &str, args: &[String]) -> Result<String, String> {
+    let mut cmd = Command::new("docker");
+    cmd.current_dir(path).args(["compose", "--ansi", "never"]).args(args);
+    // GUI apps get a minimal PATH; docker usually lives in /usr/local/bin
+    crate::shell_env::apply_to_command(app, &mut cmd);
+
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run docker compose: {}", e))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(format!(
+            "docker compose {} failed: {}",
+            args.first().map(|s| s.as_str()).unwrap_or(""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// run_compose_blocking off the async runtime; compose commands can take minutes (image pulls)
+async fn run_compose(app: &AppHandle, path: &str, args: Vec<String>) -> Result<String, String> {
+    let (app, path) = (app.clone(), path.to_string());
+    tauri::async_runtime::spawn_blocking(move || run_compose_blocking(&app, &path, &args))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Called once a managed process has exited after a stop request. Stopping the `logs -f`
+/// follower of a detached stack also stops the containers it stood in for.
+pub(crate) fn on_stopped(app: &AppHandle, pid: u32) {
+    let stack = STACKS.lock().ok().and_then(|mut stacks| {
+        let path = stacks.iter().find(|(_, s)| s.pid == pid).map(|(p, _)| p.clone())?;
+        stacks.remove(&path).map(|stack| (path, stack))
+    });
+    let Some((path, stack)) = stack.filter(|(_, s)| s.detached) else {
+        return;
+    };
+    let mut args = vec!["stop".to_string()];
+    args.extend(stack.services);
+    match run_compose_blocking(app, &path, &args) {
+        Ok(_) => println!("[compose] Stopped stack in {}", path),
+        Err(e) => println!("[compose] {}", e),
+    }
+}
+
+/// Run `docker compose <args>` as a managed process so its output flows through process-log
+fn spawn_compose(app: &AppHandle, path: &str, args: Vec<String>) -> Result<u32, String> {
+    let mut full = vec!["compose".to_string(), "--ansi".to_string(), "never".to_string()];
+    full.extend(args);
+    process::spawn_process_internal(app, path, "docker", &full)
+}
+
+/// Running managed `docker compose ...` processes for a project
+fn compose_processes(path: &str) -> Vec<process::ProcessRecord> {
+    process::list_processes_internal()
+        .into_iter()
+        .filter(|p| p.running && p.cwd == path && p.command == "docker")
+        .filter(|p| p.args.first().map(|a| a.as_str()) == Some("compose"))
+        .collect()
+}
+
+fn parse_container(value: &serde_json::Value) -> Option<ComposeContainer> {
+    let text = |key: &str| value.get(key).and_then(|v| v.as_str()).unwrap_or("").to_string();
+    let mut ports: Vec<ComposePort> = Vec::new();
+    let publishers = value.get("Publishers").and_then(|p| p.as_array());
+    for publisher in publishers.into_iter().flatten() {
+        let Some(container_port) = publisher.get("TargetPort").and_then(|v| v.as_u64()) else {
+            continue;
+        };
+        let port = ComposePort {
+            host_port: publisher
+                .get("PublishedPort")
+                .and_then(|v| v.as_u64())
+                .filter(|p| *p > 0)
+                .map(|p| p as u16),
+            container_port: container_port as u16,
+            protocol: publisher.get("Protocol").and_then(|v| v.as_str()).unwrap_or("tcp").to_string(),
+        };
+        // IPv4 and IPv6 bindings of the same port show up as separate publishers
+        let duplicate = ports.iter().any(|p| {
+            p.host_port == port.host_port && p.container_port == port.container_port && p.protocol == port.protocol
+        });
+        if !duplicate {
+            ports.push(port);
+        }
+    }
+
+    Some(ComposeContainer {
+        name: value.get("Name")?.as_str()?.to_string(),
+        service: text("Service"),
+        image: text("Image"),
+        state: text("State"),
+        status: text("Status"),
+        health: text("Health"),
+        ports,
+        managed_pid: None,
+    })
+}
+
+async fn compose_ps_internal(app: &AppHandle, path: &str) -> Result<Vec<ComposeContainer>, String> {
+    let args: Vec<String> = ["ps", "--all", "--format", "json"].iter().map(|a| a.to_string()).collect();
+    let output = run_compose(app, path, args).await?;
+    let trimmed = output.trim();
+    // Older Compose v2 prints one JSON array, newer versions one object per line
+    let values: Vec<serde_json::Value> = if trimmed.starts_with('[') {
+        serde_json::from_str(trimmed).map_err(|e| format!("Unexpected docker compose ps output: {}", e))?
+    } else {
+        trimmed
+            .lines()
+            .filter_map(|l| serde_json::from_str(l).ok())
+            .collect()
+    };
+    let running = compose_processes(path);
+    let stack = STACKS
+        .lock()
+        .ok()
+        .and_then(|s| s.get(path).cloned())
+        .filter(|stack| running.iter().any(|p| p.pid == stack.pid));
+    let fallback = running.first().map(|p| p.pid);
+    Ok(values
+        .iter()
+        .filter_map(parse_container)
+        .map(|c| {
+            // Only the services compose_up started belong to its process
+            let managed_pid = match &stack {
+                Some(stack) => stack.covers(&c.service).then_some(stack.pid),
+                None => fallback,
+            };
+            ComposeContainer { managed_pid, ..c }
+        })
+        .collect())
+}
+
+/// Start a compose stack (optionally only some services). Attached, `docker compose up` itself is
+/// the managed process; detached, the containers start in the background and a `logs -f`
+/// follower streams their output instead.
+#[tauri::command]
+pub async fn compose_up(
+    app: AppHandle,
+    path: String,
+    services: Option<Vec<String>>,
+    detach: Option<bool>,
+) -> Result<ComposeUpResult, String> {
+    let services = services.unwrap_or_default();
+    let detached = detach.unwrap_or(false);
+    if !compose_processes(&path).is_empty() {
+        return Err(format!("A compose stack is already running for {}", path));
+    }
+
+    let pid = if detached {
+        let mut args = vec!["up".to_string(), "--detach".to_string()];
+        args.extend(services.iter().cloned());
+        run_compose(&app, &path, args).await?;
+
+        let mut args = vec!["logs".to_string(), "--follow".to_string(), "--tail".to_string(), "0".to_string()];
+        args.extend(services.iter().cloned());
+        spawn_compose(&app, &path, args)?
+    } else {
+        let mut args = vec!["up".to_string()];
+        args.extend(services.iter().cloned());
+        spawn_compose(&app, &path, args)?
+    };
+    println!("[compose] Started stack in {} (PID {}, detached={})", path, pid, detached);
+    if let Ok(mut stacks) = STACKS.lock() {
+        stacks.insert(
+            path.clone(),
+            Stack {
+                pid,
+                services,
+                detached,
+            },
+        );
+    }
+
+    // Attached `up` is still creating containers; report what exists so far
+    let containers = compose_ps_internal(&app, &path).await.unwrap_or_default();
+    Ok(ComposeUpResult {
+        pid,
+        detached,
+        containers,
+    })
+}
+
+/// Stop the stack's managed processes and remove its containers (and volumes when asked)
+#[tauri::command]
+pub async fn compose_down(app: AppHandle, path: String, volumes: Option<bool>) -> Result<(), String> {
+    if let Ok(mut stacks) = STACKS.lock() {
+        stacks.remove(&path);
+    }
+    for record in compose_processes(&path) {
+        let _ = process::kill_process_internal(record.pid);
+    }
+
+    let mut args = vec!["down".to_string()];
+    if volumes.unwrap_or(false) {
+        args.push("--volumes".to_string());
+    }
+    run_compose(&app, &path, args).await?;
+    println!("[compose] Stack in {} is down", path);
+    Ok(())
+}
+
+/// Containers of the project's compose stack, including stopped ones
+#[tauri::command]
+pub async fn compose_ps(app: AppHandle, path: String) -> Result<Vec<ComposeContainer>, String> {
+    compose_ps_internal(&app, &path).await
+}
+
+/// Recent container logs; with `follow`, also keep streaming new lines through process-log
+#[tauri::command]
+pub async fn compose_logs(
+    app: AppHandle,
+    path: String,
+    services: Option<Vec<String>>,
+    tail: Option<u32>,
+    follow: Option<bool>,
+) -> Result<ComposeLogsResult, String> {
+    let services = services.unwrap_or_default();
+    let mut args = vec![
+        "logs".to_string(),
+        "--tail".to_string(),
+        tail.unwrap_or(DEFAULT_LOG_TAIL).to_string(),
+    ];
+    args.extend(services.iter().cloned());
+    let lines = run_compose(&app, &path, args)
+        .await?
+        .lines()
+        .map(|l| l.to_string())
+        .collect();
+
+    let pid = if follow.unwrap_or(false) && compose_processes(&path).is_empty() {
+        let mut args = vec!["logs".to_string(), "--follow".to_string(), "--tail".to_string(), "0".to_string()];
+        args.extend(services);
+        Some(spawn_compose(&app, &path, args)?)
+    } else {
+        None
+    };
+    Ok(ComposeLogsResult { lines, pid })
+}
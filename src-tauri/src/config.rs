@@ -1,13 +1,65 @@
+use crate::secrets;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use tauri::{AppHandle, Manager};
 
+// Serializes config writes, which share one temp file
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+// Serializes load-modify-save cycles so concurrent updates don't drop each other's changes
+static UPDATE_LOCK: Mutex<()> = Mutex::new(());
+
+// Backups written by older builds may still hold plaintext tokens; scrubbed once per run
+static BACKUPS_SCRUBBED: std::sync::Once = std::sync::Once::new();
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PresetProject {
     pub path: String,
     pub script: String,
+    /// Paths of projects in the same preset that must be ready before this one starts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    /// Wait this long before spawning the project
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub startup_delay_ms: u64,
+    /// When the project counts as ready for its dependents (just "spawned" if unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ready_when: Option<ReadinessCondition>,
+    /// Exact command to run instead of resolving `script` (recorded for session restore)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+}
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
+}
+
+/// Readiness check for preset startup ordering; every condition that is set must pass
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadinessCondition {
+    /// TCP port on localhost that must accept connections
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// URL that must answer with a 2xx/3xx status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Regex a log line must match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_pattern: Option<String>,
+    #[serde(default = "default_ready_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+fn default_ready_timeout_ms() -> u64 {
+    60_000
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -42,6 +94,9 @@ pub struct GitToken {
     pub token: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub label: Option<String>,
+    /// Private key for SSH remotes matching the pattern (the SSH agent is used otherwise)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssh_key: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,9 +108,6 @@ pub struct GitPreferences {
     pub polling_interval_minutes: u32,
     #[serde(default)]
     pub tokens: Vec<GitToken>,
-    // Legacy field for migration - read old configs but don't write
-    #[serde(skip_serializing, default)]
-    git_token: Option<String>,
 }
 
 impl Default for GitPreferences {
@@ -64,37 +116,759 @@ impl Default for GitPreferences {
             enabled: default_git_enabled(),
             polling_interval_minutes: default_polling_interval(),
             tokens: Vec::new(),
-            git_token: None,
         }
     }
 }
 
-impl GitPreferences {
-    /// Migrate legacy single git_token to tokens array
-    pub fn migrate_legacy_token(&mut self) {
-        if let Some(token) = self.git_token.take() {
-            if !token.is_empty() && self.tokens.is_empty() {
-                self.tokens.push(GitToken {
-                    id: uuid::Uuid::new_v4().to_string(),
-                    pattern: "*".to_string(),
-                    token,
-                    label: Some("Migrated token".to_string()),
-                });
-            }
+fn default_git_enabled() -> bool {
+    true
+}
+
+fn default_polling_interval() -> u32 {
+    10
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShellEnvPreferences {
+    /// Resolve PATH and friends from the user's login shell for spawned commands
+    #[serde(default = "default_use_login_shell")]
+    pub use_login_shell: bool,
+    /// Shell to resolve from instead of $SHELL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+    /// Extra variables applied on top of the resolved environment
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub env: HashMap<String, String>,
+}
+
+impl Default for ShellEnvPreferences {
+    fn default() -> Self {
+        Self {
+            use_login_shell: default_use_login_shell(),
+            shell: None,
+            env: HashMap::new(),
         }
     }
 }
 
-fn default_git_enabled() -> bool {
+fn default_use_login_shell() -> bool {
     true
 }
 
-fn default_polling_interval() -> u32 {
-    10
+/// Watchdog policy for restarting a crashed dev server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestartPolicy {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    #[serde(default = "default_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+    #[serde(default = "default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+    /// Only restart when the process exited with a non-zero code or a signal
+    #[serde(default = "default_true")]
+    pub only_on_nonzero_exit: bool,
+    /// A process that stayed up this long gets its retry count reset
+    #[serde(default = "default_reset_after_secs")]
+    pub reset_after_secs: u64,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_retries: default_max_retries(),
+            initial_backoff_ms: default_initial_backoff_ms(),
+            max_backoff_ms: default_max_backoff_ms(),
+            only_on_nonzero_exit: true,
+            reset_after_secs: default_reset_after_secs(),
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Exponential backoff for the given (zero-based) attempt, capped at max_backoff_ms
+    pub fn backoff_ms(&self, attempt: u32) -> u64 {
+        self.initial_backoff_ms
+            .saturating_mul(1u64 << attempt.min(20))
+            .min(self.max_backoff_ms)
+    }
+}
+
+fn default_max_retries() -> u32 {
+    5
+}
+
+fn default_initial_backoff_ms() -> u64 {
+    1000
+}
+
+fn default_max_backoff_ms() -> u64 {
+    30_000
+}
+
+fn default_reset_after_secs() -> u64 {
+    60
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// HTTP probe that decides whether a running project is actually serving
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthCheckConfig {
+    /// Path (e.g. "/api/health") or full URL; defaults to the detected base URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default = "default_health_interval_ms")]
+    pub interval_ms: u64,
+    #[serde(default = "default_health_timeout_ms")]
+    pub timeout_ms: u64,
+    /// Exact status to expect; any 2xx/3xx when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_status: Option<u16>,
+    /// Substring the response body must contain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_body: Option<String>,
+    /// Consecutive failures before a healthy project is reported unhealthy
+    #[serde(default = "default_health_failure_threshold")]
+    pub failure_threshold: u32,
+    /// How long a project may stay "starting" before it is reported unhealthy
+    #[serde(default = "default_health_startup_timeout_ms")]
+    pub startup_timeout_ms: u64,
+}
+
+impl Default for HealthCheckConfig {
+    fn default() -> Self {
+        Self {
+            url: None,
+            interval_ms: default_health_interval_ms(),
+            timeout_ms: default_health_timeout_ms(),
+            expected_status: None,
+            expected_body: None,
+            failure_threshold: default_health_failure_threshold(),
+            startup_timeout_ms: default_health_startup_timeout_ms(),
+        }
+    }
+}
+
+fn default_health_interval_ms() -> u64 {
+    5000
+}
+
+fn default_health_timeout_ms() -> u64 {
+    3000
+}
+
+fn default_health_failure_threshold() -> u32 {
+    3
 }
 
+fn default_health_startup_timeout_ms() -> u64 {
+    60_000
+}
+
+/// Per-project launch settings, keyed by project path in Preferences
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
+pub struct ProjectSettings {
+    /// Paths (e.g. "/", "/api/health") or full URLs requested once the project is ready
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warmup_requests: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_restart: Option<RestartPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_check: Option<HealthCheckConfig>,
+    /// Ports the project listens on, checked for conflicts before launching
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ports: Vec<u16>,
+    /// Script launched by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script: Option<String>,
+    /// Extra environment for the project's processes
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub env: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hooks: Option<ProjectHooks>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<ResourceLimits>,
+    /// Sustained CPU or memory usage worth flagging, checked on every metrics sample. Memory
+    /// rules stand down while `limits.max_memory_mb` is exceeded; the limit's action applies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resource_alerts: Vec<ResourceAlertRule>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watch_and_restart: Option<WatchRestart>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_shutdown: Option<IdleRule>,
+    /// Built-in rule sets applied after `log_rules`: "rails", "django", "spring"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub log_presets: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub log_rules: Vec<LogRule>,
+    /// Color codes in log lines: "strip" (default), "spans" (styled runs on each log event)
+    /// or "keep" (left in the message)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ansi_mode: Option<String>,
+    /// Run commands through a version manager so its pinned runtime is used: "nvm", "fnm",
+    /// "asdf", "mise", "volta", "login-shell", or "auto" to pick from the project's files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_manager: Option<String>,
+}
+
+/// Shell commands run around a project's launches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectHooks {
+    /// Run in order before spawning (e.g. "docker compose up -d db"); a failure aborts the launch
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pre_launch: Vec<String>,
+    /// Run in order after the project is stopped (e.g. "docker compose stop")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_stop: Vec<String>,
+    /// Max runtime per hook command
+    #[serde(default = "default_hook_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_hook_timeout_secs() -> u64 {
+    300
+}
+
+/// Resource caps applied to a project's process tree
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceLimits {
+    /// Resident memory of the whole process tree, as checked by the metrics monitor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_memory_mb: Option<u64>,
+    /// Scheduling priority, -20 (highest) to 19 (lowest); negative values need root on Unix
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+    /// What to do when max_memory_mb is exceeded: "warn", "kill" or "restart"
+    #[serde(default = "default_limit_action")]
+    pub on_exceed: String,
+    /// Also have the OS enforce max_memory_mb, so allocations past it fail instead of waiting
+    /// for the monitor. On Windows this is a Job Object commit cap shared by the whole tree.
+    /// On Linux it is RLIMIT_DATA (private writable memory, so the large virtual reservations
+    /// of V8, Go and the JVM don't count), which applies to each process on its own: every
+    /// child inherits the full cap, so the tree as a whole is only held to it by the monitor.
+    /// Other platforms have no equivalent and rely on the monitor.
+    #[serde(default)]
+    pub hard_memory_limit: bool,
+}
+
+fn default_limit_action() -> String {
+    "warn".to_string()
+}
+
+/// Alert when a project's process tree stays over a CPU or memory threshold, e.g. memory
+/// over 2048 MB for 60s or CPU over 150% for 300s
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAlertRule {
+    /// "memory" (resident MB of the tree) or "cpu" (percent, 100 per fully used core)
+    pub metric: String,
+    pub threshold: f32,
+    /// How long usage has to stay over the threshold before alerting
+    #[serde(default = "default_alert_duration_secs")]
+    pub duration_secs: u64,
+    /// Also show a native notification
+    #[serde(default)]
+    pub notify: bool,
+    /// "warn" (alert only) or "restart"
+    #[serde(default = "default_limit_action")]
+    pub action: String,
+}
+
+fn default_alert_duration_secs() -> u64 {
+    60
+}
+
+/// Restart the project when matching files change, for servers without their own reloader
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchRestart {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Globs relative to the project, e.g. "**/*.go" or "templates/**"
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Globs to skip; .git, node_modules, target and virtualenvs are always skipped
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+    /// Quiet period after the last change before restarting
+    #[serde(default = "default_watch_debounce_ms")]
+    pub debounce_ms: u64,
+}
+
+fn default_watch_debounce_ms() -> u64 {
+    500
+}
+
+/// Flag a dev server nobody is using: no log output and no connections to its port
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdleRule {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// How long the server has to be idle before acting
+    #[serde(default = "default_idle_minutes")]
+    pub minutes: u64,
+    /// "warn" (emit project-idle only) or "stop"
+    #[serde(default = "default_idle_action")]
+    pub action: String,
+}
+
+fn default_idle_minutes() -> u64 {
+    30
+}
+
+fn default_idle_action() -> String {
+    "warn".to_string()
+}
+
+/// Regex rule for classifying a project's log lines; the first matching rule wins
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogRule {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Named groups are attached to the log line as fields; `url` and `port` groups are
+    /// used for served-URL detection
+    pub pattern: String,
+    /// Level for matching lines ("error", "warn", "info", "debug"), or a {{group}} placeholder
+    /// holding a severity word or HTTP status; unset keeps the default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    /// Groups repeated errors, with {{group}} placeholders, e.g. "{{exception}} at {{file}}"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+}
+
+impl ProjectSettings {
+    /// Layer `other` on top: set fields win, env maps are merged
+    pub fn overlay(mut self, other: ProjectSettings) -> ProjectSettings {
+        if !other.warmup_requests.is_empty() {
+            self.warmup_requests = other.warmup_requests;
+        }
+        if other.auto_restart.is_some() {
+            self.auto_restart = other.auto_restart;
+        }
+        if other.health_check.is_some() {
+            self.health_check = other.health_check;
+        }
+        if !other.ports.is_empty() {
+            self.ports = other.ports;
+        }
+        if other.script.is_some() {
+            self.script = other.script;
+        }
+        if other.hooks.is_some() {
+            self.hooks = other.hooks;
+        }
+        if other.limits.is_some() {
+            self.limits = other.limits;
+        }
+        if !other.resource_alerts.is_empty() {
+            self.resource_alerts = other.resource_alerts;
+        }
+        if other.watch_and_restart.is_some() {
+            self.watch_and_restart = other.watch_and_restart;
+        }
+        if other.idle_shutdown.is_some() {
+            self.idle_shutdown = other.idle_shutdown;
+        }
+        if !other.log_presets.is_empty() {
+            self.log_presets = other.log_presets;
+        }
+        if !other.log_rules.is_empty() {
+            self.log_rules = other.log_rules;
+        }
+        if other.ansi_mode.is_some() {
+            self.ansi_mode = other.ansi_mode;
+        }
+        if other.version_manager.is_some() {
+            self.version_manager = other.version_manager;
+        }
+        self.env.extend(other.env);
+        self
+    }
+}
+
+/// Canned response for webhook requests matching a path glob and method
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookResponseRule {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Glob like "/stripe/*" or "/hooks/**"
+    pub path: String,
+    /// HTTP method, or None/"*" for any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(default = "default_rule_status")]
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// Supports {{method}}, {{path}}, {{query}}, {{body}}, {{id}}, {{timestamp}}, {{header.name}}
+    #[serde(default)]
+    pub body: String,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+    #[serde(default)]
+    pub delay_ms: u64,
+}
+
+fn default_rule_status() -> u16 {
+    200
+}
+
+/// One canned reply of a mock API route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockResponse {
+    #[serde(default = "default_rule_status")]
+    pub status: u16,
+    /// JSON body; strings may use {{params.name}}, {{query.name}}, {{method}}, {{path}}, {{timestamp}}
+    #[serde(default)]
+    pub body: serde_json::Value,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+    /// Simulated latency before responding
+    #[serde(default)]
+    pub delay_ms: u64,
+}
+
+/// Endpoint served by the mock API server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockRoute {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// HTTP method, or None/"*" for any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// Path with `:param` segments, `*` for any one segment, and a trailing `**` for the rest
+    pub path: String,
+    /// Replies returned in order on successive hits
+    pub responses: Vec<MockResponse>,
+    /// After the last reply: "last" keeps repeating it, "loop" starts over
+    #[serde(default = "default_mock_repeat")]
+    pub repeat: String,
+}
+
+fn default_mock_repeat() -> String {
+    "last".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockApiPreferences {
+    #[serde(default = "default_mock_port")]
+    pub port: u16,
+    #[serde(default)]
+    pub routes: Vec<MockRoute>,
+}
+
+impl Default for MockApiPreferences {
+    fn default() -> Self {
+        Self {
+            port: default_mock_port(),
+            routes: Vec::new(),
+        }
+    }
+}
+
+fn default_mock_port() -> u16 {
+    4010
+}
+
+/// A command run on a schedule and/or at app start
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledTask {
+    pub id: String,
+    pub name: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    pub command: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    /// Working directory (default: home directory)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Cron "min hour day month weekday" or @hourly/@daily/@weekly/@monthly, in local time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    #[serde(default)]
+    pub run_at_startup: bool,
+}
+
+/// Secrets used to verify provider signatures on incoming webhooks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookSigningSecrets {
+    /// Checked against X-Hub-Signature-256
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github: Option<String>,
+    /// Endpoint secret (whsec_...) checked against Stripe-Signature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stripe: Option<String>,
+    /// Signing secret checked against X-Slack-Signature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack: Option<String>,
+    /// Max age of Stripe/Slack signature timestamps
+    #[serde(default = "default_signature_tolerance_secs")]
+    pub tolerance_secs: u64,
+}
+
+impl Default for WebhookSigningSecrets {
+    fn default() -> Self {
+        Self {
+            github: None,
+            stripe: None,
+            slack: None,
+            tolerance_secs: default_signature_tolerance_secs(),
+        }
+    }
+}
+
+fn default_signature_tolerance_secs() -> u64 {
+    300
+}
+
+/// Opt-in on-disk history of received webhooks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookStorePreferences {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Max events kept on disk; oldest are dropped first
+    #[serde(default = "default_webhook_retention")]
+    pub max_events: usize,
+}
+
+impl Default for WebhookStorePreferences {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_events: default_webhook_retention(),
+        }
+    }
+}
+
+fn default_webhook_retention() -> usize {
+    5000
+}
+
+/// Opt-in copy of every process's output under app data (logs/<project>/<timestamp>.log)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogFilePreferences {
+    #[serde(default)]
+    pub enabled: bool,
+    /// A new file is started once the current one reaches this size
+    #[serde(default = "default_log_file_size_mb")]
+    pub max_file_size_mb: u64,
+    /// Files kept per project; oldest are deleted first
+    #[serde(default = "default_log_file_count")]
+    pub max_files: usize,
+}
+
+impl Default for LogFilePreferences {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_file_size_mb: default_log_file_size_mb(),
+            max_files: default_log_file_count(),
+        }
+    }
+}
+
+fn default_log_file_size_mb() -> u64 {
+    10
+}
+
+fn default_log_file_count() -> usize {
+    10
+}
+
+/// Prometheus-style /metrics endpoint for DevLaunch itself
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsServerPreferences {
+    /// Start the endpoint when DevLaunch launches
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_metrics_port")]
+    pub port: u16,
+}
+
+impl Default for MetricsServerPreferences {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default_metrics_port(),
+        }
+    }
+}
+
+fn default_metrics_port() -> u16 {
+    9464
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePreferences {
+    /// "stable" or "beta"
+    #[serde(default = "default_update_channel")]
+    pub channel: String,
+    #[serde(default = "default_true")]
+    pub check_on_launch: bool,
+}
+
+impl Default for UpdatePreferences {
+    fn default() -> Self {
+        Self {
+            channel: default_update_channel(),
+            check_on_launch: true,
+        }
+    }
+}
+
+fn default_update_channel() -> String {
+    "stable".to_string()
+}
+
+/// What the tray (menu bar) item shows next to the icon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrayPreferences {
+    #[serde(default = "default_true")]
+    pub show_running_count: bool,
+    /// Combined CPU of the running servers, as in `top` (100% per core)
+    #[serde(default)]
+    pub show_cpu: bool,
+}
+
+impl Default for TrayPreferences {
+    fn default() -> Self {
+        Self {
+            show_running_count: true,
+            show_cpu: false,
+        }
+    }
+}
+
+/// System-wide keyboard shortcuts, registered while DevLaunch runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutPreferences {
+    /// Off by default so DevLaunch doesn't take over keys other apps use
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_shortcut_bindings")]
+    pub bindings: Vec<ShortcutBinding>,
+}
+
+impl Default for ShortcutPreferences {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bindings: default_shortcut_bindings(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutBinding {
+    /// e.g. "CommandOrControl+Shift+D"
+    pub accelerator: String,
+    /// "toggle-window", "kill-all" or "launch-preset:<n>" (1-based, in preset order)
+    pub action: String,
+}
+
+fn default_shortcut_bindings() -> Vec<ShortcutBinding> {
+    let binding = |accelerator: String, action: String| ShortcutBinding { accelerator, action };
+    let mut bindings = vec![
+        binding("CommandOrControl+Shift+D".to_string(), "toggle-window".to_string()),
+        binding("CommandOrControl+Shift+K".to_string(), "kill-all".to_string()),
+    ];
+    bindings.extend((1..=9).map(|n| binding(format!("CommandOrControl+Shift+{}", n), format!("launch-preset:{}", n))));
+    bindings
+}
+
+/// Loopback control API for the CLI and scripts (deep links work regardless)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ControlServerPreferences {
+    /// Start the control server when DevLaunch launches
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_control_port")]
+    pub port: u16,
+}
+
+impl Default for ControlServerPreferences {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default_control_port(),
+        }
+    }
+}
+
+pub const DEFAULT_CONTROL_PORT: u16 = 47820;
+
+fn default_control_port() -> u16 {
+    DEFAULT_CONTROL_PORT
+}
+
+/// Background sampling of CPU/memory/disk/load for history graphs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemHistoryPreferences {
+    #[serde(default = "default_history_interval")]
+    pub interval_secs: u64,
+    /// How far back samples are kept in memory
+    #[serde(default = "default_history_retention")]
+    pub retention_minutes: u64,
+}
+
+impl Default for SystemHistoryPreferences {
+    fn default() -> Self {
+        Self {
+            interval_secs: default_history_interval(),
+            retention_minutes: default_history_retention(),
+        }
+    }
+}
+
+fn default_history_interval() -> u64 {
+    5
+}
+
+fn default_history_retention() -> u64 {
+    24 * 60
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Preferences {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ngrok_auth_token: Option<String>,
@@ -102,6 +876,85 @@ pub struct Preferences {
     pub default_webhook_port: u16,
     #[serde(default)]
     pub git: GitPreferences,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub projects: HashMap<String, ProjectSettings>,
+    #[serde(default)]
+    pub shell_env: ShellEnvPreferences,
+    /// Lines of log history retained per project
+    #[serde(default = "default_log_buffer_size")]
+    pub log_buffer_size: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub webhook_response_rules: Vec<WebhookResponseRule>,
+    #[serde(default)]
+    pub webhook_signing_secrets: WebhookSigningSecrets,
+    #[serde(default)]
+    pub webhook_store: WebhookStorePreferences,
+    /// Backend for public tunnels: "ngrok", "cloudflared" or "localtunnel"
+    #[serde(default = "default_tunnel_provider")]
+    pub tunnel_provider: String,
+    #[serde(default)]
+    pub log_files: LogFilePreferences,
+    #[serde(default)]
+    pub metrics_server: MetricsServerPreferences,
+    #[serde(default)]
+    pub system_history: SystemHistoryPreferences,
+    #[serde(default)]
+    pub control_server: ControlServerPreferences,
+    #[serde(default)]
+    pub mock_api: MockApiPreferences,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scheduled_tasks: Vec<ScheduledTask>,
+    /// Relaunch the projects from the last session when DevLaunch starts
+    #[serde(default)]
+    pub restore_session_on_launch: bool,
+    #[serde(default)]
+    pub shortcuts: ShortcutPreferences,
+    #[serde(default)]
+    pub tray: TrayPreferences,
+    #[serde(default)]
+    pub updates: UpdatePreferences,
+    /// Project path -> SHA-256 of the .devlaunch.json the user trusted. Env and hooks from a
+    /// project's file only apply while its contents still match.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub trusted_project_files: HashMap<String, String>,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            ngrok_auth_token: None,
+            default_webhook_port: default_webhook_port(),
+            git: GitPreferences::default(),
+            projects: HashMap::new(),
+            shell_env: ShellEnvPreferences::default(),
+            log_buffer_size: default_log_buffer_size(),
+            webhook_response_rules: Vec::new(),
+            webhook_signing_secrets: WebhookSigningSecrets::default(),
+            webhook_store: WebhookStorePreferences::default(),
+            tunnel_provider: default_tunnel_provider(),
+            log_files: LogFilePreferences::default(),
+            metrics_server: MetricsServerPreferences::default(),
+            system_history: SystemHistoryPreferences::default(),
+            control_server: ControlServerPreferences::default(),
+            mock_api: MockApiPreferences::default(),
+            scheduled_tasks: Vec::new(),
+            restore_session_on_launch: false,
+            shortcuts: ShortcutPreferences::default(),
+            tray: TrayPreferences::default(),
+            updates: UpdatePreferences::default(),
+            trusted_project_files: HashMap::new(),
+        }
+    }
+}
+
+pub const DEFAULT_LOG_BUFFER_SIZE: usize = 5000;
+
+fn default_log_buffer_size() -> usize {
+    DEFAULT_LOG_BUFFER_SIZE
+}
+
+fn default_tunnel_provider() -> String {
+    "ngrok".to_string()
 }
 
 fn default_webhook_port() -> u16 {
@@ -111,6 +964,9 @@ fn default_webhook_port() -> u16 {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct AppConfig {
+    /// Schema version the file was written with; older files are migrated on load
+    #[serde(default)]
+    pub config_version: u32,
     pub watched_dirs: Vec<String>,
     pub presets: Vec<Preset>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -121,7 +977,51 @@ pub struct AppConfig {
     pub preferences: Preferences,
 }
 
-fn get_config_path(app: &AppHandle) -> Result<PathBuf, String> {
+impl AppConfig {
+    /// Settings for a project path: app config overridden by the project's .devlaunch.json
+    /// (without its env, hooks and version manager unless the user trusted the file)
+    pub fn project_settings(&self, path: &str) -> ProjectSettings {
+        let settings = self.preferences.projects.get(path).cloned().unwrap_or_default();
+        match crate::project_config::read_trusted_project_file(path, &self.preferences.trusted_project_files) {
+            Ok(Some(file)) => settings.overlay(file),
+            _ => settings,
+        }
+    }
+
+    /// Move plaintext git/ngrok tokens into the OS keychain, keeping only references.
+    /// Returns true if anything moved.
+    pub fn move_secrets_to_keychain(&mut self) -> bool {
+        let mut moved = false;
+        for token in &mut self.preferences.git.tokens {
+            if let Some(reference) = secrets::externalize(&format!("git-token-{}", token.id), &token.token) {
+                token.token = reference;
+                moved = true;
+            }
+        }
+        if let Some(token) = &self.preferences.ngrok_auth_token {
+            if let Some(reference) = secrets::externalize("ngrok-auth-token", token) {
+                self.preferences.ngrok_auth_token = Some(reference);
+                moved = true;
+            }
+        }
+        moved
+    }
+
+    /// Keychain keys this config refers to
+    fn secret_keys(&self) -> Vec<String> {
+        self.preferences
+            .git
+            .tokens
+            .iter()
+            .map(|t| t.token.as_str())
+            .chain(self.preferences.ngrok_auth_token.as_deref())
+            .filter_map(secrets::reference_key)
+            .map(|k| k.to_string())
+            .collect()
+    }
+}
+
+pub(crate) fn get_config_path(app: &AppHandle) -> Result<PathBuf, String> {
     let app_data_dir = app
         .path()
         .app_data_dir()
@@ -136,7 +1036,269 @@ fn get_config_path(app: &AppHandle) -> Result<PathBuf, String> {
 
 #[tauri::command]
 pub fn load_config(app: AppHandle) -> Result<AppConfig, String> {
-    let config_path = get_config_path(&app)?;
+    load_config_internal(&app)
+}
+
+/// Current config schema version, written on every save
+pub const CONFIG_VERSION: u32 = 1;
+/// Timestamped backups kept in config-backups/
+const MAX_CONFIG_BACKUPS: usize = 10;
+/// Minimum age of the newest backup before a save from the UI takes another one
+const SAVE_BACKUP_INTERVAL_SECS: u64 = 15 * 60;
+
+type Migration = fn(&mut serde_json::Value) -> Result<(), String>;
+
+/// Migration from version `i` to `i + 1` is MIGRATIONS[i]
+const MIGRATIONS: &[Migration] = &[migrate_v0_single_git_token];
+
+/// v0 -> v1: the single `git.gitToken` became the `git.tokens` list
+fn migrate_v0_single_git_token(config: &mut serde_json::Value) -> Result<(), String> {
+    let Some(git) = config.pointer_mut("/preferences/git").and_then(|g| g.as_object_mut()) else {
+        return Ok(());
+    };
+    let Some(token) = git.remove("gitToken") else {
+        return Ok(());
+    };
+    let token = token.as_str().unwrap_or("").to_string();
+    let tokens = git.entry("tokens").or_insert_with(|| serde_json::json!([]));
+    let Some(tokens) = tokens.as_array_mut() else {
+        return Err("git.tokens is not a list".to_string());
+    };
+    if !token.is_empty() && tokens.is_empty() {
+        tokens.push(serde_json::json!({
+            "id": uuid::Uuid::new_v4().to_string(),
+            "pattern": "*",
+            "token": token,
+            "label": "Migrated token",
+        }));
+    }
+    Ok(())
+}
+
+fn backups_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = get_config_path(app)?
+        .parent()
+        .ok_or("Invalid config path")?
+        .join("config-backups");
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create backup dir: {}", e))?;
+    BACKUPS_SCRUBBED.call_once(|| scrub_backups(&dir));
+    Ok(dir)
+}
+
+fn scrub_backups(dir: &Path) {
+    for path in fs::read_dir(dir).into_iter().flatten().flatten().map(|e| e.path()) {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let scrubbed = without_plaintext_secrets(&content);
+        if scrubbed != content {
+            match fs::write(&path, scrubbed) {
+                Ok(()) => println!("[config] Removed plaintext tokens from backup {}", path.display()),
+                Err(e) => println!("[config] Failed to scrub backup {}: {}", path.display(), e),
+            }
+        }
+    }
+}
+
+/// Blank a plaintext secret in raw config JSON. Keychain references are kept: they name the
+/// live entries, which backups must never write to.
+fn redact(value: &mut serde_json::Value) -> bool {
+    let Some(text) = value.as_str() else {
+        return false;
+    };
+    if text.is_empty() || secrets::reference_key(text).is_some() {
+        return false;
+    }
+    *value = "".into();
+    true
+}
+
+/// Config file contents without plaintext tokens (including the legacy `git.gitToken`), so
+/// backups never keep secrets the config itself moved to the keychain. Returns `content`
+/// unchanged when it has none.
+fn without_plaintext_secrets(content: &str) -> String {
+    // Unparseable files are backed up as they are; there's nothing to find tokens in
+    let Ok(mut raw) = serde_json::from_str::<serde_json::Value>(content) else {
+        return content.to_string();
+    };
+    let mut changed = false;
+    if let Some(git) = raw.pointer_mut("/preferences/git") {
+        if let Some(token) = git.get_mut("gitToken") {
+            changed |= redact(token);
+        }
+        if let Some(tokens) = git.get_mut("tokens").and_then(|t| t.as_array_mut()) {
+            for value in tokens.iter_mut().filter_map(|t| t.get_mut("token")) {
+                changed |= redact(value);
+            }
+        }
+    }
+    if let Some(token) = raw.pointer_mut("/preferences/ngrokAuthToken") {
+        changed |= redact(token);
+    }
+    if !changed {
+        return content.to_string();
+    }
+    serde_json::to_string_pretty(&raw).unwrap_or_else(|_| content.to_string())
+}
+
+/// Backup contents to restore with the tokens the current config holds: same-id git tokens
+/// and the ngrok token keep their current values, so restoring never replaces or blanks a live
+/// secret. Tokens only the backup has are kept as they are.
+fn with_live_secrets(backup: &str, current: Option<&str>) -> String {
+    let Ok(mut raw) = serde_json::from_str::<serde_json::Value>(backup) else {
+        return backup.to_string();
+    };
+    let current: serde_json::Value = current
+        .and_then(|c| serde_json::from_str(c).ok())
+        .unwrap_or_default();
+    let live_tokens: HashMap<&str, &serde_json::Value> = current
+        .pointer("/preferences/git/tokens")
+        .and_then(|t| t.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|t| Some((t.get("id")?.as_str()?, t.get("token")?)))
+        .collect();
+
+    let mut changed = false;
+    if let Some(tokens) = raw.pointer_mut("/preferences/git/tokens").and_then(|t| t.as_array_mut()) {
+        for token in tokens {
+            let Some(live) = token.get("id").and_then(|i| i.as_str()).and_then(|id| live_tokens.get(id)) else {
+                continue;
+            };
+            let live = (*live).clone();
+            if let Some(value) = token.get_mut("token") {
+                changed |= *value != live;
+                *value = live;
+            }
+        }
+    }
+    if let Some(live) = current.pointer("/preferences/ngrokAuthToken").filter(|t| !t.is_null()) {
+        if let Some(preferences) = raw.get_mut("preferences").and_then(|p| p.as_object_mut()) {
+            changed |= preferences.get("ngrokAuthToken") != Some(live);
+            preferences.insert("ngrokAuthToken".to_string(), live.clone());
+        }
+    }
+    if !changed {
+        return backup.to_string();
+    }
+    serde_json::to_string_pretty(&raw).unwrap_or_else(|_| backup.to_string())
+}
+
+/// Copy the config aside before changing it (plaintext tokens blanked), pruning old backups
+fn backup_config(app: &AppHandle, content: &str, reason: &str) -> Result<PathBuf, String> {
+    let content = without_plaintext_secrets(content);
+    let dir = backups_dir(app)?;
+    let path = dir.join(format!(
+        "config-{}-{}.json",
+        chrono::Local::now().format("%Y%m%d-%H%M%S%.3f"),
+        reason
+    ));
+    fs::write(&path, &content).map_err(|e| format!("Failed to write config backup: {}", e))?;
+
+    let mut backups: Vec<PathBuf> = fs::read_dir(&dir)
+        .map(|entries| entries.flatten().map(|e| e.path()).collect())
+        .unwrap_or_default();
+    // Names start with the timestamp, so they sort oldest first
+    backups.sort();
+    let excess = backups.len().saturating_sub(MAX_CONFIG_BACKUPS);
+    for old in &backups[..excess] {
+        let _ = fs::remove_file(old);
+    }
+    Ok(path)
+}
+
+/// Back up the file a UI save is about to replace, unless a backup was taken recently.
+/// Backend saves (session, window geometry) happen too often to each get one.
+fn backup_before_save(app: &AppHandle) -> Result<(), String> {
+    let Ok(current) = fs::read_to_string(get_config_path(app)?) else {
+        return Ok(());
+    };
+    let dir = backups_dir(app)?;
+    let newest_age = fs::read_dir(&dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| e.metadata().ok()?.modified().ok()?.elapsed().ok())
+                .min()
+        })
+        .ok()
+        .flatten();
+    if newest_age
+        .map(|age| age.as_secs() < SAVE_BACKUP_INTERVAL_SECS)
+        .unwrap_or(false)
+    {
+        return Ok(());
+    }
+    backup_config(app, &current, "save")?;
+    Ok(())
+}
+
+/// Replace a file so a crash leaves either the old or the new contents: write a temp file
+/// next to it, fsync, then rename over the original. Callers serialize their own writes.
+pub(crate) fn write_file_atomic(path: &Path, content: &str) -> Result<(), String> {
+    let fail = |e: std::io::Error| format!("Failed to write {}: {}", path.display(), e);
+    let tmp = path.with_extension("json.tmp");
+    let mut file = fs::File::create(&tmp).map_err(fail)?;
+    file.write_all(content.as_bytes()).and_then(|_| file.sync_all()).map_err(fail)?;
+    drop(file);
+    fs::rename(&tmp, path).map_err(fail)?;
+
+    // Persist the rename itself
+    #[cfg(unix)]
+    if let Some(dir) = path.parent().and_then(|d| fs::File::open(d).ok()) {
+        let _ = dir.sync_all();
+    }
+    Ok(())
+}
+
+/// Atomically write config.json, recording the contents so the watcher skips our own save
+fn write_atomic(path: &Path, content: &str) -> Result<(), String> {
+    let _guard = WRITE_LOCK.lock().map_err(|e| e.to_string())?;
+    write_file_atomic(path, content)?;
+    crate::config_watch::note_written(content);
+    Ok(())
+}
+
+/// Bring a parsed config up to CONFIG_VERSION. Returns whether anything changed.
+fn migrate(config: &mut serde_json::Value) -> Result<bool, String> {
+    let version = config.get("configVersion").and_then(|v| v.as_u64()).unwrap_or(0) as usize;
+    if version >= MIGRATIONS.len() {
+        return Ok(false);
+    }
+    for (from, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+        migration(config).map_err(|e| format!("Config migration from v{} failed: {}", from, e))?;
+    }
+    if let Some(object) = config.as_object_mut() {
+        object.insert("configVersion".to_string(), CONFIG_VERSION.into());
+    }
+    Ok(true)
+}
+
+/// Whether two raw configs differ only in configVersion
+fn same_apart_from_version(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+    let without_version = |value: &serde_json::Value| {
+        let mut value = value.clone();
+        if let Some(object) = value.as_object_mut() {
+            object.remove("configVersion");
+        }
+        value
+    };
+    without_version(a) == without_version(b)
+}
+
+/// Parse config file contents, migrating in memory only. For reading a file DevLaunch didn't
+/// write (an outside edit) without writing it back.
+pub(crate) fn parse_config(content: &str) -> Result<AppConfig, String> {
+    let mut raw: serde_json::Value = serde_json::from_str(content)
+        .map_err(|e| format!("Failed to parse config: {} (restore a backup from Preferences)", e))?;
+    migrate(&mut raw)?;
+    serde_json::from_value(raw)
+        .map_err(|e| format!("Failed to parse config: {} (restore a backup from Preferences)", e))
+}
+
+/// Load config from disk (internal version for backend services)
+pub fn load_config_internal(app: &AppHandle) -> Result<AppConfig, String> {
+    let config_path = get_config_path(app)?;
 
     if !config_path.exists() {
         return Ok(AppConfig::default());
@@ -145,30 +1307,296 @@ pub fn load_config(app: AppHandle) -> Result<AppConfig, String> {
     let content = fs::read_to_string(&config_path)
         .map_err(|e| format!("Failed to read config: {}", e))?;
 
-    let mut config: AppConfig = serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse config: {}", e))?;
+    let mut raw: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse config: {} (restore a backup from Preferences)", e))?;
+
+    let from = raw.get("configVersion").and_then(|v| v.as_u64()).unwrap_or(0);
+    let original = raw.clone();
+    // A version bump whose migrations had nothing to rewrite isn't worth a backup and a write
+    let migrated = migrate(&mut raw)? && !same_apart_from_version(&original, &raw);
+    if migrated {
+        let backup = backup_config(app, &content, &format!("v{}", from))?;
+        println!(
+            "[config] Migrated config v{} -> v{} (backup at {})",
+            from,
+            CONFIG_VERSION,
+            backup.display()
+        );
+    }
+
+    let mut config: AppConfig = serde_json::from_value(raw)
+        .map_err(|e| format!("Failed to parse config: {} (restore a backup from Preferences)", e))?;
+    if config.config_version > CONFIG_VERSION {
+        println!(
+            "[config] Config was written by a newer version (v{}); unknown settings are ignored and the file won't be overwritten",
+            config.config_version
+        );
+        return Ok(config);
+    }
 
-    // Migrate legacy single git_token to tokens array
-    config.preferences.git.migrate_legacy_token();
+    // Older configs kept tokens in plaintext - move them to the keychain once (false when
+    // there was nothing to move or the keychain is unavailable)
+    let moved = config.move_secrets_to_keychain();
+    if migrated || moved {
+        save_config_internal(app, &config)?;
+    }
 
     Ok(config)
 }
 
-#[tauri::command]
-pub fn save_config(app: AppHandle, config: AppConfig) -> Result<(), String> {
-    let config_path = get_config_path(&app)?;
+/// Load, change and save the config as one step, so backend updates (window geometry, session,
+/// trust) and frontend saves landing at the same time don't overwrite each other
+pub(crate) fn update_config(
+    app: &AppHandle,
+    change: impl FnOnce(&mut AppConfig) -> Result<(), String>,
+) -> Result<AppConfig, String> {
+    let _guard = UPDATE_LOCK.lock().map_err(|e| e.to_string())?;
+    let mut config = load_config_internal(app)?;
+    change(&mut config)?;
+    save_config_internal(app, &config)?;
+    Ok(config)
+}
+
+/// Write config to disk (internal version for backend services)
+pub fn save_config_internal(app: &AppHandle, config: &AppConfig) -> Result<(), String> {
+    let config_path = get_config_path(app)?;
+
+    // Saving would drop the settings this version doesn't know about
+    let on_disk = fs::read_to_string(&config_path)
+        .ok()
+        .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok())
+        .and_then(|raw| raw.get("configVersion").and_then(|v| v.as_u64()))
+        .unwrap_or(0);
+    if on_disk > CONFIG_VERSION as u64 {
+        return Err(format!(
+            "config.json was written by a newer version of DevLaunch (v{}); update DevLaunch to change settings",
+            on_disk
+        ));
+    }
+
+    // Whatever version the caller loaded, the file now has the current shape
+    let mut config = config.clone();
+    config.config_version = CONFIG_VERSION;
 
     let content = serde_json::to_string_pretty(&config)
         .map_err(|e| format!("Failed to serialize config: {}", e))?;
 
-    fs::write(&config_path, content)
-        .map_err(|e| format!("Failed to write config: {}", e))?;
+    write_atomic(&config_path, &content)
+}
 
+#[tauri::command]
+pub fn save_config(app: AppHandle, mut config: AppConfig) -> Result<(), String> {
+    let _guard = UPDATE_LOCK.lock().map_err(|e| e.to_string())?;
+    let previous = load_config_internal(&app).ok();
+
+    // Window geometry is owned by the backend; the frontend doesn't send it
+    if config.window_state.is_none() {
+        config.window_state = previous.as_ref().and_then(|c| c.window_state.clone());
+    }
+    // Same for the running projects recorded for session restore
+    if config.last_session.is_none() {
+        config.last_session = previous.as_ref().and_then(|c| c.last_session.clone());
+    }
+    // Trust only changes through trust_project_config
+    config.preferences.trusted_project_files = previous
+        .as_ref()
+        .map(|c| c.preferences.trusted_project_files.clone())
+        .unwrap_or_default();
+
+    // Newly added tokens arrive in plaintext
+    config.move_secrets_to_keychain();
+
+    if let Err(e) = backup_before_save(&app) {
+        println!("[config] Failed to back up config: {}", e);
+    }
+    save_config_internal(&app, &config)?;
+
+    // Drop keychain entries for tokens that were removed
+    if let Some(previous) = previous {
+        let current = config.secret_keys();
+        for key in previous.secret_keys().into_iter().filter(|k| !current.contains(k)) {
+            let _ = secrets::delete_secret_internal(&key);
+        }
+    }
+
+    apply_to_services(&app, &config);
     Ok(())
 }
 
+/// Push settings that running services hold in memory to them after the config changed
+pub(crate) fn apply_to_services(app: &AppHandle, config: &AppConfig) {
+    crate::shortcuts::apply(app, &config.preferences.shortcuts);
+
+    // Let a running webhook server pick up edited response rules and signing secrets
+    let rules = config.preferences.webhook_response_rules.clone();
+    let secrets = config.preferences.webhook_signing_secrets.clone();
+    tauri::async_runtime::spawn(async move {
+        crate::webhook_server::set_response_rules(rules).await;
+        crate::webhook_server::set_signing_secrets(secrets).await;
+    });
+}
+
 #[tauri::command]
 pub fn get_config_path_string(app: AppHandle) -> Result<String, String> {
     let path = get_config_path(&app)?;
     Ok(path.to_string_lossy().to_string())
 }
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigBackup {
+    pub name: String,
+    pub path: String,
+    pub size_bytes: u64,
+    /// Unix millis
+    pub created_at: i64,
+}
+
+/// Config backups written before migrations, restores and saves from the UI, newest first
+#[tauri::command]
+pub fn list_config_backups(app: AppHandle) -> Result<Vec<ConfigBackup>, String> {
+    let dir = backups_dir(&app)?;
+    let mut backups: Vec<ConfigBackup> = fs::read_dir(&dir)
+        .map_err(|e| format!("Failed to read backups: {}", e))?
+        .flatten()
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            let created_at = meta
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_millis() as i64)
+                .unwrap_or(0);
+            Some(ConfigBackup {
+                name: entry.file_name().to_string_lossy().to_string(),
+                path: entry.path().to_string_lossy().to_string(),
+                size_bytes: meta.len(),
+                created_at,
+            })
+        })
+        .collect();
+    backups.sort_by(|a, b| b.name.cmp(&a.name));
+    Ok(backups)
+}
+
+/// Replace the config with a backup (the current file is backed up first), migrating it
+/// if it's from an older version
+#[tauri::command]
+pub fn restore_config_backup(app: AppHandle, name: String) -> Result<AppConfig, String> {
+    if name.contains('/') || name.contains('\\') || name.contains("..") {
+        return Err(format!("Invalid backup name: {}", name));
+    }
+    let backup = backups_dir(&app)?.join(&name);
+    let content = fs::read_to_string(&backup).map_err(|e| format!("Failed to read backup {}: {}", name, e))?;
+    serde_json::from_str::<serde_json::Value>(&content).map_err(|e| format!("Backup {} is not valid JSON: {}", name, e))?;
+
+    // Held from reading the current file to loading the restored one, so a concurrent
+    // update_config can't save in between and undo the restore
+    let _guard = UPDATE_LOCK.lock().map_err(|e| e.to_string())?;
+    let config_path = get_config_path(&app)?;
+    let current = fs::read_to_string(&config_path).ok();
+    if let Some(current) = &current {
+        backup_config(&app, current, "before-restore")?;
+    }
+    write_atomic(&config_path, &with_live_secrets(&content, current.as_deref()))?;
+    println!("[config] Restored config from {}", name);
+    load_config_internal(&app)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use keyring::credential::{Credential, CredentialApi, CredentialBuilderApi};
+    use std::any::Any;
+    use std::sync::Arc;
+
+    /// Keychain shared by all entries, so a test can see what was written to a live key
+    type Store = Arc<Mutex<HashMap<String, String>>>;
+
+    struct MemoryCredential {
+        store: Store,
+        key: String,
+    }
+
+    impl CredentialApi for MemoryCredential {
+        fn set_password(&self, password: &str) -> keyring::Result<()> {
+            self.store.lock().unwrap().insert(self.key.clone(), password.to_string());
+            Ok(())
+        }
+
+        fn get_password(&self) -> keyring::Result<String> {
+            self.store.lock().unwrap().get(&self.key).cloned().ok_or(keyring::Error::NoEntry)
+        }
+
+        fn delete_password(&self) -> keyring::Result<()> {
+            self.store.lock().unwrap().remove(&self.key).map(|_| ()).ok_or(keyring::Error::NoEntry)
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    struct MemoryBuilder(Store);
+
+    impl CredentialBuilderApi for MemoryBuilder {
+        fn build(&self, _target: Option<&str>, _service: &str, user: &str) -> keyring::Result<Box<Credential>> {
+            Ok(Box::new(MemoryCredential { store: self.0.clone(), key: user.to_string() }))
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    fn config_with(git_token: &str, ngrok: &str) -> String {
+        serde_json::json!({
+            "configVersion": 1,
+            "preferences": {
+                "git": { "tokens": [{ "id": "a", "pattern": "*", "token": git_token }] },
+                "ngrokAuthToken": ngrok,
+            },
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn scrub_and_restore_leave_live_keychain_entries_alone() {
+        let store = Store::default();
+        keyring::set_default_credential_builder(Box::new(MemoryBuilder(store.clone())));
+        secrets::store_secret_internal("git-token-a", "live-git").unwrap();
+        secrets::store_secret_internal("ngrok-auth-token", "live-ngrok").unwrap();
+
+        // A backup from an older build with the tokens in plaintext
+        let dir = std::env::temp_dir().join(format!("devlaunch-backups-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let backup = dir.join("config-old.json");
+        fs::write(&backup, config_with("old-git", "old-ngrok")).unwrap();
+
+        scrub_backups(&dir);
+        let scrubbed = fs::read_to_string(&backup).unwrap();
+        assert!(!scrubbed.contains("old-git") && !scrubbed.contains("old-ngrok"));
+
+        let current = config_with("keychain:git-token-a", "keychain:ngrok-auth-token");
+        for content in [config_with("old-git", "old-ngrok"), scrubbed] {
+            let restored: serde_json::Value = serde_json::from_str(&with_live_secrets(&content, Some(&current))).unwrap();
+            assert_eq!(restored.pointer("/preferences/git/tokens/0/token").unwrap(), "keychain:git-token-a");
+            assert_eq!(restored.pointer("/preferences/ngrokAuthToken").unwrap(), "keychain:ngrok-auth-token");
+        }
+
+        let store = store.lock().unwrap();
+        assert_eq!(store.get("git-token-a").map(String::as_str), Some("live-git"));
+        assert_eq!(store.get("ngrok-auth-token").map(String::as_str), Some("live-ngrok"));
+        assert_eq!(store.len(), 2);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn restore_keeps_plaintext_tokens_when_there_is_no_keychain() {
+        let current = config_with("plain-git", "plain-ngrok");
+        let restored: serde_json::Value =
+            serde_json::from_str(&with_live_secrets(&config_with("", ""), Some(&current))).unwrap();
+        assert_eq!(restored.pointer("/preferences/git/tokens/0/token").unwrap(), "plain-git");
+        assert_eq!(restored.pointer("/preferences/ngrokAuthToken").unwrap(), "plain-ngrok");
+    }
+}
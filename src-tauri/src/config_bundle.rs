@@ -0,0 +1,201 @@
+use crate::config::{self, AppConfig, Preferences, Preset, CONFIG_VERSION};
+use crate::secrets;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+/// Identifies DevLaunch export files
+const BUNDLE_FORMAT: &str = "devlaunch-config";
+
+/// Portable subset of the config: no window geometry, session state or keychain references
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConfigBundle {
+    pub format: String,
+    pub config_version: u32,
+    pub exported_at: String,
+    pub includes_secrets: bool,
+    pub watched_dirs: Vec<String>,
+    pub presets: Vec<Preset>,
+    pub preferences: Option<Preferences>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSummary {
+    pub watched_dirs_added: usize,
+    pub presets_added: usize,
+    pub presets_updated: usize,
+    pub preferences_imported: bool,
+    /// Git tokens left without a value (exported without secrets, nothing local to keep)
+    pub tokens_missing_secrets: usize,
+    pub config: AppConfig,
+}
+
+/// Replace secrets with their plaintext (`include`) or blank them out
+fn export_secrets(preferences: &mut Preferences, include: bool) {
+    let convert = |value: &str| if include { secrets::resolve(value) } else { String::new() };
+    for token in &mut preferences.git.tokens {
+        token.token = convert(&token.token);
+    }
+    preferences.ngrok_auth_token = preferences
+        .ngrok_auth_token
+        .as_deref()
+        .map(convert)
+        .filter(|t| !t.is_empty());
+    let signing = &mut preferences.webhook_signing_secrets;
+    for secret in [&mut signing.github, &mut signing.stripe, &mut signing.slack] {
+        *secret = secret.as_deref().map(convert).filter(|s| !s.is_empty());
+    }
+}
+
+/// Write watched dirs, presets and preferences to `path`. Tokens and signing secrets are
+/// left out unless `include_secrets` is set, in which case they're written in plaintext.
+#[tauri::command]
+pub fn export_config(app: AppHandle, path: String, include_secrets: bool) -> Result<ConfigBundle, String> {
+    let config = config::load_config_internal(&app)?;
+    let mut preferences = config.preferences;
+    export_secrets(&mut preferences, include_secrets);
+
+    let bundle = ConfigBundle {
+        format: BUNDLE_FORMAT.to_string(),
+        config_version: CONFIG_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        includes_secrets: include_secrets,
+        watched_dirs: config.watched_dirs,
+        presets: config.presets,
+        preferences: Some(preferences),
+    };
+    let content = serde_json::to_string_pretty(&bundle).map_err(|e| format!("Failed to serialize config: {}", e))?;
+    if include_secrets {
+        secrets::write_private_file(std::path::Path::new(&path), &content)?;
+    } else {
+        std::fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    }
+    println!("[config_bundle] Exported config to {} (secrets: {})", path, include_secrets);
+    Ok(bundle)
+}
+
+/// Keep local values for secrets the bundle doesn't carry
+fn keep_local_secrets(imported: &mut Preferences, local: &Preferences) {
+    for token in &mut imported.git.tokens {
+        if token.token.is_empty() {
+            // By id only: different tokens may share a pattern
+            if let Some(existing) = local.git.tokens.iter().find(|t| t.id == token.id) {
+                token.token = existing.token.clone();
+            }
+        }
+    }
+    if imported.ngrok_auth_token.is_none() {
+        imported.ngrok_auth_token = local.ngrok_auth_token.clone();
+    }
+    let (signing, local_signing) = (&mut imported.webhook_signing_secrets, &local.webhook_signing_secrets);
+    signing.github = signing.github.take().or_else(|| local_signing.github.clone());
+    signing.stripe = signing.stripe.take().or_else(|| local_signing.stripe.clone());
+    signing.slack = signing.slack.take().or_else(|| local_signing.slack.clone());
+}
+
+/// Add entries from `imported` whose key isn't in `local` yet
+fn merge_by<T: Clone, K: PartialEq>(local: &mut Vec<T>, imported: &[T], key: impl Fn(&T) -> K) {
+    for item in imported {
+        if !local.iter().any(|l| key(l) == key(item)) {
+            local.push(item.clone());
+        }
+    }
+}
+
+/// Import a bundle from export_config (or a raw config.json). `merge_strategy`:
+/// - "merge" (default): add missing watched dirs, upsert presets by id, and add imported
+///   tokens, rules, routes, tasks and project settings that don't exist locally
+/// - "replace": use the bundle's watched dirs, presets and preferences wholesale
+///
+/// Secrets missing from the bundle keep their local values either way.
+#[tauri::command]
+pub fn import_config(app: AppHandle, path: String, merge_strategy: Option<String>) -> Result<ImportSummary, String> {
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let bundle: ConfigBundle =
+        serde_json::from_str(&content).map_err(|e| format!("{} is not a DevLaunch config: {}", path, e))?;
+    if !bundle.format.is_empty() && bundle.format != BUNDLE_FORMAT {
+        return Err(format!("Unsupported config format: {}", bundle.format));
+    }
+    if bundle.config_version > CONFIG_VERSION {
+        return Err(format!(
+            "This file is from a newer DevLaunch (config v{}); update to import it",
+            bundle.config_version
+        ));
+    }
+
+    let mut config = config::load_config_internal(&app)?;
+    let strategy = merge_strategy.unwrap_or_else(|| "merge".to_string());
+    let mut summary = ImportSummary {
+        watched_dirs_added: 0,
+        presets_added: 0,
+        presets_updated: 0,
+        preferences_imported: bundle.preferences.is_some(),
+        tokens_missing_secrets: 0,
+        config: AppConfig::default(),
+    };
+
+    match strategy.as_str() {
+        "replace" => {
+            summary.watched_dirs_added = bundle
+                .watched_dirs
+                .iter()
+                .filter(|d| !config.watched_dirs.contains(d))
+                .count();
+            summary.presets_added = bundle.presets.len();
+            config.watched_dirs = bundle.watched_dirs;
+            config.presets = bundle.presets;
+            if let Some(mut preferences) = bundle.preferences {
+                keep_local_secrets(&mut preferences, &config.preferences);
+                config.preferences = preferences;
+            }
+        }
+        "merge" => {
+            for dir in bundle.watched_dirs {
+                if !config.watched_dirs.contains(&dir) {
+                    config.watched_dirs.push(dir);
+                    summary.watched_dirs_added += 1;
+                }
+            }
+            for preset in bundle.presets {
+                match config.presets.iter_mut().find(|p| p.id == preset.id) {
+                    Some(existing) => {
+                        *existing = preset;
+                        summary.presets_updated += 1;
+                    }
+                    None => {
+                        config.presets.push(preset);
+                        summary.presets_added += 1;
+                    }
+                }
+            }
+            if let Some(mut imported) = bundle.preferences {
+                keep_local_secrets(&mut imported, &config.preferences);
+                let local = &mut config.preferences;
+                merge_by(&mut local.git.tokens, &imported.git.tokens, |t| t.id.clone());
+                merge_by(&mut local.webhook_response_rules, &imported.webhook_response_rules, |r| r.id.clone());
+                merge_by(&mut local.mock_api.routes, &imported.mock_api.routes, |r| r.id.clone());
+                merge_by(&mut local.scheduled_tasks, &imported.scheduled_tasks, |t| t.id.clone());
+                for (project, settings) in imported.projects {
+                    local.projects.entry(project).or_insert(settings);
+                }
+            }
+        }
+        other => return Err(format!("Unknown merge strategy: {} (use \"merge\" or \"replace\")", other)),
+    }
+
+    summary.tokens_missing_secrets = config
+        .preferences
+        .git
+        .tokens
+        .iter()
+        .filter(|t| t.token.is_empty() && t.ssh_key.is_none())
+        .count();
+    config::save_config(app.clone(), config)?;
+    summary.config = config::load_config_internal(&app)?;
+    println!(
+        "[config_bundle] Imported {} ({}): {} dirs, {} new presets, {} updated",
+        path, strategy, summary.watched_dirs_added, summary.presets_added, summary.presets_updated
+    );
+    Ok(summary)
+}
@@ -0,0 +1,121 @@
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// Editors and sync tools write in several steps; collapse them into one reload
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(300);
+
+lazy_static::lazy_static! {
+    static ref WATCHER: Mutex<Option<RecommendedWatcher>> = Mutex::new(None);
+    // Hash of the contents DevLaunch itself last wrote, so its own saves aren't reloaded
+    static ref LAST_WRITTEN: Mutex<Option<String>> = Mutex::new(None);
+    static ref LAST_EVENT: Mutex<Option<Instant>> = Mutex::new(None);
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigInvalidEvent {
+    path: String,
+    error: String,
+}
+
+fn content_hash(content: &str) -> String {
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
+
+/// Record a config write by DevLaunch, called by the config writer
+pub(crate) fn note_written(content: &str) {
+    if let Ok(mut last) = LAST_WRITTEN.lock() {
+        *last = Some(content_hash(content));
+    }
+}
+
+/// Reload the file after an outside change: emit `config-changed` with the new config, or
+/// `config-invalid` if it doesn't parse (the running services keep the previous settings).
+/// Read-only: migrating and saving here would be another change to the watched file.
+fn reload(app: &AppHandle) {
+    let Ok(path) = crate::config::get_config_path(app) else {
+        return;
+    };
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return;
+    };
+    let hash = content_hash(&content);
+    if LAST_WRITTEN.lock().map(|l| l.as_deref() == Some(hash.as_str())).unwrap_or(false) {
+        return;
+    }
+
+    match crate::config::parse_config(&content) {
+        Ok(config) => {
+            println!("[config_watch] Reloaded {}", path.display());
+            crate::config::apply_to_services(app, &config);
+            crate::tray::refresh(app);
+            let _ = app.emit("config-changed", config);
+        }
+        Err(error) => {
+            println!("[config_watch] Ignoring invalid config: {}", error);
+            let _ = app.emit(
+                "config-invalid",
+                ConfigInvalidEvent {
+                    path: path.to_string_lossy().to_string(),
+                    error,
+                },
+            );
+        }
+    }
+}
+
+fn handle_event(app: &AppHandle, event: notify::Event) {
+    let Ok(config_path) = crate::config::get_config_path(app) else {
+        return;
+    };
+    if !event.paths.iter().any(|p| p.file_name() == config_path.file_name()) {
+        return;
+    }
+    if let Ok(mut last) = LAST_EVENT.lock() {
+        if last.is_some_and(|t| t.elapsed() < RELOAD_DEBOUNCE) {
+            return;
+        }
+        *last = Some(Instant::now());
+    }
+    // Let the write finish before reading
+    let app = app.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(RELOAD_DEBOUNCE);
+        reload(&app);
+    });
+}
+
+/// Watch config.json for edits made outside DevLaunch
+pub fn start(app: &AppHandle) {
+    let Ok(config_path) = crate::config::get_config_path(app) else {
+        return;
+    };
+    // Seed with the current contents so startup doesn't count as a change
+    if let Ok(content) = std::fs::read_to_string(&config_path) {
+        note_written(&content);
+    }
+    let Some(dir) = config_path.parent() else {
+        return;
+    };
+
+    let handle = app.clone();
+    let watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+        if let Ok(event) = result {
+            handle_event(&handle, event);
+        }
+    });
+    // The directory, not the file: saves replace the file, which would end a watch on it
+    let watcher = watcher.and_then(|mut w| w.watch(dir, RecursiveMode::NonRecursive).map(|_| w));
+    match watcher {
+        Ok(watcher) => {
+            if let Ok(mut slot) = WATCHER.lock() {
+                *slot = Some(watcher);
+            }
+        }
+        Err(e) => println!("[config_watch] Failed to watch {}: {}", dir.display(), e),
+    }
+}
@@ -0,0 +1,371 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager, Url};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+use tokio::sync::{mpsc, Mutex};
+
+/// Scheme for devlaunch:// links (also declared in tauri.conf.json)
+pub const DEEP_LINK_SCHEME: &str = "devlaunch";
+
+struct ControlServer {
+    shutdown_tx: mpsc::Sender<()>,
+    port: u16,
+}
+
+lazy_static! {
+    static ref SERVER: Mutex<Option<ControlServer>> = Mutex::new(None);
+}
+
+struct ControlState {
+    app: AppHandle,
+    token: String,
+}
+
+/// Something a CLI call or deep link asked DevLaunch to do
+#[derive(Debug, Clone)]
+pub enum ControlAction {
+    /// Preset id or name (case-insensitive)
+    LaunchPreset { preset: String, parallel: bool },
+    StopAll,
+    /// Show the window and select this project
+    OpenProject { path: String },
+    Show,
+}
+
+/// Written to the app data dir so a CLI can find the port and token
+#[derive(Debug, Serialize, Deserialize)]
+struct ControlFile {
+    port: u16,
+    token: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct OpenProjectEvent {
+    path: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ControlServerStatus {
+    pub port: u16,
+    /// Where the CLI reads the port and token from
+    pub control_file: String,
+}
+
+fn control_file_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    std::fs::create_dir_all(&app_data_dir).map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("control.json"))
+}
+
+/// Reuse the token from a previous run so scripts keep working across restarts
+fn load_or_create_token(app: &AppHandle) -> String {
+    control_file_path(app)
+        .ok()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|c| serde_json::from_str::<ControlFile>(&c).ok())
+        .map(|f| f.token)
+        .filter(|t| !t.is_empty())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().simple().to_string())
+}
+
+fn write_control_file(app: &AppHandle, file: &ControlFile) -> Result<(), String> {
+    let path = control_file_path(app)?;
+    let content = serde_json::to_string_pretty(file).map_err(|e| e.to_string())?;
+    // The token grants control over local processes; keep it private to the user
+    crate::secrets::write_private_file(&path, &content)
+}
+
+fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// Run a control action, returning a JSON summary for the caller
+pub async fn perform(app: &AppHandle, action: ControlAction) -> Result<serde_json::Value, String> {
+    println!("[control] {:?}", action);
+    match action {
+        ControlAction::LaunchPreset { preset, parallel } => {
+            let config = crate::config::load_config_internal(app)?;
+            let found = config
+                .presets
+                .iter()
+                .find(|p| p.id == preset)
+                .or_else(|| config.presets.iter().find(|p| p.name.eq_ignore_ascii_case(&preset)))
+                .ok_or_else(|| format!("Preset not found: {}", preset))?;
+            let pids = crate::presets::launch_preset_internal(app, found.id.clone(), parallel).await?;
+            Ok(serde_json::json!({ "preset": found.name, "pids": pids }))
+        }
+        ControlAction::StopAll => {
+            let stopped = crate::process::kill_all_processes(app.clone()).await?;
+            Ok(serde_json::json!({ "stopped": stopped }))
+        }
+        ControlAction::OpenProject { path } => {
+            show_main_window(app);
+            let _ = app.emit("open-project", OpenProjectEvent { path: path.clone() });
+            Ok(serde_json::json!({ "opened": path }))
+        }
+        ControlAction::Show => {
+            show_main_window(app);
+            Ok(serde_json::json!({}))
+        }
+    }
+}
+
+/// devlaunch://launch/<preset>[?parallel=1], devlaunch://stop-all,
+/// devlaunch://open?path=<project path>, devlaunch://show
+pub fn parse_deep_link(url: &Url) -> Result<ControlAction, String> {
+    if url.scheme() != DEEP_LINK_SCHEME {
+        return Err(format!("Unsupported scheme: {}", url.scheme()));
+    }
+    let query = |key: &str| url.query_pairs().find(|(k, _)| k == key).map(|(_, v)| v.to_string());
+    // devlaunch://launch/web parses "launch" as the host and "/web" as the path
+    let mut segments: Vec<String> = url.host_str().map(|h| h.to_string()).into_iter().collect();
+    segments.extend(
+        url.path_segments()
+            .into_iter()
+            .flatten()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string()),
+    );
+
+    match segments.first().map(|s| s.as_str()) {
+        Some("launch") => {
+            let preset = segments
+                .get(1)
+                .cloned()
+                .or_else(|| query("preset"))
+                .ok_or("Missing preset in launch link")?;
+            let preset = crate::static_server::percent_decode(&preset);
+            let parallel = query("parallel").is_some_and(|v| v == "1" || v == "true");
+            Ok(ControlAction::LaunchPreset { preset, parallel })
+        }
+        Some("stop-all") => Ok(ControlAction::StopAll),
+        Some("open") => Ok(ControlAction::OpenProject {
+            path: query("path").ok_or("Missing path in open link")?,
+        }),
+        Some("show") | None => Ok(ControlAction::Show),
+        Some(other) => Err(format!("Unknown devlaunch action: {}", other)),
+    }
+}
+
+/// Ask before a deep link starts or stops processes: any web page or app can open one.
+/// Showing the window or a project needs no confirmation.
+async fn confirm_deep_link(app: &AppHandle, action: &ControlAction) -> bool {
+    let (message, ok_label) = match action {
+        ControlAction::LaunchPreset { preset, .. } => {
+            (format!("A link asked DevLaunch to launch the preset \"{}\".", preset), "Launch")
+        }
+        ControlAction::StopAll => ("A link asked DevLaunch to stop all running servers.".to_string(), "Stop All"),
+        ControlAction::OpenProject { .. } | ControlAction::Show => return true,
+    };
+    show_main_window(app);
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    app.dialog()
+        .message(message)
+        .title("Allow this link?")
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(ok_label.to_string(), "Cancel".to_string()))
+        .show(move |confirmed| {
+            let _ = tx.send(confirmed);
+        });
+    rx.await.unwrap_or(false)
+}
+
+/// Handle devlaunch:// URLs from the OS (first launch or forwarded by a second instance)
+pub fn handle_deep_links(app: &AppHandle, urls: Vec<Url>) {
+    for url in urls {
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let result = match parse_deep_link(&url) {
+                Ok(action) if confirm_deep_link(&app, &action).await => perform(&app, action).await,
+                Ok(_) => {
+                    println!("[control] Deep link {} declined", url);
+                    return;
+                }
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                println!("[control] Deep link {} failed: {}", url, e);
+                let _ = app.emit("control-error", e);
+            }
+        });
+    }
+}
+
+fn authorized(headers: &HeaderMap, token: &str) -> bool {
+    let provided = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .unwrap_or("");
+    crate::secrets::tokens_match(provided, token)
+}
+
+async fn run(state: &ControlState, headers: &HeaderMap, action: ControlAction) -> Response {
+    if !authorized(headers, &state.token) {
+        return (StatusCode::UNAUTHORIZED, Json(serde_json::json!({ "error": "Invalid or missing token" }))).into_response();
+    }
+    match perform(&state.app, action).await {
+        Ok(body) => Json(body).into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, Json(serde_json::json!({ "error": e }))).into_response(),
+    }
+}
+
+#[derive(Deserialize)]
+struct LaunchQuery {
+    #[serde(default)]
+    parallel: bool,
+}
+
+#[derive(Deserialize)]
+struct OpenBody {
+    path: String,
+}
+
+async fn handle_status(State(state): State<Arc<ControlState>>, headers: HeaderMap) -> Response {
+    if !authorized(&headers, &state.token) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+    Json(crate::process::list_processes_internal()).into_response()
+}
+
+async fn handle_launch(
+    State(state): State<Arc<ControlState>>,
+    Path(preset): Path<String>,
+    Query(query): Query<LaunchQuery>,
+    headers: HeaderMap,
+) -> Response {
+    run(
+        &state,
+        &headers,
+        ControlAction::LaunchPreset {
+            preset,
+            parallel: query.parallel,
+        },
+    )
+    .await
+}
+
+async fn handle_stop_all(State(state): State<Arc<ControlState>>, headers: HeaderMap) -> Response {
+    run(&state, &headers, ControlAction::StopAll).await
+}
+
+async fn handle_open(State(state): State<Arc<ControlState>>, headers: HeaderMap, Json(body): Json<OpenBody>) -> Response {
+    run(&state, &headers, ControlAction::OpenProject { path: body.path }).await
+}
+
+/// Serve the control API on loopback. Every request needs `Authorization: Bearer <token>`,
+/// with the token in control.json in the app data dir.
+pub async fn start_control_server_internal(app: &AppHandle, port: u16) -> Result<u16, String> {
+    let mut server = SERVER.lock().await;
+    if let Some(existing) = server.as_ref() {
+        return Ok(existing.port);
+    }
+
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(|e| format!("Failed to bind to port {}: {}", port, e))?;
+    let actual_port = listener.local_addr().map_err(|e| e.to_string())?.port();
+
+    let token = load_or_create_token(app);
+    write_control_file(
+        app,
+        &ControlFile {
+            port: actual_port,
+            token: token.clone(),
+        },
+    )?;
+
+    let state = Arc::new(ControlState {
+        app: app.clone(),
+        token,
+    });
+    // No CORS layer: browsers must not be able to drive this from a web page
+    let router = Router::new()
+        .route("/status", get(handle_status))
+        .route("/presets/:preset/launch", post(handle_launch))
+        .route("/stop-all", post(handle_stop_all))
+        .route("/open", post(handle_open))
+        .with_state(state);
+
+    let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
+    tokio::spawn(async move {
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async move {
+                shutdown_rx.recv().await;
+            })
+            .await
+            .ok();
+    });
+
+    *server = Some(ControlServer {
+        shutdown_tx,
+        port: actual_port,
+    });
+    println!("[control] Control server listening on 127.0.0.1:{}", actual_port);
+    Ok(actual_port)
+}
+
+/// Start the control server at launch if enabled in preferences
+pub fn start_from_preferences(app: &AppHandle) {
+    let prefs = crate::config::load_config_internal(app)
+        .map(|c| c.preferences.control_server)
+        .unwrap_or_default();
+    if !prefs.enabled {
+        return;
+    }
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = start_control_server_internal(&app, prefs.port).await {
+            println!("[control] {}", e);
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn start_control_server(app: AppHandle, port: Option<u16>) -> Result<ControlServerStatus, String> {
+    let port = start_control_server_internal(&app, port.unwrap_or(crate::config::DEFAULT_CONTROL_PORT)).await?;
+    Ok(ControlServerStatus {
+        port,
+        control_file: control_file_path(&app)?.to_string_lossy().to_string(),
+    })
+}
+
+#[tauri::command]
+pub async fn stop_control_server() -> Result<(), String> {
+    let mut server = SERVER.lock().await;
+    match server.take() {
+        Some(s) => {
+            let _ = s.shutdown_tx.send(()).await;
+            Ok(())
+        }
+        None => Err("Control server is not running".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn get_control_server_status(app: AppHandle) -> Result<Option<ControlServerStatus>, String> {
+    let port = SERVER.lock().await.as_ref().map(|s| s.port);
+    match port {
+        Some(port) => Ok(Some(ControlServerStatus {
+            port,
+            control_file: control_file_path(&app)?.to_string_lossy().to_string(),
+        })),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+/// Crashes kept per project
+const MAX_CRASHES: usize = 20;
+
+/// Output lines captured with each exit
+pub(crate) const SNAPSHOT_LINES: usize = 50;
+
+lazy_static::lazy_static! {
+    // Serializes access to the history file
+    static ref STORE: Mutex<()> = Mutex::new(());
+}
+
+/// A non-zero or signal exit nobody asked for, with what the process printed last
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashRecord {
+    pub pid: u32,
+    pub command: String,
+    pub started_at: i64,
+    pub exited_at: i64,
+    pub code: Option<i32>,
+    pub signal: Option<i32>,
+    /// e.g. "Exited with code 1" or "Killed by SIGKILL (9)"
+    pub reason: String,
+    /// Oldest first
+    pub last_output: Vec<String>,
+}
+
+fn history_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    std::fs::create_dir_all(&app_data_dir).map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("crash_history.json"))
+}
+
+fn read_history(path: &Path) -> HashMap<String, Vec<CrashRecord>> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+fn signal_name(signal: i32) -> Option<&'static str> {
+    Some(match signal {
+        1 => "SIGHUP",
+        2 => "SIGINT",
+        3 => "SIGQUIT",
+        4 => "SIGILL",
+        6 => "SIGABRT",
+        8 => "SIGFPE",
+        9 => "SIGKILL",
+        11 => "SIGSEGV",
+        13 => "SIGPIPE",
+        14 => "SIGALRM",
+        15 => "SIGTERM",
+        _ => return None,
+    })
+}
+
+/// Why a process exited, in words
+pub(crate) fn describe_exit(code: Option<i32>, signal: Option<i32>) -> String {
+    let killed_by = |signal: i32| {
+        let name = signal_name(signal).map(|n| format!("{} ({})", n, signal));
+        let hint = match signal {
+            // The OOM killer is the usual sender when nobody asked
+            9 => " - possibly out of memory",
+            11 => " - segmentation fault",
+            6 => " - aborted",
+            _ => "",
+        };
+        format!("Killed by {}{}", name.unwrap_or_else(|| format!("signal {}", signal)), hint)
+    };
+    match (code, signal) {
+        (_, Some(signal)) => killed_by(signal),
+        // Shells report a child killed by a signal as 128 + the signal number
+        (Some(code), None) if code > 128 && signal_name(code - 128).is_some() => {
+            format!("Exited with code {} ({})", code, killed_by(code - 128))
+        }
+        (Some(code), None) => format!("Exited with code {}", code),
+        (None, None) => "Exited (no exit status available)".to_string(),
+    }
+}
+
+/// Add an unexpected exit to the project's history
+pub(crate) fn record(app: &AppHandle, project: &str, crash: CrashRecord) {
+    let Ok(_guard) = STORE.lock() else {
+        return;
+    };
+    let Ok(path) = history_file(app) else {
+        return;
+    };
+    let mut history = read_history(&path);
+    let crashes = history.entry(project.to_string()).or_default();
+    crashes.push(crash);
+    let excess = crashes.len().saturating_sub(MAX_CRASHES);
+    crashes.drain(..excess);
+
+    let result = serde_json::to_string(&history)
+        .map_err(|e| e.to_string())
+        .and_then(|json| crate::config::write_file_atomic(&path, &json));
+    if let Err(e) = result {
+        println!("[crash_history] Failed to write {:?}: {}", path, e);
+    }
+}
+
+/// A project's recent crashes (clean exits excluded), oldest first, including ones that
+/// happened while the window was hidden
+#[tauri::command]
+pub async fn get_crash_history(app: AppHandle, path: String) -> Result<Vec<CrashRecord>, String> {
+    let _guard = STORE.lock().map_err(|e| e.to_string())?;
+    Ok(read_history(&history_file(&app)?).remove(&path).unwrap_or_default())
+}
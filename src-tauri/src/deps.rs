@@ -0,0 +1,354 @@
+use crate::process;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use tauri::AppHandle;
+
+/// How long a dependency check is reused while the manifest and lockfile are unchanged
+const CHECK_CACHE_SECS: i64 = 6 * 60 * 60;
+
+lazy_static::lazy_static! {
+    // Last check_dependencies report per project path
+    static ref CHECK_CACHE: Mutex<HashMap<String, DependencyReport>> = Mutex::new(HashMap::new());
+}
+
+/// What changed between two commits that needs an install or migration step
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyChanges {
+    pub needs_install: bool,
+    pub needs_migration: bool,
+    /// Changed manifests and lockfiles, relative to the project
+    pub manifest_files: Vec<String>,
+    pub migration_files: Vec<String>,
+    /// Ecosystems with changed dependencies ("node", "rust", "python", "go")
+    pub ecosystems: Vec<String>,
+    /// What run_install would run, for display
+    pub install_commands: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallProcess {
+    pub ecosystem: String,
+    pub command: String,
+    pub pid: u32,
+}
+
+/// Ecosystem a dependency manifest or lockfile belongs to
+fn manifest_ecosystem(file: &str) -> Option<&'static str> {
+    let name = file.rsplit('/').next().unwrap_or(file);
+    match name {
+        "package.json" | "package-lock.json" | "npm-shrinkwrap.json" | "yarn.lock" | "pnpm-lock.yaml"
+        | "bun.lockb" | "bun.lock" => Some("node"),
+        "Cargo.toml" | "Cargo.lock" => Some("rust"),
+        "pyproject.toml" | "poetry.lock" | "uv.lock" | "Pipfile" | "Pipfile.lock" => Some("python"),
+        "go.mod" | "go.sum" => Some("go"),
+        _ if name.starts_with("requirements") && name.ends_with(".txt") => Some("python"),
+        _ => None,
+    }
+}
+
+/// Migration files for common frameworks (Django, Rails, Prisma, Alembic, Knex, sqlx, ...)
+fn is_migration(file: &str) -> bool {
+    file.ends_with("schema.prisma")
+        || file
+            .split('/')
+            .rev()
+            .skip(1)
+            .any(|dir| matches!(dir, "migrations" | "migrate" | "migration" | "alembic"))
+}
+
+/// Install command for an ecosystem in `dir`, based on the lockfiles present
+fn install_command(dir: &Path, ecosystem: &str) -> Option<(String, Vec<String>)> {
+    let cmd = |command: &str, args: &[&str]| Some((command.to_string(), args.iter().map(|a| a.to_string()).collect()));
+    match ecosystem {
+        "node" => {
+            let manifest: serde_json::Value = std::fs::read_to_string(dir.join("package.json"))
+                .ok()
+                .and_then(|s| serde_json::from_str(&s).ok())?;
+            cmd(crate::scripts::node_package_manager(dir, &manifest), &["install"])
+        }
+        "rust" => dir.join("Cargo.toml").exists().then(|| ("cargo".to_string(), vec!["fetch".to_string()])),
+        "python" => {
+            if dir.join("poetry.lock").exists() {
+                cmd("poetry", &["install"])
+            } else if dir.join("uv.lock").exists() {
+                cmd("uv", &["sync"])
+            } else if dir.join("Pipfile").exists() {
+                cmd("pipenv", &["install"])
+            } else if dir.join("requirements.txt").exists() {
+                cmd("python", &["-m", "pip", "install", "-r", "requirements.txt"])
+            } else if dir.join("pyproject.toml").exists() {
+                cmd("python", &["-m", "pip", "install", "-e", "."])
+            } else {
+                None
+            }
+        }
+        "go" => dir.join("go.mod").exists().then(|| ("go".to_string(), vec!["mod".to_string(), "download".to_string()])),
+        _ => None,
+    }
+}
+
+fn display_command(command: &str, args: &[String]) -> String {
+    std::iter::once(command.to_string()).chain(args.iter().cloned()).collect::<Vec<_>>().join(" ")
+}
+
+/// Classify files changed (relative to `dir`) into install and migration hints
+pub fn dependency_changes(dir: &Path, changed_files: &[String]) -> DependencyChanges {
+    let mut changes = DependencyChanges::default();
+    for file in changed_files {
+        if let Some(ecosystem) = manifest_ecosystem(file) {
+            changes.manifest_files.push(file.clone());
+            if !changes.ecosystems.iter().any(|e| e == ecosystem) {
+                changes.ecosystems.push(ecosystem.to_string());
+            }
+        } else if is_migration(file) {
+            changes.migration_files.push(file.clone());
+        }
+    }
+    changes.install_commands = changes
+        .ecosystems
+        .iter()
+        .filter_map(|e| install_command(dir, e))
+        .map(|(command, args)| display_command(&command, &args))
+        .collect();
+    changes.needs_install = !changes.manifest_files.is_empty();
+    changes.needs_migration = !changes.migration_files.is_empty();
+    changes
+}
+
+/// Run the install step for `ecosystems` (default: every one detected in the project) as
+/// managed processes, so progress shows up in the process logs
+#[tauri::command]
+pub async fn run_install(app: AppHandle, path: String, ecosystems: Option<Vec<String>>) -> Result<Vec<InstallProcess>, String> {
+    let dir = Path::new(&path);
+    let ecosystems = ecosystems.unwrap_or_else(|| ["node", "rust", "python", "go"].iter().map(|e| e.to_string()).collect());
+
+    let mut started = Vec::new();
+    for ecosystem in &ecosystems {
+        let Some((command, args)) = install_command(dir, ecosystem) else {
+            continue;
+        };
+        let pid = process::spawn_process_internal(&app, &path, &command, &args)?;
+        println!("[deps] Running {} in {} (pid {})", display_command(&command, &args), path, pid);
+        started.push(InstallProcess {
+            ecosystem: ecosystem.clone(),
+            command: display_command(&command, &args),
+            pid,
+        });
+    }
+    if started.is_empty() {
+        return Err(format!("No install step found for {}", path));
+    }
+    Ok(started)
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutdatedPackage {
+    pub name: String,
+    pub current: Option<String>,
+    /// Newest version the manifest's range allows
+    pub wanted: Option<String>,
+    pub latest: Option<String>,
+    /// Latest is a new major version
+    pub major: bool,
+}
+
+/// Audit findings by severity
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VulnerabilityCounts {
+    pub info: u32,
+    pub low: u32,
+    pub moderate: u32,
+    pub high: u32,
+    pub critical: u32,
+    pub total: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyReport {
+    pub path: String,
+    pub package_manager: String,
+    pub outdated_count: usize,
+    pub outdated: Vec<OutdatedPackage>,
+    pub vulnerabilities: VulnerabilityCounts,
+    /// Unix millis
+    pub checked_at: i64,
+    /// Served from the cache rather than a fresh run
+    pub cached: bool,
+    /// Why the outdated or audit step failed, e.g. no network
+    pub errors: Vec<String>,
+    /// Newest modification time of the manifest and lockfiles, to invalidate the cache
+    #[serde(skip)]
+    manifest_modified: Option<SystemTime>,
+}
+
+fn manifest_modified(dir: &Path) -> Option<SystemTime> {
+    ["package.json", "package-lock.json", "pnpm-lock.yaml"]
+        .iter()
+        .filter_map(|f| std::fs::metadata(dir.join(f)).and_then(|m| m.modified()).ok())
+        .max()
+}
+
+/// Run the package manager and parse its JSON output. outdated and audit exit non-zero when
+/// they find something, so the status is only used when there's no JSON.
+fn package_manager_json(app: &AppHandle, dir: &Path, pm: &str, args: &[&str]) -> Result<serde_json::Value, String> {
+    // npm and pnpm are .cmd shims on Windows
+    let program = if cfg!(windows) { format!("{}.cmd", pm) } else { pm.to_string() };
+    let mut cmd = Command::new(program);
+    cmd.current_dir(dir).args(args);
+    crate::shell_env::apply_to_command(app, &mut cmd);
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run {} {}: {}", pm, args.join(" "), e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if stdout.trim().is_empty() {
+        if output.status.success() {
+            return Ok(serde_json::Value::Object(Default::default()));
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("{} {} failed: {}", pm, args.join(" "), stderr.trim()));
+    }
+    serde_json::from_str(&stdout).map_err(|e| format!("Unexpected {} {} output: {}", pm, args.join(" "), e))
+}
+
+fn major(version: &str) -> Option<u64> {
+    version.trim_start_matches(|c: char| !c.is_ascii_digit()).split('.').next()?.parse().ok()
+}
+
+/// `npm outdated --json` and `pnpm outdated --format json`: an object keyed by package name
+fn parse_outdated(json: &serde_json::Value) -> Vec<OutdatedPackage> {
+    let Some(packages) = json.as_object() else {
+        return Vec::new();
+    };
+    let field = |info: &serde_json::Value, key: &str| info.get(key).and_then(|v| v.as_str()).map(|v| v.to_string());
+    let mut outdated: Vec<OutdatedPackage> = packages
+        .iter()
+        // A failed npm run still prints JSON, as {"error": {...}}
+        .filter(|(name, info)| name.as_str() != "error" && info.is_object())
+        .map(|(name, info)| {
+            let current = field(info, "current");
+            let latest = field(info, "latest");
+            OutdatedPackage {
+                name: name.clone(),
+                major: match (current.as_deref().and_then(major), latest.as_deref().and_then(major)) {
+                    (Some(c), Some(l)) => l > c,
+                    _ => false,
+                },
+                wanted: field(info, "wanted"),
+                current,
+                latest,
+            }
+        })
+        .collect();
+    outdated.sort_by(|a, b| a.name.cmp(&b.name));
+    outdated
+}
+
+/// `npm audit --json` and `pnpm audit --json` both summarize under metadata.vulnerabilities
+fn parse_audit(json: &serde_json::Value) -> Option<VulnerabilityCounts> {
+    let counts = json.get("metadata")?.get("vulnerabilities")?;
+    let count = |key: &str| counts.get(key).and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+    let mut vulnerabilities = VulnerabilityCounts {
+        info: count("info"),
+        low: count("low"),
+        moderate: count("moderate"),
+        high: count("high"),
+        critical: count("critical"),
+        total: count("total"),
+    };
+    if vulnerabilities.total == 0 {
+        vulnerabilities.total = vulnerabilities.info
+            + vulnerabilities.low
+            + vulnerabilities.moderate
+            + vulnerabilities.high
+            + vulnerabilities.critical;
+    }
+    Some(vulnerabilities)
+}
+
+fn check_node_dependencies(app: &AppHandle, path: &str) -> Result<DependencyReport, String> {
+    let dir = Path::new(path);
+    let manifest: serde_json::Value = std::fs::read_to_string(dir.join("package.json"))
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .ok_or_else(|| format!("No package.json in {}", path))?;
+    let pm = crate::scripts::node_package_manager(dir, &manifest);
+    let (outdated_args, audit_args): (&[&str], &[&str]) = match pm {
+        "npm" => (&["outdated", "--json"], &["audit", "--json"]),
+        "pnpm" => (&["outdated", "--format", "json"], &["audit", "--json"]),
+        other => return Err(format!("Dependency checks support npm and pnpm, not {}", other)),
+    };
+
+    let mut errors = Vec::new();
+    let outdated = match package_manager_json(app, dir, pm, outdated_args) {
+        Ok(json) => parse_outdated(&json),
+        Err(e) => {
+            errors.push(e);
+            Vec::new()
+        }
+    };
+    let vulnerabilities = match package_manager_json(app, dir, pm, audit_args) {
+        Ok(json) => parse_audit(&json).unwrap_or_else(|| {
+            errors.push(format!("{} audit returned no summary", pm));
+            VulnerabilityCounts::default()
+        }),
+        Err(e) => {
+            errors.push(e);
+            VulnerabilityCounts::default()
+        }
+    };
+
+    Ok(DependencyReport {
+        path: path.to_string(),
+        package_manager: pm.to_string(),
+        outdated_count: outdated.len(),
+        outdated,
+        vulnerabilities,
+        checked_at: chrono::Utc::now().timestamp_millis(),
+        cached: false,
+        errors,
+        manifest_modified: manifest_modified(dir),
+    })
+}
+
+/// Outdated and vulnerable package counts for a Node project, from the package manager's own
+/// outdated/audit output. Results are cached for a few hours unless the manifest or lockfile
+/// changes; `force` runs the check again.
+#[tauri::command]
+pub async fn check_dependencies(app: AppHandle, path: String, force: Option<bool>) -> Result<DependencyReport, String> {
+    if !force.unwrap_or(false) {
+        let cached = CHECK_CACHE.lock().ok().and_then(|c| c.get(&path).cloned());
+        if let Some(mut report) = cached {
+            let fresh = chrono::Utc::now().timestamp_millis() - report.checked_at < CHECK_CACHE_SECS * 1000;
+            if fresh && report.manifest_modified == manifest_modified(Path::new(&path)) {
+                report.cached = true;
+                return Ok(report);
+            }
+        }
+    }
+
+    let check_path = path.clone();
+    let report = tokio::task::spawn_blocking(move || check_node_dependencies(&app, &check_path))
+        .await
+        .map_err(|e| e.to_string())??;
+    println!(
+        "[deps] {}: {} outdated, {} vulnerable",
+        path, report.outdated_count, report.vulnerabilities.total
+    );
+    // A failed step (often no network) is worth retrying on the next call
+    if report.errors.is_empty() {
+        if let Ok(mut cache) = CHECK_CACHE.lock() {
+            cache.insert(path, report.clone());
+        }
+    }
+    Ok(report)
+}
@@ -0,0 +1,302 @@
+use crate::process;
+use std::process::Command;
+use tauri::AppHandle;
+
+/// Quote a string for POSIX sh using single quotes
+pub fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+/// Build a quoted shell command line from a command and its args
+#[cfg(unix)]
+fn shell_command_line(command: &str, args: &[String]) -> String {
+    std::iter::once(command.to_string())
+        .chain(args.iter().cloned())
+        .map(|a| shell_quote(&a))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Escape a string for use inside an AppleScript string literal
+#[cfg(target_os = "macos")]
+fn applescript_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Run a shell script as root via the macOS authorization prompt
+#[cfg(target_os = "macos")]
+fn run_as_admin_macos(script: &str) -> Result<String, String> {
+    let apple_script = format!(
+        "do shell script \"{}\" with administrator privileges",
+        applescript_escape(script)
+    );
+    let output = Command::new("osascript")
+        .args(["-e", &apple_script])
+        .output()
+        .map_err(|e| format!("Failed to run osascript: {}", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("User canceled") || stderr.contains("-128") {
+            Err("Authorization was cancelled".to_string())
+        } else {
+            Err(format!("Elevated command failed: {}", stderr.trim()))
+        }
+    }
+}
+
+/// Follow a log file written by a detached root process and stream it as process logs
+#[cfg(target_os = "macos")]
+fn tail_log_file(app: AppHandle, cwd: String, pid: u32, log_path: std::path::PathBuf) {
+    std::thread::spawn(move || {
+        use std::io::{BufRead, BufReader};
+
+        // The root shell creates the file; give it a moment to appear
+        let file = (0..20).find_map(|_| {
+            std::fs::File::open(&log_path).ok().or_else(|| {
+                std::thread::sleep(std::time::Duration::from_millis(100));
+                None
+            })
+        });
+        let Some(file) = file else {
+            println!("[elevation] Log file never appeared: {:?}", log_path);
+            return;
+        };
+
+        let mut reader = BufReader::new(file);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) => {
+                    let alive = Command::new("ps")
+                        .args(["-p", &pid.to_string()])
+                        .output()
+                        .map(|o| o.status.success())
+                        .unwrap_or(false);
+                    if !alive {
+                        break;
+                    }
+                    std::thread::sleep(std::time::Duration::from_millis(250));
+                }
+                Ok(_) => {
+                    process::handle_stdout_line(&app, &cwd, line.trim_end_matches(['\r', '\n']).to_string());
+                }
+                Err(_) => break,
+            }
+        }
+
+        let _ = std::fs::remove_file(&log_path);
+    });
+}
+
+/// Spawn a process with elevated privileges (for servers binding ports below 1024).
+/// macOS shows the system authorization prompt, Linux uses pkexec, Windows uses UAC.
+/// The PID is marked as elevated in the registry so stop/kill go through elevation too.
+#[tauri::command]
+pub async fn spawn_process_elevated(
+    app: AppHandle,
+    cwd: String,
+    command: String,
+    args: Vec<String>,
+) -> Result<u32, String> {
+    crate::hooks::run_pre_launch(&app, &cwd).await?;
+    spawn_elevated_internal(&app, &cwd, &command, &args)
+}
+
+/// Spawn an elevated process (internal version, also used for restarts)
+pub fn spawn_elevated_internal(
+    app: &AppHandle,
+    cwd: &str,
+    command: &str,
+    args: &[String],
+) -> Result<u32, String> {
+    println!("[elevation] Spawning elevated: {} {:?} in {}", command, args, cwd);
+
+    #[cfg(target_os = "macos")]
+    {
+        // do shell script can't stream output, so redirect to a file and tail it
+        let log_path = std::env::temp_dir().join(format!(
+            "devlaunch-elevated-{}.log",
+            uuid::Uuid::new_v4().simple()
+        ));
+        let path_env = std::env::var("PATH").unwrap_or_default();
+        let script = format!(
+            "cd {} && PATH={} nohup {} > {} 2>&1 < /dev/null & echo $!",
+            shell_quote(cwd),
+            shell_quote(&path_env),
+            shell_command_line(command, args),
+            shell_quote(&log_path.to_string_lossy())
+        );
+        let stdout = run_as_admin_macos(&script)?;
+        let pid: u32 = stdout
+            .lines()
+            .last()
+            .and_then(|l| l.trim().parse().ok())
+            .ok_or_else(|| format!("Could not read elevated PID from: {}", stdout))?;
+
+        let prefs = crate::config::load_config_internal(app)
+            .map(|c| c.preferences.log_files)
+            .unwrap_or_default();
+        crate::log_files::start_session(app, cwd, command, args, &prefs);
+        process::register_process(app, pid, cwd, command, args, None);
+        process::mark_elevated(pid);
+        tail_log_file(app.clone(), cwd.to_string(), pid, log_path);
+        Ok(pid)
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        // pkexec execs the target directly, so the piped output and PID are the real server's
+        let path_env = std::env::var("PATH").unwrap_or_default();
+        let script = format!("cd {} && exec {}", shell_quote(cwd), shell_command_line(command, args));
+        let pkexec_args = vec![
+            "env".to_string(),
+            format!("PATH={}", path_env),
+            "sh".to_string(),
+            "-c".to_string(),
+            script,
+        ];
+        let pid = process::spawn_process_internal(app, cwd, "pkexec", &pkexec_args)
+            .map_err(|e| format!("{} (is polkit/pkexec installed?)", e))?;
+        process::mark_elevated(pid);
+        Ok(pid)
+    }
+
+    #[cfg(windows)]
+    {
+        // UAC-elevated processes can't share our console, so output isn't streamed
+        let ps_quote = |s: &str| format!("'{}'", s.replace('\'', "''"));
+        let arg_list = if args.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " -ArgumentList {}",
+                args.iter().map(|a| ps_quote(a)).collect::<Vec<_>>().join(",")
+            )
+        };
+        let script = format!(
+            "(Start-Process -FilePath {}{} -WorkingDirectory {} -Verb RunAs -PassThru).Id",
+            ps_quote(command),
+            arg_list,
+            ps_quote(cwd)
+        );
+        let output = Command::new("powershell")
+            .args(["-NoProfile", "-Command", &script])
+            .output()
+            .map_err(|e| format!("Failed to run powershell: {}", e))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Elevation failed or was cancelled: {}", stderr.trim()));
+        }
+        let pid: u32 = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse()
+            .map_err(|_| "Could not read elevated PID".to_string())?;
+        process::register_process(app, pid, cwd, command, args, None);
+        process::mark_elevated(pid);
+        Ok(pid)
+    }
+}
+
+/// Terminate an elevated process tree (prompts for authorization again)
+pub fn kill_elevated(pid: u32) -> Result<bool, String> {
+    println!("[elevation] Killing elevated process {}", pid);
+
+    #[cfg(target_os = "macos")]
+    {
+        let script = format!("pkill -TERM -P {pid}; kill -TERM {pid}", pid = pid);
+        run_as_admin_macos(&script).map(|_| true)
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        let script = format!("kill -TERM -- -{pid} 2>/dev/null || kill -TERM {pid}", pid = pid);
+        let status = Command::new("pkexec")
+            .args(["sh", "-c", &script])
+            .status()
+            .map_err(|e| format!("Failed to run pkexec: {}", e))?;
+        Ok(status.success())
+    }
+
+    #[cfg(windows)]
+    {
+        let script = format!(
+            "Start-Process -FilePath taskkill -ArgumentList '/PID','{}','/T','/F' -Verb RunAs -Wait -WindowStyle Hidden",
+            pid
+        );
+        let status = Command::new("powershell")
+            .args(["-NoProfile", "-Command", &script])
+            .status()
+            .map_err(|e| format!("Failed to run powershell: {}", e))?;
+        Ok(status.success())
+    }
+}
+
+/// Replace a root-owned file (e.g. /etc/hosts) with new contents, prompting for authorization.
+/// The file is overwritten in place so its owner and permissions are kept.
+pub fn write_file_elevated(path: &std::path::Path, contents: &str) -> Result<(), String> {
+    let staged = std::env::temp_dir().join(format!("devlaunch-elevated-{}.tmp", uuid::Uuid::new_v4().simple()));
+    std::fs::write(&staged, contents).map_err(|e| format!("Failed to stage {}: {}", path.display(), e))?;
+    println!("[elevation] Writing {}", path.display());
+
+    #[cfg(target_os = "macos")]
+    let result = {
+        let script = format!(
+            "cat {} > {}",
+            shell_quote(&staged.to_string_lossy()),
+            shell_quote(&path.to_string_lossy())
+        );
+        run_as_admin_macos(&script).map(|_| ())
+    };
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    let result = {
+        let script = format!(
+            "cat {} > {}",
+            shell_quote(&staged.to_string_lossy()),
+            shell_quote(&path.to_string_lossy())
+        );
+        Command::new("pkexec")
+            .args(["sh", "-c", &script])
+            .status()
+            .map_err(|e| format!("Failed to run pkexec: {}", e))
+            .and_then(|status| {
+                if status.success() {
+                    Ok(())
+                } else {
+                    Err("Authorization failed or was cancelled".to_string())
+                }
+            })
+    };
+
+    #[cfg(windows)]
+    let result = {
+        let ps_quote = |s: &str| format!("'{}'", s.replace('\'', "''"));
+        let inner = format!(
+            "Copy-Item -LiteralPath {} -Destination {} -Force",
+            ps_quote(&staged.to_string_lossy()),
+            ps_quote(&path.to_string_lossy())
+        );
+        let script = format!(
+            "Start-Process -FilePath powershell -ArgumentList '-NoProfile','-Command',{} -Verb RunAs -Wait -WindowStyle Hidden",
+            ps_quote(&inner)
+        );
+        Command::new("powershell")
+            .args(["-NoProfile", "-Command", &script])
+            .status()
+            .map_err(|e| format!("Failed to run powershell: {}", e))
+            .and_then(|status| {
+                if status.success() {
+                    Ok(())
+                } else {
+                    Err("Elevation failed or was cancelled".to_string())
+                }
+            })
+    };
+
+    let _ = std::fs::remove_file(&staged);
+    result
+}
@@ -0,0 +1,488 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Template files checked into repos to document the expected variables
+const EXAMPLE_NAMES: &[&str] = &[".env.example", ".env.sample", ".env.template", ".env.dist"];
+
+/// One line (or multi-line quoted value) of an env file, in file order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvEntry {
+    pub kind: String, // "variable", "comment", "blank"
+    #[serde(default)]
+    pub key: Option<String>,
+    #[serde(default)]
+    pub value: Option<String>,
+    /// Quote character the value was written with ('"' or '\''), kept when writing back
+    #[serde(default)]
+    pub quote: Option<char>,
+    /// Line was `export KEY=value`
+    #[serde(default)]
+    pub exported: bool,
+    /// Trailing `# comment` after an unquoted or quoted value
+    #[serde(default)]
+    pub inline_comment: Option<String>,
+    /// Original text, used verbatim for comments and blank lines
+    #[serde(default)]
+    pub raw: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvFileInfo {
+    pub name: String,
+    pub path: String,
+    pub variable_count: usize,
+    pub is_example: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvFile {
+    pub path: String,
+    pub entries: Vec<EnvEntry>,
+    /// Example file this one was compared against, if any
+    pub example_path: Option<String>,
+    /// Keys in the example that this file doesn't define
+    pub missing_keys: Vec<String>,
+    /// Keys defined here that the example doesn't mention
+    pub extra_keys: Vec<String>,
+}
+
+/// Endings of editor swap files and scratch copies, which look like env files but aren't
+const SCRATCH_SUFFIXES: &[&str] = &[".tmp", ".temp", ".swp", ".swo", ".swx", ".bak", ".orig", ".old", ".save", "~"];
+
+fn is_env_file_name(name: &str) -> bool {
+    if SCRATCH_SUFFIXES.iter().any(|suffix| name.ends_with(suffix)) {
+        return false;
+    }
+    name == ".env" || name.starts_with(".env.") || name.ends_with(".env")
+}
+
+fn is_example_name(name: &str) -> bool {
+    EXAMPLE_NAMES.contains(&name)
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-')
+}
+
+fn raw_entry(kind: &str, raw: &str) -> EnvEntry {
+    EnvEntry {
+        kind: kind.to_string(),
+        key: None,
+        value: None,
+        quote: None,
+        exported: false,
+        inline_comment: None,
+        raw: raw.to_string(),
+    }
+}
+
+/// Position of the closing quote in `s`, skipping backslash escapes inside double quotes
+fn closing_quote(s: &str, quote: char) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' && quote == '"' {
+            escaped = true;
+        } else if c == quote {
+            return Some(i);
+        }
+    }
+    None
+}
+
+fn unescape_double_quoted(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+fn trailing_comment(rest: &str) -> Option<String> {
+    let rest = rest.trim();
+    rest.strip_prefix('#').map(|c| c.trim().to_string())
+}
+
+/// Parse env file content, keeping comments and blank lines so the file can be written back
+/// in its original order
+pub fn parse(content: &str) -> Vec<EnvEntry> {
+    let mut entries = Vec::new();
+    let mut lines = content.lines();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            entries.push(raw_entry("blank", line));
+            continue;
+        }
+        if trimmed.starts_with('#') {
+            entries.push(raw_entry("comment", line));
+            continue;
+        }
+
+        let (exported, assignment) = match trimmed.strip_prefix("export ") {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, trimmed),
+        };
+        let Some((key, rest)) = assignment.split_once('=') else {
+            // Not KEY=VALUE; keep it verbatim so writing back doesn't lose it
+            entries.push(raw_entry("comment", line));
+            continue;
+        };
+        let key = key.trim();
+        if !is_valid_key(key) {
+            entries.push(raw_entry("comment", line));
+            continue;
+        }
+
+        let rest = rest.trim_start();
+        let mut raw = line.to_string();
+        let (value, quote, inline_comment) = match rest.chars().next() {
+            Some(q @ ('"' | '\'')) => {
+                // Quoted values may span several lines
+                let mut body = rest[1..].to_string();
+                let comment = loop {
+                    if let Some(end) = closing_quote(&body, q) {
+                        let comment = trailing_comment(&body[end + 1..]);
+                        body.truncate(end);
+                        break comment;
+                    }
+                    match lines.next() {
+                        Some(next) => {
+                            raw.push('\n');
+                            raw.push_str(next);
+                            body.push('\n');
+                            body.push_str(next);
+                        }
+                        // Unterminated quote: take the rest of the file as the value
+                        None => break None,
+                    }
+                };
+                let value = if q == '"' { unescape_double_quoted(&body) } else { body };
+                (value, Some(q), comment)
+            }
+            _ => match rest.find(" #") {
+                Some(i) => (rest[..i].trim().to_string(), None, trailing_comment(&rest[i..])),
+                None => (rest.trim().to_string(), None, None),
+            },
+        };
+
+        entries.push(EnvEntry {
+            kind: "variable".to_string(),
+            key: Some(key.to_string()),
+            value: Some(value),
+            quote,
+            exported,
+            inline_comment,
+            raw,
+        });
+    }
+
+    entries
+}
+
+fn escape_double_quoted(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn needs_quotes(value: &str) -> bool {
+    value
+        .chars()
+        .any(|c| c.is_whitespace() || matches!(c, '#' | '"' | '\'' | '\\' | '$' | '`'))
+}
+
+fn render_variable(entry: &EnvEntry, key: &str) -> String {
+    let value = entry.value.as_deref().unwrap_or("");
+    let quoted = match entry.quote {
+        Some('\'') if !value.contains('\'') && !value.contains('\n') => format!("'{}'", value),
+        Some(_) => format!("\"{}\"", escape_double_quoted(value)),
+        None if needs_quotes(value) => format!("\"{}\"", escape_double_quoted(value)),
+        None => value.to_string(),
+    };
+    let mut line = format!(
+        "{}{}={}",
+        if entry.exported { "export " } else { "" },
+        key,
+        quoted
+    );
+    if let Some(comment) = entry.inline_comment.as_deref().filter(|c| !c.is_empty()) {
+        line.push_str(" # ");
+        line.push_str(comment);
+    }
+    line
+}
+
+/// Render one entry, reusing its original text when nothing about it changed
+fn render_entry(entry: &EnvEntry) -> String {
+    let key = match (entry.kind.as_str(), entry.key.as_deref()) {
+        ("variable", Some(key)) => key,
+        _ => return entry.raw.clone(),
+    };
+
+    let unchanged = parse(&entry.raw).first().is_some_and(|original| {
+        original.key.as_deref() == Some(key)
+            && original.value == entry.value
+            && original.quote == entry.quote
+            && original.exported == entry.exported
+            && original.inline_comment == entry.inline_comment
+    });
+    if unchanged {
+        entry.raw.clone()
+    } else {
+        render_variable(entry, key)
+    }
+}
+
+/// Serialize entries back into env file content
+pub fn render(entries: &[EnvEntry]) -> String {
+    let mut content = String::new();
+    for entry in entries {
+        content.push_str(&render_entry(entry));
+        content.push('\n');
+    }
+    content
+}
+
+/// PORT from a project's .env files, the common way dev servers are told where to listen
+pub fn env_port(dir: &Path) -> Option<u16> {
+    [".env.local", ".env.development", ".env"].iter().find_map(|name| {
+        let content = fs::read_to_string(dir.join(name)).ok()?;
+        parse(&content)
+            .into_iter()
+            .find(|e| e.key.as_deref() == Some("PORT"))
+            .and_then(|e| e.value)
+            .and_then(|v| v.trim().parse().ok())
+    })
+}
+
+fn variable_keys(entries: &[EnvEntry]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    entries
+        .iter()
+        .filter(|e| e.kind == "variable")
+        .filter_map(|e| e.key.clone())
+        .filter(|k| seen.insert(k.clone()))
+        .collect()
+}
+
+fn find_example(dir: &Path) -> Option<std::path::PathBuf> {
+    EXAMPLE_NAMES.iter().map(|name| dir.join(name)).find(|p| p.is_file())
+}
+
+/// File name of an env file path, refusing anything that isn't one
+fn env_file_name(path: &Path) -> Result<String, String> {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    if is_env_file_name(&name) {
+        Ok(name)
+    } else {
+        Err(format!("Not an env file: {}", path.display()))
+    }
+}
+
+/// List .env files in a project directory (examples included, flagged as such)
+#[tauri::command]
+pub fn list_env_files(path: String) -> Result<Vec<EnvFileInfo>, String> {
+    let dir = Path::new(&path);
+    let read_dir = fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+
+    let mut files: Vec<EnvFileInfo> = read_dir
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !is_env_file_name(&name) {
+                return None;
+            }
+            let variable_count = fs::read_to_string(entry.path())
+                .map(|content| variable_keys(&parse(&content)).len())
+                .unwrap_or(0);
+            Some(EnvFileInfo {
+                is_example: is_example_name(&name),
+                path: entry.path().to_string_lossy().to_string(),
+                name,
+                variable_count,
+            })
+        })
+        .collect();
+
+    // .env first, then the rest alphabetically with examples last
+    files.sort_by(|a, b| {
+        (a.name != ".env", a.is_example, &a.name).cmp(&(b.name != ".env", b.is_example, &b.name))
+    });
+    Ok(files)
+}
+
+/// Read and parse an env file, diffing its keys against the project's .env.example
+#[tauri::command]
+pub fn read_env_file(path: String) -> Result<EnvFile, String> {
+    let file_path = Path::new(&path);
+    let is_example = is_example_name(&env_file_name(file_path)?);
+
+    let content = fs::read_to_string(file_path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let entries = parse(&content);
+
+    let example = if is_example {
+        None
+    } else {
+        file_path.parent().and_then(find_example)
+    };
+
+    let (missing_keys, extra_keys) = match &example {
+        Some(example_path) => {
+            let example_keys = fs::read_to_string(example_path)
+                .map(|c| variable_keys(&parse(&c)))
+                .unwrap_or_default();
+            let keys: HashSet<String> = variable_keys(&entries).into_iter().collect();
+            let example_set: HashSet<&String> = example_keys.iter().collect();
+
+            let missing = example_keys.iter().filter(|k| !keys.contains(*k)).cloned().collect();
+            let extra = variable_keys(&entries)
+                .into_iter()
+                .filter(|k| !example_set.contains(k))
+                .collect();
+            (missing, extra)
+        }
+        None => (Vec::new(), Vec::new()),
+    };
+
+    Ok(EnvFile {
+        path,
+        entries,
+        example_path: example.map(|p| p.to_string_lossy().to_string()),
+        missing_keys,
+        extra_keys,
+    })
+}
+
+/// Write entries back to an env file, keeping untouched lines byte-for-byte
+#[tauri::command]
+pub fn write_env_file(path: String, entries: Vec<EnvEntry>) -> Result<(), String> {
+    let file_path = Path::new(&path);
+    let name = env_file_name(file_path)?;
+
+    if let Some(bad) = entries
+        .iter()
+        .filter(|e| e.kind == "variable")
+        .find(|e| !e.key.as_deref().is_some_and(is_valid_key))
+    {
+        return Err(format!("Invalid variable name: {}", bad.key.as_deref().unwrap_or("")));
+    }
+
+    let tmp = file_path.with_file_name(format!("{}.tmp", name));
+    fs::write(&tmp, render(&entries)).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    fs::rename(&tmp, file_path).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    println!("[envfiles] Saved {} ({} entries)", path, entries.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variables(content: &str) -> Vec<(String, String)> {
+        parse(content)
+            .into_iter()
+            .filter(|e| e.kind == "variable")
+            .map(|e| (e.key.unwrap(), e.value.unwrap()))
+            .collect()
+    }
+
+    fn pair(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn parses_plain_values_and_inline_comments() {
+        let entries = parse("PORT=3000\nHOST = localhost # dev only\nURL=http://x/#anchor\n");
+        assert_eq!(entries[0].value.as_deref(), Some("3000"));
+        assert_eq!(entries[1].key.as_deref(), Some("HOST"));
+        assert_eq!(entries[1].value.as_deref(), Some("localhost"));
+        assert_eq!(entries[1].inline_comment.as_deref(), Some("dev only"));
+        assert_eq!(entries[2].value.as_deref(), Some("http://x/#anchor"));
+    }
+
+    #[test]
+    fn parses_export_prefix() {
+        let entries = parse("export API_KEY=abc\nexport   DEBUG=1");
+        assert!(entries.iter().all(|e| e.exported));
+        assert_eq!(variables("export API_KEY=abc"), [pair("API_KEY", "abc")]);
+    }
+
+    #[test]
+    fn single_quotes_are_literal() {
+        let entries = parse(r#"GREETING='hello # not a comment \n $HOME' # note"#);
+        assert_eq!(entries[0].value.as_deref(), Some(r#"hello # not a comment \n $HOME"#));
+        assert_eq!(entries[0].quote, Some('\''));
+        assert_eq!(entries[0].inline_comment.as_deref(), Some("note"));
+    }
+
+    #[test]
+    fn double_quotes_unescape() {
+        assert_eq!(
+            variables(r#"MSG="line1\nline2\t\"quoted\" \\ done""#),
+            [pair("MSG", "line1\nline2\t\"quoted\" \\ done")]
+        );
+    }
+
+    #[test]
+    fn quoted_values_span_lines() {
+        let content = "KEY=\"-----BEGIN-----\nabc\n-----END-----\"\nNEXT=1";
+        assert_eq!(
+            variables(content),
+            [pair("KEY", "-----BEGIN-----\nabc\n-----END-----"), pair("NEXT", "1")]
+        );
+    }
+
+    #[test]
+    fn keeps_comments_blanks_and_junk_verbatim() {
+        let content = "# header\n\nnot an assignment\n1BAD=x\nOK=1\n";
+        let kinds: Vec<String> = parse(content).into_iter().map(|e| e.kind).collect();
+        assert_eq!(kinds, ["comment", "blank", "comment", "comment", "variable"]);
+        assert_eq!(render(&parse(content)), content);
+    }
+
+    #[test]
+    fn render_quotes_changed_values_when_needed() {
+        let mut entries = parse("A=1\nB='x'");
+        entries[0].value = Some("has space".to_string());
+        entries[1].value = Some("it's".to_string());
+        assert_eq!(render(&entries), "A=\"has space\"\nB=\"it's\"\n");
+        assert_eq!(variables(&render(&entries)), [pair("A", "has space"), pair("B", "it's")]);
+    }
+
+    #[test]
+    fn env_file_names() {
+        for name in [".env", ".env.local", ".env.example", "prod.env"] {
+            assert!(is_env_file_name(name), "{}", name);
+        }
+        for name in [".env.tmp", ".env.swp", ".env.local.bak", ".env~", "prod.env.orig", "environment.ts", ".envrc"] {
+            assert!(!is_env_file_name(name), "{}", name);
+        }
+    }
+}
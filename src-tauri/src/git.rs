@@ -1,6 +1,12 @@
 use crate::config::GitToken;
 use serde::{Deserialize, Serialize};
-use std::process::Command;
+use regex::Regex;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -9,22 +15,85 @@ pub struct GitStatusResult {
     pub branch: Option<String>,
     pub remote: Option<String>,
     pub behind_count: u32,
+    /// Local commits not on the remote branch yet
+    pub ahead_count: u32,
+    pub staged_count: u32,
+    /// Tracked files with unstaged changes
+    pub modified_count: u32,
+    pub untracked_count: u32,
+    pub conflicted_count: u32,
+    /// Lines changed in the working tree and index against HEAD
+    pub insertions: u32,
+    pub deletions: u32,
+    pub last_commit: Option<GitCommitInfo>,
+    /// Submodules that need attention; empty when all are checked out at the recorded commit
+    #[serde(default)]
+    pub submodules: Vec<SubmoduleStatus>,
     pub error: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmoduleStatus {
+    /// Relative to the repository root
+    pub path: String,
+    /// "uninitialized", "out-of-date" (checked out commit isn't the one the parent records),
+    /// "conflict" or "ok"
+    pub state: String,
+    /// Uncommitted changes inside the submodule
+    pub dirty: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitCommitInfo {
+    pub hash: String,
+    pub author: String,
+    pub subject: String,
+    /// Unix seconds
+    pub timestamp: i64,
+    /// e.g. "3 hours ago"
+    pub relative_time: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GitPullResult {
     pub success: bool,
     pub message: String,
     pub commits_pulled: u32,
+    /// Install/migration hints from the files the pull changed
+    #[serde(skip_deserializing)]
+    pub dependency_changes: Option<crate::deps::DependencyChanges>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitOperationResult {
+    pub success: bool,
+    pub message: String,
+}
+
+/// Name of the remote to fetch and push: "origin" when there is one, otherwise the first
+fn remote_name(path: &str) -> Option<String> {
+    let remotes = git_output(path, &["remote"])?;
+    let mut names = remotes.lines().map(str::trim).filter(|r| !r.is_empty());
+    let first = names.next()?;
+    Some(
+        std::iter::once(first)
+            .chain(names)
+            .find(|r| *r == "origin")
+            .unwrap_or(first)
+            .to_string(),
+    )
 }
 
 /// Get the remote URL for a git repository
 fn get_remote_url(path: &str) -> Option<String> {
+    let remote = remote_name(path)?;
     Command::new("git")
         .current_dir(path)
-        .args(["remote", "get-url", "origin"])
+        .args(["remote", "get-url", &remote])
         .output()
         .ok()
         .and_then(|o| {
@@ -53,6 +122,7 @@ fn pattern_matches(pattern: &str, url: &str) -> bool {
     let normalized_url = url
         .replace("https://", "")
         .replace("http://", "")
+        .replace("ssh://", "")
         .replace("git@", "")
         .replace(".git", "")
         .replace(":", "/"); // for SSH URLs like git@github.com:org/repo
@@ -76,40 +146,141 @@ fn pattern_matches(pattern: &str, url: &str) -> bool {
     }
 }
 
-/// Find the best matching token for a remote URL
-fn find_matching_token<'a>(remote_url: &str, tokens: &'a [GitToken]) -> Option<&'a str> {
+/// Find the best matching token entry for a remote URL
+fn find_matching_entry<'a>(remote_url: &str, tokens: &'a [GitToken]) -> Option<&'a GitToken> {
     // First try to find a specific match (non-wildcard patterns first)
     for token in tokens.iter().filter(|t| t.pattern != "*") {
         if pattern_matches(&token.pattern, remote_url) {
-            return Some(&token.token);
+            return Some(token);
         }
     }
     // Then fall back to wildcard if exists
-    for token in tokens.iter().filter(|t| t.pattern == "*") {
-        return Some(&token.token);
+    tokens.iter().find(|t| t.pattern == "*")
+}
+
+/// Find the best matching token for a remote URL
+pub(crate) fn find_matching_token<'a>(remote_url: &str, tokens: &'a [GitToken]) -> Option<&'a str> {
+    find_matching_entry(remote_url, tokens).map(|t| t.token.as_str())
+}
+
+/// ssh:// URLs and scp-style remotes like git@github.com:org/repo
+fn is_ssh_url(url: &str) -> bool {
+    if url.starts_with("ssh://") || url.starts_with("git+ssh://") {
+        return true;
     }
-    None
+    // A one-letter "host" is a Windows drive (C:\repo), not a remote
+    !url.contains("://")
+        && url
+            .split_once(':')
+            .is_some_and(|(host, _)| host.len() > 1 && !host.contains('/') && !host.contains('\\'))
 }
 
-/// Internal fetch function - handles authentication via token
-fn git_fetch_internal(path: &str, token: Option<&str>) -> Result<(), String> {
-    let mut cmd = Command::new("git");
-    cmd.current_dir(path);
+/// How to authenticate against a repository's remote
+struct RemoteAuth {
+    token: Option<String>,
+    /// Private key from the matching token entry, used instead of the SSH agent
+    ssh_key: Option<String>,
+    ssh: bool,
+}
+
+fn remote_auth(path: &str, tokens: &[GitToken]) -> RemoteAuth {
+    url_auth(get_remote_url(path), tokens)
+}
+
+fn url_auth(remote_url: Option<String>, tokens: &[GitToken]) -> RemoteAuth {
+    let entry = remote_url.as_ref().and_then(|url| find_matching_entry(url, tokens));
+    RemoteAuth {
+        token: entry.map(|t| crate::secrets::resolve(&t.token)).filter(|t| !t.is_empty()),
+        ssh_key: entry
+            .and_then(|t| t.ssh_key.as_deref())
+            .map(|k| k.trim().to_string())
+            .filter(|k| !k.is_empty()),
+        ssh: remote_url.as_deref().is_some_and(is_ssh_url),
+    }
+}
+
+/// Configure authentication (or disable prompts) for a git command that talks to a remote.
+/// Must be called before the subcommand's arguments are added.
+fn apply_auth(cmd: &mut Command, auth: &RemoteAuth) {
+    if auth.ssh {
+        // Without a key, ssh uses the agent and ~/.ssh/config as usual
+        if let Some(key) = &auth.ssh_key {
+            // Runs through git's shell (sh on Windows too); ssh expands a leading ~ itself
+            let ssh_command = format!(
+                "ssh -i {} -o IdentitiesOnly=yes -o BatchMode=yes",
+                crate::elevation::shell_quote(&key.replace('\\', "/"))
+            );
+            cmd.args(["-c", &format!("core.sshCommand={}", ssh_command)]);
+        }
+        cmd.env("GIT_TERMINAL_PROMPT", "0");
+        return;
+    }
 
     // If token provided, use it for authentication
     // This works for GitHub/GitLab with PATs
-    if let Some(t) = token {
-        // Use credential helper approach
-        cmd.env("GIT_ASKPASS", "/bin/echo");
-        cmd.env("GIT_TERMINAL_PROMPT", "0");
-        // For HTTPS repos, set credentials in the URL isn't ideal
-        // Instead, use the GIT_CREDENTIAL environment
-        cmd.env("GIT_USERNAME", "x-access-token");
-        cmd.env("GIT_PASSWORD", t);
-    } else {
-        // Disable prompts for non-authenticated requests
-        cmd.env("GIT_TERMINAL_PROMPT", "0");
+    if let Some(t) = auth.token.as_deref() {
+        // An inline credential helper answers git with the token from the environment, so it
+        // never shows up in the process list. The empty helper first drops configured ones.
+        cmd.args(["-c", "credential.helper=", "-c", TOKEN_CREDENTIAL_HELPER]);
+        cmd.env("DEVLAUNCH_GIT_TOKEN", t);
+    }
+    // Fail instead of waiting on a prompt nobody sees
+    cmd.env("GIT_TERMINAL_PROMPT", "0");
+}
+
+/// `credential.helper` that hands git the token in DEVLAUNCH_GIT_TOKEN (runs through git's shell)
+const TOKEN_CREDENTIAL_HELPER: &str =
+    "credential.helper=!f() { test \"$1\" = get && echo username=x-access-token && echo \"password=$DEVLAUNCH_GIT_TOKEN\"; }; f";
+
+/// Why the SSH agent can't help, from `ssh-add -l` (exit 1: no identities, 2: no agent)
+fn ssh_agent_problem() -> Option<String> {
+    let output = Command::new("ssh-add").arg("-l").output().ok()?;
+    match output.status.code()? {
+        1 => Some("SSH authentication failed: the SSH agent has no identities. Run `ssh-add` to load your key, or set an SSH key for this remote in Preferences.".to_string()),
+        2 => Some("SSH authentication failed: no SSH agent is running. Start ssh-agent and add your key, or set an SSH key for this remote in Preferences.".to_string()),
+        _ => None,
+    }
+}
+
+/// User-facing message when git stderr indicates missing or rejected credentials
+fn auth_error(auth: &RemoteAuth, stderr: &str) -> Option<String> {
+    if !auth.ssh {
+        let is_auth = stderr.contains("Authentication")
+            || stderr.contains("could not read Username")
+            || stderr.contains("terminal prompts disabled");
+        return is_auth.then(|| "Authentication required. Add a Git token in Preferences.".to_string());
+    }
+
+    if stderr.contains("Host key verification failed") {
+        return Some("SSH host key is not trusted yet. Connect once from a terminal (e.g. `ssh -T git@github.com`) to accept it.".to_string());
+    }
+    if let Some(key) = &auth.ssh_key {
+        if stderr.contains("No such file") || stderr.contains("no such identity") {
+            return Some(format!("SSH key not found: {}", key));
+        }
+        if stderr.contains("passphrase") || stderr.contains("Load key") || stderr.contains("load key") {
+            return Some(format!(
+                "SSH key {} needs a passphrase. Add it to your SSH agent with `ssh-add {}` and clear the key in Preferences.",
+                key, key
+            ));
+        }
     }
+    if stderr.contains("Permission denied") {
+        return Some(match &auth.ssh_key {
+            Some(key) => format!("SSH key {} was rejected by the remote. Check that it's added to your account.", key),
+            None => ssh_agent_problem().unwrap_or_else(|| {
+                "SSH authentication failed. None of the SSH agent's keys were accepted; set an SSH key for this remote in Preferences.".to_string()
+            }),
+        });
+    }
+    None
+}
+
+/// Internal fetch function - handles authentication via token or SSH
+fn git_fetch_internal(path: &str, auth: &RemoteAuth) -> Result<(), String> {
+    let mut cmd = Command::new("git");
+    cmd.current_dir(path);
+    apply_auth(&mut cmd, auth);
 
     cmd.args(["fetch", "--quiet"]);
 
@@ -121,11 +292,8 @@ fn git_fetch_internal(path: &str, token: Option<&str>) -> Result<(), String> {
         Ok(())
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        if stderr.contains("Authentication")
-            || stderr.contains("could not read Username")
-            || stderr.contains("terminal prompts disabled")
-        {
-            Err("Authentication required. Add a Git token in Preferences.".to_string())
+        if let Some(message) = auth_error(auth, &stderr) {
+            Err(message)
         } else if stderr.is_empty() {
             // Sometimes fetch fails silently (e.g., no network)
             Ok(()) // Treat as success - we just won't have updated refs
@@ -135,30 +303,309 @@ fn git_fetch_internal(path: &str, token: Option<&str>) -> Result<(), String> {
     }
 }
 
+lazy_static::lazy_static! {
+    // Latest behind count per project from git_status, for the metrics endpoint
+    static ref BEHIND_COUNTS: std::sync::Mutex<HashMap<String, u32>> = std::sync::Mutex::new(HashMap::new());
+    // Latest status per project, from polling or git_status calls
+    static ref STATUS_CACHE: std::sync::Mutex<HashMap<String, CachedGitStatus>> = std::sync::Mutex::new(HashMap::new());
+    // `git clone --progress` lines like "Receiving objects:  45% (450/1000), 1.20 MiB | 2.00 MiB/s"
+    static ref CLONE_PROGRESS_REGEX: Regex = Regex::new(r"^(?:remote: )?([A-Za-z ]+):\s+(\d+)% \((\d+)/(\d+)\)").unwrap();
+}
+
+/// Projects checked at once while polling or running batch commands
+const BATCH_CONCURRENCY: usize = 5;
+/// Wait after startup before the first poll, so launching isn't slowed down
+const POLL_STARTUP_DELAY_SECS: u64 = 5;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedGitStatus {
+    pub path: String,
+    pub status: GitStatusResult,
+    /// Unix millis
+    pub checked_at: i64,
+}
+
+/// Outcome of a batch git command for one repo
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoResult<T> {
+    pub path: String,
+    pub result: Option<T>,
+    pub error: Option<String>,
+}
+
+impl<T> RepoResult<T> {
+    fn new(path: &str, outcome: Result<T, String>) -> Self {
+        let (result, error) = match outcome {
+            Ok(result) => (Some(result), None),
+            Err(error) => (None, Some(error)),
+        };
+        RepoResult {
+            path: path.to_string(),
+            result,
+            error,
+        }
+    }
+}
+
+/// Which watched projects git_pull_all pulls
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullAllFilter {
+    /// Only these project paths; all watched git repos when unset
+    #[serde(default)]
+    pub paths: Option<Vec<String>>,
+    /// Skip repos the last status check didn't find behind their remote
+    #[serde(default)]
+    pub only_behind: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullAllResult {
+    pub pulled: usize,
+    pub up_to_date: usize,
+    pub failed: usize,
+    pub repos: Vec<RepoResult<GitPullResult>>,
+}
+
+/// Behind counts recorded by the most recent git_status call for each project
+pub fn behind_counts() -> Vec<(String, u32)> {
+    BEHIND_COUNTS
+        .lock()
+        .map(|c| c.iter().map(|(path, count)| (path.clone(), *count)).collect())
+        .unwrap_or_default()
+}
+
+fn cache_status(path: &str, status: &GitStatusResult) -> CachedGitStatus {
+    let cached = CachedGitStatus {
+        path: path.to_string(),
+        status: status.clone(),
+        checked_at: chrono::Utc::now().timestamp_millis(),
+    };
+    if let Ok(mut cache) = STATUS_CACHE.lock() {
+        cache.insert(path.to_string(), cached.clone());
+    }
+    cached
+}
+
+/// Project paths found in the watched directories
+async fn watched_projects(dirs: Vec<String>) -> Vec<String> {
+    tokio::task::spawn_blocking(move || {
+        crate::projects::scan_projects_internal(&dirs, crate::projects::DEFAULT_SCAN_DEPTH)
+    })
+    .await
+    .unwrap_or_default()
+    .into_iter()
+    .map(|p| p.path)
+    .collect()
+}
+
+/// Run `op` on blocking threads for each path, at most BATCH_CONCURRENCY at once, passing
+/// each outcome to `on_done` as it finishes
+async fn for_each_concurrent<T, F>(paths: &[String], op: F, mut on_done: impl FnMut(&str, T))
+where
+    T: Send + 'static,
+    F: Fn(&str) -> T + Send + Sync + 'static,
+{
+    let op = std::sync::Arc::new(op);
+    let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(BATCH_CONCURRENCY));
+    let mut tasks = tokio::task::JoinSet::new();
+    for path in paths.iter().cloned() {
+        let op = op.clone();
+        let permits = permits.clone();
+        tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            tokio::task::spawn_blocking(move || {
+                let outcome = op(&path);
+                (path, outcome)
+            })
+            .await
+        });
+    }
+    while let Some(done) = tasks.join_next().await {
+        if let Ok(Ok((path, outcome))) = done {
+            on_done(&path, outcome);
+        }
+    }
+}
+
+/// Top-level directory of the repository `path` is in, if any
+fn repo_root(path: &str) -> Option<String> {
+    git_output(path, &["rev-parse", "--show-toplevel"]).filter(|r| !r.is_empty())
+}
+
+/// Check every project in the watched directories, emitting `git-status-updated` per project
+async fn poll_all(app: &AppHandle, dirs: Vec<String>, tokens: Vec<GitToken>) {
+    let paths = watched_projects(dirs).await;
+    println!("[git] Polling {} projects", paths.len());
+
+    for_each_concurrent(
+        &paths,
+        move |path| git_status_internal(path, &tokens).ok().map(|status| cache_status(path, &status)),
+        |_, cached| {
+            if let Some(cached) = cached {
+                let _ = app.emit("git-status-updated", cached);
+            }
+        },
+    )
+    .await;
+}
+
+/// Poll git status for all watched projects on the configured interval. Runs in the backend
+/// so it keeps going while the window is hidden; settings are re-read every minute.
+pub fn start_polling(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(POLL_STARTUP_DELAY_SECS)).await;
+        let mut last_poll: Option<Instant> = None;
+        loop {
+            if let Ok(config) = crate::config::load_config_internal(&app) {
+                let git = config.preferences.git;
+                let interval = Duration::from_secs(git.polling_interval_minutes as u64 * 60);
+                // Interval 0 means only check on startup
+                let due = match last_poll {
+                    None => true,
+                    Some(at) => git.polling_interval_minutes > 0 && at.elapsed() >= interval,
+                };
+                if git.enabled && due {
+                    last_poll = Some(Instant::now());
+                    poll_all(&app, config.watched_dirs, git.tokens).await;
+                }
+            }
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        }
+    });
+}
+
+/// Latest cached status of every project that has been checked
+#[tauri::command]
+pub async fn get_git_statuses() -> Result<Vec<CachedGitStatus>, String> {
+    let cache = STATUS_CACHE.lock().map_err(|e| e.to_string())?;
+    Ok(cache.values().cloned().collect())
+}
+
+/// Check git status of every watched git repo at once, emitting `git-status-updated` per repo
+#[tauri::command]
+pub async fn git_status_all(app: AppHandle) -> Result<Vec<RepoResult<GitStatusResult>>, String> {
+    let config = crate::config::load_config_internal(&app)?;
+    let tokens = config.preferences.git.tokens;
+    let paths = watched_projects(config.watched_dirs).await;
+
+    let mut repos = Vec::new();
+    for_each_concurrent(
+        &paths,
+        move |path| git_status_internal(path, &tokens).map(|status| cache_status(path, &status)),
+        |path, outcome| {
+            if let Ok(cached) = &outcome {
+                if !cached.status.is_git_repo {
+                    return;
+                }
+                let _ = app.emit("git-status-updated", cached.clone());
+            }
+            repos.push(RepoResult::new(path, outcome.map(|c| c.status)));
+        },
+    )
+    .await;
+    println!("[git] Checked {} repos", repos.len());
+    Ok(repos)
+}
+
+/// Pull (fast-forward only) every watched git repo matching the filter at once. Repos with
+/// uncommitted changes or diverged history are reported as failed rather than touched.
+#[tauri::command]
+pub async fn git_pull_all(app: AppHandle, filter: Option<PullAllFilter>) -> Result<PullAllResult, String> {
+    let filter = filter.unwrap_or_default();
+    let config = crate::config::load_config_internal(&app)?;
+    let tokens = config.preferences.git.tokens;
+    let behind: HashMap<String, u32> = behind_counts().into_iter().collect();
+
+    let candidates: Vec<String> = match filter.paths {
+        Some(paths) => paths,
+        None => watched_projects(config.watched_dirs).await,
+    }
+    .into_iter()
+    .filter(|p| !filter.only_behind || behind.get(p).map(|&n| n > 0).unwrap_or(false))
+    .collect();
+    // Same repos git_status_all reports on, but workspace packages sharing one repo pull it once
+    let paths: Vec<String> = tokio::task::spawn_blocking(move || {
+        let mut roots = std::collections::HashSet::new();
+        candidates
+            .into_iter()
+            .filter(|p| repo_root(p).is_some_and(|root| roots.insert(root)))
+            .collect()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+    println!("[git] Pulling {} repos", paths.len());
+
+    let mut result = PullAllResult {
+        pulled: 0,
+        up_to_date: 0,
+        failed: 0,
+        repos: Vec::new(),
+    };
+    for_each_concurrent(
+        &paths,
+        move |path| git_pull_internal(path, &tokens),
+        |path, outcome| {
+            match &outcome {
+                Ok(pull) if pull.success && pull.commits_pulled > 0 => result.pulled += 1,
+                Ok(pull) if pull.success => result.up_to_date += 1,
+                _ => result.failed += 1,
+            }
+            result.repos.push(RepoResult::new(path, outcome));
+        },
+    )
+    .await;
+    println!(
+        "[git] Pulled {}, {} up to date, {} failed",
+        result.pulled, result.up_to_date, result.failed
+    );
+    Ok(result)
+}
+
 /// Check git status for a project directory
 #[tauri::command]
 pub async fn git_status(path: String, tokens: Vec<GitToken>) -> Result<GitStatusResult, String> {
+    let result = git_status_internal(&path, &tokens)?;
+    cache_status(&path, &result);
+    Ok(result)
+}
+
+fn git_status_internal(path: &str, tokens: &[GitToken]) -> Result<GitStatusResult, String> {
     // 1. Check if it's a git repo
     let is_repo = Command::new("git")
-        .current_dir(&path)
+        .current_dir(path)
         .args(["rev-parse", "--is-inside-work-tree"])
         .output()
         .map(|o| o.status.success())
         .unwrap_or(false);
 
+    let mut result = GitStatusResult {
+        is_git_repo: is_repo,
+        branch: None,
+        remote: None,
+        behind_count: 0,
+        ahead_count: 0,
+        staged_count: 0,
+        modified_count: 0,
+        untracked_count: 0,
+        conflicted_count: 0,
+        insertions: 0,
+        deletions: 0,
+        last_commit: None,
+        submodules: Vec::new(),
+        error: None,
+    };
     if !is_repo {
-        return Ok(GitStatusResult {
-            is_git_repo: false,
-            branch: None,
-            remote: None,
-            behind_count: 0,
-            error: None,
-        });
+        return Ok(result);
     }
 
     // 2. Get current branch
     let branch = Command::new("git")
-        .current_dir(&path)
+        .current_dir(path)
         .args(["rev-parse", "--abbrev-ref", "HEAD"])
         .output()
         .ok()
@@ -173,80 +620,269 @@ pub async fn git_status(path: String, tokens: Vec<GitToken>) -> Result<GitStatus
         .filter(|s| !s.is_empty() && s != "HEAD"); // HEAD means detached state
 
     // 3. Get remote name (usually "origin")
-    let remote = Command::new("git")
-        .current_dir(&path)
-        .args(["remote"])
-        .output()
-        .ok()
-        .and_then(|o| {
-            if o.status.success() {
-                String::from_utf8(o.stdout).ok()
-            } else {
-                None
-            }
-        })
-        .and_then(|s| s.lines().next().map(|l| l.trim().to_string()))
-        .filter(|s| !s.is_empty());
+    let remote = remote_name(path);
 
-    // 4. If no remote, we can't check behind count
+    // 4. Local state: dirty files, diff stats and the last commit
+    apply_working_tree_summary(path, &mut result);
+    result.last_commit = last_commit(path);
+    result.submodules = submodule_status(path);
+
+    // If no remote, we can't check behind/ahead counts
     if remote.is_none() {
-        return Ok(GitStatusResult {
-            is_git_repo: true,
-            branch,
-            remote: None,
-            behind_count: 0,
-            error: None,
-        });
+        result.branch = branch;
+        return Ok(result);
     }
 
-    // 5. Find matching token based on remote URL
-    let remote_url = get_remote_url(&path);
-    let token = remote_url
-        .as_ref()
-        .and_then(|url| find_matching_token(url, &tokens));
+    // 5. Find matching token/SSH key based on remote URL
+    let auth = remote_auth(path, tokens);
 
     // 6. Fetch from remote (with optional token for auth)
-    let fetch_error = match git_fetch_internal(&path, token) {
-        Ok(()) => None,
-        Err(e) => Some(e),
-    };
+    let fetch_error = git_fetch_internal(path, &auth).err();
 
-    // 6. Count commits behind (even if fetch failed, use cached refs)
-    let behind_count = if let (Some(ref b), Some(ref r)) = (&branch, &remote) {
-        let remote_branch = format!("{}/{}", r, b);
-        Command::new("git")
-            .current_dir(&path)
-            .args(["rev-list", "--count", &format!("HEAD..{}", remote_branch)])
-            .output()
-            .ok()
-            .and_then(|o| {
-                if o.status.success() {
-                    String::from_utf8(o.stdout).ok()
-                } else {
-                    None
-                }
+    // 6. Count commits behind/ahead (even if fetch failed, use cached refs)
+    let (ahead_count, behind_count) = if let (Some(ref b), Some(ref r)) = (&branch, &remote) {
+        // "<ahead>\t<behind>" relative to the remote branch
+        git_output(path, &["rev-list", "--left-right", "--count", &format!("HEAD...{}/{}", r, b)])
+            .and_then(|s| {
+                let mut counts = s.split_whitespace().map(|n| n.parse::<u32>().ok());
+                Some((counts.next()??, counts.next()??))
             })
-            .and_then(|s| s.trim().parse::<u32>().ok())
-            .unwrap_or(0)
+            .unwrap_or((0, 0))
     } else {
-        0
+        (0, 0)
     };
 
-    Ok(GitStatusResult {
-        is_git_repo: true,
-        branch,
-        remote,
-        behind_count,
-        error: fetch_error,
+    if let Ok(mut counts) = BEHIND_COUNTS.lock() {
+        counts.insert(path.to_string(), behind_count);
+    }
+
+    result.branch = branch;
+    result.remote = remote;
+    result.behind_count = behind_count;
+    result.ahead_count = ahead_count;
+    result.error = fetch_error;
+    Ok(result)
+}
+
+/// Count staged, modified, untracked and conflicted files, plus line changes against HEAD
+fn apply_working_tree_summary(path: &str, result: &mut GitStatusResult) {
+    // Not git_output: trimming would eat the leading status column of the first line
+    let status = Command::new("git")
+        .current_dir(path)
+        .args(["status", "--porcelain"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).to_string());
+    if let Some(status) = status {
+        for line in status.lines().filter(|l| l.len() > 2) {
+            let mut codes = line.chars();
+            let (x, y) = (codes.next().unwrap_or(' '), codes.next().unwrap_or(' '));
+            match (x, y) {
+                ('?', '?') => result.untracked_count += 1,
+                ('U', _) | (_, 'U') | ('A', 'A') | ('D', 'D') => result.conflicted_count += 1,
+                _ => {
+                    if x != ' ' {
+                        result.staged_count += 1;
+                    }
+                    if y != ' ' {
+                        result.modified_count += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    // e.g. " 3 files changed, 10 insertions(+), 2 deletions(-)"
+    if let Some(stat) = git_output(path, &["diff", "HEAD", "--shortstat"]) {
+        for part in stat.split(',') {
+            let mut words = part.split_whitespace();
+            let count = words.next().and_then(|n| n.parse::<u32>().ok()).unwrap_or(0);
+            match words.next() {
+                Some(w) if w.starts_with("insertion") => result.insertions = count,
+                Some(w) if w.starts_with("deletion") => result.deletions = count,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Submodules (recursively) that are uninitialized, not at the recorded commit or dirty
+fn submodule_status(path: &str) -> Vec<SubmoduleStatus> {
+    if !Path::new(path).join(".gitmodules").exists() {
+        return Vec::new();
+    }
+    // Not git_output: trimming would eat the leading state column of the first line.
+    // Lines are "<state><sha> <path> (<describe>)", state being ' ', '-', '+' or 'U'.
+    let listing = Command::new("git")
+        .current_dir(path)
+        .args(["submodule", "status", "--recursive"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+        .unwrap_or_default();
+
+    listing
+        .lines()
+        .filter_map(|line| {
+            let state = match line.chars().next()? {
+                '-' => "uninitialized",
+                '+' => "out-of-date",
+                'U' => "conflict",
+                _ => "ok",
+            };
+            let sub_path = line.get(1..)?.split_whitespace().nth(1)?.to_string();
+            let dirty = state != "uninitialized"
+                && git_output(&Path::new(path).join(&sub_path).to_string_lossy(), &["status", "--porcelain"])
+                    .map(|s| !s.is_empty())
+                    .unwrap_or(false);
+            (state != "ok" || dirty).then(|| SubmoduleStatus {
+                path: sub_path,
+                state: state.to_string(),
+                dirty,
+            })
+        })
+        .collect()
+}
+
+/// `git log --format` for parse_commit
+const COMMIT_FORMAT: &str = "%h%x00%an%x00%ct%x00%cr%x00%s";
+
+fn parse_commit(line: &str) -> Option<GitCommitInfo> {
+    let fields: Vec<&str> = line.splitn(5, '\0').collect();
+    if fields.len() < 5 {
+        return None;
+    }
+    Some(GitCommitInfo {
+        hash: fields[0].to_string(),
+        author: fields[1].to_string(),
+        timestamp: fields[2].parse().unwrap_or(0),
+        relative_time: fields[3].to_string(),
+        subject: fields[4].to_string(),
+    })
+}
+
+fn last_commit(path: &str) -> Option<GitCommitInfo> {
+    parse_commit(&git_output(path, &["log", "-1", &format!("--format={}", COMMIT_FORMAT)])?)
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogCommit {
+    #[serde(flatten)]
+    pub commit: GitCommitInfo,
+    pub full_hash: String,
+    /// Reachable from a remote-tracking branch
+    pub pushed: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitFileStat {
+    pub path: String,
+    /// None for binary files
+    pub insertions: Option<u32>,
+    pub deletions: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitDetails {
+    #[serde(flatten)]
+    pub commit: GitCommitInfo,
+    pub full_hash: String,
+    /// Message after the subject line
+    pub body: String,
+    pub files: Vec<CommitFileStat>,
+    pub insertions: u32,
+    pub deletions: u32,
+}
+
+/// Default and maximum number of commits returned by git_log
+const DEFAULT_LOG_LIMIT: usize = 20;
+const MAX_LOG_LIMIT: usize = 500;
+
+/// Recent commits on the current branch, newest first, with whether each has been pushed
+#[tauri::command]
+pub async fn git_log(path: String, limit: Option<usize>) -> Result<Vec<LogCommit>, String> {
+    let limit = limit.unwrap_or(DEFAULT_LOG_LIMIT).clamp(1, MAX_LOG_LIMIT);
+    let log = git_output(
+        &path,
+        &["log", &format!("-{}", limit), &format!("--format=%H%x00{}", COMMIT_FORMAT)],
+    )
+    .ok_or_else(|| format!("No commits found in {}", path))?;
+    // Commits no remote-tracking branch contains; without remotes that's all of them
+    let unpushed: Vec<String> = git_output(&path, &["rev-list", &format!("-{}", limit), "HEAD", "--not", "--remotes"])
+        .unwrap_or_default()
+        .lines()
+        .map(|l| l.to_string())
+        .collect();
+
+    Ok(log
+        .lines()
+        .filter_map(|line| {
+            let (full_hash, rest) = line.split_once('\0')?;
+            Some(LogCommit {
+                commit: parse_commit(rest)?,
+                full_hash: full_hash.to_string(),
+                pushed: !unpushed.iter().any(|h| h == full_hash),
+            })
+        })
+        .collect())
+}
+
+/// One commit's message and per-file change stats
+#[tauri::command]
+pub async fn git_show(path: String, hash: String) -> Result<CommitDetails, String> {
+    // Resolve first so only a real commit id reaches the other commands
+    if hash.starts_with('-') {
+        return Err(format!("Unknown commit: {}", hash));
+    }
+    let full_hash = git_output(&path, &["rev-parse", "--verify", "--quiet", &format!("{}^{{commit}}", hash)])
+        .filter(|h| !h.is_empty())
+        .ok_or_else(|| format!("Unknown commit: {}", hash))?;
+    let commit = git_output(&path, &["show", "-s", &format!("--format={}", COMMIT_FORMAT), &full_hash])
+        .and_then(|line| parse_commit(&line))
+        .ok_or_else(|| format!("Failed to read commit {}", hash))?;
+    let body = git_output(&path, &["show", "-s", "--format=%b", &full_hash]).unwrap_or_default();
+
+    // "<insertions>\t<deletions>\t<path>", with "-" counts for binary files
+    let numstat = git_output(&path, &["show", "--numstat", "--format=", &full_hash]).unwrap_or_default();
+    let files: Vec<CommitFileStat> = numstat
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            let insertions = parts.next()?.parse().ok();
+            let deletions = parts.next()?.parse().ok();
+            Some(CommitFileStat {
+                path: parts.next()?.to_string(),
+                insertions,
+                deletions,
+            })
+        })
+        .collect();
+
+    Ok(CommitDetails {
+        insertions: files.iter().filter_map(|f| f.insertions).sum(),
+        deletions: files.iter().filter_map(|f| f.deletions).sum(),
+        commit,
+        full_hash,
+        body,
+        files,
     })
 }
 
 /// Pull updates from remote
 #[tauri::command]
 pub async fn git_pull(path: String, tokens: Vec<GitToken>) -> Result<GitPullResult, String> {
+    git_pull_internal(&path, &tokens)
+}
+
+fn git_pull_internal(path: &str, tokens: &[GitToken]) -> Result<GitPullResult, String> {
     // First, check if there are uncommitted changes
     let status_output = Command::new("git")
-        .current_dir(&path)
+        .current_dir(path)
         .args(["status", "--porcelain"])
         .output()
         .map_err(|e| format!("Failed to check status: {}", e))?;
@@ -257,12 +893,13 @@ pub async fn git_pull(path: String, tokens: Vec<GitToken>) -> Result<GitPullResu
             success: false,
             message: "Cannot pull: uncommitted changes exist. Please commit or stash your changes first.".to_string(),
             commits_pulled: 0,
+            dependency_changes: None,
         });
     }
 
     // Get current HEAD before pull
     let before_head = Command::new("git")
-        .current_dir(&path)
+        .current_dir(path)
         .args(["rev-parse", "HEAD"])
         .output()
         .ok()
@@ -275,24 +912,13 @@ pub async fn git_pull(path: String, tokens: Vec<GitToken>) -> Result<GitPullResu
         })
         .map(|s| s.trim().to_string());
 
-    // Find matching token based on remote URL
-    let remote_url = get_remote_url(&path);
-    let token = remote_url
-        .as_ref()
-        .and_then(|url| find_matching_token(url, &tokens));
+    // Find matching token/SSH key based on remote URL
+    let auth = remote_auth(path, tokens);
 
     // Perform pull
     let mut cmd = Command::new("git");
-    cmd.current_dir(&path);
-
-    if let Some(t) = token {
-        cmd.env("GIT_ASKPASS", "/bin/echo");
-        cmd.env("GIT_TERMINAL_PROMPT", "0");
-        cmd.env("GIT_USERNAME", "x-access-token");
-        cmd.env("GIT_PASSWORD", t);
-    } else {
-        cmd.env("GIT_TERMINAL_PROMPT", "0");
-    }
+    cmd.current_dir(path);
+    apply_auth(&mut cmd, &auth);
 
     cmd.args(["pull", "--ff-only"]);
 
@@ -306,19 +932,20 @@ pub async fn git_pull(path: String, tokens: Vec<GitToken>) -> Result<GitPullResu
             success: false,
             message: if stderr.contains("Not possible to fast-forward") {
                 "Cannot fast-forward. You may have local commits that diverge from remote.".to_string()
-            } else if stderr.contains("Authentication") || stderr.contains("terminal prompts disabled") {
-                "Authentication required. Add a Git token in Preferences.".to_string()
+            } else if let Some(message) = auth_error(&auth, &stderr) {
+                message
             } else {
                 stderr.trim().to_string()
             },
             commits_pulled: 0,
+            dependency_changes: None,
         });
     }
 
     // Count pulled commits
-    let commits_pulled = if let Some(before) = before_head {
+    let commits_pulled = if let Some(before) = &before_head {
         Command::new("git")
-            .current_dir(&path)
+            .current_dir(path)
             .args(["rev-list", "--count", &format!("{}..HEAD", before)])
             .output()
             .ok()
@@ -335,6 +962,16 @@ pub async fn git_pull(path: String, tokens: Vec<GitToken>) -> Result<GitPullResu
         0
     };
 
+    // Flag stale dependencies/migrations from the files the pull touched in this project
+    let dependency_changes = before_head.filter(|_| commits_pulled > 0).map(|before| {
+        let changed: Vec<String> = git_output(path, &["diff", "--name-only", "--relative", &before, "HEAD"])
+            .unwrap_or_default()
+            .lines()
+            .map(|l| l.to_string())
+            .collect();
+        crate::deps::dependency_changes(std::path::Path::new(path), &changed)
+    });
+
     Ok(GitPullResult {
         success: true,
         message: if commits_pulled > 0 {
@@ -343,5 +980,1035 @@ pub async fn git_pull(path: String, tokens: Vec<GitToken>) -> Result<GitPullResu
             "Already up to date".to_string()
         },
         commits_pulled,
+        dependency_changes,
+    })
+}
+
+/// git-clone-progress event payload
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CloneProgress {
+    url: String,
+    dest: String,
+    /// e.g. "Receiving objects", "Resolving deltas"
+    phase: String,
+    percent: u32,
+    current: u64,
+    total: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitCloneResult {
+    pub success: bool,
+    pub message: String,
+    pub path: String,
+    /// A watched directory was added so the clone shows up as a project
+    pub registered: bool,
+}
+
+/// Make a clone show up in scans: unless a watched directory already covers it, watch its parent
+fn register_clone(app: &AppHandle, dest: &Path) -> Result<bool, String> {
+    let mut config = crate::config::load_config_internal(app)?;
+    let covered = config.watched_dirs.iter().any(|dir| {
+        dest.strip_prefix(dir)
+            .map(|rel| rel.components().count() <= crate::projects::DEFAULT_SCAN_DEPTH + 1)
+            .unwrap_or(false)
+    });
+    if covered {
+        return Ok(false);
+    }
+    let Some(parent) = dest.parent() else {
+        return Ok(false);
+    };
+    config.watched_dirs.push(parent.to_string_lossy().to_string());
+    crate::config::save_config_internal(app, &config)?;
+    let _ = app.emit("config-changed", config);
+    println!("[git] Watching {} for {}", parent.display(), dest.display());
+    Ok(true)
+}
+
+fn git_clone_internal(app: &AppHandle, url: &str, dest: &str, tokens: &[GitToken]) -> Result<GitCloneResult, String> {
+    let failed = |message: String| GitCloneResult {
+        success: false,
+        message,
+        path: dest.to_string(),
+        registered: false,
+    };
+    // Anything starting with a dash would be read as an option (e.g. --upload-pack=<command>)
+    if url.trim().starts_with('-') {
+        return Ok(failed(format!("Invalid repository URL: {}", url)));
+    }
+    let dest_path = Path::new(dest);
+    if dest_path.read_dir().map(|mut d| d.next().is_some()).unwrap_or(false) {
+        return Ok(failed(format!("{} already exists and is not empty", dest)));
+    }
+    if let Some(parent) = dest_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+
+    let auth = url_auth(Some(url.to_string()), tokens);
+    let mut cmd = Command::new("git");
+    apply_auth(&mut cmd, &auth);
+    cmd.args(["clone", "--progress", "--", url, dest])
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+    let mut child = cmd.spawn().map_err(|e| format!("Failed to run git clone: {}", e))?;
+
+    // Progress lines end in \r while a phase is running; everything else is kept for errors
+    let mut stderr_text = String::new();
+    if let Some(stderr) = child.stderr.take() {
+        let mut last = (String::new(), u32::MAX);
+        let mut line = Vec::new();
+        let mut handle_line = |line: &[u8]| {
+            let text = String::from_utf8_lossy(line).trim().to_string();
+            let Some(caps) = CLONE_PROGRESS_REGEX.captures(&text) else {
+                if !text.is_empty() {
+                    stderr_text.push_str(&text);
+                    stderr_text.push('\n');
+                }
+                return;
+            };
+            let progress = CloneProgress {
+                url: url.to_string(),
+                dest: dest.to_string(),
+                phase: caps[1].trim().to_string(),
+                percent: caps[2].parse().unwrap_or(0),
+                current: caps[3].parse().unwrap_or(0),
+                total: caps[4].parse().unwrap_or(0),
+            };
+            // git redraws far more often than the percentage changes
+            if (progress.phase.as_str(), progress.percent) != (last.0.as_str(), last.1) {
+                last = (progress.phase.clone(), progress.percent);
+                let _ = app.emit("git-clone-progress", progress);
+            }
+        };
+        for byte in std::io::BufReader::new(stderr).bytes().map_while(Result::ok) {
+            if byte == b'\r' || byte == b'\n' {
+                handle_line(&line);
+                line.clear();
+            } else {
+                line.push(byte);
+            }
+        }
+        handle_line(&line);
+    }
+
+    let status = child.wait().map_err(|e| format!("Failed to run git clone: {}", e))?;
+    if !status.success() {
+        let message = auth_error(&auth, &stderr_text).unwrap_or_else(|| stderr_text.trim().to_string());
+        println!("[git] Clone of {} failed: {}", url, message);
+        return Ok(failed(message));
+    }
+    println!("[git] Cloned {} into {}", url, dest);
+    Ok(GitCloneResult {
+        success: true,
+        message: format!("Cloned into {}", dest),
+        path: dest.to_string(),
+        registered: false,
+    })
+}
+
+/// Clone a repository with the matching token/SSH key, emitting `git-clone-progress` as objects
+/// arrive. With `register`, the parent directory is watched unless the clone is already covered.
+#[tauri::command]
+pub async fn git_clone(
+    app: AppHandle,
+    url: String,
+    dest: String,
+    tokens: Vec<GitToken>,
+    register: Option<bool>,
+) -> Result<GitCloneResult, String> {
+    let clone_app = app.clone();
+    let mut result = tokio::task::spawn_blocking(move || git_clone_internal(&clone_app, &url, &dest, &tokens))
+        .await
+        .map_err(|e| e.to_string())??;
+    if result.success && register.unwrap_or(false) {
+        result.registered = register_clone(&app, Path::new(&result.path))?;
+    }
+    Ok(result)
+}
+
+/// Run a local (non-network) git command and turn its outcome into an operation result
+fn run_local_git(path: &str, args: &[&str]) -> Result<GitOperationResult, String> {
+    let output = Command::new("git")
+        .current_dir(path)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run git {}: {}", args.first().unwrap_or(&""), e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    Ok(GitOperationResult {
+        success: output.status.success(),
+        message: if output.status.success() || stderr.is_empty() {
+            stdout
+        } else {
+            stderr
+        },
+    })
+}
+
+/// Commit changes, optionally staging everything (including untracked files) first
+#[tauri::command]
+pub async fn git_commit(path: String, message: String, stage_all: bool) -> Result<GitOperationResult, String> {
+    if message.trim().is_empty() {
+        return Ok(GitOperationResult {
+            success: false,
+            message: "Commit message cannot be empty".to_string(),
+        });
+    }
+
+    if stage_all {
+        let staged = run_local_git(&path, &["add", "-A"])?;
+        if !staged.success {
+            return Ok(staged);
+        }
+    }
+
+    let result = run_local_git(&path, &["commit", "-m", &message])?;
+    if !result.success {
+        return Ok(GitOperationResult {
+            success: false,
+            message: if result.message.contains("nothing to commit")
+                || result.message.contains("no changes added")
+            {
+                "Nothing to commit".to_string()
+            } else {
+                result.message
+            },
+        });
+    }
+
+    let hash = git_output(&path, &["rev-parse", "--short", "HEAD"]).unwrap_or_default();
+    Ok(GitOperationResult {
+        success: true,
+        message: format!("Committed {}", hash),
+    })
+}
+
+/// Push the current branch, setting the upstream on first push
+#[tauri::command]
+pub async fn git_push(path: String, tokens: Vec<GitToken>) -> Result<GitOperationResult, String> {
+    let branch = git_output(&path, &["rev-parse", "--abbrev-ref", "HEAD"])
+        .filter(|b| !b.is_empty() && b != "HEAD")
+        .ok_or_else(|| "Cannot push from a detached HEAD".to_string())?;
+    let has_upstream = git_output(&path, &["rev-parse", "--abbrev-ref", "@{u}"]).is_some();
+    let remote = remote_name(&path).ok_or_else(|| "No remote to push to".to_string())?;
+
+    let auth = remote_auth(&path, &tokens);
+
+    let mut cmd = Command::new("git");
+    cmd.current_dir(&path);
+    apply_auth(&mut cmd, &auth);
+    if has_upstream {
+        cmd.args(["push"]);
+    } else {
+        cmd.args(["push", "-u", &remote, &branch]);
+    }
+
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run git push: {}", e))?;
+
+    if output.status.success() {
+        return Ok(GitOperationResult {
+            success: true,
+            message: format!("Pushed {}", branch),
+        });
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    Ok(GitOperationResult {
+        success: false,
+        message: if stderr.contains("[rejected]") || stderr.contains("non-fast-forward") {
+            "Push rejected: remote has commits you don't have. Pull first.".to_string()
+        } else if let Some(message) = auth_error(&auth, &stderr) {
+            message
+        } else {
+            stderr.trim().to_string()
+        },
+    })
+}
+
+/// Check out submodules at the commits the repository records, optionally initializing new
+/// ones and descending into nested submodules
+#[tauri::command]
+pub async fn git_submodule_update(
+    path: String,
+    init: bool,
+    recursive: bool,
+    tokens: Vec<GitToken>,
+) -> Result<GitOperationResult, String> {
+    // Submodule remotes usually live next to the parent's, so its credentials are used
+    let auth = remote_auth(&path, &tokens);
+
+    let mut cmd = Command::new("git");
+    cmd.current_dir(&path);
+    apply_auth(&mut cmd, &auth);
+    cmd.args(["submodule", "update"]);
+    if init {
+        cmd.arg("--init");
+    }
+    if recursive {
+        cmd.arg("--recursive");
+    }
+
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run git submodule update: {}", e))?;
+
+    if output.status.success() {
+        let remaining = submodule_status(&path).iter().filter(|s| s.state != "ok").count();
+        return Ok(GitOperationResult {
+            success: true,
+            message: if remaining > 0 {
+                format!("Submodules updated; {} still not checked out", remaining)
+            } else {
+                "Submodules updated".to_string()
+            },
+        });
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    Ok(GitOperationResult {
+        success: false,
+        message: auth_error(&auth, &stderr).unwrap_or_else(|| stderr.trim().to_string()),
+    })
+}
+
+/// Stash uncommitted changes (resolves the "uncommitted changes exist" pull blocker)
+#[tauri::command]
+pub async fn git_stash(
+    path: String,
+    message: Option<String>,
+    include_untracked: Option<bool>,
+) -> Result<GitOperationResult, String> {
+    let mut args = vec!["stash", "push"];
+    if include_untracked.unwrap_or(true) {
+        args.push("--include-untracked");
+    }
+    if let Some(m) = message.as_deref().filter(|m| !m.trim().is_empty()) {
+        args.push("-m");
+        args.push(m);
+    }
+
+    let result = run_local_git(&path, &args)?;
+    if result.success && result.message.contains("No local changes") {
+        return Ok(GitOperationResult {
+            success: false,
+            message: "No local changes to stash".to_string(),
+        });
+    }
+    Ok(result)
+}
+
+/// Re-apply the most recent stash
+#[tauri::command]
+pub async fn git_stash_pop(path: String) -> Result<GitOperationResult, String> {
+    let result = run_local_git(&path, &["stash", "pop"])?;
+    if !result.success && result.message.contains("CONFLICT") {
+        return Ok(GitOperationResult {
+            success: false,
+            message: "Stash applied with conflicts. Resolve them, then drop the stash manually.".to_string(),
+        });
+    }
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitBranch {
+    /// Short name, e.g. "main" or "origin/main"
+    pub name: String,
+    pub is_remote: bool,
+    pub is_current: bool,
+    pub upstream: Option<String>,
+    pub ahead: u32,
+    pub behind: u32,
+    /// Upstream was deleted on the remote
+    pub upstream_gone: bool,
+    pub last_commit: String,
+    pub last_commit_time: i64,
+}
+
+/// Parse `%(upstream:track,nobracket)` output like "ahead 2, behind 1" or "gone"
+fn parse_track(track: &str) -> (u32, u32, bool) {
+    if track.trim() == "gone" {
+        return (0, 0, true);
+    }
+    let mut ahead = 0;
+    let mut behind = 0;
+    for part in track.split(',') {
+        let mut words = part.split_whitespace();
+        match (words.next(), words.next().and_then(|n| n.parse().ok())) {
+            (Some("ahead"), Some(n)) => ahead = n,
+            (Some("behind"), Some(n)) => behind = n,
+            _ => {}
+        }
+    }
+    (ahead, behind, false)
+}
+
+/// List local and remote branches with ahead/behind counts against their upstreams.
+/// Counts reflect the last fetch (git_status fetches periodically).
+#[tauri::command]
+pub async fn git_branches(path: String) -> Result<Vec<GitBranch>, String> {
+    let output = Command::new("git")
+        .current_dir(&path)
+        .args([
+            "for-each-ref",
+            "--sort=-committerdate",
+            "--format=%(refname)%00%(refname:short)%00%(upstream:short)%00%(upstream:track,nobracket)%00%(HEAD)%00%(objectname:short)%00%(committerdate:unix)",
+            "refs/heads",
+            "refs/remotes",
+        ])
+        .output()
+        .map_err(|e| format!("Failed to run git for-each-ref: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let branches = stdout
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split('\0').collect();
+            if fields.len() < 7 {
+                return None;
+            }
+            // origin/HEAD is a pointer, not a branch
+            if fields[0].ends_with("/HEAD") {
+                return None;
+            }
+            let (ahead, behind, upstream_gone) = parse_track(fields[3]);
+            Some(GitBranch {
+                name: fields[1].to_string(),
+                is_remote: fields[0].starts_with("refs/remotes/"),
+                is_current: fields[4] == "*",
+                upstream: Some(fields[2].to_string()).filter(|u| !u.is_empty()),
+                ahead,
+                behind,
+                upstream_gone,
+                last_commit: fields[5].to_string(),
+                last_commit_time: fields[6].parse().unwrap_or(0),
+            })
+        })
+        .collect();
+
+    Ok(branches)
+}
+
+/// Switch to a branch. A remote branch ("origin/feature") without a local counterpart
+/// is checked out as a new tracking branch.
+#[tauri::command]
+pub async fn git_checkout(path: String, branch: String) -> Result<GitOperationResult, String> {
+    // A leading dash would be read as an option (e.g. -f discards uncommitted work)
+    if branch.starts_with('-') {
+        return Ok(GitOperationResult {
+            success: false,
+            message: format!("Invalid branch name: {}", branch),
+        });
+    }
+    let local_exists = git_output(&path, &["rev-parse", "--verify", "--quiet", &format!("refs/heads/{}", branch)]).is_some();
+    let remote_exists = git_output(&path, &["rev-parse", "--verify", "--quiet", &format!("refs/remotes/{}", branch)]).is_some();
+
+    let result = if !local_exists && remote_exists {
+        run_local_git(&path, &["checkout", "--track", &branch])?
+    } else {
+        run_local_git(&path, &["checkout", &branch])?
+    };
+
+    if !result.success {
+        return Ok(GitOperationResult {
+            success: false,
+            message: if result.message.contains("would be overwritten") {
+                "Uncommitted changes would be overwritten. Commit or stash them first.".to_string()
+            } else {
+                result.message
+            },
+        });
+    }
+
+    let current = git_output(&path, &["rev-parse", "--abbrev-ref", "HEAD"]).unwrap_or(branch);
+    Ok(GitOperationResult {
+        success: true,
+        message: format!("Switched to {}", current),
+    })
+}
+
+/// Create a branch from `from` (default: HEAD), switching to it unless `checkout` is false
+#[tauri::command]
+pub async fn git_create_branch(
+    path: String,
+    name: String,
+    from: Option<String>,
+    checkout: Option<bool>,
+) -> Result<GitOperationResult, String> {
+    if git_output(&path, &["check-ref-format", "--branch", &name]).is_none() {
+        return Ok(GitOperationResult {
+            success: false,
+            message: format!("Invalid branch name: {}", name),
+        });
+    }
+    if let Some(from) = from.as_deref().filter(|f| f.starts_with('-')) {
+        return Ok(GitOperationResult {
+            success: false,
+            message: format!("Invalid start point: {}", from),
+        });
+    }
+
+    let mut args = if checkout.unwrap_or(true) {
+        vec!["checkout", "-b", name.as_str()]
+    } else {
+        vec!["branch", name.as_str()]
+    };
+    if let Some(from) = from.as_deref().filter(|f| !f.is_empty()) {
+        args.push(from);
+    }
+
+    let result = run_local_git(&path, &args)?;
+    if !result.success {
+        return Ok(result);
+    }
+    Ok(GitOperationResult {
+        success: true,
+        message: format!("Created {}", name),
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitWorktree {
+    pub path: String,
+    pub head: Option<String>,
+    /// Short branch name; None when detached or bare
+    pub branch: Option<String>,
+    /// The original checkout (other worktrees share its .git)
+    pub is_main: bool,
+    /// The worktree `path` was passed for
+    pub is_current: bool,
+    pub detached: bool,
+    pub locked: bool,
+    /// Its directory is gone; `git worktree prune` would remove it
+    pub prunable: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitWorktreeAddResult {
+    pub worktree: GitWorktree,
+    /// The new checkout as a launchable project, if one was detected
+    pub project: Option<crate::projects::ProjectInfo>,
+    /// Directory to add to watched dirs when no watched dir covers the worktree yet
+    pub watch_dir: Option<String>,
+    /// .env files copied over from the source checkout
+    pub copied_env_files: Vec<String>,
+}
+
+/// Path components below a watched dir that the project scanner still picks up: its max
+/// depth plus the project folder itself
+const WATCHED_DIR_SCAN_DEPTH: usize = crate::projects::DEFAULT_SCAN_DEPTH + 1;
+
+fn canonical(path: &str) -> String {
+    std::fs::canonicalize(path)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| path.to_string())
+}
+
+/// Parse `git worktree list --porcelain` blocks
+fn parse_worktrees(output: &str, current: &str) -> Vec<GitWorktree> {
+    output
+        .split("\n\n")
+        .filter(|block| !block.trim().is_empty())
+        .enumerate()
+        .filter_map(|(i, block)| {
+            let mut worktree = GitWorktree {
+                path: String::new(),
+                head: None,
+                branch: None,
+                is_main: i == 0,
+                is_current: false,
+                detached: false,
+                locked: false,
+                prunable: false,
+            };
+            for line in block.lines() {
+                let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+                match key {
+                    "worktree" => worktree.path = value.to_string(),
+                    "HEAD" => worktree.head = Some(value.chars().take(7).collect()),
+                    "branch" => worktree.branch = Some(value.trim_start_matches("refs/heads/").to_string()),
+                    "detached" => worktree.detached = true,
+                    "locked" => worktree.locked = true,
+                    "prunable" => worktree.prunable = true,
+                    _ => {}
+                }
+            }
+            if worktree.path.is_empty() {
+                return None;
+            }
+            worktree.is_current = canonical(&worktree.path) == current;
+            Some(worktree)
+        })
+        .collect()
+}
+
+fn list_worktrees(path: &str) -> Result<Vec<GitWorktree>, String> {
+    let output = Command::new("git")
+        .current_dir(path)
+        .args(["worktree", "list", "--porcelain"])
+        .output()
+        .map_err(|e| format!("Failed to run git worktree: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    let current = git_output(path, &["rev-parse", "--show-toplevel"])
+        .map(|p| canonical(&p))
+        .unwrap_or_default();
+    Ok(parse_worktrees(&String::from_utf8_lossy(&output.stdout), &current))
+}
+
+/// List the repository's worktrees, main checkout first
+#[tauri::command]
+pub async fn git_worktrees(path: String) -> Result<Vec<GitWorktree>, String> {
+    list_worktrees(&path)
+}
+
+/// Copy untracked top-level .env files so the new checkout can run like the original
+fn copy_env_files(from: &std::path::Path, to: &std::path::Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(from) else {
+        return Vec::new();
+    };
+    let mut copied = Vec::new();
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let target = to.join(&name);
+        if !name.starts_with(".env") || !entry.path().is_file() || target.exists() {
+            continue;
+        }
+        if std::fs::copy(entry.path(), &target).is_ok() {
+            copied.push(name);
+        }
+    }
+    copied.sort();
+    copied
+}
+
+/// Check out `branch` in a new worktree at `dest` (default: a "<repo>-<branch>" sibling),
+/// creating the branch from HEAD if it doesn't exist. A relative `dest` is resolved against
+/// the repository's parent directory.
+#[tauri::command]
+pub async fn git_worktree_add(
+    app: tauri::AppHandle,
+    path: String,
+    branch: String,
+    dest: Option<String>,
+    copy_env: Option<bool>,
+) -> Result<GitWorktreeAddResult, String> {
+    let root = git_output(&path, &["rev-parse", "--show-toplevel"]).ok_or("Not a git repository")?;
+    let root = std::path::PathBuf::from(root);
+    let parent = root.parent().unwrap_or(&root).to_path_buf();
+    let repo_name = root.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+
+    let dest = match dest.filter(|d| !d.trim().is_empty()) {
+        Some(d) if std::path::Path::new(&d).is_absolute() => std::path::PathBuf::from(d),
+        Some(d) => parent.join(d),
+        None => parent.join(format!("{}-{}", repo_name, branch.replace('/', "-"))),
+    };
+    if dest.exists() {
+        return Err(format!("{} already exists", dest.display()));
+    }
+    let dest_str = dest.to_string_lossy().to_string();
+
+    if branch.starts_with('-') {
+        return Err(format!("Invalid branch name: {}", branch));
+    }
+
+    // Local branch, remote-only branch (tracked), or a new branch off HEAD. `branch` may name
+    // a remote branch on any remote ("upstream/feature"); a bare name is looked up on the
+    // remotes too, origin first.
+    let ref_exists = |name: &str| git_output(&path, &["rev-parse", "--verify", "--quiet", name]).is_some();
+    let mut remotes: Vec<String> = git_output(&path, &["remote"])
+        .unwrap_or_default()
+        .lines()
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty())
+        .collect();
+    remotes.sort_by_key(|r| r != "origin");
+    let named_remote = remotes.iter().find_map(|r| {
+        let local = branch.strip_prefix(r.as_str())?.strip_prefix('/')?;
+        ref_exists(&format!("refs/remotes/{}", branch)).then(|| local.to_string())
+    });
+    let (local, remote_ref) = match named_remote {
+        Some(local) => (local, Some(branch.clone())),
+        None => {
+            let remote_ref = remotes
+                .iter()
+                .map(|r| format!("{}/{}", r, branch))
+                .find(|r| ref_exists(&format!("refs/remotes/{}", r)));
+            (branch.clone(), remote_ref)
+        }
+    };
+    let local_exists = ref_exists(&format!("refs/heads/{}", local));
+    let args: Vec<&str> = if local_exists {
+        vec!["worktree", "add", dest_str.as_str(), local.as_str()]
+    } else if let Some(remote_ref) = &remote_ref {
+        vec!["worktree", "add", "--track", "-b", local.as_str(), dest_str.as_str(), remote_ref.as_str()]
+    } else {
+        if git_output(&path, &["check-ref-format", "--branch", &local]).is_none() {
+            return Err(format!("Invalid branch name: {}", local));
+        }
+        vec!["worktree", "add", "-b", local.as_str(), dest_str.as_str()]
+    };
+
+    let result = run_local_git(&path, &args)?;
+    if !result.success {
+        return Err(if result.message.contains("is already checked out") || result.message.contains("already used by worktree") {
+            format!("{} is already checked out in another worktree", local)
+        } else {
+            result.message
+        });
+    }
+    println!("[git] Added worktree {} for {}", dest_str, local);
+
+    let copied_env_files = if copy_env.unwrap_or(true) {
+        copy_env_files(&root, &dest)
+    } else {
+        Vec::new()
+    };
+
+    let current = canonical(&dest_str);
+    let worktree = list_worktrees(&dest_str)?
+        .into_iter()
+        .find(|w| canonical(&w.path) == current)
+        .ok_or("Worktree was created but is not listed")?;
+
+    // The scanner only finds projects a few levels below a watched dir
+    let watched = crate::config::load_config_internal(&app).map(|c| c.watched_dirs).unwrap_or_default();
+    let covered = watched.iter().any(|dir| {
+        dest.strip_prefix(dir)
+            .map(|rel| rel.components().count() <= WATCHED_DIR_SCAN_DEPTH)
+            .unwrap_or(false)
+    });
+
+    Ok(GitWorktreeAddResult {
+        worktree,
+        project: crate::projects::detect_project(&dest),
+        watch_dir: if covered {
+            None
+        } else {
+            Some(dest.parent().unwrap_or(&dest).to_string_lossy().to_string())
+        },
+        copied_env_files,
+    })
+}
+
+/// Remove a linked worktree (never the main checkout). `force` discards its uncommitted changes.
+#[tauri::command]
+pub async fn git_worktree_remove(
+    path: String,
+    worktree: String,
+    force: Option<bool>,
+) -> Result<GitOperationResult, String> {
+    let target = canonical(&worktree);
+    let entry = list_worktrees(&path)?
+        .into_iter()
+        .find(|w| canonical(&w.path) == target)
+        .ok_or_else(|| format!("{} is not a worktree of this repository", worktree))?;
+    if entry.is_main {
+        return Ok(GitOperationResult {
+            success: false,
+            message: "Cannot remove the main checkout".to_string(),
+        });
+    }
+
+    let mut args = vec!["worktree", "remove"];
+    if force.unwrap_or(false) {
+        args.push("--force");
+    }
+    args.push(entry.path.as_str());
+    // Run from the main checkout so removing the worktree `path` points at also works
+    let main = list_worktrees(&path)?
+        .into_iter()
+        .find(|w| w.is_main)
+        .map(|w| w.path)
+        .unwrap_or(path);
+    let result = run_local_git(&main, &args)?;
+    if !result.success {
+        return Ok(GitOperationResult {
+            success: false,
+            message: if result.message.contains("contains modified or untracked files") {
+                "Worktree has uncommitted changes. Commit, stash, or force-remove it.".to_string()
+            } else {
+                result.message
+            },
+        });
+    }
+    Ok(GitOperationResult {
+        success: true,
+        message: format!("Removed worktree {}", entry.path),
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LargeObject {
+    pub hash: String,
+    pub path: Option<String>,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoSizeReport {
+    pub git_dir_bytes: u64,
+    pub pack_bytes: u64,
+    pub loose_bytes: u64,
+    pub object_count: u64,
+    pub largest_objects: Vec<LargeObject>,
+    pub lfs_installed: bool,
+    pub lfs_tracked: bool,
+    pub lfs_file_count: u32,
+    pub is_partial_clone: bool,
+    pub suggestion: Option<String>,
+}
+
+/// Repos with a pack larger than this benefit from a blobless partial clone
+const PARTIAL_CLONE_THRESHOLD_BYTES: u64 = 500 * 1024 * 1024;
+
+/// Run a git command in a directory and return trimmed stdout on success
+pub(crate) fn git_output(path: &str, args: &[&str]) -> Option<String> {
+    Command::new("git")
+        .current_dir(path)
+        .args(args)
+        .output()
+        .ok()
+        .and_then(|o| {
+            if o.status.success() {
+                String::from_utf8(o.stdout).ok()
+            } else {
+                None
+            }
+        })
+        .map(|s| s.trim().to_string())
+}
+
+/// Recursively sum file sizes under a directory
+fn dir_size(path: &std::path::Path) -> u64 {
+    let mut total = 0;
+    if let Ok(entries) = std::fs::read_dir(path) {
+        for entry in entries.flatten() {
+            if let Ok(meta) = entry.metadata() {
+                if meta.is_dir() {
+                    total += dir_size(&entry.path());
+                } else {
+                    total += meta.len();
+                }
+            }
+        }
+    }
+    total
+}
+
+/// Report .git size, largest blobs, and Git LFS status for a repository
+#[tauri::command]
+pub async fn git_repo_size(path: String, limit: Option<u32>) -> Result<RepoSizeReport, String> {
+    repo_size_report(&path, limit)
+}
+
+fn repo_size_report(path: &str, limit: Option<u32>) -> Result<RepoSizeReport, String> {
+    let git_dir = git_output(path, &["rev-parse", "--absolute-git-dir"])
+        .ok_or_else(|| "Not a git repository".to_string())?;
+    let git_dir_bytes = dir_size(std::path::Path::new(&git_dir));
+
+    // Parse `git count-objects -v` (sizes are reported in KiB)
+    let mut pack_bytes = 0u64;
+    let mut loose_bytes = 0u64;
+    let mut object_count = 0u64;
+    if let Some(counts) = git_output(path, &["count-objects", "-v"]) {
+        for line in counts.lines() {
+            if let Some((key, value)) = line.split_once(':') {
+                let value: u64 = value.trim().parse().unwrap_or(0);
+                match key.trim() {
+                    "count" | "in-pack" => object_count += value,
+                    "size" => loose_bytes = value * 1024,
+                    "size-pack" => pack_bytes = value * 1024,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // Find the largest blobs across all objects
+    let mut blobs: Vec<LargeObject> = git_output(
+        path,
+        &[
+            "cat-file",
+            "--batch-all-objects",
+            "--batch-check=%(objecttype) %(objectname) %(objectsize)",
+        ],
+    )
+    .unwrap_or_default()
+    .lines()
+    .filter_map(|line| {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() == 3 && parts[0] == "blob" {
+            Some(LargeObject {
+                hash: parts[1].to_string(),
+                path: None,
+                size_bytes: parts[2].parse().unwrap_or(0),
+            })
+        } else {
+            None
+        }
+    })
+    .collect();
+    blobs.sort_by_key(|b| std::cmp::Reverse(b.size_bytes));
+    blobs.truncate(limit.unwrap_or(10) as usize);
+
+    // Resolve paths for the largest blobs from the reachable object list
+    if !blobs.is_empty() {
+        if let Some(objects) = git_output(path, &["rev-list", "--objects", "--all"]) {
+            for line in objects.lines() {
+                if let Some((hash, name)) = line.split_once(' ') {
+                    if let Some(blob) = blobs.iter_mut().find(|b| b.hash == hash && b.path.is_none()) {
+                        blob.path = Some(name.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    // Git LFS status
+    let lfs_installed = git_output(path, &["lfs", "version"]).is_some();
+    let lfs_tracked = std::fs::read_to_string(Path::new(path).join(".gitattributes"))
+        .map(|s| s.contains("filter=lfs"))
+        .unwrap_or(false);
+    let lfs_file_count = if lfs_installed && lfs_tracked {
+        git_output(path, &["lfs", "ls-files"])
+            .map(|s| s.lines().filter(|l| !l.trim().is_empty()).count() as u32)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    let is_partial_clone = git_output(path, &["config", "--get", "extensions.partialclone"]).is_some()
+        || git_output(path, &["config", "--get", "remote.origin.promisor"])
+            .map(|v| v == "true")
+            .unwrap_or(false);
+
+    let suggestion = if lfs_tracked && !lfs_installed {
+        Some("This repo tracks files with Git LFS but git-lfs is not installed. Run `git lfs install` to fetch real file contents.".to_string())
+    } else if !is_partial_clone && pack_bytes > PARTIAL_CLONE_THRESHOLD_BYTES {
+        Some(format!(
+            "Pack size is {} MB. A blobless partial clone (`git clone --filter=blob:none`) would make clones and fetches much faster.",
+            pack_bytes / 1024 / 1024
+        ))
+    } else {
+        None
+    };
+
+    Ok(RepoSizeReport {
+        git_dir_bytes,
+        pack_bytes,
+        loose_bytes,
+        object_count,
+        largest_objects: blobs,
+        lfs_installed,
+        lfs_tracked,
+        lfs_file_count,
+        is_partial_clone,
+        suggestion,
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HygieneReport {
+    /// Branch the merged check compares against, e.g. "main"
+    pub default_branch: Option<String>,
+    /// Local branches fully merged into the default branch (never the current one)
+    pub merged_branches: Vec<String>,
+    /// Local branches that have never been pushed
+    pub no_upstream: Vec<String>,
+    /// Local branches whose upstream was deleted on the remote
+    pub upstream_gone: Vec<String>,
+    pub size: RepoSizeReport,
+    /// Branches deleted by the prune action
+    pub pruned: Vec<String>,
+}
+
+/// The remote's default branch, falling back to a local main/master
+fn default_branch(path: &str) -> Option<String> {
+    git_output(path, &["symbolic-ref", "--quiet", "--short", "refs/remotes/origin/HEAD"])
+        .and_then(|r| r.strip_prefix("origin/").map(|b| b.to_string()))
+        .or_else(|| {
+            ["main", "master"]
+                .iter()
+                .find(|b| git_output(path, &["rev-parse", "--verify", "--quiet", &format!("refs/heads/{}", b)]).is_some())
+                .map(|b| b.to_string())
+        })
+}
+
+/// Stale branches and repository size in one report. With `prune`, the merged branches are
+/// deleted; they're all contained in the default branch, so no commits are lost.
+#[tauri::command]
+pub async fn git_hygiene(path: String, prune: Option<bool>) -> Result<HygieneReport, String> {
+    let size = repo_size_report(&path, None)?;
+    let default_branch = default_branch(&path);
+    let current = git_output(&path, &["rev-parse", "--abbrev-ref", "HEAD"]);
+
+    let mut merged_branches: Vec<String> = match &default_branch {
+        Some(default) => git_output(&path, &["branch", "--format=%(refname:short)", "--merged", default])
+            .unwrap_or_default()
+            .lines()
+            .map(|b| b.trim().to_string())
+            .filter(|b| !b.is_empty() && b != default && Some(b) != current.as_ref())
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let mut no_upstream = Vec::new();
+    let mut upstream_gone = Vec::new();
+    let refs = git_output(
+        &path,
+        &["for-each-ref", "--format=%(refname:short)%00%(upstream:short)%00%(upstream:track,nobracket)", "refs/heads"],
+    )
+    .unwrap_or_default();
+    for line in refs.lines() {
+        let fields: Vec<&str> = line.split('\0').collect();
+        if fields.len() < 3 {
+            continue;
+        }
+        if fields[1].is_empty() {
+            no_upstream.push(fields[0].to_string());
+        } else if parse_track(fields[2]).2 {
+            upstream_gone.push(fields[0].to_string());
+        }
+    }
+
+    let mut pruned = Vec::new();
+    if prune.unwrap_or(false) {
+        // A branch that was never pushed may just be freshly created from the default branch,
+        // which also makes it "merged"; only branches that were pushed are pruned
+        for branch in merged_branches.iter().filter(|b| !no_upstream.contains(b)) {
+            // -D: -d checks against HEAD, which may be a different branch than the default
+            if run_local_git(&path, &["branch", "-D", branch])?.success {
+                pruned.push(branch.clone());
+            }
+        }
+        merged_branches.retain(|b| !pruned.contains(b));
+        println!("[git] Pruned {} merged branch(es) in {}", pruned.len(), path);
+    }
+
+    Ok(HygieneReport {
+        default_branch,
+        merged_branches,
+        no_upstream,
+        upstream_gone,
+        size,
+        pruned,
     })
 }
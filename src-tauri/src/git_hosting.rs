@@ -0,0 +1,366 @@
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use crate::git::git_output;
+use std::sync::{Mutex, Once};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// Floor for the remote info refresh, to stay well inside API rate limits
+const MIN_POLL_MINUTES: u64 = 5;
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+lazy_static::lazy_static! {
+    // Projects whose remote info has been requested; the poller keeps these fresh
+    static ref WATCHED: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+    static ref CLIENT: reqwest::Client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .user_agent(concat!("DevLaunch/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .unwrap_or_default();
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitRemoteInfo {
+    /// "github" or "gitlab"
+    pub provider: String,
+    pub host: String,
+    /// "owner/repo" (GitLab may have nested groups)
+    pub repo: String,
+    pub web_url: Option<String>,
+    pub default_branch: Option<String>,
+    pub branch: Option<String>,
+    /// Open PRs/MRs from the current branch
+    pub open_pull_requests: u32,
+    pub pull_request_url: Option<String>,
+    /// "success", "failure", "pending", "none" or "unknown"
+    pub ci_status: String,
+    pub ci_url: Option<String>,
+    /// CI status of the default branch, for "CI failing on main"
+    pub default_branch_ci_status: String,
+    pub error: Option<String>,
+    pub checked_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct GitRemoteInfoEvent {
+    path: String,
+    info: GitRemoteInfo,
+}
+
+/// Hosting API a remote belongs to
+struct Remote {
+    provider: &'static str,
+    host: String,
+    repo: String,
+}
+
+/// Host and repo path from https, ssh:// and scp-style (git@host:org/repo) remote URLs
+fn parse_remote(url: &str) -> Option<Remote> {
+    let url = url.trim().trim_end_matches('/').trim_end_matches(".git");
+    let (host, path) = if let Some((_, rest)) = url.split_once("://") {
+        let rest = rest.rsplit_once('@').map(|(_, r)| r).unwrap_or(rest);
+        let (host, path) = rest.split_once('/')?;
+        (host.split(':').next().unwrap_or(host), path)
+    } else {
+        let rest = url.rsplit_once('@').map(|(_, r)| r).unwrap_or(url);
+        rest.split_once(':')?
+    };
+
+    let host = host.to_lowercase();
+    let provider = if host.contains("github") {
+        "github"
+    } else if host.contains("gitlab") {
+        "gitlab"
+    } else {
+        return None;
+    };
+    Some(Remote {
+        provider,
+        host,
+        repo: path.trim_matches('/').to_string(),
+    })
+}
+
+fn api_base(remote: &Remote) -> String {
+    match (remote.provider, remote.host.as_str()) {
+        ("github", "github.com") => "https://api.github.com".to_string(),
+        // GitHub Enterprise Server
+        ("github", host) => format!("https://{}/api/v3", host),
+        (_, host) => format!("https://{}/api/v4", host),
+    }
+}
+
+async fn get_json(remote: &Remote, url: &str, token: Option<&str>) -> Result<serde_json::Value, String> {
+    let mut request = CLIENT.get(url);
+    request = match (remote.provider, token) {
+        ("github", Some(token)) => request.bearer_auth(token),
+        ("gitlab", Some(token)) => request.header("PRIVATE-TOKEN", token),
+        _ => request,
+    };
+    if remote.provider == "github" {
+        request = request.header("Accept", "application/vnd.github+json");
+    }
+
+    let response = request.send().await.map_err(|e| format!("Request failed: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(match status.as_u16() {
+            401 | 403 => format!("{} API denied access ({}); check the token for {}", remote.provider, status, remote.host),
+            404 => format!("Repository {} not found (or the token can't see it)", remote.repo),
+            _ => format!("{} API returned {}", remote.provider, status),
+        });
+    }
+    response.json().await.map_err(|e| format!("Invalid API response: {}", e))
+}
+
+/// Roll individual check states up into one status: any failure wins, then anything running
+fn summarize(states: &[&str]) -> String {
+    if states.is_empty() {
+        "none"
+    } else if states
+        .iter()
+        .any(|s| matches!(*s, "failure" | "failed" | "error" | "cancelled" | "canceled" | "timed_out" | "action_required"))
+    {
+        "failure"
+    } else if states
+        .iter()
+        .any(|s| matches!(*s, "pending" | "queued" | "in_progress" | "running" | "created" | "waiting" | "preparing"))
+    {
+        "pending"
+    } else {
+        "success"
+    }
+    .to_string()
+}
+
+/// Percent-encode a branch name or project path for use as one URL path segment or query value
+fn encode_component(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Check runs plus legacy commit statuses for a ref
+async fn github_ci(remote: &Remote, base: &str, git_ref: &str, token: Option<&str>) -> (String, Option<String>) {
+    let repo_url = format!("{}/repos/{}", base, remote.repo);
+    let git_ref = encode_component(git_ref);
+    let mut states: Vec<String> = Vec::new();
+    let mut ci_url = None;
+
+    if let Ok(runs) = get_json(remote, &format!("{}/commits/{}/check-runs?per_page=100", repo_url, git_ref), token).await {
+        for run in runs.get("check_runs").and_then(|r| r.as_array()).into_iter().flatten() {
+            let status = run.get("status").and_then(|s| s.as_str()).unwrap_or("");
+            let state = if status == "completed" {
+                run.get("conclusion").and_then(|c| c.as_str()).unwrap_or("success")
+            } else {
+                status
+            };
+            if state == "failure" && ci_url.is_none() {
+                ci_url = run.get("html_url").and_then(|u| u.as_str()).map(|u| u.to_string());
+            }
+            states.push(state.to_string());
+        }
+    }
+    if let Ok(combined) = get_json(remote, &format!("{}/commits/{}/status", repo_url, git_ref), token).await {
+        // "pending" with no statuses just means nothing reports through this API
+        let has_statuses = combined
+            .get("statuses")
+            .and_then(|s| s.as_array())
+            .is_some_and(|s| !s.is_empty());
+        if has_statuses {
+            if let Some(state) = combined.get("state").and_then(|s| s.as_str()) {
+                states.push(state.to_string());
+            }
+        }
+    }
+
+    let refs: Vec<&str> = states.iter().map(|s| s.as_str()).collect();
+    (summarize(&refs), ci_url)
+}
+
+async fn github_info(remote: &Remote, branch: Option<&str>, token: Option<&str>, info: &mut GitRemoteInfo) -> Result<(), String> {
+    let base = api_base(remote);
+    let repo = get_json(remote, &format!("{}/repos/{}", base, remote.repo), token).await?;
+    info.web_url = repo.get("html_url").and_then(|u| u.as_str()).map(|u| u.to_string());
+    info.default_branch = repo.get("default_branch").and_then(|b| b.as_str()).map(|b| b.to_string());
+
+    if let Some(branch) = branch {
+        let owner = remote.repo.split('/').next().unwrap_or("");
+        let pulls = get_json(
+            remote,
+            &format!(
+                "{}/repos/{}/pulls?state=open&head={}",
+                base,
+                remote.repo,
+                encode_component(&format!("{}:{}", owner, branch))
+            ),
+            token,
+        )
+        .await?;
+        let pulls = pulls.as_array().cloned().unwrap_or_default();
+        info.open_pull_requests = pulls.len() as u32;
+        info.pull_request_url = pulls
+            .first()
+            .and_then(|p| p.get("html_url"))
+            .and_then(|u| u.as_str())
+            .map(|u| u.to_string());
+
+        let (status, url) = github_ci(remote, &base, branch, token).await;
+        info.ci_status = status;
+        info.ci_url = url;
+    }
+    if let Some(default_branch) = info.default_branch.clone() {
+        info.default_branch_ci_status = if Some(default_branch.as_str()) == branch {
+            info.ci_status.clone()
+        } else {
+            github_ci(remote, &base, &default_branch, token).await.0
+        };
+    }
+    Ok(())
+}
+
+async fn gitlab_pipeline(remote: &Remote, project_url: &str, git_ref: &str, token: Option<&str>) -> (String, Option<String>) {
+    let url = format!("{}/pipelines?ref={}&per_page=1", project_url, encode_component(git_ref));
+    match get_json(remote, &url, token).await {
+        Ok(pipelines) => match pipelines.as_array().and_then(|p| p.first()) {
+            Some(pipeline) => (
+                summarize(&[pipeline.get("status").and_then(|s| s.as_str()).unwrap_or("")]),
+                pipeline.get("web_url").and_then(|u| u.as_str()).map(|u| u.to_string()),
+            ),
+            None => ("none".to_string(), None),
+        },
+        Err(_) => ("unknown".to_string(), None),
+    }
+}
+
+async fn gitlab_info(remote: &Remote, branch: Option<&str>, token: Option<&str>, info: &mut GitRemoteInfo) -> Result<(), String> {
+    let project_url = format!("{}/projects/{}", api_base(remote), encode_component(&remote.repo));
+    let project = get_json(remote, &project_url, token).await?;
+    info.web_url = project.get("web_url").and_then(|u| u.as_str()).map(|u| u.to_string());
+    info.default_branch = project.get("default_branch").and_then(|b| b.as_str()).map(|b| b.to_string());
+
+    if let Some(branch) = branch {
+        let requests = get_json(
+            remote,
+            &format!("{}/merge_requests?state=opened&source_branch={}", project_url, encode_component(branch)),
+            token,
+        )
+        .await?;
+        let requests = requests.as_array().cloned().unwrap_or_default();
+        info.open_pull_requests = requests.len() as u32;
+        info.pull_request_url = requests
+            .first()
+            .and_then(|r| r.get("web_url"))
+            .and_then(|u| u.as_str())
+            .map(|u| u.to_string());
+
+        let (status, url) = gitlab_pipeline(remote, &project_url, branch, token).await;
+        info.ci_status = status;
+        info.ci_url = url;
+    }
+    if let Some(default_branch) = info.default_branch.clone() {
+        info.default_branch_ci_status = if Some(default_branch.as_str()) == branch {
+            info.ci_status.clone()
+        } else {
+            gitlab_pipeline(remote, &project_url, &default_branch, token).await.0
+        };
+    }
+    Ok(())
+}
+
+async fn remote_info_internal(app: &AppHandle, path: &str) -> Result<GitRemoteInfo, String> {
+    let url = git_output(path, &["remote", "get-url", "origin"])
+        .filter(|u| !u.is_empty())
+        .ok_or("No origin remote")?;
+    let remote = parse_remote(&url).ok_or_else(|| format!("Not a GitHub or GitLab remote: {}", url))?;
+    let branch = git_output(path, &["rev-parse", "--abbrev-ref", "HEAD"]).filter(|b| !b.is_empty() && b != "HEAD");
+
+    // Reuse the tokens configured for fetch/pull
+    let tokens = crate::config::load_config_internal(app)
+        .map(|c| c.preferences.git.tokens)
+        .unwrap_or_default();
+    let token = crate::git::find_matching_token(&url, &tokens)
+        .map(crate::secrets::resolve)
+        .filter(|t| !t.is_empty());
+
+    let mut info = GitRemoteInfo {
+        provider: remote.provider.to_string(),
+        host: remote.host.clone(),
+        repo: remote.repo.clone(),
+        web_url: None,
+        default_branch: None,
+        branch: branch.clone(),
+        open_pull_requests: 0,
+        pull_request_url: None,
+        ci_status: "unknown".to_string(),
+        ci_url: None,
+        default_branch_ci_status: "unknown".to_string(),
+        error: None,
+        checked_at: chrono::Utc::now().timestamp_millis(),
+    };
+    let result = match remote.provider {
+        "github" => github_info(&remote, branch.as_deref(), token.as_deref(), &mut info).await,
+        _ => gitlab_info(&remote, branch.as_deref(), token.as_deref(), &mut info).await,
+    };
+    if let Err(e) = result {
+        info.error = Some(e);
+    }
+    Ok(info)
+}
+
+/// Refresh every watched project on the git polling interval, emitting `git-remote-info`
+fn ensure_poller(app: &AppHandle) {
+    static POLLER: Once = Once::new();
+    let app = app.clone();
+    POLLER.call_once(move || {
+        tauri::async_runtime::spawn(async move {
+            loop {
+                let prefs = crate::config::load_config_internal(&app)
+                    .map(|c| c.preferences.git)
+                    .unwrap_or_default();
+                let minutes = (prefs.polling_interval_minutes as u64).max(MIN_POLL_MINUTES);
+                tokio::time::sleep(Duration::from_secs(minutes * 60)).await;
+                if !prefs.enabled {
+                    continue;
+                }
+
+                let paths: Vec<String> = WATCHED.lock().map(|w| w.iter().cloned().collect()).unwrap_or_default();
+                let mut results: HashMap<String, GitRemoteInfo> = HashMap::new();
+                for path in paths {
+                    if let Ok(info) = remote_info_internal(&app, &path).await {
+                        results.insert(path, info);
+                    }
+                }
+                for (path, info) in results {
+                    let _ = app.emit("git-remote-info", GitRemoteInfoEvent { path, info });
+                }
+            }
+        });
+    });
+}
+
+/// PR count for the current branch, CI status (current and default branch) and default branch
+/// from the GitHub/GitLab API. The project is then refreshed on the git polling interval,
+/// with updates emitted as `git-remote-info`.
+#[tauri::command]
+pub async fn git_remote_info(app: AppHandle, path: String) -> Result<GitRemoteInfo, String> {
+    let info = remote_info_internal(&app, &path).await?;
+    if let Ok(mut watched) = WATCHED.lock() {
+        watched.insert(path);
+    }
+    ensure_poller(&app);
+    Ok(info)
+}
+
+/// Stop refreshing a project's remote info (e.g. when it's removed from the list)
+#[tauri::command]
+pub async fn unwatch_git_remote_info(path: String) -> Result<(), String> {
+    if let Ok(mut watched) = WATCHED.lock() {
+        watched.remove(&path);
+    }
+    Ok(())
+}
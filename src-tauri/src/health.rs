@@ -0,0 +1,346 @@
+use crate::config::{self, HealthCheckConfig};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// Max datapoints kept per project
+const MAX_HISTORY: usize = 200;
+
+lazy_static::lazy_static! {
+    // Projects that have already been marked ready since their last spawn
+    static ref READY_PROJECTS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+    // Health datapoints (warm-ups and probes) per project path
+    static ref HEALTH_HISTORY: Mutex<HashMap<String, Vec<HealthDatapoint>>> = Mutex::new(HashMap::new());
+    // Current probe status per project path
+    static ref HEALTH_STATE: Mutex<HashMap<String, ProjectHealth>> = Mutex::new(HashMap::new());
+    // Bumped on every spawn so probe loops from a previous run stop
+    static ref PROBE_GENERATIONS: Mutex<HashMap<String, u64>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthDatapoint {
+    pub timestamp: i64,
+    pub url: String,
+    pub status: Option<u16>,
+    pub latency_ms: u64,
+    pub ok: bool,
+    pub source: String, // "warmup", "probe"
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+struct WarmupEvent {
+    path: String,
+    datapoints: Vec<HealthDatapoint>,
+}
+
+/// Probe status of a project, also emitted as `project-health` whenever it changes
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectHealth {
+    pub path: String,
+    pub status: String, // "starting", "healthy", "unhealthy"
+    /// When the current status was entered (ms)
+    pub since: i64,
+    pub consecutive_failures: u32,
+    pub last: Option<HealthDatapoint>,
+}
+
+/// Reset readiness tracking for a project (called on every spawn)
+pub fn reset_project(path: &str) {
+    if let Ok(mut ready) = READY_PROJECTS.lock() {
+        ready.remove(path);
+    }
+    if let Ok(mut generations) = PROBE_GENERATIONS.lock() {
+        *generations.entry(path.to_string()).or_insert(0) += 1;
+    }
+    if let Ok(mut state) = HEALTH_STATE.lock() {
+        state.remove(path);
+    }
+}
+
+fn probe_generation(path: &str) -> u64 {
+    PROBE_GENERATIONS
+        .lock()
+        .map(|g| g.get(path).copied().unwrap_or(0))
+        .unwrap_or(0)
+}
+
+/// Mark a project ready once its URL is detected.
+/// Only the first call after a spawn triggers warm-up requests.
+pub fn on_project_ready(app: &AppHandle, path: &str, base_url: &str) {
+    let first = READY_PROJECTS
+        .lock()
+        .map(|mut ready| ready.insert(path.to_string()))
+        .unwrap_or(false);
+    if !first {
+        return;
+    }
+
+    let settings = config::load_config_internal(app)
+        .map(|c| c.project_settings(path))
+        .unwrap_or_default();
+    let base_url = base_url.trim_end_matches('/').to_string();
+
+    if let Some(check) = settings.health_check {
+        let app = app.clone();
+        let path = path.to_string();
+        let base_url = base_url.clone();
+        let generation = probe_generation(&path);
+        tauri::async_runtime::spawn(async move {
+            run_probes(app, path, base_url, check, generation).await;
+        });
+    }
+
+    if settings.warmup_requests.is_empty() {
+        return;
+    }
+
+    let app = app.clone();
+    let path = path.to_string();
+    tauri::async_runtime::spawn(async move {
+        run_warmups(app, path, base_url, settings.warmup_requests).await;
+    });
+}
+
+/// Resolve a warm-up target against the detected base URL
+fn warmup_url(base_url: &str, target: &str) -> String {
+    if target.starts_with("http://") || target.starts_with("https://") {
+        target.to_string()
+    } else if target.starts_with('/') {
+        format!("{}{}", base_url, target)
+    } else {
+        format!("{}/{}", base_url, target)
+    }
+}
+
+async fn run_warmups(app: AppHandle, path: String, base_url: String, targets: Vec<String>) {
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+    {
+        Ok(c) => c,
+        Err(e) => {
+            println!("[health] Failed to build HTTP client: {}", e);
+            return;
+        }
+    };
+
+    let mut datapoints = Vec::new();
+    for target in targets {
+        let url = warmup_url(&base_url, &target);
+        let start = Instant::now();
+        let response = client.get(&url).send().await;
+        let latency_ms = start.elapsed().as_millis() as u64;
+
+        let datapoint = match response {
+            Ok(resp) => HealthDatapoint {
+                timestamp: chrono::Utc::now().timestamp_millis(),
+                url,
+                status: Some(resp.status().as_u16()),
+                latency_ms,
+                ok: resp.status().is_success(),
+                source: "warmup".to_string(),
+                error: None,
+            },
+            Err(e) => HealthDatapoint {
+                timestamp: chrono::Utc::now().timestamp_millis(),
+                url,
+                status: None,
+                latency_ms,
+                ok: false,
+                source: "warmup".to_string(),
+                error: Some(e.to_string()),
+            },
+        };
+
+        println!(
+            "[health] Warm-up {} -> {:?} in {}ms",
+            datapoint.url, datapoint.status, datapoint.latency_ms
+        );
+        record_datapoint(&path, datapoint.clone());
+        datapoints.push(datapoint);
+    }
+
+    let _ = app.emit("project-warmup", WarmupEvent { path, datapoints });
+}
+
+/// Request a URL once and judge the response against an expected status and body substring.
+/// Without an expected status any 2xx/3xx response is healthy.
+pub async fn probe(
+    client: &reqwest::Client,
+    url: &str,
+    expected_status: Option<u16>,
+    expected_body: Option<&str>,
+) -> HealthDatapoint {
+    let start = Instant::now();
+    let response = client.get(url).send().await;
+    let timestamp = chrono::Utc::now().timestamp_millis();
+
+    let resp = match response {
+        Ok(resp) => resp,
+        Err(e) => {
+            return HealthDatapoint {
+                timestamp,
+                url: url.to_string(),
+                status: None,
+                latency_ms: start.elapsed().as_millis() as u64,
+                ok: false,
+                source: "probe".to_string(),
+                error: Some(e.to_string()),
+            }
+        }
+    };
+
+    let status = resp.status();
+    let status_ok = match expected_status {
+        Some(expected) => status.as_u16() == expected,
+        None => status.is_success() || status.is_redirection(),
+    };
+    let mut error = if status_ok {
+        None
+    } else {
+        Some(format!("Unexpected status {}", status.as_u16()))
+    };
+
+    if let (None, Some(needle)) = (&error, expected_body) {
+        let body = resp.text().await.unwrap_or_default();
+        if !body.contains(needle) {
+            error = Some(format!("Response body does not contain \"{}\"", needle));
+        }
+    }
+
+    HealthDatapoint {
+        timestamp,
+        url: url.to_string(),
+        status: Some(status.as_u16()),
+        latency_ms: start.elapsed().as_millis() as u64,
+        ok: error.is_none(),
+        source: "probe".to_string(),
+        error,
+    }
+}
+
+/// Whether DevLaunch still has a running process for a project
+fn project_running(path: &str) -> bool {
+    crate::process::running_processes()
+        .iter()
+        .any(|(_, cwd)| cwd == path)
+}
+
+/// Store a project's health status, emitting `project-health` when the status changes
+fn update_health(app: &AppHandle, health: ProjectHealth) {
+    let changed = HEALTH_STATE
+        .lock()
+        .map(|mut state| {
+            let changed = state.get(&health.path).map(|h| h.status != health.status).unwrap_or(true);
+            state.insert(health.path.clone(), health.clone());
+            changed
+        })
+        .unwrap_or(false);
+
+    if changed {
+        println!("[health] {} is {}", health.path, health.status);
+        if health.status == "healthy" {
+            crate::launch_stats::on_healthy(app, &health.path);
+        }
+        let _ = app.emit("project-health", health);
+    }
+}
+
+/// Poll a project's health check until it is respawned or stops running
+async fn run_probes(app: AppHandle, path: String, base_url: String, check: HealthCheckConfig, generation: u64) {
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_millis(check.timeout_ms.max(1)))
+        .build()
+    {
+        Ok(c) => c,
+        Err(e) => {
+            println!("[health] Failed to build HTTP client: {}", e);
+            return;
+        }
+    };
+
+    let url = check
+        .url
+        .as_deref()
+        .map(|target| warmup_url(&base_url, target))
+        .unwrap_or_else(|| base_url.clone());
+    let started = chrono::Utc::now().timestamp_millis();
+    let mut health = ProjectHealth {
+        path: path.clone(),
+        status: "starting".to_string(),
+        since: started,
+        consecutive_failures: 0,
+        last: None,
+    };
+    update_health(&app, health.clone());
+
+    loop {
+        if probe_generation(&path) != generation || !project_running(&path) {
+            break;
+        }
+
+        let datapoint = probe(&client, &url, check.expected_status, check.expected_body.as_deref()).await;
+        record_datapoint(&path, datapoint.clone());
+
+        let next_status = if datapoint.ok {
+            health.consecutive_failures = 0;
+            "healthy"
+        } else {
+            health.consecutive_failures += 1;
+            match health.status.as_str() {
+                "healthy" if health.consecutive_failures >= check.failure_threshold.max(1) => "unhealthy",
+                "starting" if datapoint.timestamp - started >= check.startup_timeout_ms as i64 => "unhealthy",
+                current => current,
+            }
+        };
+        if next_status != health.status {
+            health.status = next_status.to_string();
+            health.since = datapoint.timestamp;
+        }
+        health.last = Some(datapoint);
+
+        // The project may have been respawned while the request was in flight
+        if probe_generation(&path) != generation {
+            break;
+        }
+        update_health(&app, health.clone());
+
+        tokio::time::sleep(Duration::from_millis(check.interval_ms.max(250))).await;
+    }
+
+    // A stopped project has no health to report
+    if probe_generation(&path) == generation {
+        if let Ok(mut state) = HEALTH_STATE.lock() {
+            state.remove(&path);
+        }
+    }
+}
+
+/// Current health-check status of a project (None if it has no health check or isn't running)
+#[tauri::command]
+pub async fn get_project_health(path: String) -> Result<Option<ProjectHealth>, String> {
+    let state = HEALTH_STATE.lock().map_err(|e| e.to_string())?;
+    Ok(state.get(&path).cloned())
+}
+
+/// Append a datapoint to a project's bounded history
+pub fn record_datapoint(path: &str, datapoint: HealthDatapoint) {
+    if let Ok(mut history) = HEALTH_HISTORY.lock() {
+        let entries = history.entry(path.to_string()).or_default();
+        entries.push(datapoint);
+        if entries.len() > MAX_HISTORY {
+            let excess = entries.len() - MAX_HISTORY;
+            entries.drain(..excess);
+        }
+    }
+}
+
+/// Get recorded health datapoints for a project
+#[tauri::command]
+pub async fn get_health_history(path: String) -> Result<Vec<HealthDatapoint>, String> {
+    let history = HEALTH_HISTORY.lock().map_err(|e| e.to_string())?;
+    Ok(history.get(&path).cloned().unwrap_or_default())
+}
@@ -0,0 +1,149 @@
+use crate::config::ProjectHooks;
+use crate::process::{self, LogEvent};
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+fn project_hooks(app: &AppHandle, cwd: &str) -> Option<ProjectHooks> {
+    crate::config::load_config_internal(app)
+        .ok()
+        .and_then(|c| c.project_settings(cwd).hooks)
+}
+
+fn log(app: &AppHandle, cwd: &str, level: &str, message: String) {
+    process::emit_log(
+        app,
+        LogEvent {
+            path: cwd.to_string(),
+            level: level.to_string(),
+            message: format!("[hook] {}", message),
+            ..Default::default()
+        },
+    );
+}
+
+fn shell_command(line: &str) -> Command {
+    #[cfg(windows)]
+    {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", line]);
+        cmd
+    }
+    #[cfg(not(windows))]
+    {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", line]);
+        cmd
+    }
+}
+
+/// Run one hook command in the project directory, streaming its output into the project's logs
+fn run_hook(app: &AppHandle, cwd: &str, line: &str, timeout: Duration) -> Result<(), String> {
+    log(app, cwd, "info", format!("$ {}", line));
+
+    let mut cmd = shell_command(line);
+    cmd.current_dir(cwd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    crate::shell_env::apply_to_command(app, &mut cmd);
+    cmd.envs(crate::shell_env::project_env(app, cwd));
+    // Own process group so a timeout takes down whatever the shell started
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+
+    let mut child = cmd.spawn().map_err(|e| format!("Failed to run hook \"{}\": {}", line, e))?;
+
+    let mut readers = Vec::new();
+    if let Some(stdout) = child.stdout.take() {
+        let (app, cwd) = (app.clone(), cwd.to_string());
+        readers.push(std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                log(&app, &cwd, "info", line);
+            }
+        }));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        let (app, cwd) = (app.clone(), cwd.to_string());
+        readers.push(std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                // Plenty of tools (docker compose included) report progress on stderr
+                log(&app, &cwd, "warn", line);
+            }
+        }));
+    }
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() >= timeout => {
+                #[cfg(unix)]
+                let _ = Command::new("kill")
+                    .args(["-KILL", &format!("-{}", child.id())])
+                    .status();
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("Hook \"{}\" timed out after {}s", line, timeout.as_secs()));
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(100)),
+            Err(e) => return Err(format!("Failed to wait for hook \"{}\": {}", line, e)),
+        }
+    };
+    for reader in readers {
+        let _ = reader.join();
+    }
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(match status.code() {
+            Some(code) => format!("Hook \"{}\" failed with exit code {}", line, code),
+            None => format!("Hook \"{}\" was terminated", line),
+        })
+    }
+}
+
+/// Run a list of hooks in order, stopping at the first failure
+fn run_all(app: &AppHandle, cwd: &str, commands: &[String], timeout: Duration) -> Result<(), String> {
+    for line in commands.iter().map(|c| c.trim()).filter(|c| !c.is_empty()) {
+        if let Err(e) = run_hook(app, cwd, line, timeout) {
+            log(app, cwd, "error", e.clone());
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// Run the project's pre-launch hooks. An error means the launch should be aborted.
+pub async fn run_pre_launch(app: &AppHandle, cwd: &str) -> Result<(), String> {
+    let Some(hooks) = project_hooks(app, cwd).filter(|h| !h.pre_launch.is_empty()) else {
+        return Ok(());
+    };
+    println!("[hooks] Running {} pre-launch hook(s) for {}", hooks.pre_launch.len(), cwd);
+    let (app, cwd) = (app.clone(), cwd.to_string());
+    tokio::task::spawn_blocking(move || {
+        run_all(&app, &cwd, &hooks.pre_launch, Duration::from_secs(hooks.timeout_secs))
+            .map_err(|e| format!("Pre-launch hook failed, not starting: {}", e))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Run the project's post-stop hooks in the background; failures are only logged
+pub fn run_post_stop(app: &AppHandle, cwd: &str) {
+    let Some(hooks) = project_hooks(app, cwd).filter(|h| !h.post_stop.is_empty()) else {
+        return;
+    };
+    println!("[hooks] Running {} post-stop hook(s) for {}", hooks.post_stop.len(), cwd);
+    let (app, cwd) = (app.clone(), cwd.to_string());
+    std::thread::spawn(move || {
+        if let Err(e) = run_all(&app, &cwd, &hooks.post_stop, Duration::from_secs(hooks.timeout_secs)) {
+            println!("[hooks] {}", e);
+        }
+    });
+}
@@ -0,0 +1,194 @@
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Trailing comment marking lines DevLaunch added, optionally followed by the owning project
+const MARKER: &str = "# devlaunch";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HostEntry {
+    pub ip: String,
+    pub hostnames: Vec<String>,
+    pub comment: Option<String>,
+    /// Added by DevLaunch, so it can be removed again
+    pub managed: bool,
+    /// Project the entry was added for
+    pub project: Option<String>,
+    /// 1-based line in the hosts file
+    pub line: usize,
+}
+
+fn hosts_path() -> PathBuf {
+    #[cfg(windows)]
+    {
+        let root = std::env::var("SystemRoot").unwrap_or_else(|_| r"C:\Windows".to_string());
+        PathBuf::from(root).join(r"System32\drivers\etc\hosts")
+    }
+    #[cfg(not(windows))]
+    PathBuf::from("/etc/hosts")
+}
+
+fn read_contents() -> Result<String, String> {
+    std::fs::read_to_string(hosts_path()).map_err(|e| format!("Failed to read hosts file: {}", e))
+}
+
+fn parse_line(number: usize, line: &str) -> Option<HostEntry> {
+    let (data, comment) = match line.split_once('#') {
+        Some((data, comment)) => (data, Some(format!("#{}", comment))),
+        None => (line, None),
+    };
+    let mut fields = data.split_whitespace();
+    let ip = fields.next()?.to_string();
+    let hostnames: Vec<String> = fields.map(|h| h.to_string()).collect();
+    if hostnames.is_empty() {
+        return None;
+    }
+
+    let project = comment
+        .as_deref()
+        .and_then(|c| c.strip_prefix(MARKER))
+        .map(|rest| rest.trim_start_matches(':').trim().to_string());
+    Some(HostEntry {
+        ip,
+        hostnames,
+        managed: project.is_some(),
+        project: project.filter(|p| !p.is_empty()),
+        comment,
+        line: number + 1,
+    })
+}
+
+fn parse(contents: &str) -> Vec<HostEntry> {
+    contents
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| parse_line(i, line))
+        .collect()
+}
+
+/// Hostnames are letters, digits, '-' and '.', so nothing else can sneak into the file
+fn validate_hostname(hostname: &str) -> Result<(), String> {
+    let valid = !hostname.is_empty()
+        && hostname.len() <= 253
+        && !hostname.starts_with(['-', '.'])
+        && hostname
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid hostname: {}", hostname))
+    }
+}
+
+/// Write new contents with elevation, then flush the resolver cache so the change applies now
+fn write_contents(contents: &str) -> Result<(), String> {
+    crate::elevation::write_file_elevated(&hosts_path(), contents)?;
+
+    #[cfg(target_os = "macos")]
+    {
+        let _ = std::process::Command::new("dscacheutil").arg("-flushcache").status();
+    }
+    #[cfg(windows)]
+    {
+        let _ = std::process::Command::new("ipconfig").arg("/flushdns").status();
+    }
+    Ok(())
+}
+
+/// Every mapping in the system hosts file
+#[tauri::command]
+pub async fn read_hosts() -> Result<Vec<HostEntry>, String> {
+    Ok(parse(&read_contents()?))
+}
+
+/// Map a hostname (e.g. "myapp.local") to an IP, defaulting to 127.0.0.1.
+/// Prompts for authorization; the line is tagged so DevLaunch can remove it later.
+#[tauri::command]
+pub async fn add_host_entry(hostname: String, ip: Option<String>, project: Option<String>) -> Result<HostEntry, String> {
+    let hostname = hostname.trim().to_lowercase();
+    validate_hostname(&hostname)?;
+    let ip = ip.unwrap_or_else(|| "127.0.0.1".to_string());
+    ip.parse::<std::net::IpAddr>()
+        .map_err(|_| format!("Invalid IP address: {}", ip))?;
+    // The project path goes into a comment, so it must stay on one line
+    let project = project.map(|p| p.replace(['\r', '\n'], " ")).filter(|p| !p.trim().is_empty());
+
+    let contents = read_contents()?;
+    let entries = parse(&contents);
+    if let Some(existing) = entries.iter().find(|e| e.hostnames.contains(&hostname)) {
+        if existing.ip == ip {
+            return Ok(existing.clone());
+        }
+        return Err(format!(
+            "{} is already mapped to {} on line {} of the hosts file",
+            hostname, existing.ip, existing.line
+        ));
+    }
+
+    let marker = match &project {
+        Some(project) => format!("{}: {}", MARKER, project),
+        None => MARKER.to_string(),
+    };
+    let newline = if contents.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut updated = contents.clone();
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push_str(newline);
+    }
+    updated.push_str(&format!("{}\t{}\t{}{}", ip, hostname, marker, newline));
+
+    println!("[hosts] Mapping {} to {}", hostname, ip);
+    write_contents(&updated)?;
+    parse(&read_contents()?)
+        .into_iter()
+        .find(|e| e.managed && e.hostnames.contains(&hostname))
+        .ok_or_else(|| "The hosts file was not updated".to_string())
+}
+
+/// Rewrite the hosts file without the managed lines `remove` selects
+fn remove_managed(remove: impl Fn(&HostEntry) -> bool) -> Result<usize, String> {
+    let contents = read_contents()?;
+    let mut removed = 0;
+    let kept: Vec<&str> = contents
+        .lines()
+        .enumerate()
+        .filter(|(i, line)| {
+            let drop = parse_line(*i, line).map(|e| e.managed && remove(&e)).unwrap_or(false);
+            if drop {
+                removed += 1;
+            }
+            !drop
+        })
+        .map(|(_, line)| line)
+        .collect();
+    if removed == 0 {
+        return Ok(0);
+    }
+
+    let newline = if contents.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut updated = kept.join(newline);
+    updated.push_str(newline);
+    write_contents(&updated)?;
+    Ok(removed)
+}
+
+/// Remove a hostname DevLaunch added; entries from elsewhere are left alone
+#[tauri::command]
+pub async fn remove_host_entry(hostname: String) -> Result<(), String> {
+    let hostname = hostname.trim().to_lowercase();
+    let removed = remove_managed(|e| e.hostnames.contains(&hostname))?;
+    if removed == 0 {
+        return Err(format!("{} is not a DevLaunch hosts entry", hostname));
+    }
+    println!("[hosts] Removed {}", hostname);
+    Ok(())
+}
+
+/// Remove every entry DevLaunch added, or only those for one project.
+/// Returns how many lines were removed.
+#[tauri::command]
+pub async fn clear_host_entries(project: Option<String>) -> Result<usize, String> {
+    let removed = remove_managed(|e| project.is_none() || e.project == project)?;
+    println!("[hosts] Removed {} DevLaunch entries", removed);
+    Ok(removed)
+}
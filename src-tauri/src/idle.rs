@@ -0,0 +1,184 @@
+use crate::process::{self, LogEvent};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// How often connections are sampled
+const SAMPLE_INTERVAL_SECS: u64 = 60;
+
+lazy_static::lazy_static! {
+    // Last time each project printed anything
+    static ref LAST_OUTPUT: Mutex<HashMap<String, Instant>> = Mutex::new(HashMap::new());
+    // Per PID: when it was last seen active, and whether the idle rule already fired
+    static ref ACTIVITY: Mutex<HashMap<u32, (Instant, bool)>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdleEvent {
+    pub pid: u32,
+    pub path: String,
+    pub idle_minutes: u64,
+    /// "warn" or "stop"
+    pub action: String,
+}
+
+/// Record output from a project, which counts as activity
+pub(crate) fn touch(path: &str) {
+    if let Ok(mut last) = LAST_OUTPUT.lock() {
+        last.insert(path.to_string(), Instant::now());
+    }
+}
+
+/// Drop a project's output time once its process exits
+pub(crate) fn forget(path: &str) {
+    if let Ok(mut last) = LAST_OUTPUT.lock() {
+        last.remove(path);
+    }
+}
+
+/// Ports with any non-listening TCP socket on either end, from /proc/net/tcp{,6}.
+/// Closed connections linger in TIME_WAIT for a while, so requests between samples still show up.
+#[cfg(target_os = "linux")]
+fn ports_in_use() -> HashSet<u16> {
+    let mut ports = HashSet::new();
+    for file in ["/proc/net/tcp", "/proc/net/tcp6"] {
+        let Ok(content) = std::fs::read_to_string(file) else {
+            continue;
+        };
+        for line in content.lines().skip(1) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            // 0A is LISTEN
+            if fields.len() < 4 || fields[3] == "0A" {
+                continue;
+            }
+            for address in &fields[1..3] {
+                if let Some(port) = address
+                    .rsplit_once(':')
+                    .and_then(|(_, port)| u16::from_str_radix(port, 16).ok())
+                {
+                    ports.insert(port);
+                }
+            }
+        }
+    }
+    ports
+}
+
+/// Ports with any non-listening TCP socket on either end, from `netstat -an`
+/// (addresses end in ".port" on macOS and ":port" on Windows)
+#[cfg(not(target_os = "linux"))]
+fn ports_in_use() -> HashSet<u16> {
+    let mut ports = HashSet::new();
+    let Ok(output) = std::process::Command::new("netstat").args(["-an", "-p", "tcp"]).output() else {
+        return ports;
+    };
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let upper = line.to_uppercase();
+        if !upper.trim_start().starts_with("TCP") || upper.contains("LISTEN") {
+            continue;
+        }
+        for field in line.split_whitespace() {
+            if let Some(port) = field
+                .rsplit_once([':', '.'])
+                .and_then(|(_, port)| port.parse::<u16>().ok())
+            {
+                ports.insert(port);
+            }
+        }
+    }
+    ports
+}
+
+/// Ports a project serves on: the configured ones plus the last one seen in its output
+fn project_ports(settings: &crate::config::ProjectSettings, path: &str) -> Vec<u16> {
+    let mut ports = settings.ports.clone();
+    if let Some(port) = process::last_port(path) {
+        ports.push(port);
+    }
+    ports
+}
+
+fn sample(app: &AppHandle) {
+    let Ok(config) = crate::config::load_config_internal(app) else {
+        return;
+    };
+    let running = process::running_processes();
+    let Ok(mut activity) = ACTIVITY.lock() else {
+        return;
+    };
+    activity.retain(|pid, _| running.iter().any(|(p, _)| p == pid));
+
+    let mut in_use: Option<HashSet<u16>> = None;
+    let now = Instant::now();
+    for (pid, path) in running {
+        let settings = config.project_settings(&path);
+        let Some(rule) = settings.idle_shutdown.clone().filter(|r| r.enabled && r.minutes > 0) else {
+            activity.remove(&pid);
+            continue;
+        };
+        // Without a known port there's no telling whether anything is using the server
+        let ports = project_ports(&settings, &path);
+        if ports.is_empty() {
+            continue;
+        }
+
+        let in_use = in_use.get_or_insert_with(ports_in_use);
+        let last_output = LAST_OUTPUT.lock().ok().and_then(|l| l.get(&path).copied());
+        let connected = ports.iter().any(|p| in_use.contains(p));
+        let entry = activity.entry(pid).or_insert((now, false));
+        if connected {
+            *entry = (now, false);
+        } else if let Some(output) = last_output.filter(|t| *t > entry.0) {
+            *entry = (output, false);
+        }
+
+        let idle = now.duration_since(entry.0);
+        if entry.1 || idle < Duration::from_secs(rule.minutes * 60) {
+            continue;
+        }
+        entry.1 = true;
+
+        let action = if rule.action == "stop" { "stop" } else { "warn" };
+        let idle_minutes = idle.as_secs() / 60;
+        println!("[idle] PID {} ({}) idle for {} minutes ({})", pid, path, idle_minutes, action);
+        process::emit_log(
+            app,
+            LogEvent {
+                path: path.clone(),
+                level: "warn".to_string(),
+                message: format!(
+                    "[idle] No output or connections for {} minutes{}",
+                    idle_minutes,
+                    if action == "stop" { ", stopping" } else { "" }
+                ),
+                ..Default::default()
+            },
+        );
+        let _ = app.emit(
+            "project-idle",
+            IdleEvent {
+                pid,
+                path: path.clone(),
+                idle_minutes,
+                action: action.to_string(),
+            },
+        );
+        if action == "stop" {
+            if let Err(e) = process::kill_with_hooks(app, pid) {
+                println!("[idle] Failed to stop PID {}: {}", pid, e);
+            }
+        }
+    }
+}
+
+/// Start sampling running servers against their projects' idle rules
+pub fn start_monitor(app: &AppHandle) {
+    let app = app.clone();
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_secs(SAMPLE_INTERVAL_SECS));
+        sample(&app);
+    });
+}
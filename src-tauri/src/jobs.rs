@@ -0,0 +1,166 @@
+use crate::config::ResourceLimits;
+
+// Windows Job Objects holding each spawned process tree, so stopping a server also stops
+// children that detached from it (taskkill /T only follows the parent chain). Everything
+// here is a no-op on other platforms, where process groups do the same job.
+#[cfg(windows)]
+lazy_static::lazy_static! {
+    // Job handle per spawned root PID, until the process exits
+    static ref JOBS: std::sync::Mutex<std::collections::HashMap<u32, isize>> =
+        std::sync::Mutex::new(std::collections::HashMap::new());
+}
+
+/// Windows priority class for a Unix-style nice value
+#[cfg(windows)]
+fn priority_class(nice: i32) -> Option<u32> {
+    use windows_sys::Win32::System::Threading::{
+        ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS, HIGH_PRIORITY_CLASS, IDLE_PRIORITY_CLASS,
+    };
+    match nice {
+        i32::MIN..=-10 => Some(HIGH_PRIORITY_CLASS),
+        -9..=-1 => Some(ABOVE_NORMAL_PRIORITY_CLASS),
+        0 => None,
+        1..=9 => Some(BELOW_NORMAL_PRIORITY_CLASS),
+        _ => Some(IDLE_PRIORITY_CLASS),
+    }
+}
+
+/// Put a freshly spawned process in its own job, carrying the project's priority class and
+/// memory cap if it has limits; children it starts join the job automatically
+#[cfg(windows)]
+pub(crate) fn attach(pid: u32, limits: Option<&ResourceLimits>) {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation, SetInformationJobObject,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_LIMIT_JOB_MEMORY, JOB_OBJECT_LIMIT_PRIORITY_CLASS,
+    };
+    use windows_sys::Win32::System::Threading::{OpenProcess, PROCESS_SET_QUOTA, PROCESS_TERMINATE};
+
+    let priority = limits.and_then(|l| l.nice).and_then(priority_class);
+    let bytes = limits.and_then(crate::limits::hard_limit_bytes);
+
+    unsafe {
+        let job = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+        if job == 0 {
+            println!("[jobs] Failed to create a job object for PID {}", pid);
+            return;
+        }
+        if priority.is_some() || bytes.is_some() {
+            let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+            if let Some(class) = priority {
+                info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_PRIORITY_CLASS;
+                info.BasicLimitInformation.PriorityClass = class;
+            }
+            if let Some(bytes) = bytes {
+                info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_JOB_MEMORY;
+                info.JobMemoryLimit = bytes as usize;
+            }
+            let configured = SetInformationJobObject(
+                job,
+                JobObjectExtendedLimitInformation,
+                &info as *const _ as *const std::ffi::c_void,
+                std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+            );
+            if configured == 0 {
+                println!("[jobs] Failed to apply resource limits to PID {}", pid);
+            }
+        }
+
+        let process = OpenProcess(PROCESS_SET_QUOTA | PROCESS_TERMINATE, 0, pid);
+        let assigned = process != 0 && AssignProcessToJobObject(job, process) != 0;
+        if process != 0 {
+            CloseHandle(process);
+        }
+        if !assigned {
+            println!("[jobs] Failed to assign PID {} to a job object", pid);
+            CloseHandle(job);
+            return;
+        }
+        if let Ok(mut jobs) = JOBS.lock() {
+            jobs.insert(pid, job);
+        } else {
+            CloseHandle(job);
+        }
+    }
+}
+
+#[cfg(not(windows))]
+pub(crate) fn attach(_pid: u32, _limits: Option<&ResourceLimits>) {}
+
+/// Create the process with its main thread suspended, so it can't start children before
+/// attach has put it in its job; resume lets it run
+#[cfg(windows)]
+pub(crate) fn start_suspended(cmd: &mut std::process::Command) {
+    use std::os::windows::process::CommandExt;
+    cmd.creation_flags(windows_sys::Win32::System::Threading::CREATE_SUSPENDED);
+}
+
+#[cfg(not(windows))]
+pub(crate) fn start_suspended(_cmd: &mut std::process::Command) {}
+
+/// Resume every thread of a process started with start_suspended
+#[cfg(windows)]
+pub(crate) fn resume(pid: u32) {
+    use windows_sys::Win32::Foundation::{CloseHandle, INVALID_HANDLE_VALUE};
+    use windows_sys::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+    };
+    use windows_sys::Win32::System::Threading::{OpenThread, ResumeThread, THREAD_SUSPEND_RESUME};
+
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0);
+        if snapshot == INVALID_HANDLE_VALUE {
+            println!("[jobs] Failed to list the threads of PID {}", pid);
+            return;
+        }
+        let mut entry: THREADENTRY32 = std::mem::zeroed();
+        entry.dwSize = std::mem::size_of::<THREADENTRY32>() as u32;
+        let mut more = Thread32First(snapshot, &mut entry) != 0;
+        while more {
+            if entry.th32OwnerProcessID == pid {
+                let thread = OpenThread(THREAD_SUSPEND_RESUME, 0, entry.th32ThreadID);
+                if thread != 0 {
+                    ResumeThread(thread);
+                    CloseHandle(thread);
+                }
+            }
+            more = Thread32Next(snapshot, &mut entry) != 0;
+        }
+        CloseHandle(snapshot);
+    }
+}
+
+#[cfg(not(windows))]
+pub(crate) fn resume(_pid: u32) {}
+
+/// Terminate every process in the PID's job; false if it has none
+#[cfg(windows)]
+pub(crate) fn terminate(pid: u32) -> bool {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::JobObjects::TerminateJobObject;
+
+    let Some(job) = JOBS.lock().ok().and_then(|mut jobs| jobs.remove(&pid)) else {
+        return false;
+    };
+    unsafe {
+        let terminated = TerminateJobObject(job, 1) != 0;
+        CloseHandle(job);
+        if !terminated {
+            println!("[jobs] Failed to terminate the job of PID {}", pid);
+        }
+        terminated
+    }
+}
+
+/// Close the job handle once its process has exited; anything still in the job keeps running
+#[cfg(windows)]
+pub(crate) fn release(pid: u32) {
+    if let Some(job) = JOBS.lock().ok().and_then(|mut jobs| jobs.remove(&pid)) {
+        unsafe {
+            windows_sys::Win32::Foundation::CloseHandle(job);
+        }
+    }
+}
+
+#[cfg(not(windows))]
+pub(crate) fn release(_pid: u32) {}
@@ -0,0 +1,80 @@
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Keys checked, in order, for each well-known field (pino, bunyan, zap, logrus, ECS)
+const LEVEL_KEYS: &[&str] = &["level", "lvl", "severity", "log.level"];
+const MESSAGE_KEYS: &[&str] = &["msg", "message", "MESSAGE"];
+const TIME_KEYS: &[&str] = &["time", "timestamp", "ts", "@timestamp"];
+
+/// A log line that was a JSON object
+pub(crate) struct JsonLog {
+    pub level: Option<String>,
+    pub message: String,
+    /// When the line was logged, unix millis
+    pub timestamp: Option<i64>,
+    /// Every other key
+    pub fields: HashMap<String, Value>,
+}
+
+fn take_first(object: &mut Map<String, Value>, keys: &[&str]) -> Option<Value> {
+    keys.iter().find_map(|key| object.remove(*key))
+}
+
+/// pino/bunyan numeric levels, or level names
+fn level_of(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(n) => {
+            let level = match n.as_u64()? {
+                0..=20 => "debug",
+                21..=30 => "info",
+                31..=40 => "warn",
+                _ => "error",
+            };
+            Some(level.to_string())
+        }
+        Value::String(name) => crate::log_rules::normalize_level(name),
+        _ => None,
+    }
+}
+
+/// Epoch millis (pino), epoch seconds as a float (zap) or an RFC 3339 string (bunyan)
+fn timestamp_of(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => {
+            let n = n.as_f64()?;
+            // Anything this large is already in milliseconds
+            Some(if n > 1e11 { n as i64 } else { (n * 1000.0) as i64 })
+        }
+        Value::String(s) => chrono::DateTime::parse_from_rfc3339(s)
+            .ok()
+            .map(|t| t.timestamp_millis()),
+        _ => None,
+    }
+}
+
+/// Parse a structured log line; None for anything that isn't a JSON object
+pub(crate) fn parse(line: &str) -> Option<JsonLog> {
+    let trimmed = line.trim();
+    if !(trimmed.starts_with('{') && trimmed.ends_with('}')) {
+        return None;
+    }
+    let Value::Object(mut object) = serde_json::from_str::<Value>(trimmed).ok()? else {
+        return None;
+    };
+
+    let level = take_first(&mut object, LEVEL_KEYS).as_ref().and_then(level_of);
+    let timestamp = take_first(&mut object, TIME_KEYS).as_ref().and_then(timestamp_of);
+    let message = match take_first(&mut object, MESSAGE_KEYS) {
+        Some(Value::String(message)) => message,
+        Some(other) => other.to_string(),
+        // Nothing to show as the message; keep the line readable as-is
+        None => trimmed.to_string(),
+    };
+
+    Some(JsonLog {
+        level,
+        message,
+        timestamp,
+        fields: object.into_iter().collect(),
+    })
+}
@@ -0,0 +1,143 @@
+use serde::Serialize;
+use std::net::{IpAddr, Ipv4Addr};
+
+/// Virtual interfaces (containers, VMs, VPNs) a phone on the same Wi-Fi can't reach
+const VIRTUAL_INTERFACE_PREFIXES: &[&str] = &[
+    "docker", "br-", "veth", "virbr", "vmnet", "vboxnet", "utun", "tun", "tap", "awdl", "llw", "bridge",
+];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanUrl {
+    pub interface: String,
+    pub ip: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanUrls {
+    pub port: u16,
+    /// Best candidate first
+    pub urls: Vec<LanUrl>,
+    /// Text to encode in a QR code: the first URL
+    pub qr_payload: Option<String>,
+    /// Addresses the server on this port listens on, if it was found
+    pub bind_addresses: Vec<String>,
+    /// The server only listens on loopback, so none of the URLs will load from another device
+    pub loopback_only: bool,
+    /// How to make the server listen on all interfaces, when it doesn't
+    pub bind_hint: Option<String>,
+}
+
+fn is_loopback_address(address: &str) -> bool {
+    address == "localhost"
+        || address
+            .parse::<IpAddr>()
+            .map(|ip| ip.is_loopback())
+            .unwrap_or(false)
+}
+
+fn is_wildcard_address(address: &str) -> bool {
+    matches!(address, "0.0.0.0" | "::" | "*" | "")
+}
+
+/// Private ranges on physical-looking interfaces sort before everything else
+fn rank(interface: &str, ip: &Ipv4Addr) -> u8 {
+    let physical = ["en", "eth", "wl", "wlan", "Wi-Fi", "Ethernet"]
+        .iter()
+        .any(|p| interface.starts_with(p));
+    match (ip.is_private(), physical) {
+        (true, true) => 0,
+        (true, false) => 1,
+        _ => 2,
+    }
+}
+
+/// Flag that makes a dev server listen on all interfaces, guessed from its command line
+fn bind_hint(command: &str, port: u16) -> String {
+    let command = command.to_lowercase();
+    let flag = if command.contains("vite") || command.contains("webpack") || command.contains("astro") {
+        "--host 0.0.0.0".to_string()
+    } else if command.contains("next") {
+        "-H 0.0.0.0".to_string()
+    } else if command.contains("rails") || command.contains("puma") {
+        "-b 0.0.0.0".to_string()
+    } else if command.contains("manage.py") {
+        format!("runserver 0.0.0.0:{}", port)
+    } else if command.contains("flask") {
+        "--host=0.0.0.0".to_string()
+    } else if command.contains("uvicorn") || command.contains("hugo") {
+        "--host 0.0.0.0".to_string()
+    } else {
+        return "Start the server bound to 0.0.0.0 instead of 127.0.0.1 so other devices can reach it".to_string();
+    };
+    format!("Add {} to the start command so other devices can reach it", flag)
+}
+
+/// URLs other devices on the local network can use to reach a port on this machine,
+/// with a warning when the server there only listens on loopback
+#[tauri::command]
+pub async fn get_lan_urls(port: u16, https: Option<bool>) -> Result<LanUrls, String> {
+    let scheme = if https.unwrap_or(false) { "https" } else { "http" };
+    let listeners: Vec<crate::process::PortInfo> = crate::process::scan_ports()
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|p| p.port == port)
+        .collect();
+    let mut bind_addresses: Vec<String> = listeners.iter().map(|p| p.address.clone()).collect();
+    bind_addresses.sort();
+    bind_addresses.dedup();
+    // A server bound to specific LAN addresses is only reachable on those
+    let reachable_on: Vec<&String> = if bind_addresses.iter().any(|a| is_wildcard_address(a)) {
+        Vec::new()
+    } else {
+        bind_addresses.iter().filter(|a| !is_loopback_address(a)).collect()
+    };
+
+    let interfaces = tauri::async_runtime::spawn_blocking(if_addrs::get_if_addrs)
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("Failed to list network interfaces: {}", e))?;
+    let mut candidates: Vec<(u8, LanUrl)> = interfaces
+        .into_iter()
+        .filter(|i| !i.is_loopback())
+        .filter(|i| !VIRTUAL_INTERFACE_PREFIXES.iter().any(|p| i.name.starts_with(p)))
+        .filter_map(|i| match i.ip() {
+            IpAddr::V4(ip) if !ip.is_link_local() => Some((i.name, ip)),
+            _ => None,
+        })
+        .filter(|(_, ip)| reachable_on.is_empty() || reachable_on.iter().any(|a| **a == ip.to_string()))
+        .map(|(interface, ip)| {
+            (
+                rank(&interface, &ip),
+                LanUrl {
+                    url: format!("{}://{}:{}", scheme, ip, port),
+                    ip: ip.to_string(),
+                    interface,
+                },
+            )
+        })
+        .collect();
+    candidates.sort_by_key(|(rank, _)| *rank);
+    let urls: Vec<LanUrl> = candidates.into_iter().map(|(_, url)| url).collect();
+
+    let loopback_only = !bind_addresses.is_empty() && bind_addresses.iter().all(|a| is_loopback_address(a));
+    let bind_hint = if loopback_only {
+        let command = listeners.first().map(|p| p.command.as_str()).unwrap_or_default();
+        println!("[lan] Port {} only listens on loopback", port);
+        Some(bind_hint(command, port))
+    } else {
+        None
+    };
+
+    Ok(LanUrls {
+        port,
+        qr_payload: urls.first().map(|u| u.url.clone()),
+        urls,
+        bind_addresses,
+        loopback_only,
+        bind_hint,
+    })
+}
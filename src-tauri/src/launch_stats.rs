@@ -0,0 +1,250 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+/// Launches kept per project
+const MAX_LAUNCHES: usize = 100;
+
+lazy_static::lazy_static! {
+    // Launch in progress per project path, until it's ready, exits or is relaunched
+    static ref PENDING: Mutex<HashMap<String, LaunchRecord>> = Mutex::new(HashMap::new());
+    // Serializes access to the history file
+    static ref STORE: Mutex<()> = Mutex::new(());
+}
+
+/// Timings of one launch, each measured from the spawn (ms)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchRecord {
+    pub started_at: i64,
+    pub command: String,
+    pub first_log_ms: Option<i64>,
+    pub url_detected_ms: Option<i64>,
+    pub health_check_pass_ms: Option<i64>,
+    /// Exited before it became ready
+    #[serde(default)]
+    pub exited_early: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DurationSummary {
+    pub count: usize,
+    pub latest_ms: Option<i64>,
+    pub median_ms: Option<i64>,
+    pub p90_ms: Option<i64>,
+    pub min_ms: Option<i64>,
+    pub max_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchStats {
+    pub path: String,
+    /// Oldest first
+    pub launches: Vec<LaunchRecord>,
+    /// A launch that hasn't become ready yet
+    pub current: Option<LaunchRecord>,
+    pub first_log: DurationSummary,
+    pub url_detected: DurationSummary,
+    pub health_check_pass: DurationSummary,
+}
+
+fn stats_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    std::fs::create_dir_all(&app_data_dir).map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("launch_stats.json"))
+}
+
+fn read_history(path: &Path) -> HashMap<String, Vec<LaunchRecord>> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+/// Append a finished launch to the project's history
+fn persist(app: &AppHandle, project: &str, record: LaunchRecord) {
+    let Ok(_guard) = STORE.lock() else {
+        return;
+    };
+    let Ok(path) = stats_file(app) else {
+        return;
+    };
+    let mut history = read_history(&path);
+    let launches = history.entry(project.to_string()).or_default();
+    launches.push(record);
+    let excess = launches.len().saturating_sub(MAX_LAUNCHES);
+    launches.drain(..excess);
+
+    let result = serde_json::to_string(&history)
+        .map_err(|e| e.to_string())
+        .and_then(|json| {
+            let tmp = path.with_extension("json.tmp");
+            std::fs::write(&tmp, json).map_err(|e| e.to_string())?;
+            std::fs::rename(&tmp, &path).map_err(|e| e.to_string())
+        });
+    if let Err(e) = result {
+        println!("[launch_stats] Failed to write {:?}: {}", path, e);
+    }
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+/// Start timing a launch; an unfinished previous launch of the project is recorded as is
+pub(crate) fn start(app: &AppHandle, path: &str, command: &str, args: &[String]) {
+    let record = LaunchRecord {
+        started_at: now_ms(),
+        command: std::iter::once(command)
+            .chain(args.iter().map(|a| a.as_str()))
+            .collect::<Vec<_>>()
+            .join(" "),
+        first_log_ms: None,
+        url_detected_ms: None,
+        health_check_pass_ms: None,
+        exited_early: false,
+    };
+    let previous = PENDING
+        .lock()
+        .ok()
+        .and_then(|mut pending| pending.insert(path.to_string(), record));
+    if let Some(previous) = previous {
+        persist(app, path, previous);
+    }
+}
+
+/// Update the pending launch; once `done` says it's complete it moves to the history
+fn update(
+    app: &AppHandle,
+    path: &str,
+    change: impl FnOnce(&mut LaunchRecord, i64),
+    done: impl FnOnce(&LaunchRecord) -> bool,
+) {
+    let finished = {
+        let Ok(mut pending) = PENDING.lock() else {
+            return;
+        };
+        let Some(record) = pending.get_mut(path) else {
+            return;
+        };
+        let elapsed = now_ms() - record.started_at;
+        change(record, elapsed);
+        if done(record) {
+            pending.remove(path)
+        } else {
+            None
+        }
+    };
+    if let Some(record) = finished {
+        println!(
+            "[launch_stats] {} launch: first log {:?} ms, URL {:?} ms, healthy {:?} ms",
+            path, record.first_log_ms, record.url_detected_ms, record.health_check_pass_ms
+        );
+        persist(app, path, record);
+    }
+}
+
+/// A line of output from the project
+pub(crate) fn on_output(app: &AppHandle, path: &str) {
+    // Cheap check first: this runs for every log line
+    let waiting = PENDING
+        .lock()
+        .map(|p| p.get(path).map(|r| r.first_log_ms.is_none()).unwrap_or(false))
+        .unwrap_or(false);
+    if waiting {
+        update(app, path, |r, elapsed| r.first_log_ms = Some(elapsed), |_| false);
+    }
+}
+
+/// The project's served URL was detected; without a health check that makes it ready
+pub(crate) fn on_url_detected(app: &AppHandle, path: &str) {
+    let waiting = PENDING
+        .lock()
+        .map(|p| p.get(path).map(|r| r.url_detected_ms.is_none()).unwrap_or(false))
+        .unwrap_or(false);
+    if !waiting {
+        return;
+    }
+    let has_health_check = crate::config::load_config_internal(app)
+        .map(|c| c.project_settings(path).health_check.is_some())
+        .unwrap_or(false);
+    update(
+        app,
+        path,
+        |r, elapsed| {
+            r.url_detected_ms = Some(elapsed);
+            if r.first_log_ms.is_none() {
+                r.first_log_ms = Some(elapsed);
+            }
+        },
+        |_| !has_health_check,
+    );
+}
+
+/// The project's health check passed for the first time
+pub(crate) fn on_healthy(app: &AppHandle, path: &str) {
+    update(app, path, |r, elapsed| r.health_check_pass_ms = Some(elapsed), |_| true);
+}
+
+/// The process started at `process_started_at` exited; if it's the pending launch, record it
+pub(crate) fn on_exit(app: &AppHandle, path: &str, process_started_at: i64) {
+    update(
+        app,
+        path,
+        |r, _| {
+            // A relaunch registers its process after the launch started; older exits don't count
+            if r.started_at <= process_started_at {
+                r.exited_early = true;
+            }
+        },
+        |r| r.exited_early,
+    );
+}
+
+fn summarize(values: Vec<i64>) -> DurationSummary {
+    let latest_ms = values.last().copied();
+    let mut sorted = values;
+    sorted.sort_unstable();
+    let percentile = |p: usize| {
+        if sorted.is_empty() {
+            None
+        } else {
+            Some(sorted[((sorted.len() - 1) * p) / 100])
+        }
+    };
+    DurationSummary {
+        count: sorted.len(),
+        latest_ms,
+        median_ms: percentile(50),
+        p90_ms: percentile(90),
+        min_ms: sorted.first().copied(),
+        max_ms: sorted.last().copied(),
+    }
+}
+
+/// Startup timings of a project's recent launches, with summaries for spotting regressions
+#[tauri::command]
+pub async fn get_launch_stats(app: AppHandle, path: String) -> Result<LaunchStats, String> {
+    let launches = {
+        let _guard = STORE.lock().map_err(|e| e.to_string())?;
+        read_history(&stats_file(&app)?).remove(&path).unwrap_or_default()
+    };
+    let current = PENDING.lock().ok().and_then(|p| p.get(&path).cloned());
+    let series = |metric: fn(&LaunchRecord) -> Option<i64>| launches.iter().filter_map(metric).collect::<Vec<_>>();
+
+    Ok(LaunchStats {
+        first_log: summarize(series(|r| r.first_log_ms)),
+        url_detected: summarize(series(|r| r.url_detected_ms)),
+        health_check_pass: summarize(series(|r| r.health_check_pass_ms)),
+        path,
+        launches,
+        current,
+    })
+}
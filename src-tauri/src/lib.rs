@@ -1,8 +1,68 @@
+mod alerts;
+mod ansi;
+mod browsers;
+mod command_line;
+mod compose;
 mod config;
+mod config_bundle;
+mod config_watch;
+mod control;
+mod crash_history;
+mod databases;
+mod deps;
+mod elevation;
+mod envfiles;
 mod git;
+mod git_hosting;
+mod health;
+mod hooks;
+mod hosts;
+mod idle;
+mod jobs;
+mod json_logs;
+mod lan;
+mod launch_stats;
+mod limits;
+mod log_files;
+mod log_rules;
+mod log_search;
+mod log_share;
+mod log_window;
+mod metrics_server;
+mod mock_server;
+mod orphans;
+mod power;
+mod presets;
 mod process;
+mod project_config;
+mod projects;
+mod problems;
+mod prompts;
+mod proxy;
+mod pty;
+mod quit;
+mod runtime;
+mod scheduler;
+mod scripts;
+mod secrets;
+mod session;
+mod shell_env;
+mod shortcuts;
+mod ssh_tunnels;
+mod static_server;
 mod system;
+mod system_history;
+mod tls;
+mod traffic_export;
+mod tray;
+mod tunnels;
+mod updates;
+mod watch_restart;
+mod webhook_filter;
 mod webhook_server;
+mod webhook_signature;
+mod webhook_store;
+mod window_state;
 
 use tauri::{
     image::Image,
@@ -14,10 +74,78 @@ use tauri::{
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        // Must be first: a second launch (e.g. from a devlaunch:// link) hands off to this instance
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(shortcuts::handle)
+                .build(),
+        )
+        // A reloaded or closed window stops listening; drop its webhook stream subscriptions
+        .on_page_load(|webview, payload| {
+            if matches!(payload.event(), tauri::webview::PageLoadEvent::Started) {
+                webhook_server::drop_window_streams(webview.label());
+            }
+        })
+        .on_window_event(|window, event| {
+            if matches!(event, WindowEvent::Destroyed) {
+                webhook_server::drop_window_streams(window.label());
+            }
+        })
         .setup(|app| {
+            // Resolve the login-shell environment in the background before the first spawn
+            shell_env::warm_cache(app.handle());
+            // Pick up hand edits and sync-tool changes to config.json
+            config_watch::start(app.handle());
+            // CPU usage needs two samples; take the first now so the first query isn't all zeros
+            system::prime_cpu_samples();
+            // Stream resource usage of running dev servers to the frontend
+            system::start_process_metrics_monitor(app.handle());
+            // Warn about (or stop) dev servers nobody has used for a while
+            idle::start_monitor(app.handle());
+            // Warn when switching to battery or the machine starts throttling
+            power::start_power_monitor(app.handle());
+            metrics_server::start_from_preferences(app.handle());
+            // Record CPU/memory/disk samples for history graphs
+            system_history::start_sampler(app.handle());
+            control::start_from_preferences(app.handle());
+            shortcuts::start_from_preferences(app.handle());
+            // Startup and cron-style scheduled tasks
+            scheduler::start(app.handle());
+            // Fetch and check git status of watched projects, even while the window is hidden
+            git::start_polling(app.handle());
+            // Dev servers a crashed previous session left running
+            orphans::detect(app.handle());
+            session::restore_on_launch(app.handle());
+            // Only release builds configured with a signing key ship the updater
+            updates::init(app.handle());
+
+            // devlaunch:// links, both the one that launched us and later ones
+            {
+                use tauri_plugin_deep_link::DeepLinkExt;
+                // Linux and Windows only pick up the scheme from the installer; register it for dev builds too
+                #[cfg(any(windows, target_os = "linux"))]
+                let _ = app.deep_link().register_all();
+                if let Ok(Some(urls)) = app.deep_link().get_current() {
+                    control::handle_deep_links(app.handle(), urls);
+                }
+                let handle = app.handle().clone();
+                app.deep_link().on_open_url(move |event| {
+                    control::handle_deep_links(&handle, event.urls());
+                });
+            }
+
             // Create app menu (macOS menu bar)
             let about = PredefinedMenuItem::about(
                 app,
@@ -77,29 +205,20 @@ pub fn run() {
                 }
             });
 
-            // Create tray menu
-            let tray_quit = MenuItem::with_id(app, "quit", "Quit DevLaunch", true, None::<&str>)?;
-            let tray_show = MenuItem::with_id(app, "show", "Show Window", true, None::<&str>)?;
-            let tray_hide = MenuItem::with_id(app, "hide", "Hide Window", true, None::<&str>)?;
-            let tray_sep = MenuItem::with_id(app, "sep", "─────────────", false, None::<&str>)?;
-
-            let tray_menu = Menu::with_items(app, &[&tray_show, &tray_hide, &tray_sep, &tray_quit])?;
+            // Create tray menu (rebuilt as projects start and stop)
+            let tray_menu = tray::build_menu(app.handle())?;
 
             // Use the app icon for the tray
-            let icon = Image::from_bytes(include_bytes!("../icons/32x32.png"))
+            let icon = Image::from_bytes(tray::ICON)
                 .expect("Failed to load tray icon");
 
-            let _tray = TrayIconBuilder::new()
+            let _tray = TrayIconBuilder::with_id(tray::TRAY_ID)
                 .icon(icon)
                 .menu(&tray_menu)
                 .show_menu_on_left_click(false)
                 .tooltip("DevLaunch")
                 .on_menu_event(|app, event| match event.id.as_ref() {
-                    "quit" => {
-                        // Kill all processes before quitting
-                        let _ = process::kill_all_processes_internal();
-                        app.exit(0);
-                    }
+                    "quit" => quit::request_quit(app),
                     "show" => {
                         if let Some(window) = app.get_webview_window("main") {
                             let _ = window.show();
@@ -111,7 +230,9 @@ pub fn run() {
                             let _ = window.hide();
                         }
                     }
-                    _ => {}
+                    id => {
+                        tray::handle_project_menu_event(app, id);
+                    }
                 })
                 .on_tray_icon_event(|tray, event| {
                     if let TrayIconEvent::Click {
@@ -137,19 +258,23 @@ pub fn run() {
             let main_window = app.get_webview_window("main").unwrap();
             let app_handle = app.handle().clone();
 
+            // Restore saved geometry while the window is still hidden, then show it
+            window_state::restore(app.handle(), &main_window);
+            let _ = main_window.show();
+
+            let state_window = main_window.clone();
             main_window.on_window_event(move |event| {
+                if matches!(event, WindowEvent::Resized(_) | WindowEvent::Moved(_)) {
+                    window_state::schedule_save(&app_handle, &state_window);
+                }
                 if let WindowEvent::CloseRequested { api, .. } = event {
-                    // Check if there are running processes
-                    let count = process::get_running_count_internal();
-                    if count > 0 {
-                        // Let the frontend handle the quit dialog
-                        // Don't prevent close here - it's handled in App.tsx
-                    } else {
+                    api.prevent_close();
+                    if process::get_running_count_internal() > 0 {
+                        // Asks whether to stop the running servers
+                        quit::request_quit(&app_handle);
+                    } else if let Some(window) = app_handle.get_webview_window("main") {
                         // No running processes - hide to tray instead of quitting
-                        if let Some(window) = app_handle.get_webview_window("main") {
-                            api.prevent_close();
-                            let _ = window.hide();
-                        }
+                        let _ = window.hide();
                     }
                 }
             });
@@ -158,28 +283,164 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             process::spawn_process,
+            process::spawn_shell,
+            elevation::spawn_process_elevated,
             process::kill_process,
             process::kill_all_processes,
+            quit::quit_app,
+            quit::resolve_quit,
             process::get_running_count,
             process::get_process_status,
+            process::list_processes,
+            process::restart_process,
+            process::get_process_logs,
+            process::clear_process_logs,
             process::scan_ports,
+            process::kill_port,
+            process::stop_process,
+            process::check_port_conflicts,
+            process::get_process_env,
+            problems::get_project_problems,
+            problems::clear_project_problems,
             system::get_system_info,
             system::get_top_processes,
+            system::get_process_metrics,
+            system::get_process_tree,
+            system::kill_tree_member,
+            orphans::list_orphans,
+            orphans::reconcile_orphans,
+            orphans::adopt_process,
+            session::restore_last_session,
+            shortcuts::get_shortcuts,
+            updates::check_for_updates,
+            updates::download_and_install,
+            system::get_network_info,
             config::load_config,
             config::save_config,
             config::get_config_path_string,
+            config::list_config_backups,
+            config::restore_config_backup,
+            config_bundle::export_config,
+            config_bundle::import_config,
             webhook_server::start_webhook_server,
             webhook_server::stop_webhook_server,
             webhook_server::get_webhook_events,
             webhook_server::clear_webhook_events,
             webhook_server::get_webhook_server_status,
+            webhook_server::set_webhook_forward_port,
+            webhook_server::replay_webhook_event,
+            webhook_server::subscribe_webhook_stream,
+            webhook_server::update_webhook_stream,
+            webhook_server::unsubscribe_webhook_stream,
             webhook_server::start_ngrok,
             webhook_server::stop_ngrok,
             webhook_server::get_ngrok_status,
             webhook_server::set_ngrok_auth_token,
+            tunnels::start_tunnel,
+            tunnels::stop_tunnel,
+            tunnels::list_tunnels,
+            ssh_tunnels::create_ssh_tunnel,
+            ssh_tunnels::list_ssh_tunnels,
+            ssh_tunnels::stop_ssh_tunnel,
+            databases::check_database,
+            databases::list_database_services,
+            databases::manage_database_service,
+            hosts::read_hosts,
+            hosts::add_host_entry,
+            hosts::remove_host_entry,
+            hosts::clear_host_entries,
+            lan::get_lan_urls,
+            launch_stats::get_launch_stats,
+            crash_history::get_crash_history,
             git::git_status,
+            git::get_git_statuses,
+            git::git_status_all,
             git::git_pull,
+            git::git_pull_all,
+            git::git_log,
+            git::git_show,
+            git::git_clone,
+            git::git_repo_size,
+            git::git_hygiene,
+            git::git_commit,
+            git::git_push,
+            git::git_submodule_update,
+            git::git_stash,
+            git::git_stash_pop,
+            git::git_branches,
+            git::git_checkout,
+            git::git_create_branch,
+            git::git_worktrees,
+            git::git_worktree_add,
+            git::git_worktree_remove,
+            git_hosting::git_remote_info,
+            git_hosting::unwatch_git_remote_info,
+            deps::run_install,
+            deps::check_dependencies,
+            scheduler::get_scheduled_tasks,
+            scheduler::run_scheduled_task,
+            project_config::load_project_config,
+            project_config::trust_project_config,
+            health::get_health_history,
+            health::get_project_health,
+            log_share::start_log_share,
+            log_share::stop_log_share,
+            log_share::list_log_shares,
+            log_window::open_log_window,
+            presets::start_preset,
+            presets::launch_preset,
+            presets::stop_preset,
+            scripts::discover_scripts,
+            envfiles::list_env_files,
+            envfiles::read_env_file,
+            envfiles::write_env_file,
+            static_server::serve_static,
+            static_server::stop_static_server,
+            static_server::get_static_servers,
+            log_files::get_log_files,
+            log_files::read_log_file,
+            log_search::search_logs,
+            browsers::list_browsers,
+            browsers::open_url,
+            pty::write_process_stdin,
+            process::send_stdin,
+            pty::resize_process_pty,
+            metrics_server::start_metrics_server,
+            metrics_server::stop_metrics_server,
+            metrics_server::get_metrics_server_status,
+            system_history::get_system_history,
+            projects::scan_projects,
+            projects::detect_workspaces,
+            control::start_control_server,
+            control::stop_control_server,
+            control::get_control_server_status,
+            mock_server::start_mock_server,
+            mock_server::stop_mock_server,
+            mock_server::get_mock_server_status,
+            mock_server::reload_mock_routes,
+            mock_server::get_mock_api_hits,
+            mock_server::clear_mock_api_hits,
+            tls::get_local_ca,
+            proxy::proxy_start,
+            proxy::proxy_stop,
+            proxy::get_proxies,
+            proxy::get_proxy_exchanges,
+            proxy::clear_proxy_exchanges,
+            traffic_export::export_webhook_events,
+            traffic_export::copy_event_as_curl,
+            compose::compose_up,
+            compose::compose_down,
+            compose::compose_ps,
+            compose::compose_logs,
+            secrets::store_secret,
+            secrets::get_secret,
+            secrets::delete_secret,
+            shell_env::get_shell_env,
+            shell_env::refresh_shell_env,
+            shell_env::resolve_command,
+            runtime::detect_runtime,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(quit::handle_run_event);
 }
@@ -0,0 +1,194 @@
+use crate::config::ResourceLimits;
+use crate::process::{self, LogEvent};
+use crate::system::ProcessMetrics;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+lazy_static::lazy_static! {
+    // PIDs currently over their memory cap, so each breach is acted on once
+    static ref OVER_LIMIT: Mutex<HashSet<u32>> = Mutex::new(HashSet::new());
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceLimitEvent {
+    pub pid: u32,
+    pub path: String,
+    pub rss_mb: f32,
+    pub max_memory_mb: u64,
+    /// "warn", "kill" or "restart"
+    pub action: String,
+}
+
+/// Limits configured for a project, if any
+pub(crate) fn project_limits(app: &AppHandle, cwd: &str) -> Option<ResourceLimits> {
+    crate::config::load_config_internal(app)
+        .ok()
+        .and_then(|c| c.project_settings(cwd).limits)
+}
+
+pub(crate) fn hard_limit_bytes(limits: &ResourceLimits) -> Option<u64> {
+    limits
+        .max_memory_mb
+        .filter(|_| limits.hard_memory_limit)
+        .map(|mb| mb * 1024 * 1024)
+}
+
+/// The hard cap as enforced on this platform. Not RLIMIT_AS: runtimes reserve far more
+/// address space than they use and would fail at startup under a realistic cap. RLIMIT_DATA
+/// is per process, so each process in the tree gets the whole cap; the tree-wide total is
+/// left to the monitor.
+#[cfg(unix)]
+fn hard_limit_data_bytes(limits: &ResourceLimits) -> Option<u64> {
+    let bytes = hard_limit_bytes(limits)?;
+    if cfg!(target_os = "linux") {
+        Some(bytes)
+    } else {
+        // macOS and the BSDs don't count mmap'd memory against RLIMIT_DATA
+        println!("[limits] Hard memory limits aren't supported on this platform; relying on the monitor");
+        None
+    }
+}
+
+/// Apply niceness and the hard memory cap in the child before exec, so every process it
+/// starts inherits them
+#[cfg(unix)]
+pub(crate) fn configure_command(limits: &ResourceLimits, cmd: &mut std::process::Command) {
+    use std::os::unix::process::CommandExt;
+    let nice = limits.nice.map(|n| n.clamp(-20, 19));
+    let bytes = hard_limit_data_bytes(limits);
+    if nice.is_none() && bytes.is_none() {
+        return;
+    }
+    // Only async-signal-safe calls in here; failures (e.g. raising priority without root)
+    // are ignored so the launch still goes ahead
+    unsafe {
+        cmd.pre_exec(move || {
+            if let Some(nice) = nice {
+                libc::setpriority(libc::PRIO_PROCESS, 0, nice);
+            }
+            if let Some(bytes) = bytes {
+                let limit = libc::rlimit {
+                    rlim_cur: bytes as libc::rlim_t,
+                    rlim_max: bytes as libc::rlim_t,
+                };
+                libc::setrlimit(libc::RLIMIT_DATA, &limit);
+            }
+            Ok(())
+        });
+    }
+}
+
+/// Apply limits to an already-running process (PTY spawns; on Windows they go on the
+/// process's job object instead, see jobs::attach)
+#[cfg(unix)]
+pub(crate) fn apply_to_process(limits: &ResourceLimits, pid: u32) {
+    use std::process::Command;
+    if let Some(nice) = limits.nice.map(|n| n.clamp(-20, 19)) {
+        // PTY children lead their own process group
+        let status = Command::new("renice")
+            .args(["-n", &nice.to_string(), "-g", &pid.to_string()])
+            .stdout(std::process::Stdio::null())
+            .status();
+        if !matches!(status, Ok(s) if s.success()) {
+            println!("[limits] Failed to renice PID {} to {}", pid, nice);
+        }
+    }
+    if let Some(bytes) = hard_limit_data_bytes(limits) {
+        let status = Command::new("prlimit")
+            .args(["--pid", &pid.to_string(), &format!("--data={}", bytes)])
+            .status();
+        if !matches!(status, Ok(s) if s.success()) {
+            println!("[limits] Failed to set memory limit on PID {}", pid);
+        }
+    }
+}
+
+/// Compare a metrics sample against each project's memory cap and act on new breaches.
+/// Returns the PIDs currently over their cap, whose memory the cap's action already covers.
+pub(crate) fn check(app: &AppHandle, metrics: &[ProcessMetrics]) -> HashSet<u32> {
+    let Ok(config) = crate::config::load_config_internal(app) else {
+        return HashSet::new();
+    };
+    let Ok(mut over) = OVER_LIMIT.lock() else {
+        return HashSet::new();
+    };
+    over.retain(|pid| metrics.iter().any(|m| m.pid == *pid));
+
+    for sample in metrics {
+        let Some(path) = &sample.path else {
+            continue;
+        };
+        let Some((limits, max)) = config
+            .project_settings(path)
+            .limits
+            .and_then(|l| l.max_memory_mb.map(|max| (l, max)))
+        else {
+            continue;
+        };
+        if (sample.rss_mb as u64) < max {
+            over.remove(&sample.pid);
+            continue;
+        }
+        if !over.insert(sample.pid) {
+            continue;
+        }
+
+        let action = match limits.on_exceed.as_str() {
+            "kill" | "restart" => limits.on_exceed.clone(),
+            _ => "warn".to_string(),
+        };
+        println!(
+            "[limits] PID {} ({}) using {:.0} MB, over its {} MB limit ({})",
+            sample.pid, path, sample.rss_mb, max, action
+        );
+        process::emit_log(
+            app,
+            LogEvent {
+                path: path.clone(),
+                level: if action == "warn" { "warn" } else { "error" }.to_string(),
+                message: format!(
+                    "[limits] Memory {:.0} MB exceeds the {} MB limit{}",
+                    sample.rss_mb,
+                    max,
+                    match action.as_str() {
+                        "kill" => ", stopping",
+                        "restart" => ", restarting",
+                        _ => "",
+                    }
+                ),
+                ..Default::default()
+            },
+        );
+        let _ = app.emit(
+            "resource-limit-exceeded",
+            ResourceLimitEvent {
+                pid: sample.pid,
+                path: path.clone(),
+                rss_mb: sample.rss_mb,
+                max_memory_mb: max,
+                action: action.clone(),
+            },
+        );
+
+        match action.as_str() {
+            "kill" => {
+                if let Err(e) = process::kill_process_internal(sample.pid) {
+                    println!("[limits] Failed to stop PID {}: {}", sample.pid, e);
+                }
+            }
+            "restart" => {
+                let (app, pid) = (app.clone(), sample.pid);
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = process::restart_process_internal(&app, pid).await {
+                        println!("[limits] Failed to restart PID {}: {}", pid, e);
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+    over.clone()
+}
@@ -0,0 +1,299 @@
+use crate::config::LogFilePreferences;
+use crate::process::LogEvent;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+lazy_static::lazy_static! {
+    // Open log file per project path, for projects started while log files are enabled
+    static ref WRITERS: Mutex<HashMap<String, LogWriter>> = Mutex::new(HashMap::new());
+}
+
+struct LogWriter {
+    dir: PathBuf,
+    file: File,
+    written: u64,
+    max_bytes: u64,
+    max_files: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogFileInfo {
+    /// Folder under logs/ the file lives in (one per project)
+    pub project: String,
+    pub name: String,
+    pub path: String,
+    pub size: u64,
+    /// Last write, unix millis
+    pub modified: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogFileChunk {
+    pub content: String,
+    /// Byte offset to pass back to continue reading
+    pub next_offset: u64,
+    pub size: u64,
+}
+
+fn logs_root(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    Ok(app_data_dir.join("logs"))
+}
+
+/// Folder name for a project: its folder name plus a short hash so same-named projects don't mix
+fn project_dir_name(project_path: &str) -> String {
+    let name: String = Path::new(project_path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "project".to_string())
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' { c } else { '_' })
+        .collect();
+    let hash = Sha256::digest(project_path.as_bytes());
+    format!("{}-{}", name, hex::encode(&hash[..4]))
+}
+
+/// Create a new `<timestamp>.log` in `dir` (suffixed if one already exists for this second)
+fn create_log_file(dir: &Path) -> Result<File, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create log folder: {}", e))?;
+    let stamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S").to_string();
+    let mut path = dir.join(format!("{}.log", stamp));
+    let mut n = 1;
+    while path.exists() {
+        path = dir.join(format!("{}-{}.log", stamp, n));
+        n += 1;
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to create log file: {}", e))
+}
+
+fn list_dir(dir: &Path) -> Vec<LogFileInfo> {
+    let project = dir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    read_dir
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "log"))
+        .filter_map(|e| {
+            let meta = e.metadata().ok()?;
+            let modified = meta
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_millis() as i64)
+                .unwrap_or(0);
+            Some(LogFileInfo {
+                project: project.clone(),
+                name: e.file_name().to_string_lossy().to_string(),
+                path: e.path().to_string_lossy().to_string(),
+                size: meta.len(),
+                modified,
+            })
+        })
+        .collect()
+}
+
+/// Delete the oldest files in `dir` so at most `max_files` remain
+fn prune(dir: &Path, max_files: usize) {
+    let mut files = list_dir(dir);
+    if files.len() <= max_files {
+        return;
+    }
+    // Timestamped names sort chronologically
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    for old in &files[..files.len() - max_files] {
+        let _ = fs::remove_file(&old.path);
+    }
+}
+
+/// Start a fresh log file for a project launch (no-op unless log files are enabled)
+pub fn start_session(app: &AppHandle, project_path: &str, command: &str, args: &[String], prefs: &LogFilePreferences) {
+    let mut writers = match WRITERS.lock() {
+        Ok(w) => w,
+        Err(_) => return,
+    };
+    writers.remove(project_path);
+    if !prefs.enabled {
+        return;
+    }
+
+    let dir = match logs_root(app) {
+        Ok(root) => root.join(project_dir_name(project_path)),
+        Err(e) => {
+            println!("[log_files] {}", e);
+            return;
+        }
+    };
+    let mut file = match create_log_file(&dir) {
+        Ok(f) => f,
+        Err(e) => {
+            println!("[log_files] {}", e);
+            return;
+        }
+    };
+
+    let header = format!(
+        "# {} {}\n# cwd: {}\n# started: {}\n",
+        command,
+        args.join(" "),
+        project_path,
+        chrono::Local::now().to_rfc3339()
+    );
+    let _ = file.write_all(header.as_bytes());
+
+    let max_files = prefs.max_files.max(1);
+    prune(&dir, max_files);
+    writers.insert(
+        project_path.to_string(),
+        LogWriter {
+            dir,
+            file,
+            written: header.len() as u64,
+            max_bytes: prefs.max_file_size_mb.max(1) * 1024 * 1024,
+            max_files,
+        },
+    );
+}
+
+/// Append a log line to its project's file, rotating once the file is full
+pub fn append(event: &LogEvent) {
+    let Ok(mut writers) = WRITERS.lock() else {
+        return;
+    };
+    let Some(writer) = writers.get_mut(&event.path) else {
+        return;
+    };
+
+    let line = format!(
+        "{} [{}] {}\n",
+        chrono::Local::now().format("%Y-%m-%dT%H:%M:%S%.3f"),
+        event.level,
+        event.message
+    );
+
+    if writer.written + line.len() as u64 > writer.max_bytes {
+        match create_log_file(&writer.dir) {
+            Ok(file) => {
+                writer.file = file;
+                writer.written = 0;
+                prune(&writer.dir, writer.max_files);
+            }
+            Err(e) => {
+                println!("[log_files] Rotation failed, stopping log file for {}: {}", event.path, e);
+                writers.remove(&event.path);
+                return;
+            }
+        }
+    }
+
+    if writer.file.write_all(line.as_bytes()).is_ok() {
+        writer.written += line.len() as u64;
+    }
+}
+
+/// Project path a log file was written for, from its header (rotated files have none)
+fn file_project(path: &Path) -> Option<String> {
+    use std::io::{BufRead, BufReader};
+    let file = File::open(path).ok()?;
+    BufReader::new(file)
+        .lines()
+        .take(3)
+        .map_while(Result::ok)
+        .find_map(|line| line.strip_prefix("# cwd: ").map(|cwd| cwd.to_string()))
+}
+
+/// Saved log files grouped by project path, oldest file first
+pub(crate) fn saved_logs(app: &AppHandle) -> Vec<(String, Vec<PathBuf>)> {
+    let Ok(root) = logs_root(app) else {
+        return Vec::new();
+    };
+    let Ok(dirs) = fs::read_dir(&root) else {
+        return Vec::new();
+    };
+    dirs.flatten()
+        .filter(|d| d.path().is_dir())
+        .filter_map(|d| {
+            let mut files = list_dir(&d.path());
+            files.sort_by(|a, b| a.name.cmp(&b.name));
+            let files: Vec<PathBuf> = files.into_iter().map(|f| PathBuf::from(f.path)).collect();
+            // Every session starts with a headed file
+            let project = files.iter().find_map(|f| file_project(f))?;
+            Some((project, files))
+        })
+        .collect()
+}
+
+/// Split a line written by `append` into (unix millis, level, message)
+pub(crate) fn parse_line(line: &str) -> Option<(i64, String, String)> {
+    let (stamp, rest) = line.split_once(' ')?;
+    let time = chrono::NaiveDateTime::parse_from_str(stamp, "%Y-%m-%dT%H:%M:%S%.3f").ok()?;
+    let timestamp = time.and_local_timezone(chrono::Local).earliest()?.timestamp_millis();
+    let (level, message) = rest.strip_prefix('[')?.split_once("] ")?;
+    Some((timestamp, level.to_string(), message.to_string()))
+}
+
+/// List saved log files, newest first (all projects unless `project_path` is given)
+#[tauri::command]
+pub fn get_log_files(app: AppHandle, project_path: Option<String>) -> Result<Vec<LogFileInfo>, String> {
+    let root = logs_root(&app)?;
+    let mut files = match project_path {
+        Some(path) => list_dir(&root.join(project_dir_name(&path))),
+        None => fs::read_dir(&root)
+            .map(|dirs| dirs.flatten().filter(|d| d.path().is_dir()).flat_map(|d| list_dir(&d.path())).collect())
+            .unwrap_or_default(),
+    };
+    files.sort_by(|a, b| b.modified.cmp(&a.modified).then_with(|| b.name.cmp(&a.name)));
+    Ok(files)
+}
+
+/// Read part of a saved log file. Without an offset, returns the last `limit_bytes` (default 256KB)
+#[tauri::command]
+pub fn read_log_file(
+    app: AppHandle,
+    path: String,
+    offset: Option<u64>,
+    limit_bytes: Option<u64>,
+) -> Result<LogFileChunk, String> {
+    let root = logs_root(&app)?;
+    let file_path = PathBuf::from(&path);
+    let canonical = file_path.canonicalize().map_err(|e| format!("Failed to open {}: {}", path, e))?;
+    let canonical_root = root.canonicalize().map_err(|e| e.to_string())?;
+    if !canonical.starts_with(&canonical_root) {
+        return Err("Not a DevLaunch log file".to_string());
+    }
+
+    let mut file = File::open(&canonical).map_err(|e| format!("Failed to open {}: {}", path, e))?;
+    let size = file.metadata().map_err(|e| e.to_string())?.len();
+    let limit = limit_bytes.unwrap_or(256 * 1024);
+    let start = offset.unwrap_or_else(|| size.saturating_sub(limit)).min(size);
+
+    file.seek(SeekFrom::Start(start)).map_err(|e| e.to_string())?;
+    let mut buf = Vec::new();
+    file.take(limit)
+        .read_to_end(&mut buf)
+        .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+
+    Ok(LogFileChunk {
+        next_offset: start + buf.len() as u64,
+        content: String::from_utf8_lossy(&buf).to_string(),
+        size,
+    })
+}
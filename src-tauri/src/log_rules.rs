@@ -0,0 +1,170 @@
+use crate::config::{LogRule, ProjectSettings};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+lazy_static::lazy_static! {
+    // Compiled rules per project path, rebuilt on each launch
+    static ref RULES: Mutex<HashMap<String, Vec<CompiledRule>>> = Mutex::new(HashMap::new());
+    static ref PLACEHOLDER: Regex = Regex::new(r"\{\{\s*(\w+)\s*\}\}").unwrap();
+}
+
+struct CompiledRule {
+    regex: Regex,
+    level: Option<String>,
+    fingerprint: Option<String>,
+}
+
+/// What a matching rule says about a log line
+pub(crate) struct LogMatch {
+    pub level: Option<String>,
+    pub fields: HashMap<String, String>,
+    pub fingerprint: Option<String>,
+    pub url: Option<(String, u16)>,
+}
+
+/// Built-in rules as (pattern, level, fingerprint)
+fn preset_rules(name: &str) -> &'static [(&'static str, &'static str, &'static str)] {
+    match name {
+        "rails" => &[
+            (r"Listening on (?P<url>https?://[^\s]+:(?P<port>\d+))", "info", ""),
+            (r#"^Started (?P<method>[A-Z]+) "(?P<path>[^"]+)""#, "info", ""),
+            (r"^Completed (?P<status>\d{3}) ", "{{status}}", ""),
+            (r"^[DIWEF], \[[^\]]+\]\s+(?P<severity>DEBUG|INFO|WARN|ERROR|FATAL)", "{{severity}}", ""),
+            (
+                r"^(?P<exception>[A-Z]\w*(?:::\w+)*(?:Error|Exception)) \((?P<message>.*)\)",
+                "error",
+                "{{exception}}",
+            ),
+        ],
+        "django" => &[
+            (r"Starting development server at (?P<url>https?://[^\s/]+:(?P<port>\d+))", "info", ""),
+            (
+                r#"^\[[^\]]+\] "(?P<method>[A-Z]+) (?P<path>\S+) HTTP/[\d.]+" (?P<status>\d{3})"#,
+                "{{status}}",
+                "",
+            ),
+            (r"^Traceback \(most recent call last\)", "error", ""),
+            (r"^(?P<exception>\w+(?:\.\w+)*(?:Error|Exception)): (?P<message>.*)", "error", "{{exception}}"),
+            (r"^(?P<severity>DEBUG|INFO|WARNING|ERROR|CRITICAL)\b", "{{severity}}", ""),
+        ],
+        "spring" => &[
+            (r"Tomcat started on port(?:\(s\))?:? (?P<port>\d+)", "info", ""),
+            (r"\b(?P<severity>TRACE|DEBUG|INFO|WARN|ERROR)\s+\d+\s+---\s+\[", "{{severity}}", ""),
+            (
+                r"^(?P<exception>(?:[a-z]\w*\.)+[A-Z]\w*(?:Exception|Error))(?::\s*(?P<message>.*))?$",
+                "error",
+                "{{exception}}",
+            ),
+            (r"^\s+at (?P<frame>[\w.$]+)\(", "error", ""),
+        ],
+        _ => &[],
+    }
+}
+
+fn compile(settings: &ProjectSettings) -> Vec<CompiledRule> {
+    let custom = settings
+        .log_rules
+        .iter()
+        .filter(|r| r.enabled)
+        .map(|r: &LogRule| (r.pattern.clone(), r.level.clone(), r.fingerprint.clone()));
+    let presets = settings.log_presets.iter().flat_map(|name| {
+        let rules = preset_rules(name);
+        if rules.is_empty() {
+            println!("[log_rules] Unknown log preset: {}", name);
+        }
+        rules.iter().map(|(pattern, level, fingerprint)| {
+            let optional = |s: &str| Some(s.to_string()).filter(|s| !s.is_empty());
+            (pattern.to_string(), optional(level), optional(fingerprint))
+        })
+    });
+
+    custom
+        .chain(presets)
+        .filter_map(|(pattern, level, fingerprint)| match Regex::new(&pattern) {
+            Ok(regex) => Some(CompiledRule {
+                regex,
+                level,
+                fingerprint,
+            }),
+            Err(e) => {
+                println!("[log_rules] Skipping invalid pattern {:?}: {}", pattern, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Compile a project's rules; called once per launch
+pub(crate) fn load(app: &AppHandle, cwd: &str) {
+    let rules = crate::config::load_config_internal(app)
+        .map(|c| compile(&c.project_settings(cwd)))
+        .unwrap_or_default();
+    if let Ok(mut all) = RULES.lock() {
+        if rules.is_empty() {
+            all.remove(cwd);
+        } else {
+            all.insert(cwd.to_string(), rules);
+        }
+    }
+}
+
+/// Map a level name, severity word or HTTP status to one of our levels
+pub(crate) fn normalize_level(value: &str) -> Option<String> {
+    let level = match value.trim().to_lowercase().as_str() {
+        "error" | "err" | "fatal" | "critical" | "crit" | "severe" => "error",
+        "warn" | "warning" => "warn",
+        "info" | "notice" => "info",
+        "debug" | "trace" | "verbose" => "debug",
+        status if status.len() == 3 && status.chars().all(|c| c.is_ascii_digit()) => match &status[..1] {
+            "5" => "error",
+            "4" => "warn",
+            _ => "info",
+        },
+        _ => return None,
+    };
+    Some(level.to_string())
+}
+
+fn fill(template: &str, fields: &HashMap<String, String>) -> String {
+    PLACEHOLDER
+        .replace_all(template, |caps: &regex::Captures| {
+            fields.get(&caps[1]).cloned().unwrap_or_default()
+        })
+        .to_string()
+}
+
+/// Run a line through the project's rules; None when no rule matches
+pub(crate) fn apply(cwd: &str, line: &str) -> Option<LogMatch> {
+    let all = RULES.lock().ok()?;
+    let (rule, caps) = all
+        .get(cwd)?
+        .iter()
+        .find_map(|rule| rule.regex.captures(line).map(|caps| (rule, caps)))?;
+
+    let fields: HashMap<String, String> = rule
+        .regex
+        .capture_names()
+        .flatten()
+        .filter_map(|name| caps.name(name).map(|m| (name.to_string(), m.as_str().to_string())))
+        .collect();
+
+    let port = fields.get("port").and_then(|p| p.parse::<u16>().ok());
+    let url = match (fields.get("url"), port) {
+        (Some(url), Some(port)) => Some((url.clone(), port)),
+        (None, Some(port)) => Some((format!("http://localhost:{}", port), port)),
+        _ => None,
+    };
+
+    Some(LogMatch {
+        level: rule.level.as_deref().and_then(|l| normalize_level(&fill(l, &fields))),
+        fingerprint: rule
+            .fingerprint
+            .as_deref()
+            .map(|f| fill(f, &fields))
+            .filter(|f| !f.trim().is_empty()),
+        url,
+        fields,
+    })
+}
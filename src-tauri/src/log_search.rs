@@ -0,0 +1,240 @@
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader};
+use tauri::AppHandle;
+
+const DEFAULT_LIMIT: usize = 500;
+const MAX_CONTEXT_LINES: usize = 20;
+
+/// Inclusive time window in unix millis; either end may be open
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LogTimeRange {
+    pub from: Option<i64>,
+    pub to: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogSearchMatch {
+    pub path: String,
+    /// "buffer" for retained lines, or the log file the line was read from
+    pub source: String,
+    /// Buffer line number, or 1-based line number within the file
+    pub line: u64,
+    pub timestamp: i64,
+    pub level: String,
+    pub message: String,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogSearchResult {
+    /// The newest matches up to the limit, oldest first
+    pub matches: Vec<LogSearchMatch>,
+    pub total_matches: usize,
+    pub truncated: bool,
+}
+
+struct Entry {
+    line: u64,
+    timestamp: i64,
+    level: String,
+    message: String,
+}
+
+struct Filters<'a> {
+    pattern: &'a Regex,
+    levels: Option<&'a [String]>,
+    range: &'a LogTimeRange,
+    context: usize,
+}
+
+impl Filters<'_> {
+    fn matches(&self, entry: &Entry) -> bool {
+        self.range.from.map(|from| entry.timestamp >= from).unwrap_or(true)
+            && self.range.to.map(|to| entry.timestamp <= to).unwrap_or(true)
+            && self.levels.map(|levels| levels.contains(&entry.level)).unwrap_or(true)
+            && self.pattern.is_match(&entry.message)
+    }
+}
+
+/// Collect matches from one source's lines, with surrounding context
+fn search_entries(path: &str, source: &str, entries: &[Entry], filters: &Filters, out: &mut Vec<LogSearchMatch>) {
+    for (i, entry) in entries.iter().enumerate() {
+        if !filters.matches(entry) {
+            continue;
+        }
+        let context = |range: std::ops::Range<usize>| -> Vec<String> {
+            entries[range].iter().map(|e| e.message.clone()).collect()
+        };
+        out.push(LogSearchMatch {
+            path: path.to_string(),
+            source: source.to_string(),
+            line: entry.line,
+            timestamp: entry.timestamp,
+            level: entry.level.clone(),
+            message: entry.message.clone(),
+            before: context(i.saturating_sub(filters.context)..i),
+            after: context(i + 1..(i + 1 + filters.context).min(entries.len())),
+        });
+    }
+}
+
+/// Search a saved log file line by line, skipping its header and anything at or after
+/// `before`. Only the context window and the newest `limit` matches are held, so a large file
+/// isn't loaded whole. Returns how many lines matched.
+fn search_file(
+    path: &str,
+    file: &std::path::Path,
+    before: Option<i64>,
+    filters: &Filters,
+    limit: usize,
+    out: &mut Vec<LogSearchMatch>,
+) -> usize {
+    let Ok(handle) = std::fs::File::open(file) else {
+        return 0;
+    };
+    let source = file.to_string_lossy().to_string();
+    let mut recent: VecDeque<String> = VecDeque::with_capacity(filters.context + 1);
+    let mut found: VecDeque<LogSearchMatch> = VecDeque::new();
+    let mut total = 0;
+
+    let entries = BufReader::new(handle)
+        .lines()
+        .map_while(Result::ok)
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let (timestamp, level, message) = crate::log_files::parse_line(&line)?;
+            Some(Entry {
+                line: i as u64 + 1,
+                timestamp,
+                level,
+                message,
+            })
+        })
+        .filter(|e| before.map(|b| e.timestamp < b).unwrap_or(true));
+    for entry in entries {
+        // Newer matches have fewer lines after them, so stop at the first one that's full
+        for waiting in found.iter_mut().rev() {
+            if waiting.after.len() >= filters.context {
+                break;
+            }
+            waiting.after.push(entry.message.clone());
+        }
+        if filters.matches(&entry) {
+            total += 1;
+            found.push_back(LogSearchMatch {
+                path: path.to_string(),
+                source: source.clone(),
+                line: entry.line,
+                timestamp: entry.timestamp,
+                level: entry.level,
+                message: entry.message.clone(),
+                before: recent.iter().cloned().collect(),
+                after: Vec::new(),
+            });
+            if found.len() > limit {
+                found.pop_front();
+            }
+        }
+        recent.push_back(entry.message);
+        if recent.len() > filters.context {
+            recent.pop_front();
+        }
+    }
+    out.extend(found);
+    total
+}
+
+/// Search retained log lines, plus saved log files when `include_files` is set. The query is a
+/// plain substring unless `regex` is set; matching is case-insensitive unless `case_sensitive`.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn search_logs(
+    app: AppHandle,
+    query: String,
+    regex: Option<bool>,
+    case_sensitive: Option<bool>,
+    project_filter: Option<Vec<String>>,
+    level_filter: Option<Vec<String>>,
+    time_range: Option<LogTimeRange>,
+    context_lines: Option<usize>,
+    include_files: Option<bool>,
+    limit: Option<usize>,
+) -> Result<LogSearchResult, String> {
+    if query.is_empty() {
+        return Err("Search query is empty".to_string());
+    }
+    let source = if regex.unwrap_or(false) {
+        query.clone()
+    } else {
+        regex::escape(&query)
+    };
+    let pattern = RegexBuilder::new(&source)
+        .case_insensitive(!case_sensitive.unwrap_or(false))
+        .build()
+        .map_err(|e| format!("Invalid regex: {}", e))?;
+    let range = time_range.unwrap_or_default();
+    let context = context_lines.unwrap_or(2).min(MAX_CONTEXT_LINES);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let filters = Filters {
+            pattern: &pattern,
+            levels: level_filter.as_deref(),
+            range: &range,
+            context,
+        };
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).max(1);
+        let mut matches = Vec::new();
+        // File matches past the per-file limit, counted but not kept
+        let mut dropped = 0;
+        // Files only cover what the buffer no longer holds, so lines aren't reported twice
+        let mut buffer_start: HashMap<String, i64> = HashMap::new();
+
+        for (path, lines) in crate::process::buffered_logs(project_filter.as_deref()) {
+            if let Some(first) = lines.first() {
+                buffer_start.insert(path.clone(), first.timestamp);
+            }
+            let entries: Vec<Entry> = lines
+                .into_iter()
+                .map(|l| Entry {
+                    line: l.line,
+                    timestamp: l.timestamp,
+                    level: l.level,
+                    message: l.message,
+                })
+                .collect();
+            search_entries(&path, "buffer", &entries, &filters, &mut matches);
+        }
+
+        if include_files.unwrap_or(false) {
+            for (path, files) in crate::log_files::saved_logs(&app) {
+                if project_filter.as_ref().is_some_and(|p| !p.contains(&path)) {
+                    continue;
+                }
+                let before = buffer_start.get(&path).copied();
+                for file in files {
+                    let start = matches.len();
+                    let found = search_file(&path, &file, before, &filters, limit, &mut matches);
+                    dropped += found - (matches.len() - start);
+                }
+            }
+        }
+
+        matches.sort_by_key(|m| m.timestamp);
+        let total_matches = matches.len() + dropped;
+        // Keep the newest
+        matches.drain(..matches.len().saturating_sub(limit));
+        LogSearchResult {
+            truncated: total_matches > matches.len(),
+            total_matches,
+            matches,
+        }
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
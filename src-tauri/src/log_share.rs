@@ -0,0 +1,231 @@
+use crate::process;
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Html, IntoResponse, Response,
+    },
+    routing::get,
+    Router,
+};
+use lazy_static::lazy_static;
+use serde::Serialize;
+use std::{collections::HashMap, net::{Ipv4Addr, SocketAddr}, sync::Arc};
+use tokio::sync::{mpsc, Mutex};
+use tokio_stream::{wrappers::BroadcastStream, StreamExt};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogShareInfo {
+    pub project_path: String,
+    pub port: u16,
+    pub token: String,
+    pub url: String,
+    pub tunneled: bool,
+    pub tunnel_name: Option<String>,
+    /// Whether other machines on the network can reach the share directly
+    pub lan: bool,
+}
+
+struct ShareState {
+    project_path: String,
+    token: String,
+}
+
+struct LogShare {
+    shutdown_tx: mpsc::Sender<()>,
+    info: LogShareInfo,
+}
+
+lazy_static! {
+    // Active log shares keyed by project path
+    static ref SHARES: Mutex<HashMap<String, LogShare>> = Mutex::new(HashMap::new());
+}
+
+fn authorized(state: &ShareState, query: &HashMap<String, String>) -> bool {
+    query
+        .get("token")
+        .is_some_and(|t| crate::secrets::tokens_match(t, &state.token))
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+async fn share_page(
+    State(state): State<Arc<ShareState>>,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    if !authorized(&state, &query) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    let title = escape_html(&state.project_path);
+    let token = escape_html(&state.token);
+    Html(format!(
+        r#"<!doctype html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>DevLaunch logs - {title}</title>
+<style>
+body {{ margin: 0; background: #0b0b0f; color: #e4e4e7; font: 13px ui-monospace, Menlo, monospace; }}
+header {{ position: sticky; top: 0; padding: 8px 12px; background: #18181b; border-bottom: 1px solid #27272a; }}
+#log {{ padding: 8px 12px; white-space: pre-wrap; word-break: break-all; }}
+.error {{ color: #f87171; }} .warn {{ color: #fbbf24; }} .debug {{ color: #a1a1aa; }}
+</style>
+</head>
+<body>
+<header>{title} &middot; <span id="state">connecting...</span></header>
+<div id="log"></div>
+<script>
+const log = document.getElementById("log");
+const state = document.getElementById("state");
+const source = new EventSource("stream?token={token}");
+source.onopen = () => state.textContent = "live";
+source.onerror = () => state.textContent = "disconnected";
+source.onmessage = (e) => {{
+  const entry = JSON.parse(e.data);
+  const line = document.createElement("div");
+  line.className = entry.level;
+  line.textContent = entry.message;
+  const atBottom = window.innerHeight + window.scrollY >= document.body.scrollHeight - 20;
+  log.appendChild(line);
+  if (atBottom) window.scrollTo(0, document.body.scrollHeight);
+}};
+</script>
+</body>
+</html>"#
+    ))
+    .into_response()
+}
+
+async fn share_stream(
+    State(state): State<Arc<ShareState>>,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    if !authorized(&state, &query) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    let project_path = state.project_path.clone();
+    // Lagged receivers just drop the missed lines
+    let stream = BroadcastStream::new(process::subscribe_logs()).filter_map(move |item| match item {
+        Ok(event) if event.path == project_path => Some(Event::default().json_data(&event)),
+        _ => None,
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default()).into_response()
+}
+
+/// Expose a read-only live log stream for one project over HTTP.
+/// With `tunnel`, the share port is also exposed through its own tunnel (preferred provider).
+/// Only this machine (and the tunnel) can connect unless `lan` is set.
+#[tauri::command]
+pub async fn start_log_share(
+    app: tauri::AppHandle,
+    project_path: String,
+    port: Option<u16>,
+    tunnel: Option<bool>,
+    lan: Option<bool>,
+) -> Result<LogShareInfo, String> {
+    let mut shares = SHARES.lock().await;
+    if let Some(existing) = shares.get(&project_path) {
+        return Ok(existing.info.clone());
+    }
+
+    let token = uuid::Uuid::new_v4().simple().to_string();
+    let state = Arc::new(ShareState {
+        project_path: project_path.clone(),
+        token: token.clone(),
+    });
+
+    let router = Router::new()
+        .route("/", get(share_page))
+        .route("/stream", get(share_stream))
+        .with_state(state);
+
+    let lan = lan.unwrap_or(false);
+    let ip = if lan { Ipv4Addr::UNSPECIFIED } else { Ipv4Addr::LOCALHOST };
+    let addr = SocketAddr::from((ip, port.unwrap_or(0)));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(|e| format!("Failed to bind log share server: {}", e))?;
+    let actual_port = listener.local_addr().map_err(|e| e.to_string())?.port();
+
+    let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
+    tokio::spawn(async move {
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async move {
+                shutdown_rx.recv().await;
+            })
+            .await
+            .ok();
+    });
+
+    let tunnel_name = format!("logs-{}", &token[..8]);
+    let public_url = if tunnel.unwrap_or(false) {
+        match crate::tunnels::start_tunnel_internal(
+            &crate::tunnels::preferred_provider(&app),
+            &tunnel_name,
+            actual_port,
+            "http",
+        )
+        .await {
+            Ok(t) => Some(t.public_url),
+            Err(e) => {
+                let _ = shutdown_tx.send(()).await;
+                return Err(e);
+            }
+        }
+    } else {
+        None
+    };
+
+    let base_url = public_url
+        .clone()
+        .unwrap_or_else(|| format!("http://localhost:{}", actual_port));
+    let info = LogShareInfo {
+        project_path: project_path.clone(),
+        port: actual_port,
+        url: format!("{}/?token={}", base_url.trim_end_matches('/'), token),
+        token,
+        tunneled: public_url.is_some(),
+        tunnel_name: public_url.map(|_| tunnel_name),
+        lan,
+    };
+    println!("[log_share] Sharing logs for {} on port {}", project_path, actual_port);
+
+    shares.insert(
+        project_path,
+        LogShare {
+            shutdown_tx,
+            info: info.clone(),
+        },
+    );
+
+    Ok(info)
+}
+
+#[tauri::command]
+pub async fn stop_log_share(project_path: String) -> Result<(), String> {
+    let mut shares = SHARES.lock().await;
+    let share = shares
+        .remove(&project_path)
+        .ok_or_else(|| "Logs for this project are not being shared".to_string())?;
+
+    let _ = share.shutdown_tx.send(()).await;
+    if let Some(name) = &share.info.tunnel_name {
+        let _ = crate::tunnels::stop_tunnel_internal(name).await;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn list_log_shares() -> Result<Vec<LogShareInfo>, String> {
+    let shares = SHARES.lock().await;
+    Ok(shares.values().map(|s| s.info.clone()).collect())
+}
@@ -0,0 +1,79 @@
+use crate::process::LogEvent;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, EventTarget, Manager, WebviewUrl, WebviewWindowBuilder, WindowEvent};
+
+lazy_static::lazy_static! {
+    // Open log windows: window label -> project path
+    static ref LOG_WINDOWS: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+}
+
+/// One window per project, so reopening focuses the existing one
+fn window_label(project_path: &str) -> String {
+    format!("logs-{}", &hex::encode(Sha256::digest(project_path.as_bytes()))[..12])
+}
+
+/// Send a log line to the windows showing its project, as `log-window-log`.
+/// Only those windows receive it, unlike the app-wide `process-log`.
+pub(crate) fn forward(app: &AppHandle, event: &LogEvent) {
+    let labels: Vec<String> = LOG_WINDOWS
+        .lock()
+        .map(|windows| {
+            windows
+                .iter()
+                .filter(|(_, path)| **path == event.path)
+                .map(|(label, _)| label.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+    for label in labels {
+        let _ = app.emit_to(EventTarget::webview_window(label), "log-window-log", event.clone());
+    }
+}
+
+/// Open a separate window streaming one project's logs (or focus it if already open).
+/// The page gets the project as `window.__DEVLAUNCH_LOG_WINDOW__.projectPath`; backlog comes
+/// from get_process_logs, new lines from `log-window-log` events on that window.
+/// Returns the window label.
+#[tauri::command]
+pub async fn open_log_window(app: AppHandle, project_path: String) -> Result<String, String> {
+    if !Path::new(&project_path).is_dir() {
+        return Err(format!("Project folder not found: {}", project_path));
+    }
+    let label = window_label(&project_path);
+    if let Some(window) = app.get_webview_window(&label) {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+        return Ok(label);
+    }
+
+    let name = crate::projects::project_name(&project_path);
+    let script = format!(
+        "window.__DEVLAUNCH_LOG_WINDOW__ = {};",
+        serde_json::json!({ "projectPath": project_path })
+    );
+    let window = WebviewWindowBuilder::new(&app, &label, WebviewUrl::App("index.html#/logs".into()))
+        .title(format!("{} — Logs", name))
+        .inner_size(900.0, 600.0)
+        .min_inner_size(400.0, 300.0)
+        .initialization_script(&script)
+        .build()
+        .map_err(|e| format!("Failed to open log window: {}", e))?;
+
+    if let Ok(mut windows) = LOG_WINDOWS.lock() {
+        windows.insert(label.clone(), project_path.clone());
+    }
+    let closed = label.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Destroyed = event {
+            if let Ok(mut windows) = LOG_WINDOWS.lock() {
+                windows.remove(&closed);
+            }
+        }
+    });
+    println!("[log_window] Opened logs for {}", project_path);
+    Ok(label)
+}
@@ -0,0 +1,172 @@
+use crate::projects::project_name;
+use axum::{
+    http::header,
+    response::IntoResponse,
+    routing::get,
+    Router,
+};
+use lazy_static::lazy_static;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use tauri::AppHandle;
+use tokio::sync::{mpsc, Mutex};
+
+struct MetricsServer {
+    shutdown_tx: mpsc::Sender<()>,
+    port: u16,
+}
+
+lazy_static! {
+    static ref SERVER: Mutex<Option<MetricsServer>> = Mutex::new(None);
+}
+
+/// Escape a label value for the Prometheus text format
+fn label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn describe(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+/// Render current state in the Prometheus text exposition format
+fn render() -> String {
+    let mut out = String::new();
+
+    describe(&mut out, "devlaunch_processes_running", "gauge", "Dev servers currently running under DevLaunch");
+    let _ = writeln!(out, "devlaunch_processes_running {}", crate::process::running_processes().len());
+
+    // sysinfo refreshes block briefly; fine for a scrape every few seconds
+    let metrics = crate::system::tracked_process_metrics();
+    describe(&mut out, "devlaunch_process_cpu_percent", "gauge", "CPU usage of a dev server and its children");
+    for m in &metrics {
+        let path = m.path.as_deref().unwrap_or("");
+        let _ = writeln!(
+            out,
+            "devlaunch_process_cpu_percent{{project=\"{}\",path=\"{}\",pid=\"{}\"}} {}",
+            label(&project_name(path)),
+            label(path),
+            m.pid,
+            m.cpu_percent
+        );
+    }
+    describe(&mut out, "devlaunch_process_memory_bytes", "gauge", "Resident memory of a dev server and its children");
+    for m in &metrics {
+        let path = m.path.as_deref().unwrap_or("");
+        let _ = writeln!(
+            out,
+            "devlaunch_process_memory_bytes{{project=\"{}\",path=\"{}\",pid=\"{}\"}} {}",
+            label(&project_name(path)),
+            label(path),
+            m.pid,
+            (m.rss_mb as f64 * 1024.0 * 1024.0).round()
+        );
+    }
+
+    describe(
+        &mut out,
+        "devlaunch_webhook_events_received_total",
+        "counter",
+        "Webhook requests received since DevLaunch started",
+    );
+    let _ = writeln!(
+        out,
+        "devlaunch_webhook_events_received_total {}",
+        crate::webhook_server::events_received_total()
+    );
+
+    describe(&mut out, "devlaunch_git_behind_commits", "gauge", "Commits the current branch is behind its remote, as of the last check");
+    let mut behind = crate::git::behind_counts();
+    behind.sort();
+    for (path, count) in behind {
+        let _ = writeln!(
+            out,
+            "devlaunch_git_behind_commits{{project=\"{}\",path=\"{}\"}} {}",
+            label(&project_name(&path)),
+            label(&path),
+            count
+        );
+    }
+
+    out
+}
+
+async fn handle_metrics() -> impl IntoResponse {
+    let body = tokio::task::spawn_blocking(render).await.unwrap_or_default();
+    ([(header::CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")], body)
+}
+
+pub async fn start_metrics_server_internal(port: u16) -> Result<String, String> {
+    let mut server = SERVER.lock().await;
+    if server.is_some() {
+        return Err("Metrics server is already running".to_string());
+    }
+
+    // Loopback only: the endpoint exposes project paths
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(|e| format!("Failed to bind to port {}: {}", port, e))?;
+    let actual_port = listener.local_addr().map_err(|e| e.to_string())?.port();
+
+    let router = Router::new().route("/metrics", get(handle_metrics));
+    let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
+    tokio::spawn(async move {
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async move {
+                shutdown_rx.recv().await;
+            })
+            .await
+            .ok();
+    });
+
+    *server = Some(MetricsServer {
+        shutdown_tx,
+        port: actual_port,
+    });
+
+    let url = format!("http://127.0.0.1:{}/metrics", actual_port);
+    println!("[metrics_server] Serving metrics at {}", url);
+    Ok(url)
+}
+
+/// Start the endpoint at launch if enabled in preferences
+pub fn start_from_preferences(app: &AppHandle) {
+    let prefs = crate::config::load_config_internal(app)
+        .map(|c| c.preferences.metrics_server)
+        .unwrap_or_default();
+    if !prefs.enabled {
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = start_metrics_server_internal(prefs.port).await {
+            println!("[metrics_server] {}", e);
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn start_metrics_server(port: Option<u16>) -> Result<String, String> {
+    start_metrics_server_internal(port.unwrap_or(9464)).await
+}
+
+#[tauri::command]
+pub async fn stop_metrics_server() -> Result<(), String> {
+    let mut server = SERVER.lock().await;
+    match server.take() {
+        Some(s) => {
+            let _ = s.shutdown_tx.send(()).await;
+            Ok(())
+        }
+        None => Err("Metrics server is not running".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn get_metrics_server_status() -> Result<Option<u16>, String> {
+    Ok(SERVER.lock().await.as_ref().map(|s| s.port))
+}
@@ -0,0 +1,361 @@
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{header, HeaderName, HeaderValue, StatusCode},
+    response::Response,
+    routing::any,
+    Router,
+};
+use crate::config::{self, MockResponse, MockRoute};
+use chrono::Utc;
+use lazy_static::lazy_static;
+use serde::Serialize;
+use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{mpsc, Mutex};
+use tower_http::cors::CorsLayer;
+
+/// Hits kept in memory for the mock API log
+const MAX_HITS: usize = 200;
+
+lazy_static! {
+    static ref SERVER: Mutex<Option<MockServer>> = Mutex::new(None);
+    // {{placeholder}} in mock response bodies
+    static ref TEMPLATE_REGEX: regex::Regex = regex::Regex::new(r"\{\{\s*([\w.\-]+)\s*\}\}").unwrap();
+}
+
+struct MockServer {
+    shutdown_tx: mpsc::Sender<()>,
+    port: u16,
+    tls: bool,
+    state: Arc<MockState>,
+}
+
+struct MockState {
+    routes: Mutex<Vec<MockRoute>>,
+    /// Hits per route id, to step through response sequences
+    counters: Mutex<HashMap<String, usize>>,
+    hits: Mutex<Vec<MockApiHit>>,
+    app_handle: AppHandle,
+}
+
+/// A request the mock server answered, emitted as `mock-api-hit`
+#[derive(Debug, Clone, Serialize)]
+pub struct MockApiHit {
+    pub id: String,
+    pub timestamp: i64,
+    pub method: String,
+    pub path: String,
+    pub query: String,
+    pub body: String,
+    /// None when no route matched (answered with 404)
+    pub route_id: Option<String>,
+    pub params: HashMap<String, String>,
+    pub status: u16,
+    /// Position in the route's response sequence
+    pub response_index: Option<usize>,
+    pub latency_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MockServerStatus {
+    pub port: u16,
+    pub routes: usize,
+    pub tls: bool,
+}
+
+/// Match a request path against a route pattern, returning the `:param` captures
+fn match_path(pattern: &str, path: &str) -> Option<HashMap<String, String>> {
+    let pattern: Vec<&str> = pattern.trim_matches('/').split('/').filter(|s| !s.is_empty()).collect();
+    let path: Vec<&str> = path.trim_matches('/').split('/').filter(|s| !s.is_empty()).collect();
+    let mut params = HashMap::new();
+
+    for (i, segment) in pattern.iter().enumerate() {
+        if *segment == "**" {
+            return Some(params);
+        }
+        let actual = path.get(i)?;
+        if let Some(name) = segment.strip_prefix(':') {
+            params.insert(name.to_string(), crate::static_server::percent_decode(actual));
+        } else if *segment != "*" && segment != actual {
+            return None;
+        }
+    }
+    (pattern.len() == path.len()).then_some(params)
+}
+
+fn parse_query(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .map(|pair| {
+            let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+            (
+                crate::static_server::percent_decode(&k.replace('+', " ")),
+                crate::static_server::percent_decode(&v.replace('+', " ")),
+            )
+        })
+        .collect()
+}
+
+/// Fill {{...}} placeholders in every string of a JSON body
+fn render_body(value: &serde_json::Value, vars: &HashMap<String, String>) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(s) => {
+            let rendered = TEMPLATE_REGEX.replace_all(s, |caps: &regex::Captures| {
+                vars.get(&caps[1]).cloned().unwrap_or_default()
+            });
+            serde_json::Value::String(rendered.to_string())
+        }
+        serde_json::Value::Array(items) => serde_json::Value::Array(items.iter().map(|v| render_body(v, vars)).collect()),
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), render_body(v, vars)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Pick the reply for this hit of a route according to its repeat mode
+fn next_response(route: &MockRoute, hit: usize) -> Option<(usize, &MockResponse)> {
+    if route.responses.is_empty() {
+        return None;
+    }
+    let index = if route.repeat == "loop" {
+        hit % route.responses.len()
+    } else {
+        hit.min(route.responses.len() - 1)
+    };
+    Some((index, &route.responses[index]))
+}
+
+fn json_response(status: u16, body: &serde_json::Value, headers: &HashMap<String, String>) -> Response {
+    let mut response = Response::new(Body::from(body.to_string()));
+    *response.status_mut() = StatusCode::from_u16(status).unwrap_or(StatusCode::OK);
+    response
+        .headers_mut()
+        .insert(header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    for (name, value) in headers {
+        if let (Ok(name), Ok(value)) = (HeaderName::try_from(name.as_str()), HeaderValue::from_str(value)) {
+            response.headers_mut().insert(name, value);
+        }
+    }
+    response
+}
+
+async fn handle_mock(State(state): State<Arc<MockState>>, request: Request<Body>) -> Response {
+    let started = std::time::Instant::now();
+    let method = request.method().to_string();
+    let path = request.uri().path().to_string();
+    let query = request.uri().query().unwrap_or("").to_string();
+    let body = match axum::body::to_bytes(request.into_body(), 1024 * 1024).await {
+        Ok(bytes) => String::from_utf8_lossy(&bytes).to_string(),
+        Err(_) => String::new(),
+    };
+
+    // First enabled route whose method and path match
+    let matched = {
+        let routes = state.routes.lock().await;
+        routes.iter().filter(|r| r.enabled).find_map(|route| {
+            let method_ok = route
+                .method
+                .as_deref()
+                .map(|m| m == "*" || m.eq_ignore_ascii_case(&method))
+                .unwrap_or(true);
+            if !method_ok {
+                return None;
+            }
+            match_path(&route.path, &path).map(|params| (route.clone(), params))
+        })
+    };
+
+    let mut hit = MockApiHit {
+        id: uuid::Uuid::new_v4().to_string(),
+        timestamp: Utc::now().timestamp_millis(),
+        method: method.clone(),
+        path: path.clone(),
+        query: query.clone(),
+        body,
+        route_id: None,
+        params: HashMap::new(),
+        status: 404,
+        response_index: None,
+        latency_ms: 0,
+    };
+
+    let response = match matched {
+        Some((route, params)) => {
+            let count = {
+                let mut counters = state.counters.lock().await;
+                let count = counters.entry(route.id.clone()).or_insert(0);
+                let current = *count;
+                *count += 1;
+                current
+            };
+            hit.route_id = Some(route.id.clone());
+            hit.params = params.clone();
+            match next_response(&route, count) {
+                Some((index, reply)) => {
+                    if reply.delay_ms > 0 {
+                        tokio::time::sleep(std::time::Duration::from_millis(reply.delay_ms)).await;
+                    }
+                    let mut vars: HashMap<String, String> = HashMap::new();
+                    vars.insert("method".to_string(), method.clone());
+                    vars.insert("path".to_string(), path.clone());
+                    vars.insert("timestamp".to_string(), hit.timestamp.to_string());
+                    for (k, v) in &params {
+                        vars.insert(format!("params.{}", k), v.clone());
+                    }
+                    for (k, v) in parse_query(&query) {
+                        vars.insert(format!("query.{}", k), v);
+                    }
+                    hit.status = reply.status;
+                    hit.response_index = Some(index);
+                    json_response(reply.status, &render_body(&reply.body, &vars), &reply.headers)
+                }
+                None => {
+                    hit.status = 500;
+                    json_response(
+                        500,
+                        &serde_json::json!({ "error": format!("Mock route {} has no responses", route.path) }),
+                        &HashMap::new(),
+                    )
+                }
+            }
+        }
+        None => json_response(
+            404,
+            &serde_json::json!({ "error": format!("No mock route for {} {}", method, path) }),
+            &HashMap::new(),
+        ),
+    };
+
+    hit.latency_ms = started.elapsed().as_millis() as u64;
+    {
+        let mut hits = state.hits.lock().await;
+        hits.insert(0, hit.clone());
+        hits.truncate(MAX_HITS);
+    }
+    let _ = state.app_handle.emit("mock-api-hit", hit);
+    response
+}
+
+async fn start_mock_server_internal(app: &AppHandle, port: u16, tls: bool) -> Result<MockServerStatus, String> {
+    let mut server = SERVER.lock().await;
+    if server.is_some() {
+        return Err("Mock API server is already running".to_string());
+    }
+
+    let routes = config::load_config_internal(app)?.preferences.mock_api.routes;
+    let route_count = routes.len();
+    let state = Arc::new(MockState {
+        routes: Mutex::new(routes),
+        counters: Mutex::new(HashMap::new()),
+        hits: Mutex::new(Vec::new()),
+        app_handle: app.clone(),
+    });
+
+    let router = Router::new()
+        .fallback(any(handle_mock))
+        .layer(CorsLayer::permissive())
+        .with_state(state.clone());
+
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(|e| format!("Failed to bind to port {}: {}", port, e))?;
+    let actual_port = listener.local_addr().map_err(|e| e.to_string())?.port();
+
+    let tls_config = if tls {
+        Some(crate::tls::rustls_config(app).await?)
+    } else {
+        None
+    };
+    let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>(1);
+    crate::tls::spawn_server(listener, router, tls_config, shutdown_rx)?;
+
+    *server = Some(MockServer {
+        shutdown_tx,
+        port: actual_port,
+        tls,
+        state,
+    });
+    println!(
+        "[mock_server] Serving {} routes on {}://127.0.0.1:{}",
+        route_count,
+        if tls { "https" } else { "http" },
+        actual_port
+    );
+    Ok(MockServerStatus {
+        port: actual_port,
+        routes: route_count,
+        tls,
+    })
+}
+
+/// Serve the mock API routes from preferences on `port` (default from preferences),
+/// over HTTPS with a local-CA certificate when `tls` is set
+#[tauri::command]
+pub async fn start_mock_server(app: AppHandle, port: Option<u16>, tls: Option<bool>) -> Result<MockServerStatus, String> {
+    let port = match port {
+        Some(port) => port,
+        None => config::load_config_internal(&app)?.preferences.mock_api.port,
+    };
+    start_mock_server_internal(&app, port, tls.unwrap_or(false)).await
+}
+
+#[tauri::command]
+pub async fn stop_mock_server() -> Result<(), String> {
+    let mut server = SERVER.lock().await;
+    match server.take() {
+        Some(s) => {
+            let _ = s.shutdown_tx.send(()).await;
+            Ok(())
+        }
+        None => Err("Mock API server is not running".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn get_mock_server_status() -> Result<Option<MockServerStatus>, String> {
+    let server = SERVER.lock().await;
+    match server.as_ref() {
+        Some(s) => Ok(Some(MockServerStatus {
+            port: s.port,
+            routes: s.state.routes.lock().await.len(),
+            tls: s.tls,
+        })),
+        None => Ok(None),
+    }
+}
+
+/// Pick up route edits from the saved config and restart every response sequence
+#[tauri::command]
+pub async fn reload_mock_routes(app: AppHandle) -> Result<usize, String> {
+    let routes = config::load_config_internal(&app)?.preferences.mock_api.routes;
+    let server = SERVER.lock().await;
+    let server = server.as_ref().ok_or("Mock API server is not running")?;
+    let count = routes.len();
+    *server.state.routes.lock().await = routes;
+    server.state.counters.lock().await.clear();
+    Ok(count)
+}
+
+/// Recent hits, newest first
+#[tauri::command]
+pub async fn get_mock_api_hits() -> Result<Vec<MockApiHit>, String> {
+    let server = SERVER.lock().await;
+    match server.as_ref() {
+        Some(s) => Ok(s.state.hits.lock().await.clone()),
+        None => Ok(Vec::new()),
+    }
+}
+
+#[tauri::command]
+pub async fn clear_mock_api_hits() -> Result<(), String> {
+    if let Some(s) = SERVER.lock().await.as_ref() {
+        s.state.hits.lock().await.clear();
+    }
+    Ok(())
+}
@@ -0,0 +1,242 @@
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+lazy_static! {
+    // Processes from a previous session found still running at startup, until adopted or killed
+    static ref ORPHANS: Mutex<Vec<SessionProcess>> = Mutex::new(Vec::new());
+    // Serializes writes of the session file
+    static ref SAVE_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// A spawned process as recorded on disk, with enough to recognise it after a restart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SessionProcess {
+    pid: u32,
+    cwd: String,
+    command: String,
+    args: Vec<String>,
+    started_at: i64,
+    /// OS start time of the PID, unix seconds
+    process_start: u64,
+    /// SHA-256 of the PID's command line as the OS reports it
+    command_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanProcess {
+    pub pid: u32,
+    pub cwd: String,
+    pub command: String,
+    pub args: Vec<String>,
+    pub started_at: i64,
+    /// Ports the process or its children are listening on
+    pub ports: Vec<u16>,
+}
+
+fn session_file(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    std::fs::create_dir_all(&app_data_dir).map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("session_processes.json"))
+}
+
+fn command_hash(cmd: &[String]) -> String {
+    hex::encode(Sha256::digest(cmd.join("\0").as_bytes()))
+}
+
+/// Whether a recorded PID still belongs to the same process (PIDs get reused)
+fn still_same(entry: &SessionProcess, identities: &std::collections::HashMap<u32, (u64, Vec<String>)>) -> bool {
+    identities
+        .get(&entry.pid)
+        .map(|(start, cmd)| *start == entry.process_start && command_hash(cmd) == entry.command_hash)
+        .unwrap_or(false)
+}
+
+/// Write the running spawned processes (and unreconciled orphans) to disk, so a crash or
+/// force-quit doesn't lose track of them. Called whenever processes start or exit.
+pub(crate) fn save_session(app: &AppHandle) {
+    let running: Vec<crate::process::ProcessRecord> = crate::process::list_processes_internal()
+        .into_iter()
+        // Root-owned processes can't be signalled without a prompt, so they're left out
+        .filter(|r| r.running && !r.elevated)
+        .collect();
+    let pids: Vec<u32> = running.iter().map(|r| r.pid).collect();
+    let identities = crate::system::process_identities(&pids);
+
+    let mut entries: Vec<SessionProcess> = running
+        .into_iter()
+        .filter_map(|r| {
+            let (process_start, cmd) = identities.get(&r.pid)?;
+            Some(SessionProcess {
+                pid: r.pid,
+                cwd: r.cwd,
+                command: r.command,
+                args: r.args,
+                started_at: r.started_at,
+                process_start: *process_start,
+                command_hash: command_hash(cmd),
+            })
+        })
+        .collect();
+    if let Ok(orphans) = ORPHANS.lock() {
+        entries.extend(orphans.iter().cloned());
+    }
+
+    let Ok(_guard) = SAVE_LOCK.lock() else {
+        return;
+    };
+    let Ok(path) = session_file(app) else {
+        return;
+    };
+    match serde_json::to_string_pretty(&entries) {
+        Ok(json) => {
+            if let Err(e) = crate::config::write_file_atomic(&path, &json) {
+                println!("[orphans] {}", e);
+            }
+        }
+        Err(e) => println!("[orphans] Failed to serialize session: {}", e),
+    }
+}
+
+/// Check the previous session's processes on startup; any still running are orphans.
+/// Runs before session restore so restored projects don't collide with them.
+pub fn detect(app: &AppHandle) {
+    let entries: Vec<SessionProcess> = session_file(app)
+        .ok()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default();
+    let pids: Vec<u32> = entries.iter().map(|e| e.pid).collect();
+    let identities = crate::system::process_identities(&pids);
+    let orphans: Vec<SessionProcess> = entries.into_iter().filter(|e| still_same(e, &identities)).collect();
+
+    let found = orphans.len();
+    if let Ok(mut all) = ORPHANS.lock() {
+        *all = orphans;
+    }
+    save_session(app);
+    if found > 0 {
+        println!("[orphans] {} process(es) from the previous session are still running", found);
+        // Listing scans ports, which is slow; don't hold up startup for it
+        let app = app.clone();
+        std::thread::spawn(move || {
+            let _ = app.emit("orphans-detected", list());
+        });
+    }
+}
+
+/// Whether a project still has a dev server running from the previous session
+pub(crate) fn is_orphaned(path: &str) -> bool {
+    ORPHANS.lock().map(|o| o.iter().any(|p| p.cwd == path)).unwrap_or(false)
+}
+
+fn list() -> Vec<OrphanProcess> {
+    let orphans = ORPHANS.lock().map(|o| o.clone()).unwrap_or_default();
+    if orphans.is_empty() {
+        return Vec::new();
+    }
+    let listeners = tauri::async_runtime::block_on(crate::process::scan_ports()).unwrap_or_default();
+    let pids: Vec<u32> = listeners.iter().map(|l| l.pid).filter(|&pid| pid != 0).collect();
+    let ancestors = crate::system::ancestor_pids(&pids);
+
+    orphans
+        .into_iter()
+        .map(|o| {
+            let mut ports: Vec<u16> = listeners
+                .iter()
+                .filter(|l| ancestors.get(&l.pid).is_some_and(|chain| chain.contains(&o.pid)))
+                .map(|l| l.port)
+                .collect();
+            ports.sort_unstable();
+            ports.dedup();
+            OrphanProcess {
+                pid: o.pid,
+                cwd: o.cwd,
+                command: o.command,
+                args: o.args,
+                started_at: o.started_at,
+                ports,
+            }
+        })
+        .collect()
+}
+
+/// Dev servers left running by a previous DevLaunch session
+#[tauri::command]
+pub async fn list_orphans() -> Result<Vec<OrphanProcess>, String> {
+    tauri::async_runtime::spawn_blocking(list).await.map_err(|e| e.to_string())
+}
+
+/// Adopt (track again, without output) or kill orphans; `pids` defaults to all of them.
+/// Returns the PIDs that were handled. Orphans that couldn't be killed stay listed, and the
+/// errors are returned together once the rest are handled.
+#[tauri::command]
+pub async fn reconcile_orphans(app: AppHandle, action: String, pids: Option<Vec<u32>>) -> Result<Vec<u32>, String> {
+    if !matches!(action.as_str(), "adopt" | "kill") {
+        return Err(format!("Unknown action: {}", action));
+    }
+    let selected: Vec<SessionProcess> = {
+        let mut orphans = ORPHANS.lock().map_err(|e| e.to_string())?;
+        let (selected, rest) = orphans
+            .drain(..)
+            .partition(|o| pids.as_ref().map(|p| p.contains(&o.pid)).unwrap_or(true));
+        *orphans = rest;
+        selected
+    };
+
+    let identities = crate::system::process_identities(&selected.iter().map(|o| o.pid).collect::<Vec<_>>());
+    let mut handled = Vec::new();
+    let mut errors = Vec::new();
+    for orphan in selected {
+        // Never signal a process that merely inherited the PID
+        if !still_same(&orphan, &identities) {
+            println!("[orphans] PID {} has already exited", orphan.pid);
+            continue;
+        }
+        if action == "adopt" {
+            crate::process::adopt_process(
+                &app,
+                orphan.pid,
+                &orphan.cwd,
+                &orphan.command,
+                &orphan.args,
+                orphan.started_at,
+            );
+            println!("[orphans] Adopted PID {} ({})", orphan.pid, orphan.cwd);
+        } else if let Err(e) = crate::process::kill_process_internal(orphan.pid) {
+            println!("[orphans] Failed to kill PID {}: {}", orphan.pid, e);
+            errors.push(format!("PID {}: {}", orphan.pid, e));
+            if let Ok(mut orphans) = ORPHANS.lock() {
+                orphans.push(orphan);
+            }
+            continue;
+        } else {
+            println!("[orphans] Killed PID {} ({})", orphan.pid, orphan.cwd);
+        }
+        handled.push(orphan.pid);
+    }
+
+    save_session(&app);
+    if !errors.is_empty() {
+        return Err(format!("Failed to kill {} orphan(s): {}", errors.len(), errors.join("; ")));
+    }
+    Ok(handled)
+}
+
+/// Adopt a single orphan
+#[tauri::command]
+pub async fn adopt_process(app: AppHandle, pid: u32) -> Result<(), String> {
+    let handled = reconcile_orphans(app, "adopt".to_string(), Some(vec![pid])).await?;
+    if handled.is_empty() {
+        return Err(format!("PID {} is not an orphaned dev server", pid));
+    }
+    Ok(())
+}
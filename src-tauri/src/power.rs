@@ -0,0 +1,258 @@
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+/// How often the power monitor checks for battery/thermal changes
+const POWER_POLL_SECS: u64 = 30;
+
+lazy_static::lazy_static! {
+    // Last state the monitor emitted, so events only fire on change
+    static ref LAST_POWER_STATE: Mutex<Option<PowerStateEvent>> = Mutex::new(None);
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BatteryInfo {
+    pub percent: f32,
+    /// "charging", "discharging", "charged" or "unknown"
+    pub state: String,
+    pub on_battery: bool,
+    /// Estimated minutes until empty (discharging) or full (charging), if the OS reports it
+    pub minutes_remaining: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ThermalInfo {
+    /// "nominal", "fair", "serious" or "critical"
+    pub pressure: String,
+    /// Hottest sensor reading, where available (Linux)
+    pub temperature_c: Option<f32>,
+    /// Percent of full CPU speed currently allowed (macOS)
+    pub cpu_speed_limit: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+struct PowerStateEvent {
+    on_battery: bool,
+    low_battery: bool,
+    thermal_pressure: String,
+}
+
+/// Battery at or below this percent while discharging counts as low
+const LOW_BATTERY_PERCENT: f32 = 20.0;
+
+/// Parse "H:MM" as minutes
+#[cfg(target_os = "macos")]
+fn parse_hours_minutes(s: &str) -> Option<u32> {
+    let (h, m) = s.split_once(':')?;
+    Some(h.trim().parse::<u32>().ok()? * 60 + m.trim().parse::<u32>().ok()?)
+}
+
+/// From `pmset -g batt`, e.g. "Now drawing from 'Battery Power'\n -InternalBattery-0 (id=1)\t85%; discharging; 4:12 remaining"
+#[cfg(target_os = "macos")]
+pub fn battery_info() -> Option<BatteryInfo> {
+    let output = std::process::Command::new("pmset").args(["-g", "batt"]).output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    let on_battery = text.contains("'Battery Power'");
+    let line = text.lines().find(|l| l.contains("InternalBattery"))?;
+    let details = line.split('\t').nth(1).unwrap_or(line);
+
+    let mut parts = details.split(';').map(str::trim);
+    let percent = parts.next()?.trim_end_matches('%').parse::<f32>().ok()?;
+    let state = match parts.next().unwrap_or("") {
+        "charging" => "charging",
+        "discharging" => "discharging",
+        "charged" | "finishing charge" => "charged",
+        _ => "unknown",
+    };
+    let minutes_remaining = parts
+        .next()
+        .and_then(|r| r.split_whitespace().next())
+        .and_then(parse_hours_minutes)
+        .filter(|m| *m > 0);
+
+    Some(BatteryInfo {
+        percent,
+        state: state.to_string(),
+        on_battery,
+        minutes_remaining,
+    })
+}
+
+/// From /sys/class/power_supply/BAT*: capacity, status, and energy/charge vs power/current for time left
+#[cfg(target_os = "linux")]
+pub fn battery_info() -> Option<BatteryInfo> {
+    let read = |dir: &std::path::Path, name: &str| {
+        std::fs::read_to_string(dir.join(name)).ok().map(|s| s.trim().to_string())
+    };
+    let read_num = |dir: &std::path::Path, name: &str| read(dir, name).and_then(|s| s.parse::<f64>().ok());
+
+    let dir = std::fs::read_dir("/sys/class/power_supply")
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .find(|p| read(p, "type").as_deref() == Some("Battery"))?;
+
+    let percent = read_num(&dir, "capacity")? as f32;
+    let status = read(&dir, "status").unwrap_or_default();
+    let state = match status.as_str() {
+        "Charging" => "charging",
+        "Discharging" => "discharging",
+        "Full" | "Not charging" => "charged",
+        _ => "unknown",
+    };
+
+    // Either energy (µWh) with power (µW), or charge (µAh) with current (µA)
+    let (now, full, rate) = match (read_num(&dir, "energy_now"), read_num(&dir, "power_now")) {
+        (Some(now), Some(rate)) => (now, read_num(&dir, "energy_full"), rate),
+        _ => (
+            read_num(&dir, "charge_now")?,
+            read_num(&dir, "charge_full"),
+            read_num(&dir, "current_now").unwrap_or(0.0),
+        ),
+    };
+    let minutes_remaining = if rate <= 0.0 {
+        None
+    } else if state == "discharging" {
+        Some((now / rate * 60.0) as u32)
+    } else if state == "charging" {
+        full.map(|full| ((full - now).max(0.0) / rate * 60.0) as u32)
+    } else {
+        None
+    };
+
+    Some(BatteryInfo {
+        percent,
+        on_battery: state == "discharging",
+        state: state.to_string(),
+        minutes_remaining,
+    })
+}
+
+/// From Win32_Battery via PowerShell (BatteryStatus 1 = discharging, 2 = on AC)
+#[cfg(windows)]
+pub fn battery_info() -> Option<BatteryInfo> {
+    let output = std::process::Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            "Get-CimInstance Win32_Battery | Select-Object -First 1 EstimatedChargeRemaining,BatteryStatus,EstimatedRunTime | ConvertTo-Json",
+        ])
+        .output()
+        .ok()?;
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+    let percent = json.get("EstimatedChargeRemaining")?.as_f64()? as f32;
+    let status = json.get("BatteryStatus").and_then(|v| v.as_u64()).unwrap_or(0);
+    let state = match status {
+        1 => "discharging",
+        3 => "charged",
+        2 | 6..=9 => "charging",
+        _ => "unknown",
+    };
+    // 71582788 is Windows' "unknown" sentinel for run time
+    let minutes_remaining = json
+        .get("EstimatedRunTime")
+        .and_then(|v| v.as_u64())
+        .filter(|m| *m > 0 && *m < 71_582_788)
+        .map(|m| m as u32);
+
+    Some(BatteryInfo {
+        percent,
+        on_battery: status == 1,
+        state: state.to_string(),
+        minutes_remaining: if status == 1 { minutes_remaining } else { None },
+    })
+}
+
+/// From `pmset -g therm`: CPU_Speed_Limit below 100 means the system is throttling
+#[cfg(target_os = "macos")]
+pub fn thermal_info() -> Option<ThermalInfo> {
+    let output = std::process::Command::new("pmset").args(["-g", "therm"]).output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    let limit = text
+        .lines()
+        .find(|l| l.contains("CPU_Speed_Limit"))
+        .and_then(|l| l.split('=').nth(1))
+        .and_then(|v| v.trim().parse::<u32>().ok());
+
+    let pressure = match limit {
+        Some(l) if l < 50 => "critical",
+        Some(l) if l < 80 => "serious",
+        Some(l) if l < 100 => "fair",
+        _ => "nominal",
+    };
+    Some(ThermalInfo {
+        pressure: pressure.to_string(),
+        temperature_c: None,
+        cpu_speed_limit: limit,
+    })
+}
+
+/// Hottest /sys/class/thermal zone, bucketed into a pressure level
+#[cfg(target_os = "linux")]
+pub fn thermal_info() -> Option<ThermalInfo> {
+    let max_temp = std::fs::read_dir("/sys/class/thermal")
+        .ok()?
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with("thermal_zone"))
+        .filter_map(|e| std::fs::read_to_string(e.path().join("temp")).ok())
+        .filter_map(|t| t.trim().parse::<f32>().ok())
+        .map(|millideg| millideg / 1000.0)
+        .fold(None, |max: Option<f32>, t| Some(max.map_or(t, |m| m.max(t))))?;
+
+    let pressure = if max_temp >= 95.0 {
+        "critical"
+    } else if max_temp >= 85.0 {
+        "serious"
+    } else if max_temp >= 75.0 {
+        "fair"
+    } else {
+        "nominal"
+    };
+    Some(ThermalInfo {
+        pressure: pressure.to_string(),
+        temperature_c: Some(max_temp),
+        cpu_speed_limit: None,
+    })
+}
+
+#[cfg(windows)]
+pub fn thermal_info() -> Option<ThermalInfo> {
+    None
+}
+
+/// Emit `power-state-changed` when switching to battery, hitting low battery, or throttling,
+/// so the UI can warn (and offer to stop nonessential servers)
+pub fn start_power_monitor(app: &AppHandle) {
+    let app = app.clone();
+    std::thread::spawn(move || loop {
+        let battery = battery_info();
+        let thermal = thermal_info();
+
+        if battery.is_some() || thermal.is_some() {
+            let state = PowerStateEvent {
+                on_battery: battery.as_ref().is_some_and(|b| b.on_battery),
+                low_battery: battery
+                    .as_ref()
+                    .is_some_and(|b| b.on_battery && b.percent <= LOW_BATTERY_PERCENT),
+                thermal_pressure: thermal.map(|t| t.pressure).unwrap_or_else(|| "nominal".to_string()),
+            };
+            let changed = LAST_POWER_STATE
+                .lock()
+                .map(|mut last| {
+                    let changed = last.as_ref() != Some(&state);
+                    *last = Some(state.clone());
+                    changed
+                })
+                .unwrap_or(false);
+            if changed {
+                println!(
+                    "[power] on_battery={} low={} thermal={}",
+                    state.on_battery, state.low_battery, state.thermal_pressure
+                );
+                let _ = app.emit("power-state-changed", state);
+            }
+        }
+
+        std::thread::sleep(std::time::Duration::from_secs(POWER_POLL_SECS));
+    });
+}
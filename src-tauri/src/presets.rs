@@ -0,0 +1,474 @@
+use crate::config::{self, PresetProject, ReadinessCondition};
+use crate::process::{self, LogEvent};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast;
+use tokio::task::JoinSet;
+
+/// How often port/URL readiness is re-checked
+const READY_POLL_INTERVAL_MS: u64 = 250;
+
+/// Default number of projects spawned at the same time
+pub(crate) const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Overall time an ordered stop may take before whatever is left is force-killed
+const STOP_TIMEOUT_MS: u64 = 15000;
+
+/// Least time each process in an ordered stop gets before SIGKILL, even past the overall timeout
+const STOP_MIN_GRACE_MS: u64 = 1000;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PresetProjectResult {
+    pub path: String,
+    pub script: String,
+    pub status: String, // "started", "failed", "skipped"
+    pub pid: Option<u32>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PresetSummary {
+    pub preset_id: String,
+    pub started: u32,
+    pub failed: u32,
+    pub skipped: u32,
+    pub results: Vec<PresetProjectResult>,
+}
+
+#[derive(Clone, Serialize)]
+struct PresetProgressEvent {
+    preset_id: String,
+    path: String,
+    status: String, // "starting", "waiting", "started", "failed", "skipped"
+    pid: Option<u32>,
+    error: Option<String>,
+}
+
+/// Resolve a project script into the command and args to spawn.
+/// Mirrors the frontend's getCommand/getArgs so backend launches behave the same.
+pub fn resolve_script_command(path: &str, script: &str) -> (String, Vec<String>) {
+    let dir = Path::new(path);
+    let has = |name: &str| dir.join(name).exists();
+
+    if has("package.json") {
+        ("npm".to_string(), vec!["run".to_string(), script.to_string()])
+    } else if has("pyproject.toml") || has("requirements.txt") {
+        ("python".to_string(), vec![script.to_string()])
+    } else if has("go.mod") {
+        ("go".to_string(), vec!["run".to_string(), ".".to_string()])
+    } else if has("Cargo.toml") {
+        ("cargo".to_string(), vec!["run".to_string()])
+    } else if has("docker-compose.yml") || has("docker-compose.yaml") || has("compose.yaml") {
+        ("docker".to_string(), vec!["compose".to_string(), "up".to_string()])
+    } else {
+        ("npm".to_string(), vec!["run".to_string(), script.to_string()])
+    }
+}
+
+fn emit_progress(app: &AppHandle, preset_id: &str, result: &PresetProjectResult) {
+    let _ = app.emit(
+        "preset-progress",
+        PresetProgressEvent {
+            preset_id: preset_id.to_string(),
+            path: result.path.clone(),
+            status: result.status.clone(),
+            pid: result.pid,
+            error: result.error.clone(),
+        },
+    );
+}
+
+/// Wait until every condition in `ready` passes, or the timeout elapses
+async fn wait_until_ready(
+    path: &str,
+    ready: &ReadinessCondition,
+    mut logs: Option<broadcast::Receiver<LogEvent>>,
+) -> Result<(), String> {
+    let deadline = Instant::now() + Duration::from_millis(ready.timeout_ms);
+    let pattern = match &ready.log_pattern {
+        Some(p) => Some(Regex::new(p).map_err(|e| format!("Invalid log pattern: {}", e))?),
+        None => None,
+    };
+    let mut log_matched = pattern.is_none();
+    let mut port_open = ready.port.is_none();
+    let mut url_ok = ready.url.is_none();
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    loop {
+        if let (false, Some(port)) = (port_open, ready.port) {
+            port_open = tokio::net::TcpStream::connect(("127.0.0.1", port)).await.is_ok();
+        }
+        if let (false, Some(url)) = (url_ok, &ready.url) {
+            url_ok = crate::health::probe(&client, url, None, None).await.ok;
+        }
+        if log_matched && port_open && url_ok {
+            return Ok(());
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            let mut missing = Vec::new();
+            if !port_open {
+                missing.push("port");
+            }
+            if !url_ok {
+                missing.push("url");
+            }
+            if !log_matched {
+                missing.push("log pattern");
+            }
+            return Err(format!(
+                "Not ready after {}ms (waiting on {})",
+                ready.timeout_ms,
+                missing.join(", ")
+            ));
+        }
+
+        // Read log lines until the next poll tick
+        let tick = remaining.min(Duration::from_millis(READY_POLL_INTERVAL_MS));
+        match (&mut logs, &pattern) {
+            (Some(rx), Some(re)) if !log_matched => {
+                let tick_end = Instant::now() + tick;
+                while let Ok(received) = tokio::time::timeout_at(tick_end.into(), rx.recv()).await {
+                    match received {
+                        Ok(event) if event.path == path && re.is_match(&event.message) => {
+                            log_matched = true;
+                            break;
+                        }
+                        Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    }
+                }
+            }
+            _ => tokio::time::sleep(tick).await,
+        }
+
+        if !process::running_processes().iter().any(|(_, cwd)| cwd == path) {
+            return Err("Process exited before it became ready".to_string());
+        }
+    }
+}
+
+async fn launch_project(app: AppHandle, preset_id: String, project: PresetProject) -> PresetProjectResult {
+    let _ = app.emit(
+        "preset-progress",
+        PresetProgressEvent {
+            preset_id: preset_id.clone(),
+            path: project.path.clone(),
+            status: "starting".to_string(),
+            pid: None,
+            error: None,
+        },
+    );
+
+    if project.startup_delay_ms > 0 {
+        tokio::time::sleep(Duration::from_millis(project.startup_delay_ms)).await;
+    }
+
+    // Subscribe before spawning so no early log line is missed
+    let logs = project
+        .ready_when
+        .as_ref()
+        .filter(|r| r.log_pattern.is_some())
+        .map(|_| process::subscribe_logs());
+
+    let (command, args) = match &project.command {
+        Some(command) => (command.clone(), project.args.clone()),
+        None => resolve_script_command(&project.path, &project.script),
+    };
+    let spawned = match crate::hooks::run_pre_launch(&app, &project.path).await {
+        Ok(()) => process::spawn_process_internal(&app, &project.path, &command, &args),
+        Err(e) => Err(e),
+    };
+    if let Ok(pid) = &spawned {
+        crate::watch_restart::watch(&app, &project.path, *pid);
+        crate::session::remember(&app, &project.path, &project.script, &command, &args);
+    }
+
+    let readiness = match (&spawned, &project.ready_when) {
+        (Ok(pid), Some(ready)) => {
+            let _ = app.emit(
+                "preset-progress",
+                PresetProgressEvent {
+                    preset_id: preset_id.clone(),
+                    path: project.path.clone(),
+                    status: "waiting".to_string(),
+                    pid: Some(*pid),
+                    error: None,
+                },
+            );
+            wait_until_ready(&project.path, ready, logs).await
+        }
+        _ => Ok(()),
+    };
+
+    let result = match (spawned, readiness) {
+        (Ok(pid), Ok(())) => PresetProjectResult {
+            path: project.path,
+            script: project.script,
+            status: "started".to_string(),
+            pid: Some(pid),
+            error: None,
+        },
+        // Left running so its logs can explain why it never became ready
+        (Ok(pid), Err(e)) => PresetProjectResult {
+            path: project.path,
+            script: project.script,
+            status: "failed".to_string(),
+            pid: Some(pid),
+            error: Some(e),
+        },
+        (Err(e), _) => PresetProjectResult {
+            path: project.path,
+            script: project.script,
+            status: "failed".to_string(),
+            pid: None,
+            error: Some(e),
+        },
+    };
+
+    emit_progress(&app, &preset_id, &result);
+    result
+}
+
+/// Start every project in a preset with bounded concurrency.
+/// Projects only start once all of their `depends_on` entries started and passed their
+/// `ready_when` condition; dependents of a failed or never-ready project are skipped.
+#[tauri::command]
+pub async fn start_preset(
+    app: AppHandle,
+    id: String,
+    concurrency: Option<usize>,
+) -> Result<PresetSummary, String> {
+    start_preset_internal(&app, id, concurrency.unwrap_or(DEFAULT_CONCURRENCY)).await
+}
+
+/// Launch a preset one project at a time (default) or in parallel, honoring each
+/// project's startup delay, and return the PIDs that started
+#[tauri::command]
+pub async fn launch_preset(app: AppHandle, preset_id: String, parallel: Option<bool>) -> Result<Vec<u32>, String> {
+    launch_preset_internal(&app, preset_id, parallel.unwrap_or(false)).await
+}
+
+/// Launch a preset (internal version, also used by the tray)
+pub async fn launch_preset_internal(app: &AppHandle, preset_id: String, parallel: bool) -> Result<Vec<u32>, String> {
+    let concurrency = if parallel { DEFAULT_CONCURRENCY } else { 1 };
+    let summary = start_preset_internal(app, preset_id, concurrency).await?;
+    Ok(summary.results.iter().filter_map(|r| r.pid).collect())
+}
+
+async fn start_preset_internal(app: &AppHandle, id: String, concurrency: usize) -> Result<PresetSummary, String> {
+    let config = config::load_config_internal(app)?;
+    let preset = config
+        .presets
+        .into_iter()
+        .find(|p| p.id == id)
+        .ok_or_else(|| format!("Preset not found: {}", id))?;
+
+    println!("[presets] Starting preset '{}' ({} projects)", preset.name, preset.projects.len());
+    Ok(launch_projects(app, id, preset.projects, Vec::new(), concurrency).await)
+}
+
+/// Launch a set of projects in dependency order. `id` identifies the run in progress
+/// events (a preset id, or "last-session" for session restore); `decided` holds results
+/// for projects the caller already skipped or failed, reported ahead of the rest.
+pub(crate) async fn launch_projects(
+    app: &AppHandle,
+    id: String,
+    projects: Vec<PresetProject>,
+    decided: Vec<PresetProjectResult>,
+    concurrency: usize,
+) -> PresetSummary {
+    let app = app.clone();
+    let concurrency = concurrency.max(1);
+    let mut pending: Vec<PresetProject> = projects;
+    let mut order: Vec<String> = decided.iter().map(|r| r.path.clone()).collect();
+    let mut finished: HashMap<String, PresetProjectResult> = HashMap::new();
+    for result in decided {
+        emit_progress(&app, &id, &result);
+        finished.insert(result.path.clone(), result);
+    }
+    let mut tasks = JoinSet::new();
+
+    loop {
+        // Skip anything whose dependency failed or never became ready
+        let (blocked, rest): (Vec<_>, Vec<_>) = pending.into_iter().partition(|p| {
+            p.depends_on
+                .iter()
+                .any(|dep| finished.get(dep).map(|r| r.status != "started").unwrap_or(false))
+        });
+        pending = rest;
+        for project in blocked {
+            let result = PresetProjectResult {
+                path: project.path.clone(),
+                script: project.script,
+                status: "skipped".to_string(),
+                pid: None,
+                error: Some("A dependency failed to start".to_string()),
+            };
+            emit_progress(&app, &id, &result);
+            order.push(project.path.clone());
+            finished.insert(project.path, result);
+        }
+
+        // Start projects whose dependencies are all ready, in preset order, up to the limit
+        let mut i = 0;
+        while i < pending.len() && tasks.len() < concurrency {
+            if pending[i].depends_on.iter().all(|dep| finished.contains_key(dep)) {
+                let project = pending.remove(i);
+                order.push(project.path.clone());
+                tasks.spawn(launch_project(app.clone(), id.clone(), project));
+            } else {
+                i += 1;
+            }
+        }
+
+        if tasks.is_empty() {
+            // Remaining projects depend on each other or on paths outside the preset
+            for project in pending.drain(..) {
+                let result = PresetProjectResult {
+                    path: project.path.clone(),
+                    script: project.script,
+                    status: "skipped".to_string(),
+                    pid: None,
+                    error: Some("Unresolvable dependency (cycle or missing project)".to_string()),
+                };
+                emit_progress(&app, &id, &result);
+                order.push(project.path.clone());
+                finished.insert(project.path, result);
+            }
+            break;
+        }
+
+        // Each completion may unblock dependents
+        if let Some(Ok(result)) = tasks.join_next().await {
+            finished.insert(result.path.clone(), result);
+        }
+    }
+
+    let results: Vec<PresetProjectResult> = order
+        .iter()
+        .filter_map(|path| finished.remove(path))
+        .collect();
+    let count = |status: &str| results.iter().filter(|r| r.status == status).count() as u32;
+
+    let summary = PresetSummary {
+        preset_id: id,
+        started: count("started"),
+        failed: count("failed"),
+        skipped: count("skipped"),
+        results,
+    };
+
+    println!(
+        "[presets] Preset finished: {} started, {} failed, {} skipped",
+        summary.started, summary.failed, summary.skipped
+    );
+    let _ = app.emit("preset-complete", summary.clone());
+
+    summary
+}
+
+/// Stop a preset's running projects in reverse dependency order and return how many stopped
+#[tauri::command]
+pub async fn stop_preset(app: AppHandle, preset_id: String) -> Result<u32, String> {
+    let config = config::load_config_internal(&app)?;
+    let preset = config
+        .presets
+        .into_iter()
+        .find(|p| p.id == preset_id)
+        .ok_or_else(|| format!("Preset not found: {}", preset_id))?;
+    let paths: HashSet<String> = preset.projects.into_iter().map(|p| p.path).collect();
+    let running: Vec<(u32, String)> = process::running_processes()
+        .into_iter()
+        .filter(|(_, path)| paths.contains(path))
+        .collect();
+
+    println!("[presets] Stopping preset '{}' ({} running)", preset.name, running.len());
+    process::stop_ordered(&app, running, true).await
+}
+
+/// Every project's dependencies across all presets and the last session, followed
+/// transitively so a frontend still stops before the DB when the API between them isn't running
+fn dependency_map(config: &config::AppConfig) -> HashMap<String, HashSet<String>> {
+    let mut direct: HashMap<String, HashSet<String>> = HashMap::new();
+    let session = config.last_session.iter().flat_map(|s| s.running_projects.iter());
+    for project in config.presets.iter().flat_map(|p| p.projects.iter()).chain(session) {
+        direct
+            .entry(project.path.clone())
+            .or_default()
+            .extend(project.depends_on.iter().cloned());
+    }
+
+    direct
+        .iter()
+        .map(|(path, deps)| {
+            let mut all = HashSet::new();
+            let mut stack: Vec<&String> = deps.iter().collect();
+            while let Some(dep) = stack.pop() {
+                if all.insert(dep.clone()) {
+                    if let Some(next) = direct.get(dep) {
+                        stack.extend(next);
+                    }
+                }
+            }
+            (path.clone(), all)
+        })
+        .collect()
+}
+
+/// Group processes into stop steps: each step holds the ones nothing left running depends on
+fn stop_steps(running: Vec<(u32, String)>, deps: &HashMap<String, HashSet<String>>) -> Vec<Vec<(u32, String)>> {
+    let mut remaining = running;
+    let mut steps = Vec::new();
+    while !remaining.is_empty() {
+        let needed = |path: &str| {
+            remaining
+                .iter()
+                .any(|(_, other)| other != path && deps.get(other).map(|d| d.contains(path)).unwrap_or(false))
+        };
+        let (blocked, step): (Vec<_>, Vec<_>) = remaining.iter().cloned().partition(|(_, path)| needed(path));
+        if step.is_empty() {
+            // Dependency cycle: stop the rest together
+            steps.push(blocked);
+            break;
+        }
+        steps.push(step);
+        remaining = blocked;
+    }
+    steps
+}
+
+/// Stop processes in reverse dependency order (a frontend before its API before its DB),
+/// waiting for each step to exit before starting the next. Every process gets until the
+/// overall timeout (but at least STOP_MIN_GRACE_MS) before it is force-killed. Blocks until
+/// done and returns how many processes were stopped.
+pub(crate) fn stop_in_order(app: &AppHandle, running: Vec<(u32, String)>) -> u32 {
+    let deps = config::load_config_internal(app)
+        .map(|c| dependency_map(&c))
+        .unwrap_or_default();
+    let deadline = Instant::now() + Duration::from_millis(STOP_TIMEOUT_MS);
+    let mut stopped = 0u32;
+
+    for step in stop_steps(running, &deps) {
+        let names: Vec<String> = step.iter().map(|(_, path)| crate::projects::project_name(path)).collect();
+        println!("[presets] Stopping {}", names.join(", "));
+        let process_deadline = deadline.max(Instant::now() + Duration::from_millis(STOP_MIN_GRACE_MS));
+        stopped += std::thread::scope(|scope| {
+            let stops: Vec<_> = step
+                .iter()
+                .map(|&(pid, _)| scope.spawn(move || process::stop_until(pid, process_deadline)))
+                .collect();
+            stops.into_iter().filter_map(|s| s.join().ok()).filter(|&ok| ok).count() as u32
+        });
+    }
+    stopped
+}
+
@@ -0,0 +1,168 @@
+use crate::process::LogEvent;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, Once};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// Distinct problems kept per project; the least recently seen are dropped first
+const MAX_PROBLEMS_PER_PROJECT: usize = 200;
+/// How often problems-updated is emitted for projects with new errors
+const EMIT_INTERVAL: Duration = Duration::from_secs(1);
+
+lazy_static::lazy_static! {
+    static ref PROBLEMS: Mutex<HashMap<String, HashMap<String, Problem>>> = Mutex::new(HashMap::new());
+    // Projects with changes since the last problems-updated event
+    static ref DIRTY: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+    // Parts of an error message that vary between occurrences of the same problem
+    static ref VOLATILE: Vec<(Regex, &'static str)> = vec![
+        (Regex::new(r"\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(?:[.,]\d+)?(?:Z|[+-]\d{2}:?\d{2})?").unwrap(), "<time>"),
+        (Regex::new(r"(?i)\b[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}\b").unwrap(), "<id>"),
+        (Regex::new(r"(?i)\b0x[0-9a-f]+\b|\b[0-9a-f]{12,}\b").unwrap(), "<hex>"),
+        (Regex::new(r#""[^"]*"|'[^']*'"#).unwrap(), "<str>"),
+        (Regex::new(r"\d+").unwrap(), "<n>"),
+        (Regex::new(r"\s+").unwrap(), " "),
+    ];
+    // Lines that read like a diagnostic; everything on stderr is "error" level, and plenty of
+    // tools log their normal progress there
+    static ref DIAGNOSTIC: Regex = Regex::new(
+        r"(?i)\b(?:error|exception|panic(?:ked)?|fatal|traceback|uncaught|unhandled|failed|failure|segmentation fault)\b|\bERR!|\bE\d{4}\b"
+    )
+    .unwrap();
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Problem {
+    pub fingerprint: String,
+    /// First occurrence, as logged
+    pub message: String,
+    /// Most recent occurrence, as logged
+    pub last_message: String,
+    pub count: u64,
+    pub first_seen: i64,
+    pub last_seen: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectProblems {
+    pub path: String,
+    /// Distinct problems
+    pub distinct: usize,
+    /// Total error lines across them
+    pub occurrences: u64,
+    /// Most recently seen first
+    pub problems: Vec<Problem>,
+}
+
+/// Fingerprint for an error line without one from a log rule: the message with
+/// timestamps, ids, numbers and quoted values masked
+fn fingerprint(message: &str) -> String {
+    let mut normalized = message.trim().to_string();
+    for (regex, replacement) in VOLATILE.iter() {
+        normalized = regex.replace_all(&normalized, *replacement).to_string();
+    }
+    normalized.chars().take(200).collect()
+}
+
+fn summarize(path: &str, problems: Option<&HashMap<String, Problem>>) -> ProjectProblems {
+    let mut list: Vec<Problem> = problems.map(|p| p.values().cloned().collect()).unwrap_or_default();
+    list.sort_by_key(|p| std::cmp::Reverse(p.last_seen));
+    ProjectProblems {
+        path: path.to_string(),
+        distinct: list.len(),
+        occurrences: list.iter().map(|p| p.count).sum(),
+        problems: list,
+    }
+}
+
+/// Count an error-level log line towards its project's problems, if a log rule matched it
+/// or it looks like a diagnostic
+pub(crate) fn record(app: &AppHandle, event: &LogEvent) {
+    if event.level != "error" || event.message.trim().is_empty() {
+        return;
+    }
+    if event.fingerprint.is_none() && !DIAGNOSTIC.is_match(&event.message) {
+        return;
+    }
+    ensure_emitter(app);
+
+    let key = event
+        .fingerprint
+        .clone()
+        .unwrap_or_else(|| fingerprint(&event.message));
+    let now = chrono::Utc::now().timestamp_millis();
+    let Ok(mut all) = PROBLEMS.lock() else {
+        return;
+    };
+    let problems = all.entry(event.path.clone()).or_default();
+    problems
+        .entry(key.clone())
+        .and_modify(|p| {
+            p.count += 1;
+            p.last_seen = now;
+            p.last_message = event.message.clone();
+        })
+        .or_insert_with(|| Problem {
+            fingerprint: key,
+            message: event.message.clone(),
+            last_message: event.message.clone(),
+            count: 1,
+            first_seen: now,
+            last_seen: now,
+        });
+    if problems.len() > MAX_PROBLEMS_PER_PROJECT {
+        if let Some(oldest) = problems
+            .values()
+            .min_by_key(|p| p.last_seen)
+            .map(|p| p.fingerprint.clone())
+        {
+            problems.remove(&oldest);
+        }
+    }
+    drop(all);
+
+    if let Ok(mut dirty) = DIRTY.lock() {
+        dirty.insert(event.path.clone());
+    }
+}
+
+/// Emit `problems-updated` for changed projects, at most once per interval (errors come in bursts)
+fn ensure_emitter(app: &AppHandle) {
+    static EMITTER: Once = Once::new();
+    let app = app.clone();
+    EMITTER.call_once(move || {
+        std::thread::spawn(move || loop {
+            std::thread::sleep(EMIT_INTERVAL);
+            let changed: Vec<String> = DIRTY.lock().map(|mut d| d.drain().collect()).unwrap_or_default();
+            if changed.is_empty() {
+                continue;
+            }
+            let Ok(all) = PROBLEMS.lock() else {
+                continue;
+            };
+            let updates: Vec<ProjectProblems> = changed.iter().map(|path| summarize(path, all.get(path))).collect();
+            drop(all);
+            for update in updates {
+                let _ = app.emit("problems-updated", update);
+            }
+        });
+    });
+}
+
+/// Repeated error lines for a project, grouped by fingerprint
+#[tauri::command]
+pub async fn get_project_problems(path: String) -> Result<ProjectProblems, String> {
+    let all = PROBLEMS.lock().map_err(|e| e.to_string())?;
+    Ok(summarize(&path, all.get(&path)))
+}
+
+/// Forget a project's problems (e.g. after fixing them)
+#[tauri::command]
+pub async fn clear_project_problems(app: AppHandle, path: String) -> Result<(), String> {
+    PROBLEMS.lock().map_err(|e| e.to_string())?.remove(&path);
+    let _ = app.emit("problems-updated", summarize(&path, None));
+    Ok(())
+}
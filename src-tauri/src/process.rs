@@ -1,130 +1,918 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
-use std::process::{Command, Stdio};
-use std::sync::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::io::{Read, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, Once};
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 
-// Global registry of spawned process PIDs and compiled URL regex
+/// How often the exit monitor polls managed children
+const EXIT_POLL_INTERVAL_MS: u64 = 500;
+
+/// How long an exit waits for the output readers to drain before snapshotting the last lines
+/// (a grandchild holding the pipe open would otherwise stall the exit monitor)
+const READER_DRAIN_TIMEOUT_MS: u64 = 1000;
+
+/// Quiet time after output that ends mid-line before it's checked for a prompt
+const PROMPT_IDLE_MS: u64 = 300;
+
+/// Longest unterminated line kept waiting for its newline
+pub(crate) const MAX_PENDING_BYTES: usize = 64 * 1024;
+
+// Max lines retained per project in the log history buffer (from preferences)
+static LOG_BUFFER_SIZE: AtomicUsize = AtomicUsize::new(crate::config::DEFAULT_LOG_BUFFER_SIZE);
+
+// Global process manager (keyed by managed process id) and compiled URL regex
 lazy_static::lazy_static! {
-    static ref PROCESS_REGISTRY: Mutex<HashSet<u32>> = Mutex::new(HashSet::new());
+    static ref PROCESS_MANAGER: Mutex<HashMap<String, ManagedProcess>> = Mutex::new(HashMap::new());
+    // Stdin of piped children by PID, for answering prompts (PTY input goes through pty.rs)
+    static ref STDIN: Mutex<HashMap<u32, Arc<Mutex<ChildStdin>>>> = Mutex::new(HashMap::new());
+    // stdout/stderr reader threads of piped children by PID, joined when the child exits
+    static ref READERS: Mutex<HashMap<u32, Vec<std::thread::JoinHandle<()>>>> = Mutex::new(HashMap::new());
+    // Port in bind errors like "listen EACCES: permission denied 0.0.0.0:80"
+    static ref PRIVILEGED_PORT_REGEX: Regex = Regex::new(r":(\d{1,5})\b").unwrap();
+    // Recent log lines per project path, so the UI can re-hydrate after a reload
+    static ref LOG_BUFFERS: Mutex<HashMap<String, LogBuffer>> = Mutex::new(HashMap::new());
+    // Fan-out of log lines for backend consumers (e.g. shared log links)
+    static ref LOG_BROADCAST: tokio::sync::broadcast::Sender<LogEvent> = tokio::sync::broadcast::channel(1024).0;
     // Regex to detect URLs like http://localhost:3000 or http://127.0.0.1:8080
     static ref URL_REGEX: Regex = Regex::new(r"https?://(?:localhost|127\.0\.0\.1|0\.0\.0\.0):(\d+)").unwrap();
+    // Owning processes in `ss -p` output: users:(("node",pid=1234,fd=20))
+    #[cfg(target_os = "linux")]
+    static ref SS_USERS_REGEX: Regex = Regex::new(r#"\("([^"]*)",pid=(\d+)"#).unwrap();
     // Regex to detect port mentions like "listening on port 3000" or "ready on port 8080"
     static ref PORT_REGEX: Regex = Regex::new(r"(?i)(?:listening|ready|running|started|server|local)\s+(?:on|at)?\s*(?:port\s+)?:?(\d{4,5})").unwrap();
 }
 
+/// A process spawned by DevLaunch, with everything needed to restart it
+pub struct ManagedProcess {
+    pub id: String,
+    pub pid: u32,
+    pub cwd: String,
+    pub command: String,
+    pub args: Vec<String>,
+    pub started_at: i64,
+    pub elevated: bool,
+    pub restart_count: u32,
+    /// Set when we asked the process to stop, so its exit isn't treated as a crash
+    pub stop_requested: bool,
+    pub exited: bool,
+    pub exit_code: Option<i32>,
+    /// Exited on its own with a failure status (not after a stop request)
+    pub crashed: bool,
+    /// Local URL detected in the process output
+    pub url: Option<String>,
+    /// Running in a pseudo-terminal (output is raw terminal data, stdin is writable)
+    pub pty: bool,
+    /// Left running by a previous session and adopted; tracked by PID, with no output
+    pub adopted: bool,
+    // None for elevated and adopted processes we can only track by PID
+    child: Option<ChildHandle>,
+}
+
+/// A child we can reap: a regular piped process or one attached to a PTY
+pub(crate) enum ChildHandle {
+    Piped(Child),
+    Pty(Box<dyn portable_pty::Child + Send + Sync>),
+}
+
+impl From<Child> for ChildHandle {
+    fn from(child: Child) -> Self {
+        ChildHandle::Piped(child)
+    }
+}
+
+/// How a child exited
+struct ExitInfo {
+    code: Option<i32>,
+    signal: Option<i32>,
+    success: bool,
+    /// False for processes we only track by PID: they're gone, but nothing says how they ended
+    known: bool,
+}
+
+impl ChildHandle {
+    fn try_wait(&mut self) -> Option<ExitInfo> {
+        match self {
+            ChildHandle::Piped(child) => {
+                let status = child.try_wait().ok()??;
+                #[cfg(unix)]
+                let signal = {
+                    use std::os::unix::process::ExitStatusExt;
+                    status.signal()
+                };
+                #[cfg(not(unix))]
+                let signal = None;
+                Some(ExitInfo {
+                    code: status.code(),
+                    signal,
+                    success: status.success(),
+                    known: true,
+                })
+            }
+            ChildHandle::Pty(child) => {
+                let status = child.try_wait().ok()??;
+                Some(ExitInfo {
+                    code: Some(status.exit_code() as i32),
+                    signal: None,
+                    success: status.success(),
+                    known: true,
+                })
+            }
+        }
+    }
+}
+
+impl ManagedProcess {
+    fn is_running(&self) -> bool {
+        !self.exited && !self.stop_requested
+    }
+
+    fn record(&self) -> ProcessRecord {
+        ProcessRecord {
+            id: self.id.clone(),
+            pid: self.pid,
+            cwd: self.cwd.clone(),
+            command: self.command.clone(),
+            args: self.args.clone(),
+            started_at: self.started_at,
+            elevated: self.elevated,
+            restart_count: self.restart_count,
+            running: self.is_running(),
+            exit_code: self.exit_code,
+            crashed: self.crashed,
+            url: self.url.clone(),
+            pty: self.pty,
+            adopted: self.adopted,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessRecord {
+    pub id: String,
+    pub pid: u32,
+    pub cwd: String,
+    pub command: String,
+    pub args: Vec<String>,
+    pub started_at: i64,
+    pub elevated: bool,
+    pub restart_count: u32,
+    pub running: bool,
+    pub exit_code: Option<i32>,
+    /// Exited on its own with a failure status; stopping (even during the grace period) doesn't count
+    pub crashed: bool,
+    pub url: Option<String>,
+    pub pty: bool,
+    pub adopted: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessExitedEvent {
+    pub id: String,
+    pub pid: u32,
+    pub path: String,
+    pub code: Option<i32>,
+    pub signal: Option<i32>,
+    /// True when the exit followed a stop/kill request
+    pub expected: bool,
+    /// Exit cause in words, e.g. "Killed by SIGKILL (9)"
+    pub reason: String,
+    /// Last lines printed by this run, oldest first
+    pub last_output: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProcessStatus {
     pub running: bool,
     pub pid: Option<u32>,
+    pub elevated: bool,
 }
 
-/// Spawn a new process and return its PID
+/// Spawn a new process and return its PID (in a pseudo-terminal when `pty` is set)
 #[tauri::command]
 pub async fn spawn_process(
     app: AppHandle,
     cwd: String,
     command: String,
     args: Vec<String>,
+    pty: Option<bool>,
+) -> Result<u32, String> {
+    crate::hooks::run_pre_launch(&app, &cwd).await?;
+    let pid = if pty.unwrap_or(false) {
+        crate::pty::spawn_pty_internal(&app, &cwd, &command, &args)?
+    } else {
+        spawn_process_internal(&app, &cwd, &command, &args)?
+    };
+    crate::watch_restart::watch(&app, &cwd, pid);
+    let script = crate::session::script_for_command(&command, &args);
+    crate::session::remember(&app, &cwd, &script, &command, &args);
+    Ok(pid)
+}
+
+/// Command and args for running a line through the system shell
+fn shell_invocation(line: &str) -> (String, Vec<String>) {
+    if cfg!(windows) {
+        ("cmd".to_string(), vec!["/C".to_string(), line.to_string()])
+    } else {
+        ("sh".to_string(), vec!["-c".to_string(), line.to_string()])
+    }
+}
+
+/// Add arguments to a command. A `cmd /C` line is passed to cmd verbatim on Windows: the
+/// usual argument quoting follows C runtime rules that cmd doesn't, and would mangle its quotes.
+fn add_args(cmd: &mut Command, args: &[String]) {
+    #[cfg(windows)]
+    if let [flag, line] = args {
+        if flag.eq_ignore_ascii_case("/C") && cmd.get_program().eq_ignore_ascii_case("cmd") {
+            use std::os::windows::process::CommandExt;
+            cmd.arg(flag).raw_arg(line);
+            return;
+        }
+    }
+    cmd.args(args);
+}
+
+/// Command and args for a parsed line with inline variables, via `env` so restarts keep them
+fn with_inline_env(line: crate::command_line::CommandLine) -> Result<(String, Vec<String>), String> {
+    if cfg!(windows) {
+        return Err(
+            "Inline variables like NODE_ENV=test aren't supported on Windows; set them in the project's env settings"
+                .to_string(),
+        );
+    }
+    let args = line
+        .env
+        .into_iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .chain(std::iter::once(line.command))
+        .chain(line.args)
+        .collect();
+    Ok(("env".to_string(), args))
+}
+
+/// Spawn a command given as one string, e.g. "NODE_ENV=test npm run dev -- --port 4000".
+/// Plain commands are split into words (with shell quoting rules) and run directly; lines
+/// with pipes, redirects, `&&`, variables or globs run under `sh -c` (`cmd /C` on Windows).
+#[tauri::command]
+pub async fn spawn_shell(
+    app: AppHandle,
+    cwd: String,
+    command_line: String,
+    pty: Option<bool>,
+) -> Result<u32, String> {
+    let parsed = crate::command_line::parse(&command_line)?;
+    let (command, args) = if parsed.needs_shell {
+        shell_invocation(&command_line)
+    } else if parsed.env.is_empty() {
+        (parsed.command, parsed.args)
+    } else {
+        with_inline_env(parsed)?
+    };
+    spawn_process(app, cwd, command, args, pty).await
+}
+
+/// Per-launch setup shared by piped and PTY spawns; call before output starts streaming
+pub(crate) fn prepare_output(app: &AppHandle, cwd: &str, command: &str, args: &[String]) {
+    let config = crate::config::load_config_internal(app).ok();
+    if let Some(config) = &config {
+        LOG_BUFFER_SIZE.store(config.preferences.log_buffer_size.max(1), Ordering::Relaxed);
+    }
+
+    crate::health::reset_project(cwd);
+    crate::launch_stats::start(app, cwd, command, args);
+    crate::runtime::warn_on_mismatch(app, cwd);
+    crate::log_rules::load(app, cwd);
+    crate::ansi::load(app, cwd);
+    crate::log_files::start_session(
+        app,
+        cwd,
+        command,
+        args,
+        &config.map(|c| c.preferences.log_files).unwrap_or_default(),
+    );
+}
+
+/// Spawn a process with log streaming (internal version for backend-driven launches)
+pub fn spawn_process_internal(
+    app: &AppHandle,
+    cwd: &str,
+    command: &str,
+    args: &[String],
 ) -> Result<u32, String> {
-    let mut cmd = Command::new(&command);
-    cmd.current_dir(&cwd)
-        .args(&args)
+    let (program, program_args) = crate::runtime::wrap_command(app, cwd, command, args);
+    let program_name = program.clone();
+    let mut cmd = Command::new(program);
+    add_args(&mut cmd, &program_args);
+    cmd.current_dir(cwd)
+        .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
 
+    // GUI apps get a minimal PATH; use the login shell's environment instead
+    crate::shell_env::apply_to_command(app, &mut cmd);
+    cmd.envs(crate::shell_env::project_env(app, cwd));
+
     // On Unix, create a new process group so we can kill the whole tree
     #[cfg(unix)]
     {
         use std::os::unix::process::CommandExt;
         cmd.process_group(0);
     }
+    let limits = crate::limits::project_limits(app, cwd);
+    #[cfg(unix)]
+    if let Some(limits) = &limits {
+        crate::limits::configure_command(limits, &mut cmd);
+    }
+    crate::jobs::start_suspended(&mut cmd);
 
-    let mut child = cmd.spawn().map_err(|e| format!("Failed to spawn: {}", e))?;
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| crate::shell_env::spawn_error(app, &program_name, e))?;
 
     let pid = child.id();
+    // Windows: a job object so stopping it takes detached children too (and carries limits),
+    // assigned before the process gets to run
+    crate::jobs::attach(pid, limits.as_ref());
+    crate::jobs::resume(pid);
 
-    // Register the PID in our process registry
-    if let Ok(mut registry) = PROCESS_REGISTRY.lock() {
-        registry.insert(pid);
-    }
+    prepare_output(app, cwd, command, args);
 
-    // Spawn a task to read stdout and emit events
-    let app_clone = app.clone();
-    let cwd_clone = cwd.clone();
+    if let Some(stdin) = child.stdin.take() {
+        if let Ok(mut handles) = STDIN.lock() {
+            handles.insert(pid, Arc::new(Mutex::new(stdin)));
+        }
+    }
+    let mut readers = Vec::new();
     if let Some(stdout) = child.stdout.take() {
-        std::thread::spawn(move || {
-            use std::io::{BufRead, BufReader};
-            let reader = BufReader::new(stdout);
-            for line in reader.lines().map_while(Result::ok) {
-                // Emit log event
-                let _ = app_clone.emit(
-                    "process-log",
-                    LogEvent {
-                        path: cwd_clone.clone(),
-                        level: detect_log_level(&line),
-                        message: line.clone(),
-                    },
-                );
-
-                // Check for URL/port in the log line and emit URL event
-                if let Some((url, port)) = detect_url(&line) {
-                    let _ = app_clone.emit(
-                        "process-url",
-                        UrlEvent {
-                            path: cwd_clone.clone(),
-                            url,
-                            port,
-                        },
-                    );
+        readers.push(stream_lines(app.clone(), cwd.to_string(), pid, stdout, handle_stdout_line));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        readers.push(stream_lines(app.clone(), cwd.to_string(), pid, stderr, handle_stderr_line));
+    }
+    if let Ok(mut all) = READERS.lock() {
+        all.insert(pid, readers);
+    }
+
+    // Keep the child so we can detect its exit and restart it later
+    register_process(app, pid, cwd, command, args, Some(child.into()));
+
+    Ok(pid)
+}
+
+/// Read a pipe on a background thread, handing each line to `handle`. Text left without a
+/// newline once the process has been quiet for PROMPT_IDLE_MS is checked for a prompt
+/// waiting on stdin. Returns the thread that finishes once the pipe is fully handled.
+fn stream_lines(
+    app: AppHandle,
+    cwd: String,
+    pid: u32,
+    mut reader: impl Read + Send + 'static,
+    handle: fn(&AppHandle, &str, String),
+) -> std::thread::JoinHandle<()> {
+    let (tx, rx) = std::sync::mpsc::channel::<Vec<u8>>();
+    std::thread::spawn(move || {
+        let mut buf = [0u8; 8192];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    if tx.send(buf[..n].to_vec()).is_err() {
+                        break;
+                    }
                 }
             }
-        });
+        }
+    });
+    std::thread::spawn(move || {
+        use std::sync::mpsc::RecvTimeoutError;
+        let mut pending: Vec<u8> = Vec::new();
+        let mut checked = true;
+        loop {
+            match rx.recv_timeout(Duration::from_millis(PROMPT_IDLE_MS)) {
+                Ok(chunk) => {
+                    pending.extend_from_slice(&chunk);
+                    while let Some(end) = pending.iter().position(|&b| b == b'\n') {
+                        let raw: Vec<u8> = pending.drain(..=end).collect();
+                        let line = String::from_utf8_lossy(&raw).trim_end_matches(['\r', '\n']).to_string();
+                        handle(&app, &cwd, line);
+                    }
+                    // Output that never ends a line (progress bars, binary noise) is let through in pieces
+                    if pending.len() > MAX_PENDING_BYTES {
+                        handle(&app, &cwd, String::from_utf8_lossy(&pending).to_string());
+                        pending.clear();
+                    }
+                    checked = pending.is_empty();
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    if !checked {
+                        crate::prompts::check(&app, pid, &cwd, &String::from_utf8_lossy(&pending));
+                        checked = true;
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+        if !pending.is_empty() {
+            handle(&app, &cwd, String::from_utf8_lossy(&pending).to_string());
+        }
+    })
+}
+
+/// Wait (up to READER_DRAIN_TIMEOUT_MS) for an exited child's output readers to hit EOF, so
+/// its final lines are buffered before anything snapshots them
+fn drain_readers(pid: u32) {
+    let Some(readers) = READERS.lock().ok().and_then(|mut all| all.remove(&pid)) else {
+        return;
+    };
+    let deadline = Instant::now() + Duration::from_millis(READER_DRAIN_TIMEOUT_MS);
+    while readers.iter().any(|r| !r.is_finished()) && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    for reader in readers.into_iter().filter(|r| r.is_finished()) {
+        let _ = reader.join();
     }
+}
 
-    // Spawn a task to read stderr
-    let app_clone = app.clone();
-    let cwd_clone = cwd.clone();
-    if let Some(stderr) = child.stderr.take() {
-        std::thread::spawn(move || {
-            use std::io::{BufRead, BufReader};
-            let reader = BufReader::new(stderr);
-            for line in reader.lines().map_while(Result::ok) {
-                let _ = app_clone.emit(
-                    "process-log",
-                    LogEvent {
-                        path: cwd_clone.clone(),
-                        level: "error".to_string(),
-                        message: line,
+/// Write to a process's stdin, e.g. "y\n" to answer a prompt (include the newline to submit).
+/// Works for piped and PTY processes alike.
+#[tauri::command]
+pub async fn send_stdin(pid: u32, text: String) -> Result<(), String> {
+    // Clone the handle out so a child that isn't reading can't block every other writer
+    let piped = STDIN.lock().map_err(|e| e.to_string())?.get(&pid).cloned();
+    if let Some(stdin) = piped {
+        crate::prompts::reset(pid);
+        return tokio::task::spawn_blocking(move || {
+            let mut stdin = stdin.lock().map_err(|e| e.to_string())?;
+            stdin
+                .write_all(text.as_bytes())
+                .and_then(|_| stdin.flush())
+                .map_err(|e| format!("Failed to write to PID {}: {}", pid, e))
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+    }
+    let pty = PROCESS_MANAGER
+        .lock()
+        .map(|m| m.values().any(|p| p.pid == pid && p.pty && !p.exited))
+        .unwrap_or(false);
+    if !pty {
+        return Err(format!("PID {} has no stdin to write to", pid));
+    }
+    crate::prompts::reset(pid);
+    crate::pty::write_process_stdin(pid, text).await
+}
+
+/// Add a spawned process to the manager, replacing any exited entry for the same project
+pub(crate) fn register_process(
+    app: &AppHandle,
+    pid: u32,
+    cwd: &str,
+    command: &str,
+    args: &[String],
+    child: Option<ChildHandle>,
+) -> String {
+    ensure_exit_monitor(app);
+
+    let id = uuid::Uuid::new_v4().to_string();
+    if let Ok(mut manager) = PROCESS_MANAGER.lock() {
+        manager.retain(|_, p| !(p.exited && p.cwd == cwd));
+        manager.insert(
+            id.clone(),
+            ManagedProcess {
+                id: id.clone(),
+                pid,
+                cwd: cwd.to_string(),
+                command: command.to_string(),
+                args: args.to_vec(),
+                started_at: chrono::Utc::now().timestamp_millis(),
+                elevated: false,
+                restart_count: 0,
+                stop_requested: false,
+                exited: false,
+                exit_code: None,
+                crashed: false,
+                url: None,
+                pty: matches!(child, Some(ChildHandle::Pty(_))),
+                adopted: false,
+                child,
+            },
+        );
+    }
+    crate::orphans::save_session(app);
+    crate::tray::refresh(app);
+    id
+}
+
+/// Track a process left running by a previous session as if we had spawned it
+pub(crate) fn adopt_process(app: &AppHandle, pid: u32, cwd: &str, command: &str, args: &[String], started_at: i64) {
+    register_process(app, pid, cwd, command, args, None);
+    if let Ok(mut manager) = PROCESS_MANAGER.lock() {
+        for process in manager.values_mut().filter(|p| p.pid == pid) {
+            process.adopted = true;
+            process.started_at = started_at;
+        }
+    }
+}
+
+/// Start the background thread that reaps exited children (once per app run)
+fn ensure_exit_monitor(app: &AppHandle) {
+    static MONITOR: Once = Once::new();
+    let app = app.clone();
+    MONITOR.call_once(move || {
+        std::thread::spawn(move || loop {
+            poll_exits(&app);
+            std::thread::sleep(Duration::from_millis(EXIT_POLL_INTERVAL_MS));
+        });
+    });
+}
+
+/// Check every managed child for exit, emit process-exited, and drop stopped entries
+fn poll_exits(app: &AppHandle) {
+    let mut events = Vec::new();
+    let mut crashed = Vec::new();
+    let mut launches = Vec::new();
+    let mut crashes = Vec::new();
+
+    if let Ok(mut manager) = PROCESS_MANAGER.lock() {
+        for process in manager.values_mut().filter(|p| !p.exited) {
+            let status = match process.child.as_mut() {
+                Some(child) => child.try_wait(),
+                // Not our child (adopted, or started through the elevation prompt), so there's no
+                // exit status; just notice it's gone. Without a status it can't count as a crash.
+                None if process.adopted || process.elevated => {
+                    (!crate::system::pid_alive(process.pid)).then_some(ExitInfo {
+                        code: None,
+                        signal: None,
+                        success: true,
+                        known: false,
+                    })
+                }
+                None => None,
+            };
+            if let Some(status) = status {
+                crate::jobs::release(process.pid);
+                crate::prompts::reset(process.pid);
+                if let Ok(mut handles) = STDIN.lock() {
+                    handles.remove(&process.pid);
+                }
+                process.exited = true;
+                process.exit_code = status.code;
+                launches.push((process.cwd.clone(), process.started_at));
+                let reason = crate::crash_history::describe_exit(status.code, status.signal);
+                if !process.stop_requested && status.known {
+                    crashed.push((
+                        process.pid,
+                        process.cwd.clone(),
+                        status.success,
+                        process.started_at,
+                        process.restart_count,
+                    ));
+                }
+                // A clean exit isn't a crash, even when nobody asked for it
+                if !process.stop_requested && status.known && !status.success {
+                    process.crashed = true;
+                    crashes.push(crate::crash_history::CrashRecord {
+                        pid: process.pid,
+                        command: std::iter::once(&process.command)
+                            .chain(&process.args)
+                            .map(|a| a.as_str())
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                        started_at: process.started_at,
+                        exited_at: chrono::Utc::now().timestamp_millis(),
+                        code: status.code,
+                        signal: status.signal,
+                        reason: reason.clone(),
+                        last_output: Vec::new(),
+                    });
+                }
+                events.push((
+                    process.started_at,
+                    ProcessExitedEvent {
+                        id: process.id.clone(),
+                        pid: process.pid,
+                        path: process.cwd.clone(),
+                        code: status.code,
+                        signal: status.signal,
+                        expected: process.stop_requested,
+                        reason,
+                        last_output: Vec::new(),
                     },
-                );
+                ));
             }
-        });
+        }
+
+        // Processes we stopped on purpose don't need to stick around for restart
+        manager.retain(|_, p| !(p.exited && p.stop_requested));
     }
 
-    // Note: In a full implementation, you'd store `child` in a registry
-    // For now, we just detach it since we're using PIDs for management
+    if !events.is_empty() {
+        crate::orphans::save_session(app);
+        crate::tray::refresh(app);
+    }
+    for (started_at, mut event) in events {
+        drain_readers(event.pid);
+        event.last_output = recent_output(&event.path, started_at, crate::crash_history::SNAPSHOT_LINES);
+        println!(
+            "[process] PID {} exited: {} (expected {})",
+            event.pid, event.reason, event.expected
+        );
+        if let Some(i) = crashes.iter().position(|c| c.pid == event.pid) {
+            let mut crash = crashes.swap_remove(i);
+            crash.last_output = event.last_output.clone();
+            crate::crash_history::record(app, &event.path, crash);
+        }
+        let _ = app.emit("process-exited", event);
+    }
 
-    Ok(pid)
+    for (cwd, started_at) in launches {
+        crate::idle::forget(&cwd);
+        crate::launch_stats::on_exit(app, &cwd, started_at);
+    }
+
+    for (pid, cwd, success, started_at, restart_count) in crashed {
+        schedule_auto_restart(app, pid, &cwd, success, started_at, restart_count);
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessRestartedEvent {
+    pub path: String,
+    pub old_pid: u32,
+    pub new_pid: Option<u32>,
+    pub restart_count: u32,
+    pub backoff_ms: u64,
+    pub error: Option<String>,
+}
+
+/// Watchdog: restart an unexpectedly exited process according to its project's policy
+fn schedule_auto_restart(
+    app: &AppHandle,
+    pid: u32,
+    cwd: &str,
+    success: bool,
+    started_at: i64,
+    restart_count: u32,
+) {
+    let Some(policy) = crate::config::load_config_internal(app)
+        .ok()
+        .and_then(|c| c.project_settings(cwd).auto_restart)
+        .filter(|p| p.enabled)
+    else {
+        return;
+    };
+
+    if success && policy.only_on_nonzero_exit {
+        return;
+    }
+
+    // A long-lived process that crashes starts a fresh retry sequence
+    let uptime_secs = (chrono::Utc::now().timestamp_millis() - started_at).max(0) as u64 / 1000;
+    let attempt = if uptime_secs >= policy.reset_after_secs {
+        if let Ok(mut manager) = PROCESS_MANAGER.lock() {
+            for process in manager.values_mut().filter(|p| p.pid == pid) {
+                process.restart_count = 0;
+            }
+        }
+        0
+    } else {
+        restart_count
+    };
+
+    if attempt >= policy.max_retries {
+        println!("[process] Giving up on {} after {} restarts", cwd, attempt);
+        let _ = app.emit(
+            "process-restart-abandoned",
+            ProcessRestartedEvent {
+                path: cwd.to_string(),
+                old_pid: pid,
+                new_pid: None,
+                restart_count: attempt,
+                backoff_ms: 0,
+                error: Some(format!("Exceeded {} restart attempts", policy.max_retries)),
+            },
+        );
+        return;
+    }
+
+    let backoff_ms = policy.backoff_ms(attempt);
+    println!("[process] Restarting {} in {}ms (attempt {})", cwd, backoff_ms, attempt + 1);
+
+    let app = app.clone();
+    let cwd = cwd.to_string();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+
+        // The user may have stopped or restarted it during the backoff
+        let still_exited = PROCESS_MANAGER
+            .lock()
+            .map(|m| m.values().any(|p| p.pid == pid && p.exited && !p.stop_requested))
+            .unwrap_or(false);
+        if !still_exited {
+            return;
+        }
+
+        let result = restart_process_internal(&app, pid).await;
+        let event = match result {
+            Ok(new_pid) => ProcessRestartedEvent {
+                path: cwd,
+                old_pid: pid,
+                new_pid: Some(new_pid),
+                restart_count: attempt + 1,
+                backoff_ms,
+                error: None,
+            },
+            Err(e) => ProcessRestartedEvent {
+                path: cwd,
+                old_pid: pid,
+                new_pid: None,
+                restart_count: attempt + 1,
+                backoff_ms,
+                error: Some(e),
+            },
+        };
+        let _ = app.emit("process-restarted", event);
+    });
+}
+
+/// Remember the URL a running project serves; returns true if it changed
+fn set_detected_url(cwd: &str, url: &str) -> bool {
+    let Ok(mut manager) = PROCESS_MANAGER.lock() else {
+        return false;
+    };
+    let mut changed = false;
+    for process in manager.values_mut().filter(|p| p.cwd == cwd && !p.exited) {
+        if process.url.as_deref() != Some(url) {
+            process.url = Some(url.to_string());
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Build a log event: JSON lines are unpacked into message, level and fields, then the
+/// project's log rules can override the level and attach more fields
+fn classify_line(
+    cwd: &str,
+    line: String,
+    default_level: impl FnOnce(&str) -> String,
+) -> (LogEvent, Option<(String, u16)>) {
+    let (line, spans) = crate::ansi::process(cwd, line);
+    let (mut level, mut fields, timestamp, message) = match crate::json_logs::parse(&line) {
+        Some(json) => (json.level, json.fields, json.timestamp, json.message),
+        None => (None, HashMap::new(), None, line),
+    };
+    let (fingerprint, url) = match crate::log_rules::apply(cwd, &message) {
+        Some(m) => {
+            level = m.level.or(level);
+            fields.extend(m.fields.into_iter().map(|(k, v)| (k, serde_json::Value::String(v))));
+            (m.fingerprint, m.url)
+        }
+        None => (None, None),
+    };
+    let event = LogEvent {
+        path: cwd.to_string(),
+        level: level.unwrap_or_else(|| default_level(&message)),
+        message,
+        fields,
+        fingerprint,
+        timestamp,
+        spans,
+    };
+    (event, url)
+}
+
+lazy_static::lazy_static! {
+    // Last port each project was seen serving on (survives the process exiting)
+    static ref LAST_PORTS: Mutex<HashMap<String, u16>> = Mutex::new(HashMap::new());
+}
+
+/// Last port a project was seen serving on
+pub(crate) fn last_port(path: &str) -> Option<u16> {
+    LAST_PORTS.lock().ok().and_then(|p| p.get(path).copied())
+}
+
+/// Record a served URL found in a project's output and emit process-url
+fn on_url_detected(app: &AppHandle, cwd: &str, url: String, port: u16) {
+    if let Ok(mut ports) = LAST_PORTS.lock() {
+        ports.insert(cwd.to_string(), port);
+    }
+    crate::launch_stats::on_url_detected(app, cwd);
+    crate::health::on_project_ready(app, cwd, &url);
+    if set_detected_url(cwd, &url) {
+        crate::tray::refresh(app);
+    }
+    let _ = app.emit(
+        "process-url",
+        UrlEvent {
+            path: cwd.to_string(),
+            url,
+            port,
+        },
+    );
+}
+
+/// Emit a stdout line as a log event and check it for a served URL
+pub(crate) fn handle_stdout_line(app: &AppHandle, cwd: &str, line: String) {
+    crate::idle::touch(cwd);
+    crate::launch_stats::on_output(app, cwd);
+    let (event, rule_url) = classify_line(cwd, line, detect_log_level);
+    // Check for URL/port in the log line and emit URL event
+    let url = rule_url.or_else(|| detect_url(&event.message));
+    emit_log(app, event);
+
+    if let Some((url, port)) = url {
+        on_url_detected(app, cwd, url, port);
+    }
+}
+
+/// Emit a stderr line as an error log event (unless a log rule says otherwise)
+pub(crate) fn handle_stderr_line(app: &AppHandle, cwd: &str, line: String) {
+    crate::idle::touch(cwd);
+    crate::launch_stats::on_output(app, cwd);
+    // Binding a port below 1024 without root fails with EACCES - tell the UI it can elevate
+    if let Some(port) = detect_privileged_port_error(&line) {
+        let _ = app.emit(
+            "process-needs-elevation",
+            ElevationHintEvent {
+                path: cwd.to_string(),
+                port,
+                message: line.clone(),
+            },
+        );
+    }
+
+    let (event, rule_url) = classify_line(cwd, line, |_| "error".to_string());
+    emit_log(app, event);
+    // Some servers (Django's runserver among them) announce their URL on stderr
+    if let Some((url, port)) = rule_url {
+        on_url_detected(app, cwd, url, port);
+    }
 }
 
 /// Kill a process by PID
 #[tauri::command]
-pub async fn kill_process(pid: u32) -> Result<bool, String> {
-    let result = kill_process_internal(pid);
+pub async fn kill_process(app: AppHandle, pid: u32) -> Result<bool, String> {
+    kill_with_hooks(&app, pid)
+}
 
-    // Remove from registry if killed successfully
-    if result.as_ref().unwrap_or(&false) == &true {
-        if let Ok(mut registry) = PROCESS_REGISTRY.lock() {
-            registry.remove(&pid);
-        }
+/// Kill a process the user asked to stop, then run its project's post-stop hooks
+pub(crate) fn kill_with_hooks(app: &AppHandle, pid: u32) -> Result<bool, String> {
+    let cwd = managed_cwd(pid);
+    if let Some(cwd) = &cwd {
+        crate::watch_restart::unwatch(cwd);
+        crate::session::forget(app, cwd);
     }
+    let killed = kill_process_internal(pid)?;
+    if let (true, Some(cwd)) = (killed, cwd) {
+        run_post_stop_hooks(app, pid, cwd, Duration::from_millis(STOP_GRACE_MS));
+    }
+    Ok(killed)
+}
+
+/// Project path of a managed process
+fn managed_cwd(pid: u32) -> Option<String> {
+    PROCESS_MANAGER
+        .lock()
+        .ok()
+        .and_then(|m| m.values().find(|p| p.pid == pid).map(|p| p.cwd.clone()))
+}
 
-    result
+/// Run the project's post-stop hooks once the process has actually exited
+fn run_post_stop_hooks(app: &AppHandle, pid: u32, cwd: String, timeout: Duration) {
+    let app = app.clone();
+    std::thread::spawn(move || {
+        if wait_for_exit(pid, Instant::now() + timeout) {
+            crate::compose::on_stopped(&app, pid);
+            crate::hooks::run_post_stop(&app, &cwd);
+        }
+    });
 }
 
-fn kill_process_internal(pid: u32) -> Result<bool, String> {
+/// Mark a managed process as intentionally stopped (its exit won't count as a crash)
+fn mark_stop_requested(pid: u32) {
+    if let Ok(mut manager) = PROCESS_MANAGER.lock() {
+        for process in manager.values_mut().filter(|p| p.pid == pid) {
+            process.stop_requested = true;
+        }
+    }
+}
+
+pub(crate) fn kill_process_internal(pid: u32) -> Result<bool, String> {
+    mark_stop_requested(pid);
+
+    // Root-owned processes can't be signalled by us - go through the elevation prompt
+    if is_elevated(pid) {
+        let killed = crate::elevation::kill_elevated(pid)?;
+        if killed {
+            forget_untracked(pid);
+        }
+        return Ok(killed);
+    }
+
     #[cfg(unix)]
     {
         use std::process::Command;
@@ -156,6 +944,10 @@ fn kill_process_internal(pid: u32) -> Result<bool, String> {
     #[cfg(windows)]
     {
         use std::process::Command;
+        // The job holds the whole tree, including children that detached from the parent
+        if crate::jobs::terminate(pid) {
+            return Ok(true);
+        }
         let status = Command::new("taskkill")
             .args(["/PID", &pid.to_string(), "/T", "/F"])
             .status()
@@ -164,34 +956,37 @@ fn kill_process_internal(pid: u32) -> Result<bool, String> {
     }
 }
 
-/// Kill all tracked processes
+/// Stop all tracked processes in reverse dependency order
 #[tauri::command]
-pub async fn kill_all_processes() -> Result<u32, String> {
-    let pids: Vec<u32> = {
-        let registry = PROCESS_REGISTRY.lock().map_err(|e| e.to_string())?;
-        registry.iter().copied().collect()
-    };
+pub async fn kill_all_processes(app: AppHandle) -> Result<u32, String> {
+    stop_ordered(&app, running_processes(), false).await
+}
 
-    let mut killed = 0u32;
-    for pid in pids {
-        if kill_process_internal(pid).unwrap_or(false) {
-            killed += 1;
+/// Stop processes the user asked to stop in reverse dependency order, then run their
+/// post-stop hooks; `forget` also drops them from the saved session
+pub(crate) async fn stop_ordered(app: &AppHandle, running: Vec<(u32, String)>, forget: bool) -> Result<u32, String> {
+    for (_, cwd) in &running {
+        crate::watch_restart::unwatch(cwd);
+        if forget {
+            crate::session::forget(app, cwd);
         }
     }
-
-    // Clear the registry
-    if let Ok(mut registry) = PROCESS_REGISTRY.lock() {
-        registry.clear();
+    let handle = app.clone();
+    let processes = running.clone();
+    let stopped = tauri::async_runtime::spawn_blocking(move || crate::presets::stop_in_order(&handle, processes))
+        .await
+        .map_err(|e| e.to_string())?;
+    for (pid, cwd) in running {
+        run_post_stop_hooks(app, pid, cwd, Duration::from_millis(STOP_GRACE_MS));
     }
-
-    Ok(killed)
+    Ok(stopped)
 }
 
-/// Kill all tracked processes (internal non-async version for tray)
+/// Kill all tracked processes at once, without ordering or waiting (last resort on exit)
 pub fn kill_all_processes_internal() -> Result<u32, String> {
     let pids: Vec<u32> = {
-        let registry = PROCESS_REGISTRY.lock().map_err(|e| e.to_string())?;
-        registry.iter().copied().collect()
+        let manager = PROCESS_MANAGER.lock().map_err(|e| e.to_string())?;
+        manager.values().filter(|p| p.is_running()).map(|p| p.pid).collect()
     };
 
     let mut killed = 0u32;
@@ -201,47 +996,175 @@ pub fn kill_all_processes_internal() -> Result<u32, String> {
         }
     }
 
-    // Clear the registry
-    if let Ok(mut registry) = PROCESS_REGISTRY.lock() {
-        registry.clear();
+    Ok(killed)
+}
+
+/// Drop a stopped entry that has no child to reap (elevated processes tracked by PID only)
+fn forget_untracked(pid: u32) {
+    if let Ok(mut manager) = PROCESS_MANAGER.lock() {
+        manager.retain(|_, p| !(p.pid == pid && p.child.is_none()));
+    }
+}
+
+/// Mark a managed process as running with elevated privileges
+pub(crate) fn mark_elevated(pid: u32) {
+    if let Ok(mut manager) = PROCESS_MANAGER.lock() {
+        for process in manager.values_mut().filter(|p| p.pid == pid) {
+            process.elevated = true;
+        }
     }
+}
 
-    Ok(killed)
+/// Whether a PID was spawned with elevated privileges
+pub fn is_elevated(pid: u32) -> bool {
+    PROCESS_MANAGER
+        .lock()
+        .map(|m| m.values().any(|p| p.pid == pid && p.elevated))
+        .unwrap_or(false)
+}
+
+/// PIDs and project paths of every running managed process
+pub fn running_processes() -> Vec<(u32, String)> {
+    PROCESS_MANAGER
+        .lock()
+        .map(|m| {
+            m.values()
+                .filter(|p| p.is_running())
+                .map(|p| (p.pid, p.cwd.clone()))
+                .collect()
+        })
+        .unwrap_or_default()
 }
 
 /// Get count of tracked running processes (internal non-async version for tray)
 pub fn get_running_count_internal() -> u32 {
-    PROCESS_REGISTRY
+    PROCESS_MANAGER
         .lock()
-        .map(|r| r.len() as u32)
+        .map(|m| m.values().filter(|p| p.is_running()).count() as u32)
         .unwrap_or(0)
 }
 
 /// Get count of tracked running processes
 #[tauri::command]
 pub async fn get_running_count() -> Result<u32, String> {
-    let registry = PROCESS_REGISTRY.lock().map_err(|e| e.to_string())?;
-    Ok(registry.len() as u32)
+    Ok(get_running_count_internal())
+}
+
+/// Look up a managed process by its id
+pub fn find_process(id: &str) -> Option<ProcessRecord> {
+    PROCESS_MANAGER
+        .lock()
+        .ok()
+        .and_then(|m| m.get(id).map(|p| p.record()))
+}
+
+/// List every managed process, including exited ones that can be restarted
+#[tauri::command]
+pub async fn list_processes() -> Result<Vec<ProcessRecord>, String> {
+    Ok(list_processes_internal())
+}
+
+/// List every managed process, oldest first (internal non-async version for tray)
+pub fn list_processes_internal() -> Vec<ProcessRecord> {
+    let Ok(manager) = PROCESS_MANAGER.lock() else {
+        return Vec::new();
+    };
+    let mut records: Vec<ProcessRecord> = manager.values().map(|p| p.record()).collect();
+    records.sort_by_key(|r| r.started_at);
+    records
+}
+
+/// Restart a managed process (running or exited) with its original command, args and cwd.
+/// Returns the new PID.
+#[tauri::command]
+pub async fn restart_process(app: AppHandle, pid: u32) -> Result<u32, String> {
+    restart_process_internal(&app, pid).await
+}
+
+pub async fn restart_process_internal(app: &AppHandle, pid: u32) -> Result<u32, String> {
+    let (id, cwd, command, args, elevated, has_child, running, restart_count, pty) = {
+        let manager = PROCESS_MANAGER.lock().map_err(|e| e.to_string())?;
+        let process = manager
+            .values()
+            .find(|p| p.pid == pid)
+            .ok_or_else(|| format!("No managed process with PID {}", pid))?;
+        (
+            process.id.clone(),
+            process.cwd.clone(),
+            process.command.clone(),
+            process.args.clone(),
+            process.elevated,
+            process.child.is_some(),
+            !process.exited,
+            process.restart_count,
+            process.pty,
+        )
+    };
+
+    if running {
+        kill_process_internal(pid)?;
+        // Wait for the exit monitor to reap it so the port is free again
+        for _ in 0..50 {
+            let gone = PROCESS_MANAGER
+                .lock()
+                .map(|m| !m.contains_key(&id))
+                .unwrap_or(true);
+            if gone || !has_child {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    if let Ok(mut manager) = PROCESS_MANAGER.lock() {
+        manager.remove(&id);
+    }
+
+    // Elevated processes without a child were started through the elevation prompt
+    let new_pid = if elevated && !has_child {
+        crate::elevation::spawn_elevated_internal(app, &cwd, &command, &args)?
+    } else if pty {
+        crate::pty::spawn_pty_internal(app, &cwd, &command, &args)?
+    } else {
+        spawn_process_internal(app, &cwd, &command, &args)?
+    };
+
+    if let Ok(mut manager) = PROCESS_MANAGER.lock() {
+        for process in manager.values_mut().filter(|p| p.pid == new_pid) {
+            process.restart_count = restart_count + 1;
+            process.elevated = elevated;
+        }
+    }
+
+    println!("[process] Restarted {} (PID {} -> {})", cwd, pid, new_pid);
+    Ok(new_pid)
 }
 
 /// Check if a process is still running
 #[tauri::command]
 pub async fn get_process_status(pid: u32) -> Result<ProcessStatus, String> {
+    let elevated = is_elevated(pid);
+
     #[cfg(unix)]
     {
         use std::process::Command;
-        let output = Command::new("kill")
-            .args(["-0", &pid.to_string()])
-            .output();
+        // kill -0 fails with EPERM for root-owned processes, so ask ps instead
+        let output = if elevated {
+            Command::new("ps").args(["-p", &pid.to_string()]).output()
+        } else {
+            Command::new("kill").args(["-0", &pid.to_string()]).output()
+        };
 
         match output {
             Ok(o) => Ok(ProcessStatus {
                 running: o.status.success(),
                 pid: Some(pid),
+                elevated,
             }),
             Err(_) => Ok(ProcessStatus {
                 running: false,
                 pid: None,
+                elevated,
             }),
         }
     }
@@ -258,14 +1181,187 @@ pub async fn get_process_status(pid: u32) -> Result<ProcessStatus, String> {
         Ok(ProcessStatus {
             running: stdout.contains(&pid.to_string()),
             pid: Some(pid),
+            elevated,
         })
     }
 }
 
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct LogEvent {
+    pub path: String,
+    pub level: String,
+    pub message: String,
+    /// Keys of a JSON log line and named groups captured by the project's log rules
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub fields: HashMap<String, serde_json::Value>,
+    /// Groups repeated errors, from the matching rule's template
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    /// When the process logged the line (JSON logs only), unix millis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
+    /// Styled runs making up the message, for projects with ansi_mode "spans"
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub spans: Vec<crate::ansi::AnsiSpan>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BufferedLogLine {
+    pub line: u64,
+    pub timestamp: i64,
+    pub level: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub fields: HashMap<String, serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    /// When DevLaunch read the line, unix millis (`timestamp` may come from the line itself)
+    #[serde(skip)]
+    pub received_at: i64,
+}
+
+#[derive(Default)]
+struct LogBuffer {
+    lines: VecDeque<BufferedLogLine>,
+    next_line: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct LogPage {
+    pub lines: Vec<BufferedLogLine>,
+    /// Pass as since_line to fetch the next page
+    pub next_line: u64,
+    pub has_more: bool,
+    /// Oldest line number still retained
+    pub first_line: u64,
+}
+
+/// Append a log line to its project's ring buffer
+fn buffer_log(event: &LogEvent) {
+    let capacity = LOG_BUFFER_SIZE.load(Ordering::Relaxed);
+    let now = chrono::Utc::now().timestamp_millis();
+    if let Ok(mut buffers) = LOG_BUFFERS.lock() {
+        let buffer = buffers.entry(event.path.clone()).or_default();
+        buffer.lines.push_back(BufferedLogLine {
+            line: buffer.next_line,
+            timestamp: event.timestamp.unwrap_or(now),
+            level: event.level.clone(),
+            message: event.message.clone(),
+            fields: event.fields.clone(),
+            fingerprint: event.fingerprint.clone(),
+            received_at: now,
+        });
+        buffer.next_line += 1;
+        while buffer.lines.len() > capacity {
+            buffer.lines.pop_front();
+        }
+    }
+}
+
+/// Read retained log lines for a project, starting at since_line (inclusive)
+#[tauri::command]
+pub async fn get_process_logs(
+    project_path: String,
+    since_line: Option<u64>,
+    level_filter: Option<Vec<String>>,
+    limit: Option<usize>,
+) -> Result<LogPage, String> {
+    let buffers = LOG_BUFFERS.lock().map_err(|e| e.to_string())?;
+    let Some(buffer) = buffers.get(&project_path) else {
+        return Ok(LogPage {
+            lines: Vec::new(),
+            next_line: 0,
+            has_more: false,
+            first_line: 0,
+        });
+    };
+
+    let since = since_line.unwrap_or(0);
+    let limit = limit.unwrap_or(1000);
+    let mut matching = buffer
+        .lines
+        .iter()
+        .filter(|l| l.line >= since)
+        .filter(|l| {
+            level_filter
+                .as_ref()
+                .map(|levels| levels.iter().any(|lvl| lvl == &l.level))
+                .unwrap_or(true)
+        });
+
+    let lines: Vec<BufferedLogLine> = matching.by_ref().take(limit).cloned().collect();
+    let has_more = matching.next().is_some();
+    let next_line = lines.last().map(|l| l.line + 1).unwrap_or(buffer.next_line.max(since));
+
+    Ok(LogPage {
+        lines,
+        next_line,
+        has_more,
+        first_line: buffer.lines.front().map(|l| l.line).unwrap_or(buffer.next_line),
+    })
+}
+
+/// Copy of the retained log lines, per project path (all projects unless filtered)
+pub(crate) fn buffered_logs(projects: Option<&[String]>) -> Vec<(String, Vec<BufferedLogLine>)> {
+    let Ok(buffers) = LOG_BUFFERS.lock() else {
+        return Vec::new();
+    };
+    buffers
+        .iter()
+        .filter(|(path, _)| projects.map(|p| p.contains(path)).unwrap_or(true))
+        .map(|(path, buffer)| (path.clone(), buffer.lines.iter().cloned().collect()))
+        .collect()
+}
+
+/// The last `count` lines a project logged since `since` (unix millis, by when they were
+/// received rather than any timestamp the process printed), oldest first
+fn recent_output(path: &str, since: i64, count: usize) -> Vec<String> {
+    let Ok(buffers) = LOG_BUFFERS.lock() else {
+        return Vec::new();
+    };
+    let Some(buffer) = buffers.get(path) else {
+        return Vec::new();
+    };
+    let mut lines: Vec<String> = buffer
+        .lines
+        .iter()
+        .rev()
+        .take_while(|l| l.received_at >= since)
+        .take(count)
+        .map(|l| l.message.clone())
+        .collect();
+    lines.reverse();
+    lines
+}
+
+/// Drop the retained log history for a project
+#[tauri::command]
+pub async fn clear_process_logs(project_path: String) -> Result<(), String> {
+    let mut buffers = LOG_BUFFERS.lock().map_err(|e| e.to_string())?;
+    buffers.remove(&project_path);
+    Ok(())
+}
+
+/// Emit a log line to the frontend and to any backend subscribers
+pub(crate) fn emit_log(app: &AppHandle, event: LogEvent) {
+    buffer_log(&event);
+    crate::log_files::append(&event);
+    crate::problems::record(app, &event);
+    // Sending only fails when nobody is subscribed
+    let _ = LOG_BROADCAST.send(event.clone());
+    crate::log_window::forward(app, &event);
+    let _ = app.emit("process-log", event);
+}
+
+/// Subscribe to every log line emitted by spawned processes
+pub fn subscribe_logs() -> tokio::sync::broadcast::Receiver<LogEvent> {
+    LOG_BROADCAST.subscribe()
+}
+
 #[derive(Clone, Serialize)]
-struct LogEvent {
+struct ElevationHintEvent {
     path: String,
-    level: String,
+    port: Option<u16>,
     message: String,
 }
 
@@ -302,6 +1398,27 @@ fn detect_url(msg: &str) -> Option<(String, u16)> {
     None
 }
 
+/// Detect a permission error while binding a privileged port.
+/// Returns Some(port) when the error matches (port is None if it couldn't be parsed).
+fn detect_privileged_port_error(msg: &str) -> Option<Option<u16>> {
+    let lower = msg.to_lowercase();
+    if !(lower.contains("eacces") || lower.contains("permission denied")) {
+        return None;
+    }
+
+    let port = PRIVILEGED_PORT_REGEX
+        .captures(msg)
+        .and_then(|caps| caps.get(1))
+        .and_then(|m| m.as_str().parse::<u16>().ok());
+
+    match port {
+        Some(p) if p < 1024 => Some(Some(p)),
+        Some(_) => None,
+        None if lower.contains("listen") || lower.contains("bind") => Some(None),
+        None => None,
+    }
+}
+
 /// Simple heuristic to detect log level from message content
 fn detect_log_level(msg: &str) -> String {
     let lower = msg.to_lowercase();
@@ -368,9 +1485,90 @@ fn get_process_details(pid: u32) -> (String, String, f32, f32, String) {
     }
 }
 
+/// Scan listening TCP sockets with `ss` (iproute2), filling details from sysinfo.
+/// Returns None when ss isn't available so the caller can fall back to lsof.
+#[cfg(target_os = "linux")]
+fn scan_ports_ss() -> Option<Vec<PortInfo>> {
+    let output = Command::new("ss").args(["-H", "-l", "-t", "-n", "-p"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // (port, pid, process name from ss, address)
+    let mut listeners: Vec<(u16, u32, String, String)> = Vec::new();
+
+    for line in stdout.lines() {
+        // ss output: State Recv-Q Send-Q Local:Port Peer:Port [users:(...)]
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() < 5 {
+            continue;
+        }
+        let Some((addr, port_str)) = parts[3].rsplit_once(':') else {
+            continue;
+        };
+        let Ok(port) = port_str.parse::<u16>() else {
+            continue;
+        };
+
+        // "[::]" -> "::", "127.0.0.53%lo" -> "127.0.0.53"
+        let addr = addr.trim_start_matches('[').trim_end_matches(']');
+        let addr = addr.split('%').next().unwrap_or(addr);
+        let address = if addr == "*" { "0.0.0.0".to_string() } else { addr.to_string() };
+
+        // Sockets owned by other users show no process without root
+        let owners: Vec<(String, u32)> = SS_USERS_REGEX
+            .captures_iter(line)
+            .filter_map(|c| Some((c[1].to_string(), c[2].parse().ok()?)))
+            .collect();
+        let owners = if owners.is_empty() { vec![(String::new(), 0)] } else { owners };
+
+        for (name, pid) in owners {
+            if !listeners.iter().any(|(p, id, _, _)| *p == port && *id == pid) {
+                listeners.push((port, pid, name, address.clone()));
+            }
+        }
+    }
+
+    let pids: Vec<u32> = listeners.iter().map(|(_, pid, _, _)| *pid).filter(|pid| *pid != 0).collect();
+    let details = crate::system::port_process_details(&pids);
+
+    let mut ports: Vec<PortInfo> = listeners
+        .into_iter()
+        .map(|(port, pid, name, address)| {
+            let (process_name, command, uptime, cpu_percent, mem_percent, user) =
+                details.get(&pid).cloned().unwrap_or_else(|| {
+                    (name.clone(), String::new(), String::new(), 0.0, 0.0, String::new())
+                });
+            PortInfo {
+                port,
+                pid,
+                process_name: if name.is_empty() { process_name } else { name },
+                address,
+                command,
+                uptime,
+                cpu_percent,
+                mem_percent,
+                user,
+            }
+        })
+        .collect();
+
+    ports.sort_by_key(|p| p.port);
+    Some(ports)
+}
+
 /// Scan for listening ports on the system
 #[tauri::command]
 pub async fn scan_ports() -> Result<Vec<PortInfo>, String> {
+    // Linux: prefer ss, which is always present where lsof often isn't
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(ports) = scan_ports_ss() {
+            return Ok(ports);
+        }
+    }
+
     #[cfg(unix)]
     {
         use std::process::Command;
@@ -439,7 +1637,8 @@ pub async fn scan_ports() -> Result<Vec<PortInfo>, String> {
             .map_err(|e| format!("Failed to run netstat: {}", e))?;
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        let mut ports = Vec::new();
+        // (port, pid, address)
+        let mut listeners: Vec<(u16, u32, String)> = Vec::new();
 
         for line in stdout.lines() {
             if line.contains("LISTENING") {
@@ -449,22 +1648,13 @@ pub async fn scan_ports() -> Result<Vec<PortInfo>, String> {
                     let local_addr = parts[1];
                     let pid: u32 = parts.last().and_then(|s| s.parse().ok()).unwrap_or(0);
 
-                    if let Some(port_str) = local_addr.rsplit(':').next() {
+                    if let Some((addr, port_str)) = local_addr.rsplit_once(':') {
                         if let Ok(port) = port_str.parse::<u16>() {
-                            let address = local_addr.replace(&format!(":{}", port), "");
-
-                            if !ports.iter().any(|p: &PortInfo| p.port == port) {
-                                ports.push(PortInfo {
-                                    port,
-                                    pid,
-                                    process_name: format!("PID:{}", pid),
-                                    address,
-                                    command: String::new(),
-                                    uptime: String::new(),
-                                    cpu_percent: 0.0,
-                                    mem_percent: 0.0,
-                                    user: String::new(),
-                                });
+                            // "[::]" -> "::"
+                            let address = addr.trim_start_matches('[').trim_end_matches(']').to_string();
+
+                            if !listeners.iter().any(|(p, id, _)| *p == port && *id == pid) {
+                                listeners.push((port, pid, address));
                             }
                         }
                     }
@@ -472,7 +1662,547 @@ pub async fn scan_ports() -> Result<Vec<PortInfo>, String> {
             }
         }
 
+        // Command line, uptime, CPU, memory and user come from sysinfo
+        let pids: Vec<u32> = listeners.iter().map(|(_, pid, _)| *pid).collect();
+        let details = crate::system::port_process_details(&pids);
+
+        let mut ports: Vec<PortInfo> = listeners
+            .into_iter()
+            .map(|(port, pid, address)| {
+                let (process_name, command, uptime, cpu_percent, mem_percent, user) = details
+                    .get(&pid)
+                    .cloned()
+                    .unwrap_or_else(|| (format!("PID:{}", pid), String::new(), String::new(), 0.0, 0.0, String::new()));
+                PortInfo {
+                    port,
+                    pid,
+                    process_name,
+                    address,
+                    command,
+                    uptime,
+                    cpu_percent,
+                    mem_percent,
+                    user,
+                }
+            })
+            .collect();
+
         ports.sort_by_key(|p| p.port);
         Ok(ports)
     }
 }
+
+/// Default time a process gets to exit after SIGTERM before kill_port escalates to SIGKILL
+const KILL_PORT_GRACE_MS: u64 = 5000;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct KilledPortProcess {
+    pub pid: u32,
+    pub process_name: String,
+    pub terminated: bool,
+    /// True when the process ignored the graceful signal and had to be force-killed
+    pub forced: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct KillPortResult {
+    pub port: u16,
+    pub processes: Vec<KilledPortProcess>,
+}
+
+/// Kill whatever is listening on a port: SIGTERM, wait `grace_ms`, then SIGKILL if still alive.
+/// With `force` the graceful step is skipped.
+#[tauri::command]
+pub async fn kill_port(port: u16, force: Option<bool>, grace_ms: Option<u64>) -> Result<KillPortResult, String> {
+    let mut listeners: Vec<(u32, String)> = Vec::new();
+    for info in scan_ports().await?.into_iter().filter(|p| p.port == port && p.pid != 0) {
+        if !listeners.iter().any(|(pid, _)| *pid == info.pid) {
+            listeners.push((info.pid, info.process_name));
+        }
+    }
+
+    if listeners.is_empty() {
+        return Err(format!("Nothing is listening on port {}", port));
+    }
+
+    let force = force.unwrap_or(false);
+    let grace = Duration::from_millis(grace_ms.unwrap_or(KILL_PORT_GRACE_MS));
+    let mut processes = Vec::new();
+
+    for (pid, process_name) in listeners {
+        println!("[process] Killing PID {} ({}) on port {}", pid, process_name, port);
+        // If DevLaunch spawned it, the exit is intentional rather than a crash
+        mark_stop_requested(pid);
+
+        let (terminated, forced, error) = if is_elevated(pid) {
+            match crate::elevation::kill_elevated(pid) {
+                Ok(ok) => (ok, false, None),
+                Err(e) => (false, false, Some(e)),
+            }
+        } else {
+            let signal = if force { "KILL" } else { "TERM" };
+            match stop_with_grace_async(pid, signal, false, grace).await {
+                StopOutcome::Exited => (true, false, None),
+                StopOutcome::Killed => (true, true, None),
+                StopOutcome::Failed { forced, error } => (false, forced, Some(error)),
+            }
+        };
+
+        processes.push(KilledPortProcess {
+            pid,
+            process_name,
+            terminated,
+            forced,
+            error,
+        });
+    }
+
+    Ok(KillPortResult { port, processes })
+}
+
+/// Default wait before stop_process escalates to SIGKILL
+const STOP_GRACE_MS: u64 = 5000;
+
+/// Signals stop_process accepts (names without the SIG prefix)
+const STOP_SIGNALS: &[&str] = &["INT", "TERM", "HUP", "QUIT", "KILL"];
+
+#[derive(Debug, Serialize, Clone)]
+pub struct StopProcessResult {
+    pub pid: u32,
+    /// Signal sent first, e.g. "SIGINT"
+    pub signal: String,
+    /// "exited" (left after the signal), "killed" (escalated to SIGKILL), "not-running",
+    /// "elevated" (stopped through the elevation prompt) or "failed"
+    pub outcome: String,
+    pub stopped: bool,
+    pub elapsed_ms: u64,
+    pub error: Option<String>,
+}
+
+/// How long to wait for a process to go after SIGKILL
+const KILL_WAIT_MS: u64 = 1000;
+
+/// How a stop that escalates to SIGKILL ended
+pub(crate) enum StopOutcome {
+    /// Left on the first signal
+    Exited,
+    /// Needed SIGKILL
+    Killed,
+    Failed { forced: bool, error: String },
+}
+
+/// Send a signal by name (e.g. "TERM") to a PID, or to its whole process group when `group` is
+/// set (falling back to the single PID for processes we didn't spawn). Windows has no signals:
+/// KILL is taskkill /F (or the job object for a group), anything else asks the tree to close.
+fn send_signal(pid: u32, signal: &str, group: bool) -> Result<bool, String> {
+    #[cfg(unix)]
+    {
+        let flag = format!("-{}", signal);
+        if group {
+            let status = Command::new("kill")
+                .args([flag.as_str(), &format!("-{}", pid)])
+                .stderr(Stdio::null())
+                .status();
+            if matches!(&status, Ok(s) if s.success()) {
+                return Ok(true);
+            }
+        }
+        Command::new("kill")
+            .args([flag.as_str(), &pid.to_string()])
+            .status()
+            .map(|s| s.success())
+            .map_err(|e| format!("Failed to signal: {}", e))
+    }
+
+    #[cfg(windows)]
+    {
+        let force = signal == "KILL";
+        if group && force && crate::jobs::terminate(pid) {
+            return Ok(true);
+        }
+        let pid_str = pid.to_string();
+        let mut args = vec!["/PID", pid_str.as_str(), "/T"];
+        if force {
+            args.push("/F");
+        }
+        Command::new("taskkill")
+            .args(&args)
+            .status()
+            .map(|s| s.success())
+            .map_err(|e| format!("Failed to kill: {}", e))
+    }
+}
+
+/// Send `signal`, wait until `deadline` for the process to exit, then SIGKILL it. This is the
+/// one escalation path behind kill_port, stop_process and ordered stops; it blocks, so async
+/// callers use stop_with_grace_async.
+pub(crate) fn stop_with_grace(pid: u32, signal: &str, group: bool, deadline: Instant) -> StopOutcome {
+    if signal != "KILL" {
+        if let Err(error) = send_signal(pid, signal, group) {
+            return StopOutcome::Failed { forced: false, error };
+        }
+        if wait_for_exit(pid, deadline) {
+            return StopOutcome::Exited;
+        }
+        println!("[process] PID {} ignored SIG{}, sending SIGKILL", pid, signal);
+    }
+    if let Err(error) = send_signal(pid, "KILL", group) {
+        return StopOutcome::Failed { forced: true, error };
+    }
+    if wait_for_exit(pid, Instant::now() + Duration::from_millis(KILL_WAIT_MS)) {
+        StopOutcome::Killed
+    } else {
+        StopOutcome::Failed {
+            forced: true,
+            error: "Process survived SIGKILL".to_string(),
+        }
+    }
+}
+
+async fn stop_with_grace_async(pid: u32, signal: &str, group: bool, grace: Duration) -> StopOutcome {
+    let signal = signal.to_string();
+    let deadline = Instant::now() + grace;
+    tokio::task::spawn_blocking(move || stop_with_grace(pid, &signal, group, deadline))
+        .await
+        .unwrap_or_else(|e| StopOutcome::Failed {
+            forced: false,
+            error: e.to_string(),
+        })
+}
+
+/// Whether a process has exited. Managed children are checked through the exit monitor,
+/// since an unreaped child still shows up as running.
+pub(crate) fn has_exited(pid: u32) -> bool {
+    let managed = PROCESS_MANAGER.lock().ok().and_then(|m| {
+        m.values()
+            .find(|p| p.pid == pid && p.child.is_some())
+            .map(|p| p.exited)
+    });
+    managed.unwrap_or_else(|| !crate::system::pid_alive(pid))
+}
+
+/// Stop a process the user asked to stop, escalating to SIGKILL if it's still running at
+/// `deadline`. Elevated processes go through the elevation prompt instead.
+pub(crate) fn stop_until(pid: u32, deadline: Instant) -> bool {
+    if is_elevated(pid) {
+        return kill_process_internal(pid).unwrap_or(false);
+    }
+    mark_stop_requested(pid);
+    !matches!(stop_with_grace(pid, "TERM", true, deadline), StopOutcome::Failed { .. })
+}
+
+/// Poll until the process exits or `deadline` passes; true if it exited
+fn wait_for_exit(pid: u32, deadline: Instant) -> bool {
+    while Instant::now() < deadline {
+        if has_exited(pid) {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    has_exited(pid)
+}
+
+/// Stop a process with a chosen signal (default SIGTERM; SIGINT is what Ctrl+C sends), wait up to
+/// `grace_timeout_ms` for it to exit, then escalate to SIGKILL
+#[tauri::command]
+pub async fn stop_process(
+    app: AppHandle,
+    pid: u32,
+    signal: Option<String>,
+    grace_timeout_ms: Option<u64>,
+) -> Result<StopProcessResult, String> {
+    let name = signal
+        .as_deref()
+        .unwrap_or("TERM")
+        .trim()
+        .trim_start_matches("SIG")
+        .to_uppercase();
+    if !STOP_SIGNALS.contains(&name.as_str()) {
+        return Err(format!("Unsupported signal: {}", signal.unwrap_or_default()));
+    }
+    let grace = Duration::from_millis(grace_timeout_ms.unwrap_or(STOP_GRACE_MS));
+    let started = Instant::now();
+    let result = |outcome: &str, stopped: bool, error: Option<String>| StopProcessResult {
+        pid,
+        signal: format!("SIG{}", name),
+        outcome: outcome.to_string(),
+        stopped,
+        elapsed_ms: started.elapsed().as_millis() as u64,
+        error,
+    };
+
+    if has_exited(pid) {
+        return Ok(result("not-running", true, None));
+    }
+    mark_stop_requested(pid);
+    if let Some(cwd) = managed_cwd(pid) {
+        crate::watch_restart::unwatch(&cwd);
+        crate::session::forget(&app, &cwd);
+        // Covers the SIGKILL escalation too
+        run_post_stop_hooks(&app, pid, cwd, grace + Duration::from_millis(2000));
+    }
+
+    if is_elevated(pid) {
+        return Ok(match crate::elevation::kill_elevated(pid) {
+            Ok(true) => {
+                forget_untracked(pid);
+                result("elevated", true, None)
+            }
+            Ok(false) => result("failed", false, None),
+            Err(e) => result("failed", false, Some(e)),
+        });
+    }
+
+    println!("[process] Stopping PID {} with SIG{} (grace {}ms)", pid, name, grace.as_millis());
+    Ok(match stop_with_grace_async(pid, &name, true, grace).await {
+        StopOutcome::Exited => result("exited", true, None),
+        StopOutcome::Killed => result("killed", true, None),
+        StopOutcome::Failed { error, .. } => result("failed", false, Some(error)),
+    })
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ExpectedPort {
+    pub port: u16,
+    /// Where the port came from: "requested", "settings", "preset", "env" or "last-run"
+    pub source: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PortConflict {
+    pub port: u16,
+    pub pid: u32,
+    pub process_name: String,
+    pub command: String,
+    /// Project path if the listener is another project DevLaunch is running
+    pub managed_by: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PortConflictReport {
+    pub project_path: String,
+    pub checked: Vec<ExpectedPort>,
+    pub conflicts: Vec<PortConflict>,
+    /// Ports already served by a running instance of this same project
+    pub already_running: Vec<u16>,
+}
+
+/// Ports a project is configured or known to use, first source wins per port
+fn known_ports(app: &AppHandle, project_path: &str, requested: &[u16]) -> Vec<ExpectedPort> {
+    let mut ports: Vec<ExpectedPort> = Vec::new();
+    let mut add = |port: u16, source: &str| {
+        if port != 0 && !ports.iter().any(|p| p.port == port) {
+            ports.push(ExpectedPort {
+                port,
+                source: source.to_string(),
+            });
+        }
+    };
+
+    for &port in requested {
+        add(port, "requested");
+    }
+    if let Ok(config) = crate::config::load_config_internal(app) {
+        for &port in &config.project_settings(project_path).ports {
+            add(port, "settings");
+        }
+        let preset_ports = config
+            .presets
+            .iter()
+            .flat_map(|preset| preset.projects.iter())
+            .filter(|p| p.path == project_path)
+            .filter_map(|p| p.ready_when.as_ref().and_then(|r| r.port));
+        for port in preset_ports {
+            add(port, "preset");
+        }
+    }
+    if let Some(port) = crate::envfiles::env_port(std::path::Path::new(project_path)) {
+        add(port, "env");
+    }
+    if let Some(port) = last_port(project_path) {
+        add(port, "last-run");
+    }
+    ports
+}
+
+/// Before launching, find listeners already holding the ports a project needs
+#[tauri::command]
+pub async fn check_port_conflicts(
+    app: AppHandle,
+    project_path: String,
+    expected_ports: Option<Vec<u16>>,
+) -> Result<PortConflictReport, String> {
+    let checked = known_ports(&app, &project_path, &expected_ports.unwrap_or_default());
+    if checked.is_empty() {
+        return Ok(PortConflictReport {
+            project_path,
+            checked,
+            conflicts: Vec::new(),
+            already_running: Vec::new(),
+        });
+    }
+
+    let listeners = scan_ports().await?;
+    let managed = running_processes();
+    let pids: Vec<u32> = listeners.iter().map(|l| l.pid).filter(|&pid| pid != 0).collect();
+    let ancestors = crate::system::ancestor_pids(&pids);
+    let mut conflicts: Vec<PortConflict> = Vec::new();
+    let mut already_running = Vec::new();
+
+    for expected in &checked {
+        for info in listeners.iter().filter(|l| l.port == expected.port && l.pid != 0) {
+            if conflicts.iter().any(|c| c.port == info.port && c.pid == info.pid) {
+                continue;
+            }
+            // Dev servers usually run under npm/sh, so match the managed PID anywhere up the tree
+            let chain = ancestors.get(&info.pid).map(Vec::as_slice).unwrap_or_default();
+            let managed_by = managed
+                .iter()
+                .find(|(pid, _)| chain.contains(pid))
+                .map(|(_, cwd)| cwd.clone());
+
+            if managed_by.as_deref() == Some(project_path.as_str()) {
+                if !already_running.contains(&info.port) {
+                    already_running.push(info.port);
+                }
+                continue;
+            }
+
+            let message = match &managed_by {
+                Some(other) => format!(
+                    "Port {} is already used by {} (PID {}), started by DevLaunch for {}",
+                    info.port,
+                    info.process_name,
+                    info.pid,
+                    std::path::Path::new(other)
+                        .file_name()
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_else(|| other.clone())
+                ),
+                None => format!(
+                    "Port {} is already used by {} (PID {})",
+                    info.port, info.process_name, info.pid
+                ),
+            };
+            conflicts.push(PortConflict {
+                port: info.port,
+                pid: info.pid,
+                process_name: info.process_name.clone(),
+                command: info.command.clone(),
+                managed_by,
+                message,
+            });
+        }
+    }
+
+    Ok(PortConflictReport {
+        project_path,
+        checked,
+        conflicts,
+        already_running,
+    })
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessEnvironment {
+    pub pid: u32,
+    pub command: String,
+    pub env: std::collections::BTreeMap<String, String>,
+}
+
+/// Check that a process belongs to the current user (we never read other users' environments)
+#[cfg(unix)]
+fn is_same_user(pid: u32) -> bool {
+    let read = |cmd: &mut Command| {
+        cmd.output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+    let owner = read(Command::new("ps").args(["-o", "uid=", "-p", &pid.to_string()]));
+    let me = read(Command::new("id").arg("-u"));
+    matches!((owner, me), (Some(a), Some(b)) if !a.is_empty() && a == b)
+}
+
+/// Whether a token looks like an environment assignment (KEY=value)
+#[cfg(target_os = "macos")]
+fn is_env_assignment(token: &str) -> bool {
+    match token.split_once('=') {
+        Some((key, _)) => {
+            !key.is_empty()
+                && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                && !key.chars().next().map(|c| c.is_ascii_digit()).unwrap_or(true)
+        }
+        None => false,
+    }
+}
+
+/// Read the environment a process was started with (same-user processes only)
+#[tauri::command]
+pub async fn get_process_env(pid: u32) -> Result<ProcessEnvironment, String> {
+    #[cfg(unix)]
+    {
+        if !is_same_user(pid) {
+            return Err(format!(
+                "Process {} is not running as the current user (or has exited)",
+                pid
+            ));
+        }
+        let (command, _, _, _, _) = get_process_details(pid);
+        let mut env = std::collections::BTreeMap::new();
+
+        #[cfg(target_os = "linux")]
+        {
+            let raw = std::fs::read(format!("/proc/{}/environ", pid))
+                .map_err(|e| format!("Failed to read environment: {}", e))?;
+            for entry in String::from_utf8_lossy(&raw).split('\0') {
+                if let Some((key, value)) = entry.split_once('=') {
+                    if !key.is_empty() {
+                        env.insert(key.to_string(), value.to_string());
+                    }
+                }
+            }
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            // `ps eww` prints the command line followed by the environment, space separated.
+            // Values containing spaces are ambiguous, so continuation tokens are re-joined onto
+            // the previous assignment once the trailing environment block has started.
+            let output = Command::new("ps")
+                .args(["eww", "-o", "command=", "-p", &pid.to_string()])
+                .output()
+                .map_err(|e| format!("Failed to run ps: {}", e))?;
+            let line = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            let rest = line.strip_prefix(command.as_str()).unwrap_or(&line);
+
+            let mut current: Option<(String, String)> = None;
+            for token in rest.split(' ') {
+                if is_env_assignment(token) {
+                    if let Some((k, v)) = current.take() {
+                        env.insert(k, v);
+                    }
+                    if let Some((k, v)) = token.split_once('=') {
+                        current = Some((k.to_string(), v.to_string()));
+                    }
+                } else if let Some((_, v)) = current.as_mut() {
+                    v.push(' ');
+                    v.push_str(token);
+                }
+            }
+            if let Some((k, v)) = current {
+                env.insert(k, v);
+            }
+        }
+
+        Ok(ProcessEnvironment { pid, command, env })
+    }
+
+    #[cfg(windows)]
+    {
+        let _ = pid;
+        Err("Reading process environments is not supported on Windows".to_string())
+    }
+}
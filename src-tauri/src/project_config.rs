@@ -0,0 +1,239 @@
+use crate::config::{self, ProjectSettings};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+use tauri::{AppHandle, Emitter};
+
+/// Project-local settings committed alongside the code
+pub const PROJECT_FILE: &str = ".devlaunch.json";
+/// Editors save in several steps; collapse them into one reload
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(300);
+
+lazy_static::lazy_static! {
+    static ref WATCHER: Mutex<Option<RecommendedWatcher>> = Mutex::new(None);
+    static ref WATCHED: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+    static ref LAST_RELOAD: Mutex<HashMap<String, Instant>> = Mutex::new(HashMap::new());
+    // Parsed .devlaunch.json per project path, reused while the file's mtime and size hold
+    static ref CACHE: Mutex<HashMap<String, CachedFile>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Clone)]
+struct CachedFile {
+    modified: Option<SystemTime>,
+    len: u64,
+    /// SHA-256 of the contents, matched against `Preferences::trusted_project_files`
+    hash: String,
+    parsed: Result<ProjectSettings, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectConfigInfo {
+    pub path: String,
+    pub file_path: String,
+    pub exists: bool,
+    /// Settings from .devlaunch.json alone
+    pub file: Option<ProjectSettings>,
+    /// App config with .devlaunch.json applied on top (what launches use)
+    pub effective: ProjectSettings,
+    /// Set when the file exists but can't be parsed
+    pub error: Option<String>,
+    /// Whether the user trusted the file's current contents; until then its env, hooks and
+    /// version manager are ignored
+    pub trusted: bool,
+    /// SHA-256 of the file, passed back to trust_project_config
+    pub hash: Option<String>,
+}
+
+/// A project's .devlaunch.json with its hash, from the cache when the file is unchanged
+fn load_cached(path: &str) -> Result<Option<CachedFile>, String> {
+    let file = Path::new(path).join(PROJECT_FILE);
+    let meta = match std::fs::metadata(&file) {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            if let Ok(mut cache) = CACHE.lock() {
+                cache.remove(path);
+            }
+            return Ok(None);
+        }
+        Err(e) => return Err(format!("Failed to read {}: {}", file.display(), e)),
+    };
+    let modified = meta.modified().ok();
+    if let Some(cached) = CACHE.lock().ok().and_then(|cache| cache.get(path).cloned()) {
+        if modified.is_some() && cached.modified == modified && cached.len == meta.len() {
+            return Ok(Some(cached));
+        }
+    }
+
+    let content = match std::fs::read(&file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Failed to read {}: {}", file.display(), e)),
+    };
+    let cached = CachedFile {
+        modified,
+        len: meta.len(),
+        hash: hex::encode(Sha256::digest(&content)),
+        parsed: serde_json::from_slice(&content).map_err(|e| format!("Invalid {}: {}", PROJECT_FILE, e)),
+    };
+    if let Ok(mut cache) = CACHE.lock() {
+        cache.insert(path.to_string(), cached.clone());
+    }
+    Ok(Some(cached))
+}
+
+/// Parse a project's .devlaunch.json, if it has one. Checked on every call (re-read only
+/// when it changed) so edits apply to the next launch without a reload.
+pub fn read_project_file(path: &str) -> Result<Option<ProjectSettings>, String> {
+    load_cached(path)?.map(|cached| cached.parsed).transpose()
+}
+
+/// Like read_project_file, but without the settings that run commands or reach the
+/// environment (env, hooks, version manager) unless `trusted` pins the file's current contents.
+/// A freshly cloned repo can't inject them until the user has looked at the file.
+pub fn read_trusted_project_file(
+    path: &str,
+    trusted: &HashMap<String, String>,
+) -> Result<Option<ProjectSettings>, String> {
+    let Some(cached) = load_cached(path)? else {
+        return Ok(None);
+    };
+    let mut settings = cached.parsed?;
+    if trusted.get(path) != Some(&cached.hash) {
+        settings.env.clear();
+        settings.hooks = None;
+        settings.version_manager = None;
+    }
+    Ok(Some(settings))
+}
+
+fn project_config_info(app: &AppHandle, path: &str) -> ProjectConfigInfo {
+    let (cached, error) = match load_cached(path) {
+        Ok(cached) => (cached, None),
+        Err(e) => (None, Some(e)),
+    };
+    let (file, error) = match cached.as_ref().map(|c| c.parsed.clone()) {
+        Some(Ok(file)) => (Some(file), error),
+        Some(Err(e)) => (None, Some(e)),
+        None => (None, error),
+    };
+    let config = config::load_config_internal(app).ok();
+    let hash = cached.map(|c| c.hash);
+    let trusted = hash.as_ref().is_some_and(|hash| {
+        config
+            .as_ref()
+            .and_then(|c| c.preferences.trusted_project_files.get(path))
+            .is_some_and(|t| t == hash)
+    });
+    let file_path = Path::new(path).join(PROJECT_FILE);
+    ProjectConfigInfo {
+        path: path.to_string(),
+        exists: file_path.exists(),
+        file_path: file_path.to_string_lossy().to_string(),
+        file,
+        effective: config.map(|c| c.project_settings(path)).unwrap_or_default(),
+        error,
+        trusted,
+        hash,
+    }
+}
+
+fn handle_change(app: &AppHandle, event: notify::Event) {
+    for changed in event.paths {
+        if changed.file_name().and_then(|n| n.to_str()) != Some(PROJECT_FILE) {
+            continue;
+        }
+        let Some(dir) = changed.parent().map(|d| d.to_string_lossy().to_string()) else {
+            continue;
+        };
+        // mtime granularity can hide a quick second save
+        if let Ok(mut cache) = CACHE.lock() {
+            cache.remove(&dir);
+        }
+        if let Ok(mut last) = LAST_RELOAD.lock() {
+            if last.get(&dir).is_some_and(|t| t.elapsed() < RELOAD_DEBOUNCE) {
+                continue;
+            }
+            last.insert(dir.clone(), Instant::now());
+        }
+        // Let the write finish before reading
+        let app = app.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(RELOAD_DEBOUNCE);
+            println!("[project_config] Reloaded {}/{}", dir, PROJECT_FILE);
+            let _ = app.emit("project-config-changed", project_config_info(&app, &dir));
+        });
+    }
+}
+
+/// Watch a project directory for .devlaunch.json changes, emitting `project-config-changed`
+fn watch(app: &AppHandle, path: &str) -> Result<(), String> {
+    let mut watched = WATCHED.lock().map_err(|e| e.to_string())?;
+    if watched.contains(path) {
+        return Ok(());
+    }
+    let mut watcher = WATCHER.lock().map_err(|e| e.to_string())?;
+    if watcher.is_none() {
+        let app = app.clone();
+        let created = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+            if let Ok(event) = result {
+                handle_change(&app, event);
+            }
+        })
+        .map_err(|e| format!("Failed to start file watcher: {}", e))?;
+        *watcher = Some(created);
+    }
+    if let Some(watcher) = watcher.as_mut() {
+        // The directory, not the file, so a .devlaunch.json created later is picked up
+        watcher
+            .watch(Path::new(path), RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Failed to watch {}: {}", path, e))?;
+    }
+    watched.insert(path.to_string());
+    Ok(())
+}
+
+/// A project's .devlaunch.json and the effective settings. Later edits to the file are
+/// reported as `project-config-changed` events.
+#[tauri::command]
+pub async fn load_project_config(app: AppHandle, path: String) -> Result<ProjectConfigInfo, String> {
+    if let Err(e) = watch(&app, &path) {
+        println!("[project_config] {}", e);
+    }
+    Ok(project_config_info(&app, &path))
+}
+
+/// Trust a project's .devlaunch.json as it is now, so its env, hooks and version manager
+/// apply. `hash` is the one shown to the user; if the file changed since, trusting fails.
+/// Pass `trust: false` to revoke.
+#[tauri::command]
+pub async fn trust_project_config(
+    app: AppHandle,
+    path: String,
+    hash: String,
+    trust: bool,
+) -> Result<ProjectConfigInfo, String> {
+    if trust {
+        let current = load_cached(&path)?.map(|c| c.hash);
+        if current.as_deref() != Some(hash.as_str()) {
+            return Err(format!("{} changed since it was reviewed; check it again", PROJECT_FILE));
+        }
+    }
+    config::update_config(&app, |config| {
+        let trusted = &mut config.preferences.trusted_project_files;
+        if trust {
+            trusted.insert(path.clone(), hash);
+        } else {
+            trusted.remove(&path);
+        }
+        Ok(())
+    })?;
+    println!("[project_config] {} {}/{}", if trust { "Trusted" } else { "Untrusted" }, path, PROJECT_FILE);
+    let info = project_config_info(&app, &path);
+    let _ = app.emit("project-config-changed", info.clone());
+    Ok(info)
+}
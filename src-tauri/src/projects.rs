@@ -0,0 +1,607 @@
+use crate::scripts::{self, DiscoveredScript};
+use regex::Regex;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+/// Directories never worth descending into (matches the frontend scanner)
+const EXCLUDED_DIRS: &[&str] = &[
+    "node_modules",
+    ".git",
+    ".next",
+    "dist",
+    "build",
+    "coverage",
+    "__pycache__",
+    ".cache",
+    ".turbo",
+    "out",
+    "target",
+    ".venv",
+    "venv",
+    "vendor",
+];
+
+/// How deep below each watched directory to look for projects
+pub(crate) const DEFAULT_SCAN_DEPTH: usize = 2;
+
+/// Node scripts preferred as the default, in order
+const NODE_DEFAULT_SCRIPTS: &[&str] = &["dev", "start", "serve", "watch", "develop"];
+
+const COMPOSE_FILES: &[&str] = &["docker-compose.yml", "docker-compose.yaml", "compose.yaml", "compose.yml"];
+
+lazy_static::lazy_static! {
+    // "--port 4000", "--port=4000", "-p 4000", "PORT=4000" in a script body
+    static ref PORT_FLAG_REGEX: Regex = Regex::new(r"(?:--port[= ]|-p |\bPORT=)(\d{2,5})\b").unwrap();
+    // Host side of a compose port mapping: "3000:3000", "127.0.0.1:8080:80", "- 5432"
+    static ref COMPOSE_PORT_REGEX: Regex =
+        Regex::new(r#"^\s*-\s*["']?(?:\d+\.\d+\.\d+\.\d+:)?(\d{2,5})(?::\d{2,5})?(?:/\w+)?["']?\s*$"#).unwrap();
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectInfo {
+    pub path: String,
+    pub name: String,
+    /// "node", "python", "go", "rust" or "docker"
+    pub project_type: String,
+    /// More specific toolchain when recognized: "vite", "next", "poetry", "django", ...
+    pub framework: Option<String>,
+    /// Node package manager or Python tool ("npm", "pnpm", "yarn", "poetry", "pip")
+    pub package_manager: Option<String>,
+    pub scripts: Vec<DiscoveredScript>,
+    /// Script to launch by default (a name in `scripts`)
+    pub default_script: Option<String>,
+    /// Port the dev server is expected to listen on, if it can be inferred
+    pub default_port: Option<u16>,
+}
+
+fn dir_name(dir: &Path) -> String {
+    dir.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| dir.to_string_lossy().to_string())
+}
+
+/// Display name for a project path: its folder name
+pub(crate) fn project_name(path: &str) -> String {
+    dir_name(Path::new(path))
+}
+
+/// `key = "value"` from a `[section]` of a TOML file; a line parser, enough for names
+fn toml_value(content: &str, section: &str, key: &str) -> Option<String> {
+    let header = format!("[{}]", section);
+    let mut in_section = false;
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_section = line == header;
+            continue;
+        }
+        if !in_section {
+            continue;
+        }
+        if let Some((k, v)) = line.split_once('=') {
+            if k.trim() == key {
+                let v = v.trim().trim_matches('"').trim_matches('\'');
+                return Some(v.to_string()).filter(|v| !v.is_empty());
+            }
+        }
+    }
+    None
+}
+
+fn port_from_script(body: &str) -> Option<u16> {
+    PORT_FLAG_REGEX
+        .captures(body)
+        .and_then(|c| c.get(1))
+        .and_then(|m| m.as_str().parse().ok())
+}
+
+/// Conventional dev server port for a framework
+fn framework_port(framework: &str) -> Option<u16> {
+    match framework {
+        "vite" | "sveltekit" => Some(5173),
+        "next" | "nuxt" | "remix" | "react-scripts" | "express" => Some(3000),
+        "astro" => Some(4321),
+        "angular" => Some(4200),
+        "django" | "fastapi" => Some(8000),
+        "flask" => Some(5000),
+        _ => None,
+    }
+}
+
+fn detect_node(dir: &Path) -> Option<ProjectInfo> {
+    let content = std::fs::read_to_string(dir.join("package.json")).ok()?;
+    let manifest: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let scripts = scripts::discover_scripts_internal(dir);
+    // A package.json without scripts is usually a library or tooling config
+    if !scripts.iter().any(|s| s.source == "package.json") {
+        return None;
+    }
+
+    let has_dep = |name: &str| {
+        ["dependencies", "devDependencies"]
+            .iter()
+            .any(|field| manifest.get(field).and_then(|d| d.get(name)).is_some())
+    };
+    // Meta-frameworks first: Nuxt, SvelteKit, Remix and Astro all depend on Vite
+    let framework = [
+        ("next", "next"),
+        ("nuxt", "nuxt"),
+        ("@sveltejs/kit", "sveltekit"),
+        ("@remix-run/dev", "remix"),
+        ("astro", "astro"),
+        ("@angular/cli", "angular"),
+        ("react-scripts", "react-scripts"),
+        ("vite", "vite"),
+        ("express", "express"),
+    ]
+    .iter()
+    .find(|(dep, _)| has_dep(dep))
+    .map(|(_, name)| name.to_string());
+
+    let default_script = NODE_DEFAULT_SCRIPTS
+        .iter()
+        .find_map(|name| scripts.iter().find(|s| s.source == "package.json" && s.name == *name))
+        .or_else(|| scripts.iter().find(|s| s.source == "package.json"))
+        .map(|s| s.name.clone());
+    let default_port = default_script
+        .as_ref()
+        .and_then(|name| scripts.iter().find(|s| &s.name == name))
+        .and_then(|s| s.description.as_deref())
+        .and_then(port_from_script)
+        .or_else(|| framework.as_deref().and_then(framework_port));
+
+    Some(ProjectInfo {
+        path: dir.to_string_lossy().to_string(),
+        name: manifest
+            .get("name")
+            .and_then(|n| n.as_str())
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| dir_name(dir)),
+        project_type: "node".to_string(),
+        framework,
+        package_manager: Some(scripts::node_package_manager(dir, &manifest).to_string()),
+        scripts,
+        default_script,
+        default_port,
+    })
+}
+
+fn detect_python(dir: &Path) -> Option<ProjectInfo> {
+    let pyproject = std::fs::read_to_string(dir.join("pyproject.toml")).ok();
+    let requirements = std::fs::read_to_string(dir.join("requirements.txt")).ok();
+    if pyproject.is_none() && requirements.is_none() {
+        return None;
+    }
+    let deps = format!(
+        "{}\n{}",
+        pyproject.as_deref().unwrap_or(""),
+        requirements.as_deref().unwrap_or("")
+    )
+    .to_lowercase();
+
+    let poetry = pyproject.as_deref().is_some_and(|p| p.contains("[tool.poetry]"));
+    let (runner, prefix): (&str, &[&str]) = if poetry { ("poetry", &["run", "python"]) } else { ("python", &[]) };
+    let source = if pyproject.is_some() { "pyproject.toml" } else { "requirements.txt" };
+    let python_script = |name: &str, args: &[&str]| {
+        let full: Vec<&str> = prefix.iter().chain(args).copied().collect();
+        scripts::script(name, source, runner, &full, None)
+    };
+
+    let mut scripts = Vec::new();
+    let framework = if dir.join("manage.py").exists() {
+        scripts.push(python_script("runserver", &["manage.py", "runserver"]));
+        Some("django")
+    } else {
+        let entry = ["main.py", "app.py", "src/main.py"].into_iter().find(|f| dir.join(f).exists())?;
+        scripts.push(python_script(entry, &[entry]));
+        if deps.contains("fastapi") {
+            Some("fastapi")
+        } else if deps.contains("flask") {
+            Some("flask")
+        } else {
+            None
+        }
+    };
+
+    let name = pyproject
+        .as_deref()
+        .and_then(|p| toml_value(p, "project", "name").or_else(|| toml_value(p, "tool.poetry", "name")))
+        .unwrap_or_else(|| dir_name(dir));
+
+    Some(ProjectInfo {
+        path: dir.to_string_lossy().to_string(),
+        name,
+        project_type: "python".to_string(),
+        framework: framework.or(poetry.then_some("poetry")).map(|f| f.to_string()),
+        package_manager: Some(if poetry { "poetry" } else { "pip" }.to_string()),
+        default_script: scripts.first().map(|s| s.name.clone()),
+        default_port: framework.and_then(framework_port),
+        scripts,
+    })
+}
+
+fn detect_go(dir: &Path) -> Option<ProjectInfo> {
+    let content = std::fs::read_to_string(dir.join("go.mod")).ok()?;
+    let name = content
+        .lines()
+        .find_map(|l| l.trim().strip_prefix("module "))
+        .map(|m| m.trim().rsplit('/').next().unwrap_or(m).to_string())
+        .unwrap_or_else(|| dir_name(dir));
+    let mut scripts = vec![scripts::script("run", "go.mod", "go", &["run", "."], None)];
+    scripts.extend(scripts::discover_scripts_internal(dir));
+
+    Some(ProjectInfo {
+        path: dir.to_string_lossy().to_string(),
+        name,
+        project_type: "go".to_string(),
+        framework: None,
+        package_manager: None,
+        scripts,
+        default_script: Some("run".to_string()),
+        default_port: None,
+    })
+}
+
+/// Whether a Cargo.toml line declares `dep = ...`
+fn cargo_has_dep(content: &str, dep: &str) -> bool {
+    content
+        .lines()
+        .filter_map(|l| l.split_once('='))
+        .any(|(key, _)| key.trim() == dep)
+}
+
+fn detect_rust(dir: &Path) -> Option<ProjectInfo> {
+    let content = std::fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    let scripts = scripts::discover_scripts_internal(dir);
+    let default_script = scripts
+        .iter()
+        .find(|s| s.source == "Cargo.toml" && s.name == "run")
+        .or_else(|| scripts.iter().find(|s| s.source == "Cargo.toml"))
+        .map(|s| s.name.clone());
+
+    Some(ProjectInfo {
+        path: dir.to_string_lossy().to_string(),
+        name: toml_value(&content, "package", "name").unwrap_or_else(|| dir_name(dir)),
+        project_type: "rust".to_string(),
+        framework: [("tauri", "tauri"), ("axum", "axum"), ("actix-web", "actix"), ("rocket", "rocket")]
+            .iter()
+            .find(|(dep, _)| cargo_has_dep(&content, dep))
+            .map(|(_, f)| f.to_string()),
+        package_manager: None,
+        scripts,
+        default_script,
+        default_port: None,
+    })
+}
+
+fn detect_compose(dir: &Path) -> Option<ProjectInfo> {
+    let file = COMPOSE_FILES.iter().find(|f| dir.join(f).exists())?;
+    let content = std::fs::read_to_string(dir.join(file)).unwrap_or_default();
+    // First published port under a `ports:` key
+    let mut in_ports = false;
+    let mut default_port = None;
+    for line in content.lines() {
+        if line.trim() == "ports:" {
+            in_ports = true;
+            continue;
+        }
+        if in_ports {
+            if let Some(port) = COMPOSE_PORT_REGEX.captures(line).and_then(|c| c[1].parse().ok()) {
+                default_port = Some(port);
+                break;
+            }
+            if !line.trim_start().starts_with('-') && !line.trim().is_empty() {
+                in_ports = false;
+            }
+        }
+    }
+
+    let mut scripts = vec![scripts::script("up", file, "docker", &["compose", "-f", file, "up"], None)];
+    scripts.extend(scripts::discover_scripts_internal(dir));
+
+    Some(ProjectInfo {
+        path: dir.to_string_lossy().to_string(),
+        name: dir_name(dir),
+        project_type: "docker".to_string(),
+        framework: Some("compose".to_string()),
+        package_manager: None,
+        scripts,
+        default_script: Some("up".to_string()),
+        default_port,
+    })
+}
+
+/// Detect what kind of project lives in `dir`, if any
+pub fn detect_project(dir: &Path) -> Option<ProjectInfo> {
+    let mut info = detect_node(dir)
+        .or_else(|| detect_python(dir))
+        .or_else(|| detect_go(dir))
+        .or_else(|| detect_rust(dir))
+        .or_else(|| detect_compose(dir))?;
+    // An explicit PORT in .env beats conventions
+    if let Some(port) = crate::envfiles::env_port(dir) {
+        info.default_port = Some(port);
+    }
+    // So does the team's choice in .devlaunch.json
+    if let Ok(Some(settings)) = crate::project_config::read_project_file(&info.path) {
+        if let Some(script) = settings.script.filter(|s| info.scripts.iter().any(|d| &d.name == s)) {
+            info.default_script = Some(script);
+        }
+        if let Some(port) = settings.ports.first() {
+            info.default_port = Some(*port);
+        }
+    }
+    Some(info)
+}
+
+fn walk(dir: &Path, depth: usize, max_depth: usize, found: &mut Vec<ProjectInfo>) {
+    if depth > max_depth {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut children: Vec<PathBuf> = entries
+        .flatten()
+        // file_type() doesn't follow symlinks, so linked dirs are skipped
+        .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            !name.starts_with('.') && !EXCLUDED_DIRS.contains(&name.as_str())
+        })
+        .map(|e| e.path())
+        .collect();
+    children.sort();
+
+    for child in children {
+        if let Some(project) = detect_project(&child) {
+            found.push(project);
+        }
+        walk(&child, depth + 1, max_depth, found);
+    }
+}
+
+pub fn scan_projects_internal(dirs: &[String], max_depth: usize) -> Vec<ProjectInfo> {
+    let mut found = Vec::new();
+    for dir in dirs {
+        let root = Path::new(dir);
+        if !root.is_dir() {
+            println!("[projects] Skipping missing directory {}", dir);
+            continue;
+        }
+        walk(root, 0, max_depth, &mut found);
+    }
+    println!("[projects] Found {} projects in {} directories", found.len(), dirs.len());
+    found
+}
+
+/// Find projects under `dir` (or every watched directory when omitted), with their type,
+/// runnable scripts, default script and expected port
+#[tauri::command]
+pub async fn scan_projects(
+    app: AppHandle,
+    dir: Option<String>,
+    max_depth: Option<usize>,
+) -> Result<Vec<ProjectInfo>, String> {
+    let dirs = match dir {
+        Some(dir) => vec![dir],
+        None => crate::config::load_config_internal(&app)?.watched_dirs,
+    };
+    let max_depth = max_depth.unwrap_or(DEFAULT_SCAN_DEPTH);
+    tokio::task::spawn_blocking(move || scan_projects_internal(&dirs, max_depth))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// How deep below a monorepo root workspace globs are expanded
+const WORKSPACE_DEPTH: usize = 4;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspacePackage {
+    /// Path relative to the workspace root, e.g. "apps/web"
+    pub relative_path: String,
+    #[serde(flatten)]
+    pub project: ProjectInfo,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceInfo {
+    pub root: String,
+    /// Workspace tooling found at the root: "pnpm", "yarn", "npm", "nx", "turbo"
+    pub tools: Vec<String>,
+    pub package_manager: String,
+    pub packages: Vec<WorkspacePackage>,
+}
+
+/// Items of the `packages:` list in pnpm-workspace.yaml
+fn pnpm_workspace_globs(content: &str) -> Vec<String> {
+    let mut globs = Vec::new();
+    let mut in_packages = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with(' ') && !line.starts_with('-') {
+            in_packages = trimmed == "packages:";
+            continue;
+        }
+        if in_packages {
+            if let Some(item) = trimmed.strip_prefix('-') {
+                globs.push(item.trim().trim_matches('"').trim_matches('\'').to_string());
+            }
+        }
+    }
+    globs
+}
+
+/// `workspaces` from package.json: either an array or `{ "packages": [...] }` (yarn)
+fn package_json_workspace_globs(manifest: &serde_json::Value) -> Vec<String> {
+    let workspaces = manifest.get("workspaces");
+    workspaces
+        .and_then(|w| w.as_array())
+        .or_else(|| workspaces.and_then(|w| w.get("packages")).and_then(|p| p.as_array()))
+        .map(|items| items.iter().filter_map(|i| i.as_str()).map(|s| s.to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// Relative paths (forward slashes) of every directory below `root`, up to `WORKSPACE_DEPTH`
+fn candidate_dirs(root: &Path, relative: &str, depth: usize, found: &mut Vec<String>) {
+    if depth >= WORKSPACE_DEPTH {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(root.join(relative)) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') || EXCLUDED_DIRS.contains(&name.as_str()) {
+            continue;
+        }
+        let path = if relative.is_empty() { name } else { format!("{}/{}", relative, name) };
+        candidate_dirs(root, &path, depth + 1, found);
+        found.push(path);
+    }
+}
+
+/// Runner for an Nx target with the repo's package manager
+fn nx_script(pm: &str, project: &str, target: &str) -> DiscoveredScript {
+    let target_ref = format!("{}:{}", project, target);
+    let (command, args): (&str, Vec<&str>) = match pm {
+        "pnpm" => ("pnpm", vec!["exec", "nx", "run", &target_ref]),
+        "yarn" => ("yarn", vec!["nx", "run", &target_ref]),
+        _ => ("npx", vec!["nx", "run", &target_ref]),
+    };
+    scripts::script(target, "project.json", command, &args, None)
+}
+
+/// Nx project.json targets, or None when the directory has no project.json
+fn nx_project(dir: &Path, pm: &str) -> Option<ProjectInfo> {
+    let content = std::fs::read_to_string(dir.join("project.json")).ok()?;
+    let manifest: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let name = manifest
+        .get("name")
+        .and_then(|n| n.as_str())
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| dir_name(dir));
+    let scripts: Vec<DiscoveredScript> = manifest
+        .get("targets")
+        .and_then(|t| t.as_object())
+        .map(|targets| targets.keys().map(|target| nx_script(pm, &name, target)).collect())
+        .unwrap_or_default();
+    let default_script = ["serve", "dev", "start"]
+        .iter()
+        .find(|t| scripts.iter().any(|s| s.name == **t))
+        .map(|t| t.to_string())
+        .or_else(|| scripts.first().map(|s| s.name.clone()));
+
+    Some(ProjectInfo {
+        path: dir.to_string_lossy().to_string(),
+        name,
+        project_type: "node".to_string(),
+        framework: Some("nx".to_string()),
+        package_manager: Some(pm.to_string()),
+        scripts,
+        default_script,
+        default_port: crate::envfiles::env_port(dir),
+    })
+}
+
+pub fn detect_workspaces_internal(root: &Path) -> Option<WorkspaceInfo> {
+    let manifest = std::fs::read_to_string(root.join("package.json"))
+        .ok()
+        .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok())
+        .unwrap_or(serde_json::Value::Null);
+    let pm = scripts::node_package_manager(root, &manifest);
+
+    let mut tools = Vec::new();
+    let mut globs = Vec::new();
+    if let Ok(content) = std::fs::read_to_string(root.join("pnpm-workspace.yaml")) {
+        tools.push("pnpm".to_string());
+        globs.extend(pnpm_workspace_globs(&content));
+    }
+    let manifest_globs = package_json_workspace_globs(&manifest);
+    if !manifest_globs.is_empty() {
+        tools.push(if pm == "yarn" { "yarn" } else { "npm" }.to_string());
+        globs.extend(manifest_globs);
+    }
+    let nx = root.join("nx.json").exists();
+    if nx {
+        tools.push("nx".to_string());
+    }
+    if root.join("turbo.json").exists() {
+        tools.push("turbo".to_string());
+    }
+    if tools.is_empty() {
+        return None;
+    }
+
+    let normalize = |g: &str| g.trim_start_matches("./").trim_end_matches('/').to_string();
+    let (excludes, includes): (Vec<String>, Vec<String>) = globs.into_iter().partition(|g| g.starts_with('!'));
+    let includes: Vec<String> = includes.iter().map(|g| normalize(g)).collect();
+    let excludes: Vec<String> = excludes.iter().map(|g| normalize(&g[1..])).collect();
+
+    let mut dirs = Vec::new();
+    candidate_dirs(root, "", 0, &mut dirs);
+    dirs.sort();
+
+    let mut packages = Vec::new();
+    for relative in dirs {
+        let dir = root.join(&relative);
+        let in_globs = includes.iter().any(|g| crate::webhook_server::glob_matches(g, &relative))
+            && !excludes.iter().any(|g| crate::webhook_server::glob_matches(g, &relative));
+        let project = in_globs
+            .then(|| detect_project(&dir))
+            .flatten()
+            // Nx projects don't have to be listed as package manager workspaces
+            .or_else(|| nx.then(|| nx_project(&dir, pm)).flatten());
+        let Some(mut project) = project else {
+            continue;
+        };
+
+        // Packages have no lockfile of their own; run them with the root's package manager
+        if project.project_type == "node" {
+            project.package_manager = Some(pm.to_string());
+            for script in project.scripts.iter_mut().filter(|s| s.source == "package.json") {
+                script.command = pm.to_string();
+            }
+        }
+        packages.push(WorkspacePackage {
+            relative_path: relative,
+            project,
+        });
+    }
+
+    println!(
+        "[projects] Workspace {} ({}): {} packages",
+        root.display(),
+        tools.join(", "),
+        packages.len()
+    );
+    Some(WorkspaceInfo {
+        root: root.to_string_lossy().to_string(),
+        tools,
+        package_manager: pm.to_string(),
+        packages,
+    })
+}
+
+/// Enumerate the packages of a pnpm/yarn/npm workspace, Nx or Turborepo monorepo with their
+/// scripts, so each one can be launched on its own. Returns None when `path` isn't a monorepo root.
+#[tauri::command]
+pub async fn detect_workspaces(path: String) -> Result<Option<WorkspaceInfo>, String> {
+    let root = PathBuf::from(&path);
+    if !root.is_dir() {
+        return Err(format!("Not a directory: {}", path));
+    }
+    tokio::task::spawn_blocking(move || detect_workspaces_internal(&root))
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,71 @@
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+lazy_static::lazy_static! {
+    // Output that ends like a question waiting on stdin
+    static ref PROMPT_PATTERNS: Vec<Regex> = [
+        // (y/n), [Y/n], (yes/no)
+        r"(?i)[(\[]\s*y(?:es)?\s*/\s*n(?:o)?\s*[)\]]\s*[:?]?\s*$",
+        // "Port 3000 is in use, use 3001 instead?"
+        r"\?\s*$",
+        r"(?i)\b(?:press|hit)\s+(?:enter|return|any key)\b",
+        r"(?i)\b(?:password|passphrase|username|token|otp|code)\b[^:\n]*:\s*$",
+        // prompts/inquirer style: "? Pick a framework ›"
+        r"^\s*\?\s.*[›>]\s*$",
+    ]
+    .iter()
+    .map(|p| Regex::new(p).unwrap())
+    .collect();
+    // Last prompt reported per PID, so a redrawn prompt isn't reported twice
+    static ref LAST_PROMPT: Mutex<HashMap<u32, String>> = Mutex::new(HashMap::new());
+}
+
+/// Emitted when a process seems to be waiting for an answer on stdin; reply with send_stdin
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WaitingForInputEvent {
+    pub pid: u32,
+    pub path: String,
+    pub prompt: String,
+}
+
+fn is_prompt(text: &str) -> bool {
+    PROMPT_PATTERNS.iter().any(|p| p.is_match(text))
+}
+
+/// Check output left without a trailing newline once the process stopped writing; a prompt
+/// is the usual reason a tool pauses mid-line
+pub(crate) fn check(app: &AppHandle, pid: u32, cwd: &str, partial: &str) {
+    let last = partial.rsplit('\r').next().unwrap_or("");
+    let prompt = crate::ansi::strip(last).trim().to_string();
+    if prompt.is_empty() || !is_prompt(&prompt) {
+        return;
+    }
+    if let Ok(mut seen) = LAST_PROMPT.lock() {
+        if seen.get(&pid) == Some(&prompt) {
+            return;
+        }
+        seen.insert(pid, prompt.clone());
+    }
+
+    println!("[prompts] PID {} is waiting for input: {}", pid, prompt);
+    let _ = app.emit(
+        "process-waiting-for-input",
+        WaitingForInputEvent {
+            pid,
+            path: cwd.to_string(),
+            prompt,
+        },
+    );
+}
+
+/// Forget the last prompt once input was sent (the same prompt again is a new question) or
+/// the process exited
+pub(crate) fn reset(pid: u32) {
+    if let Ok(mut seen) = LAST_PROMPT.lock() {
+        seen.remove(&pid);
+    }
+}
@@ -0,0 +1,297 @@
+use axum::{
+    body::{Body, Bytes},
+    extract::{Request, State},
+    http::{HeaderName, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    routing::any,
+    Router,
+};
+use crate::webhook_server::is_hop_by_hop;
+use chrono::Utc;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{mpsc, Mutex};
+
+/// Exchanges kept in memory per proxy
+const MAX_EXCHANGES: usize = 500;
+/// Bodies larger than this are forwarded in full but only partially captured
+const MAX_CAPTURE_BYTES: usize = 1024 * 1024;
+/// Largest request body accepted for forwarding
+const MAX_REQUEST_BYTES: usize = 50 * 1024 * 1024;
+
+lazy_static! {
+    // Running proxies keyed by listen port
+    static ref PROXIES: Mutex<HashMap<u16, RecordingProxy>> = Mutex::new(HashMap::new());
+    // Redirects are passed back to the client untouched, like a real reverse proxy
+    static ref CLIENT: reqwest::Client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap_or_default();
+}
+
+struct RecordingProxy {
+    shutdown_tx: mpsc::Sender<()>,
+    info: ProxyInfo,
+    exchanges: Arc<Mutex<Vec<ProxyExchange>>>,
+}
+
+struct ProxyState {
+    listen_port: u16,
+    target_port: u16,
+    exchanges: Arc<Mutex<Vec<ProxyExchange>>>,
+    app_handle: AppHandle,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyInfo {
+    pub listen_port: u16,
+    pub target_port: u16,
+    pub url: String,
+    pub started_at: String,
+}
+
+/// One request/response pair that went through a proxy, emitted as `proxy-exchange`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyExchange {
+    pub id: String,
+    pub timestamp: i64,
+    pub listen_port: u16,
+    pub target_port: u16,
+    pub method: String,
+    pub path: String,
+    pub query: String,
+    pub request_headers: HashMap<String, String>,
+    pub request_body: String,
+    /// None when the target couldn't be reached
+    pub status: Option<u16>,
+    pub response_headers: HashMap<String, String>,
+    pub response_body: String,
+    /// Full body sizes, even when the captured copy was truncated
+    pub request_size: usize,
+    pub response_size: usize,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
+/// Text copy of a body for inspection: truncated, with binary bodies summarized
+fn capture_body(bytes: &[u8]) -> String {
+    let captured = &bytes[..bytes.len().min(MAX_CAPTURE_BYTES)];
+    match std::str::from_utf8(captured) {
+        Ok(text) if captured.len() < bytes.len() => format!("{}\n[truncated, {} bytes total]", text, bytes.len()),
+        Ok(text) => text.to_string(),
+        Err(e) if e.error_len().is_none() => {
+            // Truncation cut a multi-byte character; keep the valid prefix
+            let text = String::from_utf8_lossy(&captured[..e.valid_up_to()]);
+            format!("{}\n[truncated, {} bytes total]", text, bytes.len())
+        }
+        Err(_) => format!("[binary, {} bytes]", bytes.len()),
+    }
+}
+
+fn header_map(headers: &axum::http::HeaderMap) -> HashMap<String, String> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| value.to_str().ok().map(|v| (name.to_string(), v.to_string())))
+        .collect()
+}
+
+async fn record(state: &ProxyState, exchange: ProxyExchange) {
+    {
+        let mut exchanges = state.exchanges.lock().await;
+        exchanges.push(exchange.clone());
+        let excess = exchanges.len().saturating_sub(MAX_EXCHANGES);
+        exchanges.drain(..excess);
+    }
+    let _ = state.app_handle.emit("proxy-exchange", exchange);
+}
+
+async fn handle_proxy(State(state): State<Arc<ProxyState>>, request: Request<Body>) -> Response {
+    let started = std::time::Instant::now();
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let query = request.uri().query().unwrap_or("").to_string();
+    let request_headers = request.headers().clone();
+    let request_body: Bytes = match axum::body::to_bytes(request.into_body(), MAX_REQUEST_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => return (StatusCode::PAYLOAD_TOO_LARGE, format!("Request body too large: {}", e)).into_response(),
+    };
+
+    let mut url = format!("http://127.0.0.1:{}{}", state.target_port, path);
+    if !query.is_empty() {
+        url.push('?');
+        url.push_str(&query);
+    }
+
+    // reqwest has its own http types; convert through the method name
+    let Ok(forward_method) = reqwest::Method::from_bytes(method.as_str().as_bytes()) else {
+        return (StatusCode::METHOD_NOT_ALLOWED, "Unsupported method").into_response();
+    };
+    let mut forward = CLIENT.request(forward_method, &url).body(request_body.clone());
+    for (name, value) in request_headers.iter() {
+        // Ask for uncompressed responses so captured bodies are readable
+        if !is_hop_by_hop(name.as_str()) && name.as_str() != "accept-encoding" {
+            forward = forward.header(name.as_str(), value.as_bytes());
+        }
+    }
+
+    let mut exchange = ProxyExchange {
+        id: uuid::Uuid::new_v4().to_string(),
+        timestamp: Utc::now().timestamp_millis(),
+        listen_port: state.listen_port,
+        target_port: state.target_port,
+        method: method.to_string(),
+        path,
+        query,
+        request_headers: header_map(&request_headers),
+        request_body: capture_body(&request_body),
+        status: None,
+        response_headers: HashMap::new(),
+        response_body: String::new(),
+        request_size: request_body.len(),
+        response_size: 0,
+        latency_ms: 0,
+        error: None,
+    };
+
+    let upstream = match forward.send().await {
+        Ok(upstream) => upstream,
+        Err(e) => {
+            let message = format!("Proxy could not reach port {}: {}", state.target_port, e);
+            exchange.latency_ms = started.elapsed().as_millis() as u64;
+            exchange.error = Some(message.clone());
+            record(&state, exchange).await;
+            return (StatusCode::BAD_GATEWAY, message).into_response();
+        }
+    };
+
+    let status = upstream.status();
+    let upstream_headers = upstream.headers().clone();
+    let body = upstream.bytes().await.unwrap_or_default();
+
+    exchange.status = Some(status.as_u16());
+    exchange.response_headers = upstream_headers
+        .iter()
+        .filter_map(|(name, value)| value.to_str().ok().map(|v| (name.to_string(), v.to_string())))
+        .collect();
+    exchange.response_body = capture_body(&body);
+    exchange.response_size = body.len();
+    exchange.latency_ms = started.elapsed().as_millis() as u64;
+    record(&state, exchange).await;
+
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = StatusCode::from_u16(status.as_u16()).unwrap_or(StatusCode::BAD_GATEWAY);
+    for (name, value) in upstream_headers.iter().filter(|(name, _)| !is_hop_by_hop(name.as_str())) {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_str().as_bytes()),
+            HeaderValue::from_bytes(value.as_bytes()),
+        ) {
+            response.headers_mut().append(name, value);
+        }
+    }
+    response
+}
+
+/// Reverse-proxy `listen_port` to a dev server on `target_port`, recording every
+/// request/response pair. Buffers whole bodies, so WebSocket upgrades (e.g. HMR) and
+/// streamed responses are not supported.
+#[tauri::command]
+pub async fn proxy_start(app: AppHandle, listen_port: u16, target_port: u16) -> Result<ProxyInfo, String> {
+    let mut proxies = PROXIES.lock().await;
+    if proxies.contains_key(&listen_port) {
+        return Err(format!("A proxy is already listening on port {}", listen_port));
+    }
+    if listen_port == target_port {
+        return Err("The proxy can't listen on the port it forwards to".to_string());
+    }
+
+    let addr = SocketAddr::from(([127, 0, 0, 1], listen_port));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(|e| format!("Failed to bind to port {}: {}", listen_port, e))?;
+    let actual_port = listener.local_addr().map_err(|e| e.to_string())?.port();
+
+    let exchanges = Arc::new(Mutex::new(Vec::new()));
+    let state = Arc::new(ProxyState {
+        listen_port: actual_port,
+        target_port,
+        exchanges: exchanges.clone(),
+        app_handle: app,
+    });
+    let router = Router::new().fallback(any(handle_proxy)).with_state(state);
+
+    let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
+    tokio::spawn(async move {
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async move {
+                shutdown_rx.recv().await;
+            })
+            .await
+            .ok();
+    });
+
+    let info = ProxyInfo {
+        listen_port: actual_port,
+        target_port,
+        url: format!("http://localhost:{}", actual_port),
+        started_at: Utc::now().to_rfc3339(),
+    };
+    println!("[proxy] Recording {} -> 127.0.0.1:{}", info.url, target_port);
+    proxies.insert(
+        actual_port,
+        RecordingProxy {
+            shutdown_tx,
+            info: info.clone(),
+            exchanges,
+        },
+    );
+    Ok(info)
+}
+
+#[tauri::command]
+pub async fn proxy_stop(listen_port: u16) -> Result<(), String> {
+    let proxy = PROXIES
+        .lock()
+        .await
+        .remove(&listen_port)
+        .ok_or_else(|| format!("No proxy is listening on port {}", listen_port))?;
+    let _ = proxy.shutdown_tx.send(()).await;
+    println!("[proxy] Stopped proxy on port {}", listen_port);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_proxies() -> Result<Vec<ProxyInfo>, String> {
+    let proxies = PROXIES.lock().await;
+    let mut infos: Vec<ProxyInfo> = proxies.values().map(|p| p.info.clone()).collect();
+    infos.sort_by_key(|i| i.listen_port);
+    Ok(infos)
+}
+
+/// Recorded exchanges, oldest first, from one proxy or all of them
+pub async fn exchanges_internal(listen_port: Option<u16>) -> Vec<ProxyExchange> {
+    let proxies = PROXIES.lock().await;
+    let mut all = Vec::new();
+    for proxy in proxies.values().filter(|p| listen_port.is_none() || listen_port == Some(p.info.listen_port)) {
+        all.extend(proxy.exchanges.lock().await.iter().cloned());
+    }
+    all.sort_by_key(|e| e.timestamp);
+    all
+}
+
+#[tauri::command]
+pub async fn get_proxy_exchanges(listen_port: Option<u16>) -> Result<Vec<ProxyExchange>, String> {
+    Ok(exchanges_internal(listen_port).await)
+}
+
+#[tauri::command]
+pub async fn clear_proxy_exchanges(listen_port: Option<u16>) -> Result<(), String> {
+    let proxies = PROXIES.lock().await;
+    for proxy in proxies.values().filter(|p| listen_port.is_none() || listen_port == Some(p.info.listen_port)) {
+        proxy.exchanges.lock().await.clear();
+    }
+    Ok(())
+}
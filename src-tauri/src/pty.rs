@@ -0,0 +1,199 @@
+use crate::process::{self, ChildHandle};
+use portable_pty::{native_pty_system, CommandBuilder, MasterPty, PtySize};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+/// Initial terminal size until the frontend reports its own
+const DEFAULT_COLS: u16 = 120;
+const DEFAULT_ROWS: u16 = 30;
+
+lazy_static::lazy_static! {
+    // Open PTY sessions keyed by PID, for stdin writes and resizes
+    static ref SESSIONS: Mutex<HashMap<u32, PtySession>> = Mutex::new(HashMap::new());
+}
+
+struct PtySession {
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+}
+
+/// Raw terminal output chunk, for rendering with a terminal emulator
+#[derive(Clone, Serialize)]
+struct PtyOutputEvent {
+    pid: u32,
+    path: String,
+    data: String,
+}
+
+fn size(cols: u16, rows: u16) -> PtySize {
+    PtySize {
+        rows,
+        cols,
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+/// Longest prefix of `buf` that is complete UTF-8 (a chunk can end mid-character)
+fn utf8_boundary(buf: &[u8]) -> usize {
+    match std::str::from_utf8(buf) {
+        Ok(_) => buf.len(),
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        Err(_) => buf.len(),
+    }
+}
+
+/// Stream PTY output: raw chunks go to the terminal view, cleaned lines to the normal log pipeline
+fn stream_output(app: AppHandle, cwd: String, pid: u32, mut reader: Box<dyn Read + Send>) {
+    std::thread::spawn(move || {
+        let mut buf = [0u8; 8192];
+        let mut pending: Vec<u8> = Vec::new();
+        let mut line = String::new();
+
+        loop {
+            let n = match reader.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => n,
+            };
+            pending.extend_from_slice(&buf[..n]);
+            let complete = utf8_boundary(&pending);
+            let text = String::from_utf8_lossy(&pending[..complete]).to_string();
+            pending.drain(..complete);
+
+            let _ = app.emit(
+                "process-pty-output",
+                PtyOutputEvent {
+                    pid,
+                    path: cwd.clone(),
+                    data: text.clone(),
+                },
+            );
+
+            line.push_str(&text);
+            while let Some(end) = line.find('\n') {
+                let raw: String = line.drain(..=end).collect();
+                // Spinners redraw with \r; keep only the final state of the line
+                let last = raw
+                    .trim_end_matches(['\r', '\n'])
+                    .rsplit('\r')
+                    .next()
+                    .unwrap_or("")
+                    .to_string();
+                // Escape codes are left for the log pipeline to strip or turn into spans
+                if !crate::ansi::strip(&last).trim().is_empty() {
+                    process::handle_stdout_line(&app, &cwd, last);
+                }
+            }
+            // Still no newline: a spinner only needs its latest redraw, and anything else this
+            // long is let through as a line of its own
+            if line.len() > process::MAX_PENDING_BYTES {
+                if let Some(cr) = line.rfind('\r') {
+                    line.drain(..=cr);
+                }
+            }
+            if line.len() > process::MAX_PENDING_BYTES {
+                let long = std::mem::take(&mut line);
+                if !crate::ansi::strip(&long).trim().is_empty() {
+                    process::handle_stdout_line(&app, &cwd, long);
+                }
+            }
+            if !line.is_empty() {
+                crate::prompts::check(&app, pid, &cwd, &line);
+            }
+        }
+
+        if let Ok(mut sessions) = SESSIONS.lock() {
+            sessions.remove(&pid);
+        }
+    });
+}
+
+/// Spawn a command attached to a pseudo-terminal so it keeps colors and interactive prompts
+pub fn spawn_pty_internal(app: &AppHandle, cwd: &str, command: &str, args: &[String]) -> Result<u32, String> {
+    let pair = native_pty_system()
+        .openpty(size(DEFAULT_COLS, DEFAULT_ROWS))
+        .map_err(|e| format!("Failed to open PTY: {}", e))?;
+
+    let (program, program_args) = crate::runtime::wrap_command(app, cwd, command, args);
+    let mut cmd = CommandBuilder::new(&program);
+    cmd.args(program_args);
+    cmd.cwd(cwd);
+    // GUI apps get a minimal PATH; use the login shell's environment instead
+    for (key, value) in crate::shell_env::spawn_env(app).into_iter().chain(crate::shell_env::project_env(app, cwd)) {
+        cmd.env(key, value);
+    }
+    cmd.env("TERM", "xterm-256color");
+    cmd.env("COLORTERM", "truecolor");
+
+    let child = pair
+        .slave
+        .spawn_command(cmd)
+        .map_err(|e| crate::shell_env::spawn_error(app, &program, e))?;
+    // Keep only the master side open so reads hit EOF when the child exits
+    drop(pair.slave);
+
+    let pid = child
+        .process_id()
+        .ok_or_else(|| "Spawned PTY process has no PID".to_string())?;
+    let limits = crate::limits::project_limits(app, cwd);
+    crate::jobs::attach(pid, limits.as_ref());
+    #[cfg(unix)]
+    if let Some(limits) = &limits {
+        crate::limits::apply_to_process(limits, pid);
+    }
+
+    let reader = pair
+        .master
+        .try_clone_reader()
+        .map_err(|e| format!("Failed to read PTY: {}", e))?;
+    let writer = pair
+        .master
+        .take_writer()
+        .map_err(|e| format!("Failed to open PTY input: {}", e))?;
+
+    process::prepare_output(app, cwd, command, args);
+    if let Ok(mut sessions) = SESSIONS.lock() {
+        sessions.insert(
+            pid,
+            PtySession {
+                master: pair.master,
+                writer,
+            },
+        );
+    }
+    stream_output(app.clone(), cwd.to_string(), pid, reader);
+
+    process::register_process(app, pid, cwd, command, args, Some(ChildHandle::Pty(child)));
+    println!("[pty] Spawned {} in {} (PID {})", command, cwd, pid);
+    Ok(pid)
+}
+
+/// Send input (keystrokes, pasted text, control characters like "\u0003") to a PTY process
+#[tauri::command]
+pub async fn write_process_stdin(pid: u32, data: String) -> Result<(), String> {
+    let mut sessions = SESSIONS.lock().map_err(|e| e.to_string())?;
+    let session = sessions
+        .get_mut(&pid)
+        .ok_or_else(|| format!("PID {} is not running in terminal mode", pid))?;
+    session
+        .writer
+        .write_all(data.as_bytes())
+        .and_then(|_| session.writer.flush())
+        .map_err(|e| format!("Failed to write to PID {}: {}", pid, e))
+}
+
+/// Match the PTY to the frontend terminal's size so line wrapping and TUIs render correctly
+#[tauri::command]
+pub async fn resize_process_pty(pid: u32, cols: u16, rows: u16) -> Result<(), String> {
+    let sessions = SESSIONS.lock().map_err(|e| e.to_string())?;
+    let session = sessions
+        .get(&pid)
+        .ok_or_else(|| format!("PID {} is not running in terminal mode", pid))?;
+    session
+        .master
+        .resize(size(cols.max(1), rows.max(1)))
+        .map_err(|e| format!("Failed to resize PTY: {}", e))
+}
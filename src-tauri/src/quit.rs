@@ -0,0 +1,178 @@
+use crate::process;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, RunEvent};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind, MessageDialogResult};
+
+/// How long the frontend gets to answer quit-requested before the native prompt takes over
+/// (a frozen or reloading webview would otherwise block quitting for good)
+const PROMPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Set once a quit is confirmed, so the resulting exit request goes through
+static QUITTING: AtomicBool = AtomicBool::new(false);
+// Set when the user chose to leave servers running, so the final exit doesn't stop them
+static LEAVE_RUNNING: AtomicBool = AtomicBool::new(false);
+
+lazy_static::lazy_static! {
+    // Answer channel for the quit-requested prompt shown by the frontend
+    static ref PENDING: Mutex<Option<tokio::sync::oneshot::Sender<String>>> = Mutex::new(None);
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuitRequest {
+    pub running_count: usize,
+    /// Project paths of the running servers
+    pub projects: Vec<String>,
+}
+
+/// Stop servers (unless leaving them running) and tunnels, then exit
+fn finish(app: &AppHandle, stop: bool) {
+    if !stop {
+        LEAVE_RUNNING.store(true, Ordering::SeqCst);
+        println!("[quit] Leaving servers running");
+        exit(app);
+        return;
+    }
+    // An ordered stop waits for each step to exit; don't hold up the dialog or runtime thread
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let stopped = crate::presets::stop_in_order(&app, process::running_processes());
+        println!("[quit] Stopped {} process(es)", stopped);
+        exit(&app);
+    });
+}
+
+fn exit(app: &AppHandle) {
+    crate::ssh_tunnels::stop_all();
+    QUITTING.store(true, Ordering::SeqCst);
+    app.exit(0);
+}
+
+fn apply_choice(app: &AppHandle, choice: &str) {
+    match choice {
+        "stop-all" => finish(app, true),
+        "leave-running" => finish(app, false),
+        _ => println!("[quit] Quit cancelled"),
+    }
+}
+
+/// Without a window to ask in (or an answer from it), fall back to a native prompt with the
+/// same three choices
+fn confirm_natively(app: &AppHandle, running: usize) {
+    const STOP: &str = "Stop All and Quit";
+    const LEAVE: &str = "Quit and Leave Running";
+    let app_handle = app.clone();
+    app.dialog()
+        .message(format!(
+            "{} server{} still running. Stop them and quit DevLaunch?",
+            running,
+            if running == 1 { " is" } else { "s are" }
+        ))
+        .title("Quit DevLaunch")
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::YesNoCancelCustom(
+            STOP.to_string(),
+            LEAVE.to_string(),
+            "Cancel".to_string(),
+        ))
+        // Platforms report custom buttons either by role or by label
+        .show_with_result(move |result| match result {
+            MessageDialogResult::Yes => apply_choice(&app_handle, "stop-all"),
+            MessageDialogResult::No => apply_choice(&app_handle, "leave-running"),
+            MessageDialogResult::Custom(label) if label == STOP => apply_choice(&app_handle, "stop-all"),
+            MessageDialogResult::Custom(label) if label == LEAVE => apply_choice(&app_handle, "leave-running"),
+            _ => apply_choice(&app_handle, "cancel"),
+        });
+}
+
+/// Quit, asking first when servers are running: the frontend gets `quit-requested` and
+/// answers with resolve_quit ("stop-all", "leave-running" or "cancel")
+pub fn request_quit(app: &AppHandle) {
+    let running = process::running_processes();
+    if running.is_empty() {
+        exit(app);
+        return;
+    }
+
+    let Some(window) = app.get_webview_window("main") else {
+        confirm_natively(app, running.len());
+        return;
+    };
+    let _ = window.show();
+    let _ = window.set_focus();
+
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    {
+        let Ok(mut pending) = PENDING.lock() else {
+            return;
+        };
+        // Already asking; bringing the window forward is enough
+        if pending.as_ref().map(|p| !p.is_closed()).unwrap_or(false) {
+            return;
+        }
+        *pending = Some(tx);
+    }
+    let running_count = running.len();
+    let _ = app.emit(
+        "quit-requested",
+        QuitRequest {
+            running_count,
+            projects: running.into_iter().map(|(_, path)| path).collect(),
+        },
+    );
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match tokio::time::timeout(PROMPT_TIMEOUT, rx).await {
+            Ok(Ok(choice)) => apply_choice(&app, &choice),
+            Ok(Err(_)) => {}
+            Err(_) => {
+                println!("[quit] No answer from the window; asking natively");
+                if let Ok(mut pending) = PENDING.lock() {
+                    pending.take();
+                }
+                let _ = app.emit("quit-prompt-expired", ());
+                confirm_natively(&app, running_count);
+            }
+        }
+    });
+}
+
+/// Ask to quit (same flow as ⌘Q and closing the window with servers running)
+#[tauri::command]
+pub async fn quit_app(app: AppHandle) -> Result<(), String> {
+    request_quit(&app);
+    Ok(())
+}
+
+/// Answer a quit-requested prompt: "stop-all", "leave-running" or "cancel"
+#[tauri::command]
+pub async fn resolve_quit(choice: String) -> Result<(), String> {
+    if !matches!(choice.as_str(), "stop-all" | "leave-running" | "cancel") {
+        return Err(format!("Unknown quit choice: {}", choice));
+    }
+    let sender = PENDING.lock().map_err(|e| e.to_string())?.take();
+    let sender = sender.ok_or_else(|| "No quit is pending".to_string())?;
+    sender.send(choice).map_err(|_| "No quit is pending".to_string())
+}
+
+/// App-level events: route every exit (⌘Q, app.exit, last window closed) through the
+/// confirmation, and stop servers on the way out unless the user chose to leave them
+pub fn handle_run_event(app: &AppHandle, event: RunEvent) {
+    match event {
+        RunEvent::ExitRequested { api, .. } if !QUITTING.load(Ordering::SeqCst) => {
+            api.prevent_exit();
+            request_quit(app);
+        }
+        RunEvent::Exit => {
+            if !LEAVE_RUNNING.load(Ordering::SeqCst) {
+                let _ = process::kill_all_processes_internal();
+            }
+            crate::browsers::remove_temp_profiles();
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,411 @@
+use crate::process::{self, LogEvent};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tauri::{AppHandle, Manager};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeCheck {
+    /// "node", "python" or "go"
+    pub runtime: String,
+    /// Version or range the project asks for, e.g. "20.11.0", ">=18", "^3.11"
+    pub required: String,
+    /// File the requirement came from, e.g. ".nvmrc" or "package.json engines"
+    pub source: String,
+    /// Version of the binary spawned commands would run
+    pub installed: Option<String>,
+    pub binary: Option<String>,
+    /// "ok", "mismatch", "missing" (not on PATH) or "unknown" (e.g. "lts/*")
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeReport {
+    pub path: String,
+    pub runtimes: Vec<RuntimeCheck>,
+    /// Installed version managers: "nvm", "fnm", "asdf", "volta", "pyenv", "mise"
+    pub version_managers: Vec<String>,
+    pub mismatches: usize,
+}
+
+fn read(dir: &Path, file: &str) -> Option<String> {
+    std::fs::read_to_string(dir.join(file))
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// First line of a version file, without comments
+fn version_file(dir: &Path, file: &str) -> Option<String> {
+    read(dir, file)?
+        .lines()
+        .map(|l| l.split('#').next().unwrap_or("").trim())
+        .find(|l| !l.is_empty())
+        .map(|l| l.to_string())
+}
+
+/// A tool's version from asdf/mise's .tool-versions ("nodejs 20.11.0")
+fn tool_versions(dir: &Path, tools: &[&str]) -> Option<String> {
+    read(dir, ".tool-versions")?.lines().find_map(|line| {
+        let mut words = line.split('#').next()?.split_whitespace();
+        if tools.contains(&words.next()?) {
+            words.next().map(|v| v.to_string())
+        } else {
+            None
+        }
+    })
+}
+
+fn package_json(dir: &Path) -> Option<serde_json::Value> {
+    serde_json::from_str(&read(dir, "package.json")?).ok()
+}
+
+/// What the project asks for per runtime, in order of precedence: (runtime, required, source)
+fn requirements(dir: &Path) -> Vec<(&'static str, String, &'static str)> {
+    let manifest = package_json(dir);
+    let pointer = |path: &str| {
+        manifest
+            .as_ref()
+            .and_then(|m| m.pointer(path))
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string())
+    };
+    let node = version_file(dir, ".nvmrc")
+        .map(|v| (v, ".nvmrc"))
+        .or_else(|| version_file(dir, ".node-version").map(|v| (v, ".node-version")))
+        .or_else(|| tool_versions(dir, &["nodejs", "node"]).map(|v| (v, ".tool-versions")))
+        .or_else(|| pointer("/volta/node").map(|v| (v, "package.json volta")))
+        .or_else(|| pointer("/engines/node").map(|v| (v, "package.json engines")));
+
+    let requires_python = read(dir, "pyproject.toml").and_then(|toml| {
+        toml.lines()
+            .find_map(|l| l.trim().strip_prefix("requires-python"))
+            .and_then(|rest| rest.trim_start().strip_prefix('='))
+            .map(|v| v.trim().trim_matches(|c| c == '"' || c == '\'').to_string())
+    });
+    let python = version_file(dir, ".python-version")
+        .map(|v| (v, ".python-version"))
+        .or_else(|| tool_versions(dir, &["python"]).map(|v| (v, ".tool-versions")))
+        .or_else(|| requires_python.map(|v| (v, "pyproject.toml requires-python")));
+
+    // go.mod's directive is a minimum
+    let go = tool_versions(dir, &["golang", "go"])
+        .map(|v| (v, ".tool-versions"))
+        .or_else(|| {
+            read(dir, "go.mod")?
+                .lines()
+                .find_map(|l| l.trim().strip_prefix("go "))
+                .map(|v| (format!(">={}", v.trim()), "go.mod"))
+        });
+
+    [("node", node), ("python", python), ("go", go)]
+        .into_iter()
+        .filter_map(|(runtime, req)| req.map(|(required, source)| (runtime, required, source)))
+        .collect()
+}
+
+/// Leading numeric components of a version: "v20.11.0" -> [20, 11, 0], "3.12.1rc1" -> [3, 12, 1]
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    let parts: Vec<u64> = version
+        .trim()
+        .trim_start_matches(['v', '='])
+        .split('.')
+        .map_while(|part| {
+            let digits: String = part.chars().take_while(|c| c.is_ascii_digit()).collect();
+            digits.parse().ok()
+        })
+        .collect();
+    (!parts.is_empty()).then_some(parts)
+}
+
+fn compare(installed: &[u64], wanted: &[u64]) -> std::cmp::Ordering {
+    let component = |v: &[u64], i: usize| v.get(i).copied().unwrap_or(0);
+    (0..3)
+        .map(|i| component(installed, i).cmp(&component(wanted, i)))
+        .find(|o| o.is_ne())
+        .unwrap_or(std::cmp::Ordering::Equal)
+}
+
+/// The given components match; "18" and "18.x" accept any 18
+fn prefix_matches(installed: &[u64], wanted: &[u64]) -> bool {
+    wanted.iter().enumerate().all(|(i, w)| installed.get(i) == Some(w))
+}
+
+fn comparator_matches(installed: &[u64], comparator: &str) -> Option<bool> {
+    use std::cmp::Ordering::*;
+    let ops = [">=", "<=", "==", "!=", "~=", ">", "<", "=", "^", "~"];
+    let op = ops.iter().find(|op| comparator.starts_with(**op)).copied().unwrap_or("");
+    let wanted = parse_version(&comparator[op.len()..])?;
+    let ordering = compare(installed, &wanted);
+    Some(match op {
+        ">=" => ordering != Less,
+        "<=" => ordering != Greater,
+        ">" => ordering == Greater,
+        "<" => ordering == Less,
+        "!=" => !prefix_matches(installed, &wanted),
+        // npm caret: same major (same minor below 1.0)
+        "^" => {
+            let fixed = if wanted[0] == 0 { 2 } else { 1 };
+            ordering != Less && prefix_matches(installed, &wanted[..fixed.min(wanted.len())])
+        }
+        // npm tilde: same major.minor
+        "~" => ordering != Less && prefix_matches(installed, &wanted[..2.min(wanted.len())]),
+        // PEP 440 compatible release: everything but the last given component is fixed
+        "~=" => ordering != Less && prefix_matches(installed, &wanted[..(wanted.len() - 1).max(1)]),
+        _ => prefix_matches(installed, &wanted),
+    })
+}
+
+/// Whether a version satisfies an npm-style range or PEP 440 specifier; None when the
+/// requirement isn't a version (e.g. "lts/*", "system", "latest")
+fn satisfies(installed: &str, required: &str) -> Option<bool> {
+    let installed = parse_version(installed)?;
+    let mut any = false;
+    for alternative in required.split("||") {
+        // Comparators in one alternative must all hold; PEP 440 separates them with commas
+        let normalized = alternative.replace(',', " ").replace(">= ", ">=").replace("<= ", "<=");
+        let mut all = true;
+        for comparator in normalized.split_whitespace() {
+            all &= comparator_matches(&installed, comparator)?;
+        }
+        any |= all;
+    }
+    Some(any)
+}
+
+/// Run `<binary> <args>` and pull the first version number out of its output
+fn binary_version(binary: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new(binary).args(args).output().ok()?;
+    // Python 2 printed its version to stderr
+    let text = format!(
+        "{} {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    text.split(|c: char| c.is_whitespace())
+        .map(|w| w.trim_start_matches("go").trim_start_matches('v'))
+        .find(|w| w.chars().next().map(|c| c.is_ascii_digit()).unwrap_or(false) && w.contains('.'))
+        .map(|w| w.to_string())
+}
+
+/// (binary, version) of the runtime spawned commands would get
+fn installed(runtime: &str, path_var: &str) -> Option<(PathBuf, Option<String>)> {
+    let candidates: &[(&str, &[&str])] = match runtime {
+        "node" => &[("node", &["--version"])],
+        "python" => &[("python3", &["--version"]), ("python", &["--version"])],
+        "go" => &[("go", &["version"])],
+        _ => &[],
+    };
+    candidates.iter().find_map(|(name, args)| {
+        let binary = crate::shell_env::find_in_path(name, path_var)?;
+        let version = binary_version(&binary, args);
+        Some((binary, version))
+    })
+}
+
+fn version_managers(app: &AppHandle, path_var: &str) -> Vec<String> {
+    let home = app.path().home_dir().unwrap_or_default();
+    let nvm_dir = std::env::var("NVM_DIR").map(PathBuf::from).unwrap_or_else(|_| home.join(".nvm"));
+    [
+        ("nvm", nvm_dir.join("nvm.sh")),
+        ("fnm", home.join(".fnm")),
+        ("asdf", home.join(".asdf")),
+        ("volta", home.join(".volta")),
+        ("pyenv", home.join(".pyenv")),
+        ("mise", home.join(".local/share/mise")),
+    ]
+    .into_iter()
+    .filter(|(name, dir)| dir.exists() || crate::shell_env::find_in_path(name, path_var).is_some())
+    .map(|(name, _)| name.to_string())
+    .collect()
+}
+
+pub(crate) fn detect(app: &AppHandle, path: &str) -> RuntimeReport {
+    let path_var = crate::shell_env::spawn_path(app);
+    let runtimes: Vec<RuntimeCheck> = requirements(Path::new(path))
+        .into_iter()
+        .map(|(runtime, required, source)| {
+            let found = installed(runtime, &path_var);
+            let version = found.as_ref().and_then(|(_, v)| v.clone());
+            let status = match (&found, &version) {
+                (None, _) => "missing",
+                (Some(_), None) => "unknown",
+                (Some(_), Some(v)) => match satisfies(v, &required) {
+                    Some(true) => "ok",
+                    Some(false) => "mismatch",
+                    None => "unknown",
+                },
+            };
+            RuntimeCheck {
+                runtime: runtime.to_string(),
+                required,
+                source: source.to_string(),
+                installed: version,
+                binary: found.map(|(b, _)| b.to_string_lossy().to_string()),
+                status: status.to_string(),
+            }
+        })
+        .collect();
+
+    RuntimeReport {
+        path: path.to_string(),
+        mismatches: runtimes.iter().filter(|r| r.status == "mismatch" || r.status == "missing").count(),
+        version_managers: version_managers(app, &path_var),
+        runtimes,
+    }
+}
+
+/// Version manager a project's commands run through, from its `versionManager` setting:
+/// "nvm", "fnm", "asdf", "mise", "volta", "login-shell", or "auto" to pick one from the
+/// project's version files and the installed managers
+fn launch_manager(app: &AppHandle, cwd: &str) -> Option<String> {
+    let setting = crate::config::load_config_internal(app)
+        .ok()
+        .and_then(|c| c.project_settings(cwd).version_manager)?;
+    if setting != "auto" {
+        return Some(setting);
+    }
+
+    let dir = Path::new(cwd);
+    let installed = version_managers(app, &crate::shell_env::spawn_path(app));
+    let has = |name: &str| installed.iter().any(|m| m == name);
+    let node_file = dir.join(".nvmrc").exists() || dir.join(".node-version").exists();
+    let volta_pin = package_json(dir).map(|m| m.pointer("/volta/node").is_some()).unwrap_or(false);
+    let tool_versions = dir.join(".tool-versions").exists();
+
+    let manager = if volta_pin && has("volta") {
+        "volta"
+    } else if (tool_versions || dir.join(".mise.toml").exists()) && has("mise") {
+        "mise"
+    } else if tool_versions && has("asdf") {
+        "asdf"
+    } else if node_file && has("fnm") {
+        "fnm"
+    } else if node_file && has("nvm") {
+        "nvm"
+    } else {
+        return None;
+    };
+    Some(manager.to_string())
+}
+
+/// Rewrite a command to run under the project's version manager, so the Node/Python version
+/// it pins is used instead of whatever is first on PATH. Unchanged when no manager applies.
+pub(crate) fn wrap_command(app: &AppHandle, cwd: &str, command: &str, args: &[String]) -> (String, Vec<String>) {
+    // Inline variables from spawn_shell stay outermost; the manager wraps the real command
+    if command == "env" {
+        let assignments = args.iter().take_while(|a| a.contains('=')).count();
+        if let Some((inner, inner_args)) = args[assignments..].split_first() {
+            let (program, program_args) = wrap_command(app, cwd, inner, inner_args);
+            let mut wrapped = args[..assignments].to_vec();
+            wrapped.push(program);
+            wrapped.extend(program_args);
+            return ("env".to_string(), wrapped);
+        }
+    }
+    let unchanged = || (command.to_string(), args.to_vec());
+    let Some(manager) = launch_manager(app, cwd) else {
+        return unchanged();
+    };
+    let path_var = crate::shell_env::spawn_path(app);
+    let program = |name: &str| crate::shell_env::find_in_path(name, &path_var).map(|p| p.to_string_lossy().to_string());
+    let with = |program: String, prefix: &[&str]| {
+        let mut wrapped: Vec<String> = prefix.iter().map(|a| a.to_string()).collect();
+        wrapped.push(command.to_string());
+        wrapped.extend(args.iter().cloned());
+        (program, wrapped)
+    };
+
+    let wrapped = match manager.as_str() {
+        "volta" => program("volta").map(|p| with(p, &["run"])),
+        "asdf" => program("asdf").map(|p| with(p, &["exec"])),
+        "mise" => program("mise").map(|p| with(p, &["exec", "--"])),
+        "fnm" => program("fnm").map(|p| {
+            let dir = Path::new(cwd);
+            match [".node-version", ".nvmrc"].iter().find(|f| dir.join(f).exists()) {
+                Some(file) => with(p, &["exec", &format!("--using={}", file), "--"]),
+                None => with(p, &["exec", "--"]),
+            }
+        }),
+        // nvm is a shell function, so it has to be sourced; `nvm use` reads .nvmrc
+        #[cfg(unix)]
+        "nvm" => {
+            let home = app.path().home_dir().unwrap_or_default();
+            let nvm_dir = std::env::var("NVM_DIR").map(PathBuf::from).unwrap_or_else(|_| home.join(".nvm"));
+            let script = format!(
+                ". \"{}/nvm.sh\" && nvm use --silent >/dev/null 2>&1; exec \"$@\"",
+                nvm_dir.display()
+            );
+            Some(with("bash".to_string(), &["-c", &script, "devlaunch"]))
+        }
+        // Whatever the user's login shell sets up in its profile files. Not interactive (-i):
+        // without a terminal that hangs or prints job-control errors in bash and zsh.
+        #[cfg(unix)]
+        "login-shell" => {
+            let prefs = crate::config::load_config_internal(app)
+                .map(|c| c.preferences.shell_env)
+                .unwrap_or_default();
+            let shell = crate::shell_env::user_shell(&prefs);
+            // fish passes the arguments in $argv and has no $0 slot
+            if shell.ends_with("fish") {
+                Some(with(shell, &["-l", "-c", "exec $argv"]))
+            } else {
+                Some(with(shell, &["-l", "-c", "exec \"$@\"", "devlaunch"]))
+            }
+        }
+        _ => None,
+    };
+    match wrapped {
+        Some(wrapped) => {
+            println!("[runtime] Running {} in {} through {}", command, cwd, manager);
+            wrapped
+        }
+        None => {
+            println!("[runtime] Version manager {} isn't available for {}; running {} directly", manager, cwd, command);
+            unchanged()
+        }
+    }
+}
+
+/// Warn in the project's logs when the runtime on PATH doesn't match what it asks for.
+/// Runs in the background so launching isn't held up.
+pub(crate) fn warn_on_mismatch(app: &AppHandle, path: &str) {
+    let app = app.clone();
+    let path = path.to_string();
+    std::thread::spawn(move || {
+        // The manager picks the version, so what's on PATH doesn't matter
+        if launch_manager(&app, &path).is_some() {
+            return;
+        }
+        let report = detect(&app, &path);
+        for check in report.runtimes.iter().filter(|r| r.status == "mismatch" || r.status == "missing") {
+            let found = match &check.installed {
+                Some(version) => format!("{} {} is on PATH", check.runtime, version),
+                None => format!("{} was not found on PATH", check.runtime),
+            };
+            process::emit_log(
+                &app,
+                LogEvent {
+                    path: path.clone(),
+                    level: "warn".to_string(),
+                    message: format!(
+                        "[runtime] {} requires {} {}, but {}",
+                        check.source, check.runtime, check.required, found
+                    ),
+                    ..Default::default()
+                },
+            );
+        }
+    });
+}
+
+/// Compare the runtime versions a project asks for (.nvmrc, .node-version, .tool-versions,
+/// engines, .python-version, go.mod) with the binaries spawned commands would run
+#[tauri::command]
+pub async fn detect_runtime(app: AppHandle, path: String) -> Result<RuntimeReport, String> {
+    tokio::task::spawn_blocking(move || detect(&app, &path))
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,457 @@
+use crate::config::{self, ScheduledTask};
+use chrono::{DateTime, Datelike, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, Timelike};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// How often a running task is checked for completion
+const COMPLETION_POLL_SECS: u64 = 1;
+/// How far ahead next_run looks for a matching minute (long enough to reach a Feb 29)
+const NEXT_RUN_HORIZON_DAYS: i64 = 4 * 366;
+
+lazy_static::lazy_static! {
+    // Most recent run per task id, mirrored to scheduler.json
+    static ref RUNS: Mutex<HashMap<String, TaskRun>> = Mutex::new(HashMap::new());
+}
+
+/// Outcome of a task's most recent run, emitted as `scheduled-task-run`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRun {
+    pub task_id: String,
+    /// "schedule", "startup" or "manual"
+    pub trigger: String,
+    pub started_at: i64,
+    pub finished_at: Option<i64>,
+    pub pid: Option<u32>,
+    pub exit_code: Option<i32>,
+    /// "running", "success", "failed", "error" (couldn't start) or "interrupted" (app quit)
+    pub status: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledTaskStatus {
+    #[serde(flatten)]
+    pub task: ScheduledTask,
+    pub last_run: Option<TaskRun>,
+    /// Unix millis of the next scheduled run
+    pub next_run: Option<i64>,
+    /// Set when the schedule can't be parsed
+    pub schedule_error: Option<String>,
+}
+
+/// Parsed cron expression: which minutes, hours, days, months and weekdays match
+struct Cron {
+    minutes: Vec<bool>,
+    hours: Vec<bool>,
+    days: Vec<bool>,
+    months: Vec<bool>,
+    weekdays: Vec<bool>,
+    /// Cron ORs day-of-month and day-of-week when both are restricted
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+const MONTH_NAMES: &[&str] = &["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+const WEEKDAY_NAMES: &[&str] = &["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// Expand one cron field ("*", "*/15", "1-5", "mon,wed", "0-30/10") into a match table
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str], name_base: u32) -> Result<Vec<bool>, String> {
+    let value = |s: &str| -> Result<u32, String> {
+        let lower = s.to_lowercase();
+        if let Some(i) = names.iter().position(|n| *n == lower) {
+            return Ok(i as u32 + name_base);
+        }
+        s.parse::<u32>().map_err(|_| format!("Invalid cron value: {}", s))
+    };
+
+    let mut table = vec![false; max as usize + 1];
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().map_err(|_| format!("Invalid cron step: {}", step))?),
+            None => (part, 1),
+        };
+        if step == 0 {
+            return Err("Cron step can't be 0".to_string());
+        }
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (value(a)?, value(b)?)
+        } else {
+            let v = value(range)?;
+            // "5/10" means every 10 starting at 5
+            (v, if part.contains('/') { max } else { v })
+        };
+        if start < min || end > max || start > end {
+            return Err(format!("Cron field out of range: {}", part));
+        }
+        for v in (start..=end).step_by(step as usize) {
+            table[v as usize] = true;
+        }
+    }
+    Ok(table)
+}
+
+fn parse_cron(expr: &str) -> Result<Cron, String> {
+    let expr = match expr.trim() {
+        "@hourly" => "0 * * * *",
+        "@daily" | "@midnight" => "0 0 * * *",
+        "@weekly" => "0 0 * * 0",
+        "@monthly" => "0 0 1 * *",
+        "@yearly" | "@annually" => "0 0 1 1 *",
+        other => other,
+    };
+    let fields: Vec<&str> = expr.split_whitespace().collect();
+    if fields.len() != 5 {
+        return Err(format!("Expected 5 cron fields (min hour day month weekday), got {}", fields.len()));
+    }
+
+    let mut weekdays = parse_field(fields[4], 0, 7, WEEKDAY_NAMES, 0)?;
+    // 7 is Sunday too
+    if weekdays[7] {
+        weekdays[0] = true;
+    }
+    weekdays.truncate(7);
+    Ok(Cron {
+        minutes: parse_field(fields[0], 0, 59, &[], 0)?,
+        hours: parse_field(fields[1], 0, 23, &[], 0)?,
+        days: parse_field(fields[2], 1, 31, &[], 0)?,
+        months: parse_field(fields[3], 1, 12, MONTH_NAMES, 1)?,
+        weekdays,
+        days_restricted: fields[2] != "*",
+        weekdays_restricted: fields[4] != "*",
+    })
+}
+
+impl Cron {
+    fn day_matches(&self, date: NaiveDate) -> bool {
+        let day = self.days[date.day() as usize];
+        let weekday = self.weekdays[date.weekday().num_days_from_sunday() as usize];
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => day || weekday,
+            _ => day && weekday,
+        }
+    }
+
+    fn matches(&self, time: &DateTime<Local>) -> bool {
+        self.minutes[time.minute() as usize]
+            && self.hours[time.hour() as usize]
+            && self.months[time.month() as usize]
+            && self.day_matches(time.date_naive())
+    }
+
+    /// First matching minute after `from`. Steps field by field (skipping whole months, days
+    /// and hours that can't match) in local wall-clock time; minutes that don't exist because
+    /// of a DST jump are skipped, and repeated ones run at their first occurrence after `from`.
+    fn next_after(&self, from: &DateTime<Local>) -> Option<DateTime<Local>> {
+        let start_of_hour = |t: NaiveDateTime| t.date().and_hms_opt(t.hour(), 0, 0);
+        let mut time = from.naive_local().with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let limit = time + Duration::days(NEXT_RUN_HORIZON_DAYS);
+
+        while time < limit {
+            if !self.months[time.month() as usize] {
+                let (year, month) = match time.month() {
+                    12 => (time.year() + 1, 1),
+                    month => (time.year(), month + 1),
+                };
+                time = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.day_matches(time.date()) {
+                time = time.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.hours[time.hour() as usize] {
+                time = start_of_hour(time)? + Duration::hours(1);
+                continue;
+            }
+            let Some(minute) = (time.minute()..60).find(|&m| self.minutes[m as usize]) else {
+                time = start_of_hour(time)? + Duration::hours(1);
+                continue;
+            };
+            let candidate = time.with_minute(minute)?;
+            let resolved = match candidate.and_local_timezone(Local) {
+                LocalResult::Single(t) => Some(t),
+                LocalResult::Ambiguous(first, second) => [first, second].into_iter().find(|t| t > from),
+                LocalResult::None => None,
+            };
+            match resolved {
+                Some(t) if t > *from => return Some(t),
+                _ => time = candidate + Duration::minutes(1),
+            }
+        }
+        None
+    }
+}
+
+fn runs_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(dir.join("scheduler.json"))
+}
+
+fn save_runs(app: &AppHandle) {
+    let path = match runs_path(app) {
+        Ok(path) => path,
+        Err(e) => {
+            println!("[scheduler] {}", e);
+            return;
+        }
+    };
+    // Held through the write so concurrent saves can't interleave
+    let Ok(runs) = RUNS.lock() else {
+        return;
+    };
+    let result = serde_json::to_string_pretty(&*runs)
+        .map_err(|e| e.to_string())
+        .and_then(|json| crate::config::write_file_atomic(&path, &json));
+    if let Err(e) = result {
+        println!("[scheduler] Failed to save run history: {}", e);
+    }
+}
+
+/// Load last-run history, marking runs cut short by the app quitting
+fn load_runs(app: &AppHandle) {
+    let mut runs: HashMap<String, TaskRun> = runs_path(app)
+        .ok()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default();
+    for run in runs.values_mut().filter(|r| r.status == "running") {
+        run.status = "interrupted".to_string();
+    }
+    if let Ok(mut current) = RUNS.lock() {
+        *current = runs;
+    }
+}
+
+fn update_run(app: &AppHandle, run: TaskRun) {
+    if let Ok(mut runs) = RUNS.lock() {
+        runs.insert(run.task_id.clone(), run.clone());
+    }
+    save_runs(app);
+    let _ = app.emit("scheduled-task-run", run);
+}
+
+fn is_running(task_id: &str) -> bool {
+    RUNS.lock()
+        .map(|runs| runs.get(task_id).is_some_and(|r| r.status == "running"))
+        .unwrap_or(false)
+}
+
+/// Start a task as a managed process (logs stream like any other process) and record
+/// its outcome when it exits
+fn run_task(app: &AppHandle, task: &ScheduledTask, trigger: &str) -> Result<TaskRun, String> {
+    if is_running(&task.id) {
+        return Err(format!("{} is still running", task.name));
+    }
+    let cwd = match task.cwd.as_deref().filter(|c| !c.is_empty()) {
+        Some(cwd) => cwd.to_string(),
+        None => app
+            .path()
+            .home_dir()
+            .map_err(|e| format!("Failed to get home dir: {}", e))?
+            .to_string_lossy()
+            .to_string(),
+    };
+
+    let mut run = TaskRun {
+        task_id: task.id.clone(),
+        trigger: trigger.to_string(),
+        started_at: chrono::Utc::now().timestamp_millis(),
+        finished_at: None,
+        pid: None,
+        exit_code: None,
+        status: "running".to_string(),
+        error: None,
+    };
+    let pid = match crate::process::spawn_process_internal(app, &cwd, &task.command, &task.args) {
+        Ok(pid) => pid,
+        Err(e) => {
+            run.status = "error".to_string();
+            run.error = Some(e.clone());
+            run.finished_at = Some(run.started_at);
+            update_run(app, run);
+            return Err(e);
+        }
+    };
+    println!("[scheduler] Running {} ({}, pid {})", task.name, trigger, pid);
+    run.pid = Some(pid);
+    update_run(app, run.clone());
+
+    let app = app.clone();
+    let mut finished = run.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(COMPLETION_POLL_SECS)).await;
+            let record = crate::process::list_processes_internal().into_iter().find(|p| p.pid == pid);
+            // Gone entirely means it was stopped (or replaced) before we saw the exit code
+            if record.as_ref().is_some_and(|r| r.running) {
+                continue;
+            }
+            finished.exit_code = record.and_then(|r| r.exit_code);
+            finished.finished_at = Some(chrono::Utc::now().timestamp_millis());
+            finished.status = if finished.exit_code == Some(0) { "success" } else { "failed" }.to_string();
+            println!("[scheduler] {} finished: {}", finished.task_id, finished.status);
+            update_run(&app, finished);
+            break;
+        }
+    });
+    Ok(run)
+}
+
+/// Run startup tasks, then check schedules at the top of every minute
+pub fn start(app: &AppHandle) {
+    load_runs(app);
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let tasks = config::load_config_internal(&app)
+            .map(|c| c.preferences.scheduled_tasks)
+            .unwrap_or_default();
+        for task in tasks.iter().filter(|t| t.enabled && t.run_at_startup) {
+            if let Err(e) = run_task(&app, task, "startup") {
+                println!("[scheduler] {} failed to start: {}", task.name, e);
+            }
+        }
+
+        loop {
+            let now = Local::now();
+            let wait = 60 - now.second() as u64;
+            tokio::time::sleep(std::time::Duration::from_secs(wait)).await;
+
+            let now = Local::now();
+            let tasks = config::load_config_internal(&app)
+                .map(|c| c.preferences.scheduled_tasks)
+                .unwrap_or_default();
+            for task in tasks.iter().filter(|t| t.enabled) {
+                let Some(schedule) = task.schedule.as_deref().filter(|s| !s.trim().is_empty()) else {
+                    continue;
+                };
+                match parse_cron(schedule) {
+                    Ok(cron) if cron.matches(&now) => {
+                        if let Err(e) = run_task(&app, task, "schedule") {
+                            println!("[scheduler] {} failed to start: {}", task.name, e);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => println!("[scheduler] Skipping {}: {}", task.name, e),
+                }
+            }
+        }
+    });
+}
+
+/// Configured tasks with their last run and next scheduled time
+#[tauri::command]
+pub async fn get_scheduled_tasks(app: AppHandle) -> Result<Vec<ScheduledTaskStatus>, String> {
+    let tasks = config::load_config_internal(&app)?.preferences.scheduled_tasks;
+    let runs = RUNS.lock().map(|r| r.clone()).unwrap_or_default();
+    let now = Local::now();
+    Ok(tasks
+        .into_iter()
+        .map(|task| {
+            let cron = task.schedule.as_deref().filter(|s| !s.trim().is_empty()).map(parse_cron);
+            ScheduledTaskStatus {
+                last_run: runs.get(&task.id).cloned(),
+                next_run: match &cron {
+                    Some(Ok(cron)) if task.enabled => cron.next_after(&now).map(|t| t.timestamp_millis()),
+                    _ => None,
+                },
+                schedule_error: cron.and_then(|c| c.err()),
+                task,
+            }
+        })
+        .collect())
+}
+
+/// Run a task now, regardless of its schedule or enabled flag
+#[tauri::command]
+pub async fn run_scheduled_task(app: AppHandle, id: String) -> Result<TaskRun, String> {
+    let task = config::load_config_internal(&app)?
+        .preferences
+        .scheduled_tasks
+        .into_iter()
+        .find(|t| t.id == id)
+        .ok_or_else(|| format!("Scheduled task not found: {}", id))?;
+    run_task(&app, &task, "manual")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+    }
+
+    fn next(expr: &str, from: DateTime<Local>) -> Option<DateTime<Local>> {
+        parse_cron(expr).unwrap().next_after(&from)
+    }
+
+    #[test]
+    fn parses_fields() {
+        let table = parse_field("0-30/10,45", 0, 59, &[], 0).unwrap();
+        let set: Vec<usize> = (0..60).filter(|&m| table[m]).collect();
+        assert_eq!(set, [0, 10, 20, 30, 45]);
+        let table = parse_field("5/20", 0, 59, &[], 0).unwrap();
+        assert_eq!((0..60).filter(|&m| table[m]).collect::<Vec<usize>>(), [5, 25, 45]);
+        let months = parse_field("jan,MAR-apr", 1, 12, MONTH_NAMES, 1).unwrap();
+        assert_eq!((1..=12).filter(|&m| months[m]).collect::<Vec<usize>>(), [1, 3, 4]);
+    }
+
+    #[test]
+    fn rejects_bad_expressions() {
+        assert!(parse_cron("* * * *").is_err());
+        assert!(parse_cron("60 * * * *").is_err());
+        assert!(parse_cron("*/0 * * * *").is_err());
+        assert!(parse_cron("5-1 * * * *").is_err());
+        assert!(parse_cron("* * 0 * *").is_err());
+        assert!(parse_cron("* * * foo *").is_err());
+    }
+
+    #[test]
+    fn sunday_is_0_or_7() {
+        let cron = parse_cron("0 9 * * 7").unwrap();
+        // 2026-01-04 is a Sunday
+        assert!(cron.matches(&at(2026, 1, 4, 9, 0)));
+        assert!(!cron.matches(&at(2026, 1, 5, 9, 0)));
+    }
+
+    #[test]
+    fn next_is_strictly_after_from() {
+        assert_eq!(next("*/15 * * * *", at(2026, 1, 10, 12, 0)), Some(at(2026, 1, 10, 12, 15)));
+        assert_eq!(next("*/15 * * * *", at(2026, 1, 10, 12, 7)), Some(at(2026, 1, 10, 12, 15)));
+        assert_eq!(next("* * * * *", at(2026, 1, 10, 23, 59)), Some(at(2026, 1, 11, 0, 0)));
+    }
+
+    #[test]
+    fn next_rolls_over_hours_days_months_and_years() {
+        assert_eq!(next("30 9 * * *", at(2026, 1, 10, 10, 0)), Some(at(2026, 1, 11, 9, 30)));
+        assert_eq!(next("@monthly", at(2026, 1, 31, 12, 0)), Some(at(2026, 2, 1, 0, 0)));
+        assert_eq!(next("0 12 1 jan *", at(2026, 6, 15, 0, 0)), Some(at(2027, 1, 1, 12, 0)));
+    }
+
+    #[test]
+    fn next_weekday_schedules() {
+        // Weekdays at 9:00, from Friday 2026-01-09 10:00 -> Monday
+        assert_eq!(next("0 9 * * mon-fri", at(2026, 1, 9, 10, 0)), Some(at(2026, 1, 12, 9, 0)));
+        // Day of month and weekday both restricted: either matches (the 15th, or a Monday)
+        assert_eq!(next("0 0 15 * mon", at(2026, 1, 13, 0, 0)), Some(at(2026, 1, 15, 0, 0)));
+        assert_eq!(next("0 0 15 * mon", at(2026, 1, 15, 1, 0)), Some(at(2026, 1, 19, 0, 0)));
+    }
+
+    #[test]
+    fn next_finds_leap_days_and_gives_up_on_impossible_dates() {
+        assert_eq!(next("0 0 29 2 *", at(2026, 3, 1, 0, 0)), Some(at(2028, 2, 29, 0, 0)));
+        assert_eq!(next("0 0 30 2 *", at(2026, 1, 1, 0, 0)), None);
+    }
+}
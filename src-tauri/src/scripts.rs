@@ -0,0 +1,249 @@
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiscoveredScript {
+    pub name: String,
+    pub source: String, // "package.json", "Cargo.toml", "Makefile", "composer.json"
+    pub command: String,
+    pub args: Vec<String>,
+    pub description: Option<String>,
+}
+
+pub(crate) fn script(name: &str, source: &str, command: &str, args: &[&str], description: Option<String>) -> DiscoveredScript {
+    DiscoveredScript {
+        name: name.to_string(),
+        source: source.to_string(),
+        command: command.to_string(),
+        args: args.iter().map(|a| a.to_string()).collect(),
+        description,
+    }
+}
+
+/// Pick the Node package manager from the lockfile (or the `packageManager` field)
+pub(crate) fn node_package_manager(dir: &Path, manifest: &serde_json::Value) -> &'static str {
+    if let Some(pm) = manifest.get("packageManager").and_then(|v| v.as_str()) {
+        if pm.starts_with("pnpm") {
+            return "pnpm";
+        } else if pm.starts_with("yarn") {
+            return "yarn";
+        }
+    }
+
+    if dir.join("pnpm-lock.yaml").exists() {
+        "pnpm"
+    } else if dir.join("yarn.lock").exists() {
+        "yarn"
+    } else {
+        "npm"
+    }
+}
+
+fn discover_package_json(dir: &Path) -> Vec<DiscoveredScript> {
+    let Ok(content) = std::fs::read_to_string(dir.join("package.json")) else {
+        return Vec::new();
+    };
+    let Ok(manifest) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return Vec::new();
+    };
+
+    let pm = node_package_manager(dir, &manifest);
+    let Some(scripts) = manifest.get("scripts").and_then(|s| s.as_object()) else {
+        return Vec::new();
+    };
+
+    scripts
+        .iter()
+        // pre/post lifecycle hooks run automatically around their main script
+        .filter(|(name, _)| {
+            !["pre", "post"].iter().any(|prefix| {
+                name.strip_prefix(prefix)
+                    .map(|base| scripts.contains_key(base))
+                    .unwrap_or(false)
+            })
+        })
+        .map(|(name, body)| {
+            script(
+                name,
+                "package.json",
+                pm,
+                &["run", name],
+                body.as_str().map(|s| s.to_string()),
+            )
+        })
+        .collect()
+}
+
+/// Read `name = "..."` values from the given `[[table]]` sections of a Cargo.toml.
+/// A minimal line parser - enough for bin/example names without a full TOML parser.
+fn cargo_table_names(content: &str, table: &str) -> Vec<String> {
+    let header = format!("[[{}]]", table);
+    let mut names = Vec::new();
+    let mut in_table = false;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_table = line == header;
+            continue;
+        }
+        if !in_table {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            if key.trim() == "name" {
+                let value = value.trim().trim_matches('"').trim_matches('\'');
+                if !value.is_empty() {
+                    names.push(value.to_string());
+                }
+                in_table = false;
+            }
+        }
+    }
+
+    names
+}
+
+fn discover_cargo(dir: &Path) -> Vec<DiscoveredScript> {
+    let Ok(content) = std::fs::read_to_string(dir.join("Cargo.toml")) else {
+        return Vec::new();
+    };
+
+    let mut scripts = Vec::new();
+    let is_workspace_only = content.lines().any(|l| l.trim() == "[workspace]")
+        && !content.lines().any(|l| l.trim() == "[package]");
+    if !is_workspace_only {
+        scripts.push(script("run", "Cargo.toml", "cargo", &["run"], None));
+    }
+
+    for bin in cargo_table_names(&content, "bin") {
+        scripts.push(script(
+            &format!("run --bin {}", bin),
+            "Cargo.toml",
+            "cargo",
+            &["run", "--bin", &bin],
+            None,
+        ));
+    }
+    for example in cargo_table_names(&content, "example") {
+        scripts.push(script(
+            &format!("run --example {}", example),
+            "Cargo.toml",
+            "cargo",
+            &["run", "--example", &example],
+            None,
+        ));
+    }
+
+    scripts.push(script("build", "Cargo.toml", "cargo", &["build"], None));
+    scripts.push(script("test", "Cargo.toml", "cargo", &["test"], None));
+    scripts
+}
+
+fn discover_makefile(dir: &Path) -> Vec<DiscoveredScript> {
+    let content = ["Makefile", "makefile", "GNUmakefile"]
+        .iter()
+        .find_map(|name| std::fs::read_to_string(dir.join(name)).ok());
+    let Some(content) = content else {
+        return Vec::new();
+    };
+
+    let mut seen = HashSet::new();
+    let mut scripts = Vec::new();
+    let mut previous_comment: Option<String> = None;
+
+    for line in content.lines() {
+        // Recipe lines and blank lines don't declare targets
+        if line.starts_with('\t') || line.trim().is_empty() {
+            previous_comment = None;
+            continue;
+        }
+        if let Some(comment) = line.trim().strip_prefix('#') {
+            previous_comment = Some(comment.trim().to_string());
+            continue;
+        }
+
+        let Some(colon) = line.find(':') else {
+            previous_comment = None;
+            continue;
+        };
+        // Skip variable assignments (`:=`, `::=`) and lines containing `=` before the colon
+        let after = &line[colon + 1..];
+        let target_part = &line[..colon];
+        if after.starts_with('=') || after.starts_with(":=") || target_part.contains('=') {
+            previous_comment = None;
+            continue;
+        }
+
+        // `## description` after the prerequisites is a common self-documenting convention
+        let inline = after.split_once("##").map(|(_, d)| d.trim().to_string());
+        let description = inline.or_else(|| previous_comment.take());
+
+        for target in target_part.split_whitespace() {
+            let special = target.starts_with('.') || target.contains('%') || target.contains('$');
+            if !special && seen.insert(target.to_string()) {
+                scripts.push(script(target, "Makefile", "make", &[target], description.clone()));
+            }
+        }
+        previous_comment = None;
+    }
+
+    scripts
+}
+
+fn discover_composer(dir: &Path) -> Vec<DiscoveredScript> {
+    let Ok(content) = std::fs::read_to_string(dir.join("composer.json")) else {
+        return Vec::new();
+    };
+    let Ok(manifest) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return Vec::new();
+    };
+    let Some(scripts) = manifest.get("scripts").and_then(|s| s.as_object()) else {
+        return Vec::new();
+    };
+
+    let descriptions = manifest.get("scripts-descriptions").and_then(|d| d.as_object());
+    scripts
+        .iter()
+        // Composer event hooks (pre-install-cmd, post-update-cmd, ...) aren't run targets
+        .filter(|(name, _)| !(name.starts_with("pre-") || name.starts_with("post-")))
+        .map(|(name, body)| {
+            let description = descriptions
+                .and_then(|d| d.get(name))
+                .and_then(|d| d.as_str())
+                .map(|s| s.to_string())
+                .or_else(|| match body {
+                    serde_json::Value::String(s) => Some(s.clone()),
+                    serde_json::Value::Array(items) => Some(
+                        items
+                            .iter()
+                            .filter_map(|i| i.as_str())
+                            .collect::<Vec<_>>()
+                            .join(" && "),
+                    ),
+                    _ => None,
+                });
+            script(name, "composer.json", "composer", &["run-script", name], description)
+        })
+        .collect()
+}
+
+pub fn discover_scripts_internal(dir: &Path) -> Vec<DiscoveredScript> {
+    let mut scripts = discover_package_json(dir);
+    scripts.extend(discover_cargo(dir));
+    scripts.extend(discover_makefile(dir));
+    scripts.extend(discover_composer(dir));
+    scripts
+}
+
+/// Discover the run targets a project exposes (package.json, Cargo.toml, Makefile, composer.json)
+/// along with the launcher command and args to run each one.
+#[tauri::command]
+pub async fn discover_scripts(path: String) -> Result<Vec<DiscoveredScript>, String> {
+    let dir = Path::new(&path);
+    if !dir.is_dir() {
+        return Err(format!("Not a directory: {}", path));
+    }
+    Ok(discover_scripts_internal(dir))
+}
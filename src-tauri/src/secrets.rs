@@ -0,0 +1,125 @@
+use keyring::Entry;
+
+/// Keychain service name all DevLaunch secrets are stored under
+const SERVICE: &str = "com.devlaunch.app";
+
+/// Config values starting with this prefix name a keychain entry instead of holding the secret
+pub const REFERENCE_PREFIX: &str = "keychain:";
+
+fn entry(key: &str) -> Result<Entry, String> {
+    Entry::new(SERVICE, key).map_err(|e| format!("Failed to open keychain entry: {}", e))
+}
+
+pub fn store_secret_internal(key: &str, value: &str) -> Result<(), String> {
+    entry(key)?
+        .set_password(value)
+        .map_err(|e| format!("Failed to store secret in keychain: {}", e))
+}
+
+pub fn get_secret_internal(key: &str) -> Result<Option<String>, String> {
+    match entry(key)?.get_password() {
+        Ok(value) => Ok(Some(value)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(format!("Failed to read secret from keychain: {}", e)),
+    }
+}
+
+pub fn delete_secret_internal(key: &str) -> Result<(), String> {
+    match entry(key)?.delete_password() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(format!("Failed to delete secret from keychain: {}", e)),
+    }
+}
+
+/// Config value referring to a keychain entry
+pub fn reference(key: &str) -> String {
+    format!("{}{}", REFERENCE_PREFIX, key)
+}
+
+/// Keychain key named by a config value, if it is a reference
+pub fn reference_key(value: &str) -> Option<&str> {
+    value.strip_prefix(REFERENCE_PREFIX)
+}
+
+/// Resolve a config value to the actual secret (plaintext values pass through)
+pub fn resolve(value: &str) -> String {
+    match reference_key(value) {
+        Some(key) => match get_secret_internal(key) {
+            Ok(Some(secret)) => secret,
+            Ok(None) => {
+                println!("[secrets] Keychain entry '{}' is missing", key);
+                String::new()
+            }
+            Err(e) => {
+                println!("[secrets] {}", e);
+                String::new()
+            }
+        },
+        None => value.to_string(),
+    }
+}
+
+/// Move a plaintext secret into the keychain, returning the reference to keep in config.
+/// Returns None if the value is empty, already a reference, or the keychain is unavailable
+/// (in which case the plaintext stays put rather than being lost).
+pub fn externalize(key: &str, value: &str) -> Option<String> {
+    if value.is_empty() || reference_key(value).is_some() {
+        return None;
+    }
+    match store_secret_internal(key, value) {
+        Ok(()) => Some(reference(key)),
+        Err(e) => {
+            println!("[secrets] Keeping '{}' in config: {}", key, e);
+            None
+        }
+    }
+}
+
+/// Compare a provided token with the expected one in constant time, so it can't be guessed
+/// byte by byte from response timing
+pub fn tokens_match(provided: &str, expected: &str) -> bool {
+    provided.len() == expected.len()
+        && provided
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+/// Write a file only the user can read (tokens, private keys). The permissions are set before
+/// any content goes in, so the secret is never briefly readable by others.
+pub fn write_private_file(path: &std::path::Path, content: &str) -> Result<(), String> {
+    use std::io::Write;
+    let fail = |e: std::io::Error| format!("Failed to write {}: {}", path.display(), e);
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        let file = options.open(path).map_err(fail)?;
+        // The mode only applies to new files; tighten one left by an older build too
+        file.set_permissions(std::fs::Permissions::from_mode(0o600)).map_err(fail)?;
+        (&file).write_all(content.as_bytes()).map_err(fail)
+    }
+    #[cfg(not(unix))]
+    {
+        options.open(path).and_then(|mut f| f.write_all(content.as_bytes())).map_err(fail)
+    }
+}
+
+#[tauri::command]
+pub fn store_secret(key: String, value: String) -> Result<String, String> {
+    store_secret_internal(&key, &value)?;
+    Ok(reference(&key))
+}
+
+#[tauri::command]
+pub fn get_secret(key: String) -> Result<Option<String>, String> {
+    get_secret_internal(reference_key(&key).unwrap_or(&key))
+}
+
+#[tauri::command]
+pub fn delete_secret(key: String) -> Result<(), String> {
+    delete_secret_internal(reference_key(&key).unwrap_or(&key))
+}
@@ -0,0 +1,125 @@
+use crate::config::{self, LastSession, PresetProject};
+use crate::presets::{PresetProjectResult, PresetSummary};
+use tauri::AppHandle;
+
+/// Progress/summary id for restore runs in preset-progress and preset-complete events
+const RESTORE_ID: &str = "last-session";
+
+/// Script name shown for a spawn in restore results (the exact command is what gets relaunched)
+pub(crate) fn script_for_command(command: &str, args: &[String]) -> String {
+    // `env KEY=value <command>` from spawn_shell
+    if command == "env" {
+        let assignments = args.iter().take_while(|a| a.contains('=')).count();
+        if let Some((inner, inner_args)) = args[assignments..].split_first() {
+            return script_for_command(inner, inner_args);
+        }
+    }
+    match args {
+        // `sh -c <line>` / `cmd /C <line>` from spawn_shell
+        [flag, line] if flag == "-c" || flag.eq_ignore_ascii_case("/c") => line.clone(),
+        [run, script, ..] if run == "run" => script.clone(),
+        [script, ..] if command.starts_with("python") => script.clone(),
+        _ => args.first().cloned().unwrap_or_else(|| command.to_string()),
+    }
+}
+
+// Presets record projects from parallel tasks, so this goes through update_config
+fn update(app: &AppHandle, change: impl FnOnce(&mut Vec<PresetProject>)) {
+    let updated = config::update_config(app, |config| {
+        let mut projects = config.last_session.take().map(|s| s.running_projects).unwrap_or_default();
+        change(&mut projects);
+        config.last_session = Some(LastSession {
+            running_projects: projects,
+        });
+        Ok(())
+    });
+    if let Err(e) = updated {
+        println!("[session] Failed to record session: {}", e);
+    }
+}
+
+/// Record a user-launched project as part of the running session, with the exact command it
+/// was spawned with
+pub(crate) fn remember(app: &AppHandle, path: &str, script: &str, command: &str, args: &[String]) {
+    let recorded = PresetProject {
+        path: path.to_string(),
+        script: script.to_string(),
+        command: Some(command.to_string()),
+        args: args.to_vec(),
+        ..Default::default()
+    };
+    update(app, |projects| match projects.iter_mut().find(|p| p.path == path) {
+        Some(project) => *project = recorded,
+        None => projects.push(recorded),
+    });
+}
+
+/// Drop a project the user stopped; quitting leaves the session intact for the next launch
+pub(crate) fn forget(app: &AppHandle, path: &str) {
+    update(app, |projects| projects.retain(|p| p.path != path));
+}
+
+fn not_launched(project: PresetProject, status: &str, reason: &str) -> PresetProjectResult {
+    PresetProjectResult {
+        path: project.path,
+        script: project.script,
+        status: status.to_string(),
+        pid: None,
+        error: Some(reason.to_string()),
+    }
+}
+
+async fn restore(app: &AppHandle) -> Result<PresetSummary, String> {
+    let projects = config::load_config_internal(app)?
+        .last_session
+        .map(|s| s.running_projects)
+        .unwrap_or_default();
+    println!("[session] Restoring {} project(s) from the last session", projects.len());
+
+    let running: Vec<String> = crate::process::running_processes().into_iter().map(|(_, path)| path).collect();
+    let mut decided = Vec::new();
+    let mut launch = Vec::new();
+    for project in projects {
+        if running.contains(&project.path) {
+            decided.push(not_launched(project, "skipped", "Already running"));
+        } else if crate::orphans::is_orphaned(&project.path) {
+            decided.push(not_launched(project, "skipped", "Still running from the previous session"));
+        } else if !std::path::Path::new(&project.path).is_dir() {
+            decided.push(not_launched(project, "failed", "Project folder no longer exists"));
+        } else {
+            launch.push(project);
+        }
+    }
+
+    Ok(crate::presets::launch_projects(
+        app,
+        RESTORE_ID.to_string(),
+        launch,
+        decided,
+        crate::presets::DEFAULT_CONCURRENCY,
+    )
+    .await)
+}
+
+/// Relaunch the projects that were running when DevLaunch last quit, with their scripts.
+/// Projects already running, or left running by a crashed session, are skipped.
+#[tauri::command]
+pub async fn restore_last_session(app: AppHandle) -> Result<PresetSummary, String> {
+    restore(&app).await
+}
+
+/// Restore the last session on startup when the preference is on
+pub fn restore_on_launch(app: &AppHandle) {
+    let enabled = config::load_config_internal(app)
+        .map(|c| c.preferences.restore_session_on_launch)
+        .unwrap_or(false);
+    if !enabled {
+        return;
+    }
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = restore(&app).await {
+            println!("[session] Failed to restore the last session: {}", e);
+        }
+    });
+}
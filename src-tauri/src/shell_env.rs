@@ -0,0 +1,288 @@
+use crate::config::{self, ShellEnvPreferences};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+#[cfg(unix)]
+const ENV_START_MARKER: &str = "__DEVLAUNCH_ENV_START__";
+#[cfg(unix)]
+const ENV_END_MARKER: &str = "__DEVLAUNCH_ENV_END__";
+
+/// How long to wait for the login shell (slow rc files shouldn't hang spawning)
+#[cfg(unix)]
+const SHELL_TIMEOUT_SECS: u64 = 10;
+
+lazy_static::lazy_static! {
+    // Cached login-shell environment, keyed by shell path
+    static ref LOGIN_ENV: Mutex<HashMap<String, HashMap<String, String>>> = Mutex::new(HashMap::new());
+}
+
+/// The user's login shell, from preferences override or $SHELL
+#[cfg(unix)]
+pub(crate) fn user_shell(prefs: &ShellEnvPreferences) -> String {
+    prefs
+        .shell
+        .clone()
+        .filter(|s| !s.is_empty())
+        .or_else(|| std::env::var("SHELL").ok())
+        .unwrap_or_else(|| "/bin/zsh".to_string())
+}
+
+/// Parse null-separated `env -0` output between our markers
+#[cfg(unix)]
+fn parse_env_output(output: &str) -> HashMap<String, String> {
+    let start = match output.find(ENV_START_MARKER) {
+        Some(i) => i + ENV_START_MARKER.len(),
+        None => return HashMap::new(),
+    };
+    let end = output[start..]
+        .find(ENV_END_MARKER)
+        .map(|i| start + i)
+        .unwrap_or(output.len());
+
+    output[start..end]
+        .split('\0')
+        .filter_map(|entry| {
+            let entry = entry.trim_start_matches('\n');
+            entry
+                .split_once('=')
+                // Shell bookkeeping variables would be wrong for the spawned command
+                .filter(|(key, _)| !key.is_empty() && !matches!(*key, "PWD" | "OLDPWD" | "SHLVL" | "_"))
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+        })
+        .collect()
+}
+
+/// Run the login shell once and capture its environment
+#[cfg(unix)]
+fn resolve_login_env(shell: &str) -> Result<HashMap<String, String>, String> {
+    use std::io::Read;
+    use std::process::Stdio;
+    use std::time::{Duration, Instant};
+
+    // Login shell so profile files (nvm, pyenv, asdf setup) run; markers skip any banner output.
+    // Not interactive: -i without a terminal hangs or prints job-control errors in bash and zsh.
+    let script = format!(
+        "printf '{}'; /usr/bin/env -0; printf '{}'",
+        ENV_START_MARKER, ENV_END_MARKER
+    );
+    let mut child = Command::new(shell)
+        .args(["-l", "-c", &script])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to run login shell {}: {}", shell, e))?;
+
+    let mut stdout = child.stdout.take().ok_or("Failed to capture shell output")?;
+    let reader = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stdout.read_to_end(&mut buf);
+        buf
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(SHELL_TIMEOUT_SECS);
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(50)),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("Login shell {} timed out", shell));
+            }
+        }
+    }
+
+    let output = reader.join().unwrap_or_default();
+    let env = parse_env_output(&String::from_utf8_lossy(&output));
+    if env.is_empty() {
+        Err(format!("Login shell {} returned no environment", shell))
+    } else {
+        Ok(env)
+    }
+}
+
+/// Get the login-shell environment, resolving it on first use
+pub fn login_env(prefs: &ShellEnvPreferences) -> HashMap<String, String> {
+    #[cfg(unix)]
+    {
+        let shell = user_shell(prefs);
+        if let Some(env) = LOGIN_ENV.lock().ok().and_then(|c| c.get(&shell).cloned()) {
+            return env;
+        }
+
+        match resolve_login_env(&shell) {
+            Ok(env) => {
+                println!("[shell_env] Resolved {} variables from {}", env.len(), shell);
+                if let Ok(mut cache) = LOGIN_ENV.lock() {
+                    cache.insert(shell, env.clone());
+                }
+                env
+            }
+            Err(e) => {
+                println!("[shell_env] {}", e);
+                HashMap::new()
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = prefs;
+        HashMap::new()
+    }
+}
+
+/// Environment spawned commands should receive: login shell env plus preference overrides
+pub fn spawn_env(app: &AppHandle) -> HashMap<String, String> {
+    let prefs = config::load_config_internal(app)
+        .map(|c| c.preferences.shell_env)
+        .unwrap_or_default();
+
+    let mut env = if prefs.use_login_shell {
+        login_env(&prefs)
+    } else {
+        HashMap::new()
+    };
+    env.extend(prefs.env.clone());
+    env
+}
+
+/// Per-project variables (app config and .devlaunch.json), applied on top of spawn_env
+pub fn project_env(app: &AppHandle, cwd: &str) -> HashMap<String, String> {
+    config::load_config_internal(app)
+        .map(|c| c.project_settings(cwd).env)
+        .unwrap_or_default()
+}
+
+/// PATH spawned commands search: the resolved environment's, else this process's
+pub fn spawn_path(app: &AppHandle) -> String {
+    spawn_env(app)
+        .remove("PATH")
+        .or_else(|| std::env::var("PATH").ok())
+        .unwrap_or_default()
+}
+
+/// Find an executable on a PATH string, like `which` (PATHEXT extensions on Windows)
+pub fn find_in_path(name: &str, path: &str) -> Option<PathBuf> {
+    let candidate = Path::new(name);
+    if candidate.components().count() > 1 {
+        return candidate.is_file().then(|| candidate.to_path_buf());
+    }
+    #[cfg(windows)]
+    let extensions: Vec<String> = std::iter::once(String::new())
+        .chain(
+            std::env::var("PATHEXT")
+                .unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string())
+                .split(';')
+                .map(|e| e.to_lowercase()),
+        )
+        .collect();
+    #[cfg(not(windows))]
+    let extensions = [String::new()];
+
+    std::env::split_paths(path).find_map(|dir| {
+        extensions
+            .iter()
+            .map(|ext| dir.join(format!("{}{}", name, ext)))
+            .find(|p| is_executable(p))
+    })
+}
+
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path)
+            .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedCommand {
+    pub command: String,
+    /// Full path of the executable, if found
+    pub path: Option<String>,
+    /// PATH directories searched, in order
+    pub searched: Vec<String>,
+    /// PATH came from the login shell rather than the app's own environment
+    pub from_login_shell: bool,
+}
+
+/// Look a command up the way spawned processes will find it
+pub fn resolve_command_internal(app: &AppHandle, command: &str) -> ResolvedCommand {
+    let from_login_shell = spawn_env(app).contains_key("PATH");
+    let path = spawn_path(app);
+    ResolvedCommand {
+        command: command.to_string(),
+        path: find_in_path(command, &path).map(|p| p.to_string_lossy().to_string()),
+        searched: std::env::split_paths(&path)
+            .map(|p| p.to_string_lossy().to_string())
+            .filter(|p| !p.is_empty())
+            .collect(),
+        from_login_shell,
+    }
+}
+
+/// Spawn failure message; a missing command lists the PATH that was searched
+pub fn spawn_error(app: &AppHandle, command: &str, error: impl std::fmt::Display) -> String {
+    let resolved = resolve_command_internal(app, command);
+    if resolved.path.is_some() {
+        return format!("Failed to spawn {}: {}", command, error);
+    }
+    let hint = if resolved.from_login_shell {
+        "If it was installed or added to your shell profile since launch, refresh the shell environment."
+    } else {
+        "Only the app's own PATH was searched; the login shell's environment is disabled or failed to load."
+    };
+    format!(
+        "Command not found: {}. Searched PATH: {}. {}",
+        command,
+        resolved.searched.join(if cfg!(windows) { ";" } else { ":" }),
+        hint
+    )
+}
+
+/// Apply the resolved environment to a command before spawning
+pub fn apply_to_command(app: &AppHandle, cmd: &mut Command) {
+    cmd.envs(spawn_env(app));
+}
+
+/// Prime the cache at startup so the first spawn doesn't pay for the login shell
+pub fn warm_cache(app: &AppHandle) {
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let _ = spawn_env(&app);
+    });
+}
+
+/// Get the environment spawned commands receive
+#[tauri::command]
+pub async fn get_shell_env(app: AppHandle) -> Result<HashMap<String, String>, String> {
+    Ok(spawn_env(&app))
+}
+
+/// Where a command resolves on the PATH spawned processes get, for diagnosing "not found"
+#[tauri::command]
+pub async fn resolve_command(app: AppHandle, command: String) -> Result<ResolvedCommand, String> {
+    Ok(resolve_command_internal(&app, &command))
+}
+
+/// Drop the cached login-shell environment (e.g. after editing ~/.zshrc)
+#[tauri::command]
+pub async fn refresh_shell_env(app: AppHandle) -> Result<HashMap<String, String>, String> {
+    if let Ok(mut cache) = LOGIN_ENV.lock() {
+        cache.clear();
+    }
+    Ok(spawn_env(&app))
+}
@@ -0,0 +1,149 @@
+use crate::config::ShortcutPreferences;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutEvent, ShortcutState};
+
+lazy_static::lazy_static! {
+    // Action for each registered shortcut, by shortcut id
+    static ref ACTIONS: Mutex<HashMap<u32, String>> = Mutex::new(HashMap::new());
+    // Outcome of the last registration, for the settings UI
+    static ref STATUS: Mutex<Vec<ShortcutStatus>> = Mutex::new(Vec::new());
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutStatus {
+    pub accelerator: String,
+    pub action: String,
+    pub registered: bool,
+    pub error: Option<String>,
+}
+
+fn valid_action(action: &str) -> bool {
+    match action {
+        "toggle-window" | "kill-all" => true,
+        _ => action
+            .strip_prefix("launch-preset:")
+            .and_then(|n| n.parse::<usize>().ok())
+            .map(|n| n > 0)
+            .unwrap_or(false),
+    }
+}
+
+/// Replace the registered shortcuts with the configured ones (none when disabled)
+pub fn apply(app: &AppHandle, preferences: &ShortcutPreferences) {
+    let shortcuts = app.global_shortcut();
+    if let Err(e) = shortcuts.unregister_all() {
+        println!("[shortcuts] Failed to unregister shortcuts: {}", e);
+    }
+    let mut actions = HashMap::new();
+    let mut status = Vec::new();
+
+    for binding in preferences.bindings.iter().filter(|_| preferences.enabled) {
+        let result = if valid_action(&binding.action) {
+            binding
+                .accelerator
+                .parse::<Shortcut>()
+                .map_err(|e| format!("Invalid shortcut: {}", e))
+                .and_then(|shortcut| {
+                    if actions.contains_key(&shortcut.id()) {
+                        return Err("Bound more than once".to_string());
+                    }
+                    // Fails when another app already holds the combination
+                    shortcuts.register(shortcut).map_err(|e| e.to_string())?;
+                    actions.insert(shortcut.id(), binding.action.clone());
+                    Ok(())
+                })
+        } else {
+            Err(format!("Unknown action: {}", binding.action))
+        };
+        if let Err(e) = &result {
+            println!("[shortcuts] {} ({}): {}", binding.accelerator, binding.action, e);
+        }
+        status.push(ShortcutStatus {
+            accelerator: binding.accelerator.clone(),
+            action: binding.action.clone(),
+            registered: result.is_ok(),
+            error: result.err(),
+        });
+    }
+
+    if preferences.enabled {
+        println!("[shortcuts] Registered {} shortcut(s)", actions.len());
+    }
+    if let Ok(mut registered) = ACTIONS.lock() {
+        *registered = actions;
+    }
+    if let Ok(mut last) = STATUS.lock() {
+        *last = status;
+    }
+}
+
+/// Register the configured shortcuts on startup
+pub fn start_from_preferences(app: &AppHandle) {
+    let preferences = crate::config::load_config_internal(app)
+        .map(|c| c.preferences.shortcuts)
+        .unwrap_or_default();
+    apply(app, &preferences);
+}
+
+fn toggle_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        if window.is_visible().unwrap_or(false) && window.is_focused().unwrap_or(false) {
+            let _ = window.hide();
+        } else {
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+    }
+}
+
+fn launch_preset(app: &AppHandle, number: usize) {
+    let preset = crate::config::load_config_internal(app)
+        .ok()
+        .and_then(|c| c.presets.into_iter().nth(number - 1));
+    let Some(preset) = preset else {
+        println!("[shortcuts] No preset {}", number);
+        return;
+    };
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = crate::presets::launch_preset_internal(&app, preset.id, true).await {
+            println!("[shortcuts] Failed to launch preset {}: {}", preset.name, e);
+        }
+    });
+}
+
+/// Global shortcut handler, installed with the plugin in lib.rs
+pub fn handle(app: &AppHandle, shortcut: &Shortcut, event: ShortcutEvent) {
+    if event.state != ShortcutState::Pressed {
+        return;
+    }
+    let Some(action) = ACTIONS.lock().ok().and_then(|a| a.get(&shortcut.id()).cloned()) else {
+        return;
+    };
+    match action.as_str() {
+        "toggle-window" => toggle_window(app),
+        "kill-all" => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = crate::process::kill_all_processes(app).await {
+                    println!("[shortcuts] Failed to stop processes: {}", e);
+                }
+            });
+        }
+        _ => {
+            if let Some(number) = action.strip_prefix("launch-preset:").and_then(|n| n.parse().ok()) {
+                launch_preset(app, number);
+            }
+        }
+    }
+}
+
+/// Configured shortcuts and whether each one could be registered
+#[tauri::command]
+pub async fn get_shortcuts() -> Result<Vec<ShortcutStatus>, String> {
+    STATUS.lock().map(|s| s.clone()).map_err(|e| e.to_string())
+}
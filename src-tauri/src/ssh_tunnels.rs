@@ -0,0 +1,322 @@
+use lazy_static::lazy_static;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::net::{TcpListener, TcpStream};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// How long ssh gets to open the local listener before the attempt counts as failed
+const CONNECT_TIMEOUT_SECS: u64 = 20;
+
+/// Reconnect backoff doubles from the first delay up to the cap
+const RECONNECT_DELAY_SECS: u64 = 1;
+const MAX_RECONNECT_DELAY_SECS: u64 = 30;
+
+/// A connection that stayed up this long resets the backoff
+const STABLE_AFTER_SECS: u64 = 60;
+
+lazy_static! {
+    // Forwards keyed by tunnel id; each has a supervisor thread that owns the ssh process
+    static ref TUNNELS: Mutex<HashMap<String, SshTunnel>> = Mutex::new(HashMap::new());
+}
+
+struct SshTunnel {
+    info: SshTunnelInfo,
+    stop: Arc<AtomicBool>,
+    /// Current ssh process, shared with the supervisor so it can be killed on quit
+    child: Arc<Mutex<Option<Child>>>,
+}
+
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SshTunnelInfo {
+    pub id: String,
+    /// ssh destination: "user@host" or a Host alias from ~/.ssh/config
+    pub host: String,
+    pub remote_host: String,
+    pub remote_port: u16,
+    pub local_port: u16,
+    pub key: Option<String>,
+    /// "connecting", "connected" or "reconnecting"
+    pub status: String,
+    pub reconnects: u32,
+    pub last_error: Option<String>,
+    /// PID of the current ssh process
+    pub pid: Option<u32>,
+    pub started_at: i64,
+}
+
+fn update(id: &str, change: impl FnOnce(&mut SshTunnelInfo)) -> Option<SshTunnelInfo> {
+    let mut tunnels = TUNNELS.lock().ok()?;
+    let tunnel = tunnels.get_mut(id)?;
+    change(&mut tunnel.info);
+    Some(tunnel.info.clone())
+}
+
+fn set_status(app: &AppHandle, id: &str, change: impl FnOnce(&mut SshTunnelInfo)) {
+    if let Some(info) = update(id, change) {
+        let _ = app.emit("ssh-tunnel-status", info);
+    }
+}
+
+fn ssh_command(info: &SshTunnelInfo) -> Command {
+    let mut cmd = Command::new("ssh");
+    cmd.args([
+        "-N",
+        "-L",
+        &format!("127.0.0.1:{}:{}:{}", info.local_port, info.remote_host, info.remote_port),
+        // Exit instead of lingering without the forward, and notice dead connections
+        "-o",
+        "ExitOnForwardFailure=yes",
+        "-o",
+        "ServerAliveInterval=15",
+        "-o",
+        "ServerAliveCountMax=3",
+        // There's no terminal to answer password or host-key prompts
+        "-o",
+        "BatchMode=yes",
+    ]);
+    if let Some(key) = &info.key {
+        cmd.arg("-i").arg(key);
+    }
+    cmd.arg(&info.host)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+    cmd
+}
+
+fn port_open(port: u16) -> bool {
+    TcpStream::connect_timeout(&([127, 0, 0, 1], port).into(), Duration::from_millis(200)).is_ok()
+}
+
+/// Run one ssh connection until it exits or the tunnel is stopped
+fn run_once(
+    app: &AppHandle,
+    id: &str,
+    info: &SshTunnelInfo,
+    stop: &AtomicBool,
+    slot: &Mutex<Option<Child>>,
+) -> Result<(), String> {
+    let mut child = ssh_command(info)
+        .spawn()
+        .map_err(|e| format!("Failed to start ssh: {}", e))?;
+    println!("[ssh_tunnels] ssh for {} started with PID {}", id, child.id());
+    let pid = child.id();
+    set_status(app, id, |t| t.pid = Some(pid));
+
+    // Keep the last thing ssh complained about as the error to show
+    let last_line = Arc::new(Mutex::new(String::new()));
+    if let Some(stderr) = child.stderr.take() {
+        let last_line = last_line.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                let line = line.trim().to_string();
+                if !line.is_empty() {
+                    if let Ok(mut last) = last_line.lock() {
+                        *last = line;
+                    }
+                }
+            }
+        });
+    }
+    *slot.lock().map_err(|e| e.to_string())? = Some(child);
+    let kill = || {
+        if let Some(mut child) = slot.lock().ok().and_then(|mut c| c.take()) {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    };
+
+    let started = Instant::now();
+    let mut connected = false;
+    loop {
+        if stop.load(Ordering::SeqCst) {
+            kill();
+            return Ok(());
+        }
+        let exited = match slot.lock().map_err(|e| e.to_string())?.as_mut() {
+            Some(child) => child.try_wait().ok().flatten(),
+            // Taken by stop_all
+            None => return Ok(()),
+        };
+        if let Some(status) = exited {
+            *slot.lock().map_err(|e| e.to_string())? = None;
+            let detail = last_line.lock().map(|l| l.clone()).unwrap_or_default();
+            let reason = if detail.is_empty() {
+                format!("ssh exited with {}", status)
+            } else {
+                detail
+            };
+            // Not a failure worth backing off from if the link was healthy for a while
+            if connected && started.elapsed() >= Duration::from_secs(STABLE_AFTER_SECS) {
+                return Ok(());
+            }
+            return Err(reason);
+        }
+        if !connected {
+            if port_open(info.local_port) {
+                connected = true;
+                println!("[ssh_tunnels] {} forwarding localhost:{}", id, info.local_port);
+                set_status(app, id, |t| {
+                    t.status = "connected".to_string();
+                    t.last_error = None;
+                });
+            } else if started.elapsed() >= Duration::from_secs(CONNECT_TIMEOUT_SECS) {
+                kill();
+                return Err("Timed out waiting for the forward to open".to_string());
+            }
+        }
+        std::thread::sleep(Duration::from_millis(500));
+    }
+}
+
+/// Keep a tunnel's ssh process running until it's stopped, reconnecting with backoff
+fn supervise(app: AppHandle, id: String, stop: Arc<AtomicBool>, slot: Arc<Mutex<Option<Child>>>) {
+    let mut delay = RECONNECT_DELAY_SECS;
+    loop {
+        let Some(info) = TUNNELS.lock().ok().and_then(|t| t.get(&id).map(|t| t.info.clone())) else {
+            return;
+        };
+        let result = run_once(&app, &id, &info, &stop, &slot);
+        if stop.load(Ordering::SeqCst) {
+            return;
+        }
+
+        match &result {
+            Ok(()) => delay = RECONNECT_DELAY_SECS,
+            Err(e) => println!("[ssh_tunnels] {} dropped: {}", id, e),
+        }
+        set_status(&app, &id, |t| {
+            t.status = "reconnecting".to_string();
+            t.pid = None;
+            t.reconnects += 1;
+            t.last_error = result.err();
+        });
+
+        // Sleep in short steps so a stop doesn't wait out the backoff
+        let resume = Instant::now() + Duration::from_secs(delay);
+        while Instant::now() < resume {
+            if stop.load(Ordering::SeqCst) {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(250));
+        }
+        delay = (delay * 2).min(MAX_RECONNECT_DELAY_SECS);
+    }
+}
+
+/// Forward a local port to a port reachable from an SSH host, e.g. a staging database.
+/// `remote_host` defaults to "localhost" (the SSH host itself); `key` is an identity file.
+#[tauri::command]
+pub fn create_ssh_tunnel(
+    app: AppHandle,
+    host: String,
+    remote_port: u16,
+    local_port: u16,
+    key: Option<String>,
+    remote_host: Option<String>,
+) -> Result<SshTunnelInfo, String> {
+    let host = host.trim().to_string();
+    // Anything starting with '-' would be read as an ssh option
+    if host.is_empty() || host.starts_with('-') || host.contains(char::is_whitespace) {
+        return Err(format!("Invalid SSH host: {}", host));
+    }
+    let remote_host = remote_host
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty())
+        .unwrap_or_else(|| "localhost".to_string());
+    if remote_host.contains(|c: char| c == ':' || c.is_whitespace()) {
+        return Err(format!("Invalid remote host: {}", remote_host));
+    }
+    let key = key.map(|k| k.trim().to_string()).filter(|k| !k.is_empty());
+    if let Some(key) = &key {
+        if !std::path::Path::new(key).is_file() {
+            return Err(format!("Key file not found: {}", key));
+        }
+    }
+
+    let mut tunnels = TUNNELS.lock().map_err(|e| e.to_string())?;
+    if let Some(existing) = tunnels.values().find(|t| t.info.local_port == local_port) {
+        return Err(format!(
+            "Port {} is already forwarded to {}",
+            local_port, existing.info.host
+        ));
+    }
+    // Bind briefly to make sure nothing else holds the port
+    TcpListener::bind(("127.0.0.1", local_port)).map_err(|_| format!("Port {} is already in use", local_port))?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let info = SshTunnelInfo {
+        id: id.clone(),
+        host,
+        remote_host,
+        remote_port,
+        local_port,
+        key,
+        status: "connecting".to_string(),
+        reconnects: 0,
+        last_error: None,
+        pid: None,
+        started_at: chrono::Utc::now().timestamp_millis(),
+    };
+    let stop = Arc::new(AtomicBool::new(false));
+    let child = Arc::new(Mutex::new(None));
+    tunnels.insert(
+        id.clone(),
+        SshTunnel {
+            info: info.clone(),
+            stop: stop.clone(),
+            child: child.clone(),
+        },
+    );
+    drop(tunnels);
+
+    println!(
+        "[ssh_tunnels] Forwarding localhost:{} to {}:{} via {}",
+        info.local_port, info.remote_host, info.remote_port, info.host
+    );
+    std::thread::spawn(move || supervise(app, id, stop, child));
+    Ok(info)
+}
+
+#[tauri::command]
+pub fn list_ssh_tunnels() -> Vec<SshTunnelInfo> {
+    let mut tunnels: Vec<SshTunnelInfo> = TUNNELS
+        .lock()
+        .map(|t| t.values().map(|t| t.info.clone()).collect())
+        .unwrap_or_default();
+    tunnels.sort_by_key(|t| t.local_port);
+    tunnels
+}
+
+/// Close a forward; its supervisor kills ssh and stops reconnecting
+#[tauri::command]
+pub fn stop_ssh_tunnel(id: String) -> Result<(), String> {
+    let tunnel = TUNNELS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(&id)
+        .ok_or_else(|| format!("SSH tunnel {} is not running", id))?;
+    tunnel.stop.store(true, Ordering::SeqCst);
+    println!("[ssh_tunnels] Closed localhost:{}", tunnel.info.local_port);
+    Ok(())
+}
+
+/// Close every forward, killing ssh directly since supervisors may not get to run before exit
+pub fn stop_all() {
+    let Ok(mut tunnels) = TUNNELS.lock() else {
+        return;
+    };
+    for (_, tunnel) in tunnels.drain() {
+        tunnel.stop.store(true, Ordering::SeqCst);
+        if let Some(mut child) = tunnel.child.lock().ok().and_then(|mut c| c.take()) {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
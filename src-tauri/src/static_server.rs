@@ -0,0 +1,354 @@
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{header, HeaderValue, Method, StatusCode},
+    response::{IntoResponse, Response},
+    routing::any,
+    Router,
+};
+use lazy_static::lazy_static;
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    net::{Ipv4Addr, SocketAddr},
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+use tokio::sync::{mpsc, Mutex};
+
+/// Build output folders tried (in order) when a project root is passed instead of the output folder
+const BUILD_DIRS: &[&str] = &["dist", "build", "out", "public", "_site"];
+
+/// Files that mark a project root, which holds sources and secrets and is never served itself
+const PROJECT_MARKERS: &[&str] = &[".git", ".env", "package.json", "Cargo.toml", "go.mod", "pyproject.toml"];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaticServerInfo {
+    pub port: u16,
+    pub url: String,
+    /// Folder actually being served (may be a build folder inside the requested path)
+    pub root: String,
+    pub spa_fallback: bool,
+    /// Reachable from other devices on the network, not just this machine
+    pub lan: bool,
+    pub started_at: String,
+}
+
+struct StaticState {
+    root: PathBuf,
+    spa_fallback: bool,
+}
+
+struct StaticServer {
+    shutdown_tx: mpsc::Sender<()>,
+    info: StaticServerInfo,
+}
+
+lazy_static! {
+    // Running preview servers keyed by port
+    static ref SERVERS: Arc<Mutex<HashMap<u16, StaticServer>>> = Arc::new(Mutex::new(HashMap::new()));
+}
+
+/// Serve `path` itself if it has an index.html and isn't a project root, otherwise its first
+/// build folder that does
+fn resolve_root(path: &Path) -> Result<PathBuf, String> {
+    let is_project_root = PROJECT_MARKERS.iter().any(|marker| path.join(marker).exists());
+    if !is_project_root && path.join("index.html").is_file() {
+        return Ok(path.to_path_buf());
+    }
+    BUILD_DIRS
+        .iter()
+        .map(|dir| path.join(dir))
+        .find(|dir| dir.join("index.html").is_file())
+        .ok_or_else(|| {
+            format!(
+                "No build output in {} (looked for index.html in {}); build the project or pass its output folder",
+                path.display(),
+                BUILD_DIRS.join(", ")
+            )
+        })
+}
+
+fn content_type(path: &Path) -> &'static str {
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" | "mjs" | "cjs" => "text/javascript; charset=utf-8",
+        "json" | "map" => "application/json",
+        "webmanifest" => "application/manifest+json",
+        "txt" | "md" => "text/plain; charset=utf-8",
+        "xml" => "application/xml",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "ico" => "image/x-icon",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "wasm" => "application/wasm",
+        "pdf" => "application/pdf",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Decode %XX escapes in a URL path (invalid escapes are kept as-is)
+pub(crate) fn percent_decode(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Ok(byte) = u8::from_str_radix(std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or(""), 16) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+/// Map a request path onto the served folder, refusing dotfiles and anything that climbs out
+/// of it, including through symlinks. `root` must be canonical.
+fn file_path(root: &Path, request_path: &str) -> Option<PathBuf> {
+    let decoded = percent_decode(request_path);
+    let mut path = root.to_path_buf();
+    for component in Path::new(decoded.trim_start_matches('/')).components() {
+        match component {
+            Component::Normal(part) if !part.to_string_lossy().starts_with('.') => path.push(part),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    match path.canonicalize() {
+        Ok(real) if real.starts_with(root) => Some(real),
+        Ok(_) => None,
+        // Nothing there to follow; the request 404s or falls back to index.html
+        Err(_) => Some(path),
+    }
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn directory_listing(dir: &Path, request_path: &str) -> Response {
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return StatusCode::FORBIDDEN.into_response();
+    };
+
+    let mut entries: Vec<(String, bool)> = read_dir
+        .flatten()
+        .map(|e| {
+            let is_dir = e.path().is_dir();
+            (e.file_name().to_string_lossy().to_string(), is_dir)
+        })
+        .filter(|(name, _)| !name.starts_with('.'))
+        .collect();
+    // Folders first, then files, each alphabetically
+    entries.sort_by_key(|(name, is_dir)| (!*is_dir, name.to_lowercase()));
+
+    let base = if request_path.ends_with('/') {
+        request_path.to_string()
+    } else {
+        format!("{}/", request_path)
+    };
+    let title = html_escape(&base);
+
+    let mut html = format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>Index of {0}</title>\
+         <style>body{{font-family:ui-monospace,monospace;padding:1rem 2rem}}a{{text-decoration:none}}li{{padding:2px 0}}</style>\
+         </head><body><h1>Index of {0}</h1><ul>",
+        title
+    );
+    if base != "/" {
+        html.push_str("<li><a href=\"../\">../</a></li>");
+    }
+    for (name, is_dir) in entries {
+        let suffix = if is_dir { "/" } else { "" };
+        let name = html_escape(&name);
+        html.push_str(&format!("<li><a href=\"{0}{1}\">{0}{1}</a></li>", name, suffix));
+    }
+    html.push_str("</ul></body></html>");
+
+    Response::builder()
+        .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+        .body(Body::from(html))
+        .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response())
+}
+
+async fn file_response(path: &Path, head_only: bool) -> Response {
+    match tokio::fs::read(path).await {
+        Ok(bytes) => {
+            let len = bytes.len();
+            let body = if head_only { Body::empty() } else { Body::from(bytes) };
+            Response::builder()
+                .header(header::CONTENT_TYPE, HeaderValue::from_static(content_type(path)))
+                .header(header::CONTENT_LENGTH, len)
+                // Previews should always reflect the latest build
+                .header(header::CACHE_CONTROL, "no-cache")
+                .body(body)
+                .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response())
+        }
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Requests that look like client-side routes (no file extension) get index.html in SPA mode
+fn is_route_like(request_path: &str) -> bool {
+    request_path
+        .rsplit('/')
+        .next()
+        .map(|last| !last.contains('.'))
+        .unwrap_or(true)
+}
+
+async fn handle_static(State(state): State<Arc<StaticState>>, request: Request) -> Response {
+    let method = request.method().clone();
+    if method != Method::GET && method != Method::HEAD {
+        return StatusCode::METHOD_NOT_ALLOWED.into_response();
+    }
+    let head_only = method == Method::HEAD;
+    let request_path = request.uri().path().to_string();
+
+    let Some(path) = file_path(&state.root, &request_path) else {
+        return StatusCode::FORBIDDEN.into_response();
+    };
+
+    if path.is_dir() {
+        // Relative links in index.html and listings need the trailing slash
+        if !request_path.ends_with('/') {
+            return Response::builder()
+                .status(StatusCode::MOVED_PERMANENTLY)
+                .header(header::LOCATION, format!("{}/", request_path))
+                .body(Body::empty())
+                .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response());
+        }
+        let index = path.join("index.html");
+        if index.is_file() {
+            return file_response(&index, head_only).await;
+        }
+        return directory_listing(&path, &request_path);
+    }
+
+    if path.is_file() {
+        return file_response(&path, head_only).await;
+    }
+
+    if state.spa_fallback && is_route_like(&request_path) {
+        let index = state.root.join("index.html");
+        if index.is_file() {
+            return file_response(&index, head_only).await;
+        }
+    }
+
+    StatusCode::NOT_FOUND.into_response()
+}
+
+/// Serve a build output folder over HTTP (port 0 picks a free port). Only this machine can
+/// connect unless `lan` is set.
+#[tauri::command]
+pub async fn serve_static(
+    path: String,
+    port: u16,
+    spa_fallback: Option<bool>,
+    lan: Option<bool>,
+) -> Result<StaticServerInfo, String> {
+    let requested = PathBuf::from(&path);
+    if !requested.is_dir() {
+        return Err(format!("Folder not found: {}", path));
+    }
+    let root = resolve_root(&requested)?
+        .canonicalize()
+        .map_err(|e| format!("Failed to open {}: {}", path, e))?;
+    let spa_fallback = spa_fallback.unwrap_or(false);
+    let lan = lan.unwrap_or(false);
+
+    let mut servers = SERVERS.lock().await;
+    if port != 0 && servers.contains_key(&port) {
+        return Err(format!("A preview server is already running on port {}", port));
+    }
+
+    let ip = if lan { Ipv4Addr::UNSPECIFIED } else { Ipv4Addr::LOCALHOST };
+    let addr = SocketAddr::from((ip, port));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(|e| format!("Failed to bind to port {}: {}", port, e))?;
+    let actual_port = listener.local_addr().map_err(|e| e.to_string())?.port();
+
+    let state = Arc::new(StaticState {
+        root: root.clone(),
+        spa_fallback,
+    });
+    let router = Router::new()
+        .fallback(any(handle_static))
+        .with_state(state);
+
+    let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
+    tokio::spawn(async move {
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async move {
+                shutdown_rx.recv().await;
+            })
+            .await
+            .ok();
+    });
+
+    let info = StaticServerInfo {
+        port: actual_port,
+        url: format!("http://localhost:{}", actual_port),
+        root: root.to_string_lossy().to_string(),
+        spa_fallback,
+        lan,
+        started_at: chrono::Utc::now().to_rfc3339(),
+    };
+    println!("[static_server] Serving {} at {}", info.root, info.url);
+
+    servers.insert(
+        actual_port,
+        StaticServer {
+            shutdown_tx,
+            info: info.clone(),
+        },
+    );
+    Ok(info)
+}
+
+#[tauri::command]
+pub async fn stop_static_server(port: u16) -> Result<(), String> {
+    let mut servers = SERVERS.lock().await;
+    match servers.remove(&port) {
+        Some(server) => {
+            let _ = server.shutdown_tx.send(()).await;
+            println!("[static_server] Stopped server on port {}", port);
+            Ok(())
+        }
+        None => Err(format!("No preview server is running on port {}", port)),
+    }
+}
+
+#[tauri::command]
+pub async fn get_static_servers() -> Result<Vec<StaticServerInfo>, String> {
+    let servers = SERVERS.lock().await;
+    let mut list: Vec<StaticServerInfo> = servers.values().map(|s| s.info.clone()).collect();
+    list.sort_by_key(|s| s.port);
+    Ok(list)
+}
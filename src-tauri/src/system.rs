@@ -1,5 +1,20 @@
 use serde::Serialize;
+#[cfg(target_os = "macos")]
 use std::process::Command;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use sysinfo::{Disks, Networks, Pid, System, Users};
+use tauri::{AppHandle, Emitter};
+
+/// Interval between process-metrics events for tracked dev servers
+const PROCESS_METRICS_INTERVAL_SECS: u64 = 5;
+
+lazy_static::lazy_static! {
+    // Kept between calls so CPU usage is measured over the interval since the last refresh
+    static ref SYSTEM: Mutex<System> = Mutex::new(System::new());
+    // Same for network throughput: byte deltas are measured since the previous refresh
+    static ref NETWORKS: Mutex<Option<(Networks, std::time::Instant)>> = Mutex::new(None);
+}
 
 #[derive(Debug, Serialize, Clone)]
 pub struct ProcessInfo {
@@ -10,8 +25,9 @@ pub struct ProcessInfo {
     pub memory_mb: f32,
     pub memory_percent: f32,
     pub vsz_mb: f32,
-    pub private_mb: f32,
-    pub shared_mb: f32,
+    /// Resident memory split into private and shared pages, where the platform reports it
+    pub private_mb: Option<f32>,
+    pub shared_mb: Option<f32>,
     pub user: String,
     pub state: String,
     pub elapsed: String,
@@ -25,16 +41,32 @@ pub struct SystemInfo {
     pub gpu: Option<GpuInfo>,
     pub uptime: String,
     pub load_average: Vec<f32>,
+    /// None on machines without a battery
+    pub battery: Option<crate::power::BatteryInfo>,
+    pub thermal: Option<crate::power::ThermalInfo>,
 }
 
 #[derive(Debug, Serialize, Clone)]
 pub struct CpuInfo {
     pub usage_percent: f32,
-    pub user_percent: f32,
-    pub system_percent: f32,
+    /// User/kernel split of the usage, where the platform reports it
+    pub user_percent: Option<f32>,
+    pub system_percent: Option<f32>,
     pub idle_percent: f32,
     pub core_count: u32,
     pub model: String,
+    /// Per logical core, in OS order
+    pub cores: Vec<CoreUsage>,
+    /// Highest single-core usage (spots one pegged core behind a low average)
+    pub max_core_percent: f32,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CoreUsage {
+    pub index: u32,
+    pub usage_percent: f32,
+    /// Current clock in MHz, where the platform reports it
+    pub frequency_mhz: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -65,206 +97,254 @@ pub struct GpuInfo {
     pub name: String,
     pub vendor: String,
     pub vram_mb: Option<u32>,
+    /// Live utilization from the IOAccelerator performance statistics (macOS).
+    /// ANE usage is only exposed through `sudo powermetrics`, so it isn't reported.
+    pub utilization_percent: Option<f32>,
+    pub renderer_utilization_percent: Option<f32>,
+    pub tiler_utilization_percent: Option<f32>,
+    /// Unified memory currently held by the GPU
+    pub in_use_memory_mb: Option<u32>,
 }
 
 /// Get comprehensive system information
 #[tauri::command]
 pub async fn get_system_info() -> Result<SystemInfo, String> {
+    let cpu = get_cpu_info()?;
+    let memory = get_memory_info()?;
+    let disk = get_disk_info()?;
     #[cfg(target_os = "macos")]
-    {
-        let cpu = get_cpu_info_macos()?;
-        let memory = get_memory_info_macos()?;
-        let disk = get_disk_info_macos()?;
-        let gpu = get_gpu_info_macos().ok();
-        let uptime = get_uptime_macos()?;
-        let load_average = get_load_average_macos()?;
-
-        Ok(SystemInfo {
-            cpu,
-            memory,
-            disk,
-            gpu,
-            uptime,
-            load_average,
-        })
-    }
-
+    let gpu = gpu_identity_macos().map(|mut gpu| {
+        apply_gpu_utilization_macos(&mut gpu);
+        gpu
+    });
     #[cfg(not(target_os = "macos"))]
-    {
-        Err("System monitoring only supported on macOS".to_string())
-    }
-}
+    let gpu = None;
+    let uptime = format_uptime(System::uptime());
+    let load = System::load_average();
+    let load_average = vec![load.one as f32, load.five as f32, load.fifteen as f32];
 
-#[cfg(target_os = "macos")]
-fn get_cpu_info_macos() -> Result<CpuInfo, String> {
-    // Get CPU model
-    let model_output = Command::new("sysctl")
-        .args(["-n", "machdep.cpu.brand_string"])
-        .output()
-        .map_err(|e| e.to_string())?;
-    let model = String::from_utf8_lossy(&model_output.stdout).trim().to_string();
+    Ok(SystemInfo {
+        cpu,
+        memory,
+        disk,
+        gpu,
+        uptime,
+        load_average,
+        battery: crate::power::battery_info(),
+        thermal: crate::power::thermal_info(),
+    })
+}
 
-    // Get core count
-    let cores_output = Command::new("sysctl")
-        .args(["-n", "hw.ncpu"])
-        .output()
-        .map_err(|e| e.to_string())?;
-    let core_count: u32 = String::from_utf8_lossy(&cores_output.stdout)
-        .trim()
-        .parse()
-        .unwrap_or(1);
-
-    // Get CPU usage from top
-    let top_output = Command::new("top")
-        .args(["-l", "1", "-n", "0", "-stats", "cpu"])
-        .output()
-        .map_err(|e| e.to_string())?;
-    let top_str = String::from_utf8_lossy(&top_output.stdout);
-
-    let mut user_percent = 0.0f32;
-    let mut system_percent = 0.0f32;
-    let mut idle_percent = 100.0f32;
-
-    for line in top_str.lines() {
-        if line.contains("CPU usage:") {
-            // Parse "CPU usage: 5.26% user, 10.52% sys, 84.21% idle"
-            let parts: Vec<&str> = line.split(',').collect();
-            for part in parts {
-                let trimmed = part.trim();
-                if trimmed.contains("user") {
-                    if let Some(val) = trimmed.split('%').next() {
-                        user_percent = val.split_whitespace().last()
-                            .and_then(|s| s.parse().ok())
-                            .unwrap_or(0.0);
-                    }
-                } else if trimmed.contains("sys") {
-                    if let Some(val) = trimmed.split('%').next() {
-                        system_percent = val.split_whitespace().last()
-                            .and_then(|s| s.parse().ok())
-                            .unwrap_or(0.0);
-                    }
-                } else if trimmed.contains("idle") {
-                    if let Some(val) = trimmed.split('%').next() {
-                        idle_percent = val.split_whitespace().last()
-                            .and_then(|s| s.parse().ok())
-                            .unwrap_or(100.0);
-                    }
-                }
-            }
-            break;
+/// Take the first CPU and per-process sample in the background, so the first real query
+/// measures usage over the time since startup instead of reporting zero
+pub fn prime_cpu_samples() {
+    std::thread::spawn(|| {
+        if let Ok(mut sys) = SYSTEM.lock() {
+            sys.refresh_cpu();
+            sys.refresh_processes();
         }
-    }
+        // Lock released while waiting, so callers aren't held up
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        if let Ok(mut sys) = SYSTEM.lock() {
+            sys.refresh_cpu();
+            sys.refresh_processes();
+        }
+    });
+}
+
+pub(crate) fn get_cpu_info() -> Result<CpuInfo, String> {
+    // Outside the lock: on macOS this runs `top`
+    let split = cpu_split();
+    let mut sys = SYSTEM.lock().map_err(|e| e.to_string())?;
+    sys.refresh_cpu();
+
+    let usage_percent = sys.global_cpu_info().cpu_usage();
+    let model = sys
+        .cpus()
+        .first()
+        .map(|c| c.brand().trim().to_string())
+        .unwrap_or_default();
+
+    let cores: Vec<CoreUsage> = sys
+        .cpus()
+        .iter()
+        .enumerate()
+        .map(|(i, c)| CoreUsage {
+            index: i as u32,
+            usage_percent: c.cpu_usage(),
+            frequency_mhz: Some(c.frequency()).filter(|f| *f > 0),
+        })
+        .collect();
+    let max_core_percent = cores.iter().map(|c| c.usage_percent).fold(0.0, f32::max);
 
     Ok(CpuInfo {
-        usage_percent: user_percent + system_percent,
-        user_percent,
-        system_percent,
-        idle_percent,
-        core_count,
+        usage_percent,
+        user_percent: split.map(|(user, _)| user),
+        system_percent: split.map(|(_, system)| system),
+        idle_percent: (100.0 - usage_percent).max(0.0),
+        core_count: sys.cpus().len() as u32,
         model,
+        cores,
+        max_core_percent,
     })
 }
 
+/// User and system CPU percent from `top` (sysinfo only reports the total)
 #[cfg(target_os = "macos")]
-fn get_memory_info_macos() -> Result<MemoryInfo, String> {
-    // Get total memory
-    let total_output = Command::new("sysctl")
-        .args(["-n", "hw.memsize"])
-        .output()
-        .map_err(|e| e.to_string())?;
-    let total_bytes: u64 = String::from_utf8_lossy(&total_output.stdout)
-        .trim()
-        .parse()
-        .unwrap_or(0);
-    let total_gb = total_bytes as f32 / 1024.0 / 1024.0 / 1024.0;
-
-    // Get memory stats from vm_stat
-    let vm_output = Command::new("vm_stat")
-        .output()
-        .map_err(|e| e.to_string())?;
-    let vm_str = String::from_utf8_lossy(&vm_output.stdout);
-
-    let page_size: u64 = 16384; // macOS page size
-    let mut pages_free: u64 = 0;
-    let mut pages_active: u64 = 0;
-    let mut pages_inactive: u64 = 0;
-    let mut pages_wired: u64 = 0;
-    let mut pages_compressed: u64 = 0;
-    let mut pages_file_backed: u64 = 0;
-    let mut _pages_purgeable: u64 = 0;
-    let mut _pages_speculative: u64 = 0;
+fn cpu_split() -> Option<(f32, f32)> {
+    let output = Command::new("top").args(["-l", "1", "-n", "0", "-stats", "cpu"]).output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    // "CPU usage: 5.26% user, 10.52% sys, 84.21% idle"
+    let line = text.lines().find(|l| l.contains("CPU usage:"))?;
+    let percent = |label: &str| {
+        line.split(',')
+            .find(|part| part.contains(label))
+            .and_then(|part| part.split('%').next())
+            .and_then(|v| v.split_whitespace().last())
+            .and_then(|v| v.parse::<f32>().ok())
+    };
+    Some((percent("user")?, percent("sys")?))
+}
 
-    for line in vm_str.lines() {
-        let parts: Vec<&str> = line.split(':').collect();
-        if parts.len() == 2 {
-            let value: u64 = parts[1].trim().trim_end_matches('.').parse().unwrap_or(0);
-            let key = parts[0].trim();
-            match key {
-                "Pages free" => pages_free = value,
-                "Pages active" => pages_active = value,
-                "Pages inactive" => pages_inactive = value,
-                "Pages wired down" => pages_wired = value,
-                "Pages stored in compressor" => pages_compressed = value,
-                "File-backed pages" => pages_file_backed = value,
-                "Pages purgeable" => _pages_purgeable = value,
-                "Pages speculative" => _pages_speculative = value,
-                _ => {}
-            }
-        }
+/// User and system CPU percent since the previous call, from /proc/stat
+#[cfg(target_os = "linux")]
+fn cpu_split() -> Option<(f32, f32)> {
+    lazy_static::lazy_static! {
+        // (user, system, total) jiffies at the previous call; zero means "since boot"
+        static ref LAST: Mutex<(u64, u64, u64)> = Mutex::new((0, 0, 0));
     }
+    let stat = std::fs::read_to_string("/proc/stat").ok()?;
+    let fields: Vec<u64> = stat
+        .lines()
+        .next()?
+        .split_whitespace()
+        .skip(1)
+        .filter_map(|v| v.parse().ok())
+        .collect();
+    // user nice system idle iowait irq softirq steal
+    if fields.len() < 7 {
+        return None;
+    }
+    let user = fields[0] + fields[1];
+    let system = fields[2] + fields[5] + fields[6];
+    let total: u64 = fields.iter().take(8).sum();
 
-    let free_bytes = pages_free * page_size;
-    let app_bytes = (pages_active + pages_inactive) * page_size;
-    let wired_bytes = pages_wired * page_size;
-    let compressed_bytes = pages_compressed * page_size;
-    let cached_bytes = pages_file_backed * page_size;
-    let used_bytes = total_bytes - free_bytes;
-
-    let free_gb = free_bytes as f32 / 1024.0 / 1024.0 / 1024.0;
-    let used_gb = used_bytes as f32 / 1024.0 / 1024.0 / 1024.0;
-    let app_memory_gb = app_bytes as f32 / 1024.0 / 1024.0 / 1024.0;
-    let wired_memory_gb = wired_bytes as f32 / 1024.0 / 1024.0 / 1024.0;
-    let compressed_gb = compressed_bytes as f32 / 1024.0 / 1024.0 / 1024.0;
-    let cached_files_gb = cached_bytes as f32 / 1024.0 / 1024.0 / 1024.0;
-
-    // Get swap usage from sysctl
-    // Output format: "vm.swapusage: total = 2048.00M  used = 512.00M  free = 1536.00M"
-    let swap_output = Command::new("sysctl")
-        .args(["vm.swapusage"])
-        .output()
-        .map_err(|e| e.to_string())?;
-    let swap_str = String::from_utf8_lossy(&swap_output.stdout);
-
-    let mut swap_total_gb: f32 = 0.0;
-    let mut swap_used_gb: f32 = 0.0;
-
-    // Parse swap values
-    for part in swap_str.split_whitespace() {
-        if part.ends_with('M') {
-            let val: f32 = part.trim_end_matches('M').parse().unwrap_or(0.0);
-            // Determine which field this is by checking what came before
-            if swap_str.contains(&format!("total = {}", part)) {
-                swap_total_gb = val / 1024.0;
-            } else if swap_str.contains(&format!("used = {}", part)) {
-                swap_used_gb = val / 1024.0;
-            }
-        } else if part.ends_with('G') {
-            let val: f32 = part.trim_end_matches('G').parse().unwrap_or(0.0);
-            if swap_str.contains(&format!("total = {}", part)) {
-                swap_total_gb = val;
-            } else if swap_str.contains(&format!("used = {}", part)) {
-                swap_used_gb = val;
-            }
-        }
+    let mut last = LAST.lock().ok()?;
+    let (user_delta, system_delta, total_delta) = (
+        user.saturating_sub(last.0),
+        system.saturating_sub(last.1),
+        total.saturating_sub(last.2),
+    );
+    *last = (user, system, total);
+    if total_delta == 0 {
+        return None;
     }
+    let percent = |delta: u64| delta as f32 / total_delta as f32 * 100.0;
+    Some((percent(user_delta), percent(system_delta)))
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn cpu_split() -> Option<(f32, f32)> {
+    None
+}
+
+/// `ps` memory column like "512K", "12M" or "1.5G", in bytes
+#[cfg(target_os = "macos")]
+fn parse_memory_value(value: &str) -> Option<f32> {
+    let value = value.trim().trim_end_matches(['+', '-']);
+    let (number, multiplier) = match value.chars().last()? {
+        'B' => (&value[..value.len() - 1], 1.0),
+        'K' => (&value[..value.len() - 1], 1024.0),
+        'M' => (&value[..value.len() - 1], 1024.0 * 1024.0),
+        'G' => (&value[..value.len() - 1], 1024.0 * 1024.0 * 1024.0),
+        _ => (value, 1.0),
+    };
+    number.parse::<f32>().ok().map(|n| n * multiplier)
+}
+
+/// Private and shared resident memory (MB) per PID, from `ps -o rprvt,rshrd`
+#[cfg(target_os = "macos")]
+fn memory_split(pids: &[u32]) -> HashMap<u32, (f32, f32)> {
+    let list = pids.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(",");
+    let Ok(output) = Command::new("ps").args(["-o", "pid=,rprvt=,rshrd=", "-p", &list]).output() else {
+        return HashMap::new();
+    };
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let pid = fields.first()?.parse().ok()?;
+            let private = parse_memory_value(fields.get(1)?)?;
+            let shared = parse_memory_value(fields.get(2)?)?;
+            Some((pid, (private / 1024.0 / 1024.0, shared / 1024.0 / 1024.0)))
+        })
+        .collect()
+}
+
+/// Private and shared resident memory (MB) per PID, from /proc/<pid>/statm
+#[cfg(target_os = "linux")]
+fn memory_split(pids: &[u32]) -> HashMap<u32, (f32, f32)> {
+    let page_mb = unsafe { libc::sysconf(libc::_SC_PAGESIZE) }.max(1) as f32 / 1024.0 / 1024.0;
+    pids.iter()
+        .filter_map(|&pid| {
+            let statm = std::fs::read_to_string(format!("/proc/{}/statm", pid)).ok()?;
+            // size resident shared text lib data dt, in pages
+            let fields: Vec<u64> = statm.split_whitespace().filter_map(|v| v.parse().ok()).collect();
+            let (resident, shared) = (*fields.get(1)?, *fields.get(2)?);
+            Some((pid, (resident.saturating_sub(shared) as f32 * page_mb, shared as f32 * page_mb)))
+        })
+        .collect()
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn memory_split(_pids: &[u32]) -> HashMap<u32, (f32, f32)> {
+    HashMap::new()
+}
+
+fn bytes_to_gb(bytes: u64) -> f32 {
+    bytes as f32 / 1024.0 / 1024.0 / 1024.0
+}
+
+pub(crate) fn get_memory_info() -> Result<MemoryInfo, String> {
+    let mut sys = SYSTEM.lock().map_err(|e| e.to_string())?;
+    sys.refresh_memory();
+
+    let total_bytes = sys.total_memory();
+    let used_bytes = sys.used_memory();
+    let free_bytes = sys.free_memory();
+    let available_bytes = sys.available_memory();
+
+    let total_gb = bytes_to_gb(total_bytes);
+    let used_gb = bytes_to_gb(used_bytes);
+    let free_gb = bytes_to_gb(free_bytes);
+    let swap_total_gb = bytes_to_gb(sys.total_swap());
+    let swap_used_gb = bytes_to_gb(sys.used_swap());
+
+    // sysinfo has no wired/compressed breakdown, so macOS still reads it from vm_stat
+    #[cfg(target_os = "macos")]
+    let (app_memory_gb, wired_memory_gb, compressed_gb, cached_files_gb) =
+        get_memory_breakdown_macos().unwrap_or((used_gb, 0.0, 0.0, 0.0));
+    #[cfg(not(target_os = "macos"))]
+    let (app_memory_gb, wired_memory_gb, compressed_gb, cached_files_gb) = (
+        used_gb,
+        0.0,
+        0.0,
+        bytes_to_gb(available_bytes.saturating_sub(free_bytes)),
+    );
+    #[cfg(target_os = "macos")]
+    let _ = available_bytes;
 
-    // Calculate memory pressure based on macOS heuristics
+    // Memory pressure heuristics
     // Low: plenty of free + purgeable memory
     // Medium: using compressed memory significantly
     // High: very low free memory, heavy compression, or using swap
-    let memory_pressure = if swap_used_gb > 0.1 || free_gb < 0.5 || compressed_gb > 4.0 {
+    let available_gb = bytes_to_gb(available_bytes.max(free_bytes));
+    let memory_pressure = if (swap_used_gb > 0.1 && available_gb < 1.0)
+        || available_gb < 0.5
+        || compressed_gb > 4.0
+    {
         "high".to_string()
-    } else if free_gb < 1.5 || compressed_gb > 2.0 {
+    } else if available_gb < 1.5 || compressed_gb > 2.0 {
         "medium".to_string()
     } else {
         "low".to_string()
@@ -274,7 +354,7 @@ fn get_memory_info_macos() -> Result<MemoryInfo, String> {
         total_gb,
         used_gb,
         free_gb,
-        usage_percent: (used_gb / total_gb) * 100.0,
+        usage_percent: if total_gb > 0.0 { (used_gb / total_gb) * 100.0 } else { 0.0 },
         app_memory_gb,
         wired_memory_gb,
         compressed_gb,
@@ -285,54 +365,68 @@ fn get_memory_info_macos() -> Result<MemoryInfo, String> {
     })
 }
 
+/// App/wired/compressed/file-backed memory from vm_stat, in GB
 #[cfg(target_os = "macos")]
-fn get_disk_info_macos() -> Result<DiskInfo, String> {
-    let output = Command::new("df")
-        .args(["-h", "/"])
+fn get_memory_breakdown_macos() -> Result<(f32, f32, f32, f32), String> {
+    let vm_output = Command::new("vm_stat")
         .output()
         .map_err(|e| e.to_string())?;
-    let df_str = String::from_utf8_lossy(&output.stdout);
-
-    // Parse df output: Filesystem Size Used Avail Capacity
-    for line in df_str.lines().skip(1) {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() >= 5 {
-            let total_str = parts[1];
-            let used_str = parts[2];
-            let avail_str = parts[3];
-            let capacity_str = parts[4];
-
-            let parse_size = |s: &str| -> f32 {
-                let s = s.trim();
-                if s.ends_with("Gi") || s.ends_with("G") {
-                    s.trim_end_matches("Gi").trim_end_matches('G').parse().unwrap_or(0.0)
-                } else if s.ends_with("Ti") || s.ends_with("T") {
-                    s.trim_end_matches("Ti").trim_end_matches('T').parse::<f32>().unwrap_or(0.0) * 1024.0
-                } else if s.ends_with("Mi") || s.ends_with("M") {
-                    s.trim_end_matches("Mi").trim_end_matches('M').parse::<f32>().unwrap_or(0.0) / 1024.0
-                } else {
-                    0.0
-                }
-            };
+    let vm_str = String::from_utf8_lossy(&vm_output.stdout);
+
+    let page_size: u64 = 16384; // macOS page size
+    let mut pages_active: u64 = 0;
+    let mut pages_inactive: u64 = 0;
+    let mut pages_wired: u64 = 0;
+    let mut pages_compressed: u64 = 0;
+    let mut pages_file_backed: u64 = 0;
 
-            let total_gb = parse_size(total_str);
-            let used_gb = parse_size(used_str);
-            let free_gb = parse_size(avail_str);
-            let usage_percent: f32 = capacity_str
-                .trim_end_matches('%')
-                .parse()
-                .unwrap_or(0.0);
-
-            return Ok(DiskInfo {
-                total_gb,
-                used_gb,
-                free_gb,
-                usage_percent,
-            });
+    for line in vm_str.lines() {
+        let parts: Vec<&str> = line.split(':').collect();
+        if parts.len() == 2 {
+            let value: u64 = parts[1].trim().trim_end_matches('.').parse().unwrap_or(0);
+            match parts[0].trim() {
+                "Pages active" => pages_active = value,
+                "Pages inactive" => pages_inactive = value,
+                "Pages wired down" => pages_wired = value,
+                "Pages stored in compressor" => pages_compressed = value,
+                "File-backed pages" => pages_file_backed = value,
+                _ => {}
+            }
         }
     }
 
-    Err("Could not parse disk info".to_string())
+    Ok((
+        bytes_to_gb((pages_active + pages_inactive) * page_size),
+        bytes_to_gb(pages_wired * page_size),
+        bytes_to_gb(pages_compressed * page_size),
+        bytes_to_gb(pages_file_backed * page_size),
+    ))
+}
+
+pub(crate) fn get_disk_info() -> Result<DiskInfo, String> {
+    let disks = Disks::new_with_refreshed_list();
+
+    // Prefer the root / system volume, fall back to the largest disk
+    let root = disks
+        .list()
+        .iter()
+        .find(|d| {
+            let mount = d.mount_point().to_string_lossy();
+            mount == "/" || mount.eq_ignore_ascii_case("C:\\")
+        })
+        .or_else(|| disks.list().iter().max_by_key(|d| d.total_space()))
+        .ok_or_else(|| "Could not find a disk".to_string())?;
+
+    let total = root.total_space();
+    let free = root.available_space();
+    let used = total.saturating_sub(free);
+
+    Ok(DiskInfo {
+        total_gb: bytes_to_gb(total),
+        used_gb: bytes_to_gb(used),
+        free_gb: bytes_to_gb(free),
+        usage_percent: if total > 0 { used as f32 / total as f32 * 100.0 } else { 0.0 },
+    })
 }
 
 #[cfg(target_os = "macos")]
@@ -385,9 +479,53 @@ fn get_gpu_info_macos() -> Result<GpuInfo, String> {
         name,
         vendor,
         vram_mb,
+        utilization_percent: None,
+        renderer_utilization_percent: None,
+        tiler_utilization_percent: None,
+        in_use_memory_mb: None,
     })
 }
 
+/// GPU name/vendor/VRAM; system_profiler takes about a second, so it runs once and is cached
+#[cfg(target_os = "macos")]
+fn gpu_identity_macos() -> Option<GpuInfo> {
+    lazy_static::lazy_static! {
+        static ref GPU_IDENTITY: Mutex<Option<GpuInfo>> = Mutex::new(None);
+    }
+    let mut cached = GPU_IDENTITY.lock().ok()?;
+    if cached.is_none() {
+        *cached = get_gpu_info_macos().ok();
+    }
+    cached.clone()
+}
+
+/// Fill live utilization from `ioreg`'s IOAccelerator PerformanceStatistics (no root needed)
+#[cfg(target_os = "macos")]
+fn apply_gpu_utilization_macos(gpu: &mut GpuInfo) {
+    lazy_static::lazy_static! {
+        static ref STAT_REGEX: regex::Regex =
+            regex::Regex::new(r#""(Device Utilization %|Renderer Utilization %|Tiler Utilization %|In use system memory)"=(\d+)"#).unwrap();
+    }
+
+    let Ok(output) = Command::new("ioreg").args(["-r", "-d", "1", "-c", "IOAccelerator"]).output() else {
+        return;
+    };
+    let text = String::from_utf8_lossy(&output.stdout);
+    for caps in STAT_REGEX.captures_iter(&text) {
+        let Ok(value) = caps[2].parse::<u64>() else {
+            continue;
+        };
+        match &caps[1] {
+            "Device Utilization %" => gpu.utilization_percent = Some(value as f32),
+            "Renderer Utilization %" => gpu.renderer_utilization_percent = Some(value as f32),
+            "Tiler Utilization %" => gpu.tiler_utilization_percent = Some(value as f32),
+            "In use system memory" => gpu.in_use_memory_mb = Some((value / 1024 / 1024) as u32),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
 fn extract_json_string(line: &str) -> Option<String> {
     // Extract value from JSON like: "key" : "value"
     let parts: Vec<&str> = line.splitn(2, ':').collect();
@@ -400,158 +538,649 @@ fn extract_json_string(line: &str) -> Option<String> {
     None
 }
 
-#[cfg(target_os = "macos")]
-fn get_uptime_macos() -> Result<String, String> {
-    let output = Command::new("uptime")
-        .output()
-        .map_err(|e| e.to_string())?;
-    let uptime_str = String::from_utf8_lossy(&output.stdout);
-
-    // Parse uptime like "12:34  up 5 days,  3:42, 4 users"
-    // Extract the "up X days, Y:ZZ" part
-    if let Some(up_idx) = uptime_str.find("up ") {
-        let after_up = &uptime_str[up_idx + 3..];
-        if let Some(users_idx) = after_up.find(" user") {
-            let uptime_part = after_up[..users_idx].trim();
-            // Remove trailing comma and numbers
-            let uptime_clean = uptime_part.rsplit(',').skip(1).collect::<Vec<_>>()
-                .into_iter().rev().collect::<Vec<_>>().join(",");
-            let uptime_clean = if uptime_clean.is_empty() { uptime_part.to_string() } else { uptime_clean };
-            return Ok(uptime_clean.trim_end_matches(',').trim().to_string());
+/// Format seconds of uptime like "5 days, 3:42"
+fn format_uptime(secs: u64) -> String {
+    let days = secs / 86_400;
+    let hours = (secs % 86_400) / 3600;
+    let minutes = (secs % 3600) / 60;
+    match days {
+        0 => format!("{}:{:02}", hours, minutes),
+        1 => format!("1 day, {}:{:02}", hours, minutes),
+        _ => format!("{} days, {}:{:02}", days, hours, minutes),
+    }
+}
+
+/// Format a process run time like ps etime: [[dd-]hh:]mm:ss
+fn format_elapsed(secs: u64) -> String {
+    let days = secs / 86_400;
+    let hours = (secs % 86_400) / 3600;
+    let minutes = (secs % 3600) / 60;
+    let seconds = secs % 60;
+    if days > 0 {
+        format!("{}-{:02}:{:02}:{:02}", days, hours, minutes, seconds)
+    } else if hours > 0 {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+/// Process name, command line, elapsed time, CPU%, memory% and user for a set of PIDs.
+/// Used by the port scanner on platforms where `ps` isn't available or complete.
+#[cfg(not(target_os = "macos"))]
+pub(crate) fn port_process_details(
+    pids: &[u32],
+) -> HashMap<u32, (String, String, String, f32, f32, String)> {
+    let Ok(mut sys) = SYSTEM.lock() else {
+        return HashMap::new();
+    };
+    sys.refresh_memory();
+    sys.refresh_processes();
+
+    let users = Users::new_with_refreshed_list();
+    let total_memory = sys.total_memory().max(1) as f32;
+
+    pids.iter()
+        .filter_map(|&pid| {
+            let process = sys.process(Pid::from_u32(pid))?;
+            let command = if process.cmd().is_empty() {
+                process.name().to_string()
+            } else {
+                process.cmd().join(" ")
+            };
+            let user = process
+                .user_id()
+                .and_then(|uid| users.get_user_by_id(uid))
+                .map(|u| u.name().to_string())
+                .unwrap_or_default();
+            Some((
+                pid,
+                (
+                    process.name().to_string(),
+                    command,
+                    format_elapsed(process.run_time()),
+                    process.cpu_usage(),
+                    process.memory() as f32 / total_memory * 100.0,
+                    user,
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// Each PID followed by its parent, grandparent, ... up to the root, from one process refresh
+pub(crate) fn ancestor_pids(pids: &[u32]) -> HashMap<u32, Vec<u32>> {
+    let Ok(mut sys) = SYSTEM.lock() else {
+        return pids.iter().map(|&pid| (pid, vec![pid])).collect();
+    };
+    sys.refresh_processes();
+
+    pids.iter()
+        .map(|&pid| {
+            let mut chain = vec![pid];
+            let mut current = Pid::from_u32(pid);
+            while let Some(parent) = sys.process(current).and_then(|p| p.parent()) {
+                if chain.contains(&parent.as_u32()) {
+                    break;
+                }
+                chain.push(parent.as_u32());
+                current = parent;
+            }
+            (pid, chain)
+        })
+        .collect()
+}
+
+/// Whether a PID is still running (for processes we can't wait on)
+pub(crate) fn pid_alive(pid: u32) -> bool {
+    match SYSTEM.lock() {
+        Ok(mut sys) => sys.refresh_process(Pid::from_u32(pid)),
+        Err(e) => {
+            println!("[system] Can't check PID {}: {}", pid, e);
+            false
+        }
+    }
+}
+
+/// Start time (unix seconds) and full command line of each running PID, to tell a process
+/// apart from a later one that reused its PID
+pub(crate) fn process_identities(pids: &[u32]) -> HashMap<u32, (u64, Vec<String>)> {
+    let Ok(mut sys) = SYSTEM.lock() else {
+        return HashMap::new();
+    };
+    // Only the PIDs asked about; a full refresh is too slow to run on every spawn and exit
+    pids.iter()
+        .filter_map(|&pid| {
+            if !sys.refresh_process(Pid::from_u32(pid)) {
+                return None;
+            }
+            let process = sys.process(Pid::from_u32(pid))?;
+            Some((pid, (process.start_time(), process.cmd().to_vec())))
+        })
+        .collect()
+}
+
+/// Get top processes by memory usage
+#[tauri::command]
+pub async fn get_top_processes(limit: Option<u32>) -> Result<Vec<ProcessInfo>, String> {
+    let limit = limit.unwrap_or(15) as usize;
+    let mut sys = SYSTEM.lock().map_err(|e| e.to_string())?;
+    sys.refresh_memory();
+    sys.refresh_processes();
+
+    let users = Users::new_with_refreshed_list();
+    let total_memory = sys.total_memory().max(1) as f32;
+
+    let mut processes: Vec<ProcessInfo> = sys
+        .processes()
+        .iter()
+        .filter_map(|(pid, process)| {
+            let pid = pid.as_u32();
+            let cpu_percent = process.cpu_usage();
+            let memory_mb = process.memory() as f32 / 1024.0 / 1024.0;
+
+            // Skip kernel processes and very small processes
+            if pid == 0 || (cpu_percent <= 0.0 && memory_mb <= 10.0) {
+                return None;
+            }
+
+            let command = if process.cmd().is_empty() {
+                process.name().to_string()
+            } else {
+                process.cmd().join(" ")
+            };
+            let user = process
+                .user_id()
+                .and_then(|uid| users.get_user_by_id(uid))
+                .map(|u| u.name().to_string())
+                .unwrap_or_default();
+
+            Some(ProcessInfo {
+                pid,
+                name: process.name().to_string(),
+                command,
+                cpu_percent,
+                memory_mb,
+                memory_percent: process.memory() as f32 / total_memory * 100.0,
+                vsz_mb: process.virtual_memory() as f32 / 1024.0 / 1024.0,
+                private_mb: None,
+                shared_mb: None,
+                user,
+                state: process.status().to_string(),
+                elapsed: format_elapsed(process.run_time()),
+            })
+        })
+        .collect();
+    drop(sys);
+
+    // Sort by memory (descending)
+    processes.sort_by(|a, b| b.memory_mb.partial_cmp(&a.memory_mb).unwrap_or(std::cmp::Ordering::Equal));
+    processes.truncate(limit);
+
+    let pids: Vec<u32> = processes.iter().map(|p| p.pid).collect();
+    let split = memory_split(&pids);
+    for process in &mut processes {
+        if let Some((private, shared)) = split.get(&process.pid) {
+            process.private_mb = Some(*private);
+            process.shared_mb = Some(*shared);
         }
     }
+    Ok(processes)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessTreeMember {
+    pub pid: u32,
+    pub name: String,
+    pub cpu_percent: f32,
+    pub rss_mb: f32,
+    pub threads: Option<u32>,
+    pub open_files: Option<u32>,
+}
+
+/// Resource usage of a process and all of its descendants
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessMetrics {
+    pub pid: u32,
+    /// Project path when the PID is a tracked dev server
+    pub path: Option<String>,
+    pub cpu_percent: f32,
+    pub rss_mb: f32,
+    pub threads: Option<u32>,
+    pub open_files: Option<u32>,
+    pub processes: Vec<ProcessTreeMember>,
+}
 
-    Ok("Unknown".to_string())
+/// Thread count and open file descriptor count per PID
+#[cfg(target_os = "linux")]
+fn thread_and_file_counts(pids: &[u32]) -> HashMap<u32, (Option<u32>, Option<u32>)> {
+    pids.iter()
+        .map(|&pid| {
+            let threads = std::fs::read_to_string(format!("/proc/{}/status", pid))
+                .ok()
+                .and_then(|status| {
+                    status
+                        .lines()
+                        .find_map(|l| l.strip_prefix("Threads:"))
+                        .and_then(|v| v.trim().parse().ok())
+                });
+            let files = std::fs::read_dir(format!("/proc/{}/fd", pid))
+                .ok()
+                .map(|entries| entries.count() as u32);
+            (pid, (threads, files))
+        })
+        .collect()
 }
 
+/// Thread count and open file descriptor count per PID, from one `ps` and one `lsof` call
+/// covering all of them
 #[cfg(target_os = "macos")]
-fn get_load_average_macos() -> Result<Vec<f32>, String> {
-    let output = Command::new("sysctl")
-        .args(["-n", "vm.loadavg"])
-        .output()
-        .map_err(|e| e.to_string())?;
-    let load_str = String::from_utf8_lossy(&output.stdout);
+fn thread_and_file_counts(pids: &[u32]) -> HashMap<u32, (Option<u32>, Option<u32>)> {
+    if pids.is_empty() {
+        return HashMap::new();
+    }
+    let list = pids.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(",");
+    let mut threads: HashMap<u32, u32> = HashMap::new();
+    let mut files: HashMap<u32, u32> = HashMap::new();
 
-    // Parse "{ 1.23 4.56 7.89 }"
-    let load_str = load_str.trim().trim_matches(|c| c == '{' || c == '}');
-    let loads: Vec<f32> = load_str
-        .split_whitespace()
-        .filter_map(|s| s.parse().ok())
+    // ps -M prints a header, then one line per thread; thread lines leave USER blank, so the
+    // PID is the first or second column
+    if let Ok(output) = Command::new("ps").args(["-M", "-p", &list]).output() {
+        for line in String::from_utf8_lossy(&output.stdout).lines().skip(1) {
+            let pid = line
+                .split_whitespace()
+                .take(2)
+                .filter_map(|t| t.parse::<u32>().ok())
+                .find(|p| pids.contains(p));
+            if let Some(pid) = pid {
+                *threads.entry(pid).or_default() += 1;
+            }
+        }
+    }
+
+    // -F f prints a "p<pid>" line per process followed by one "f<fd>" line per descriptor
+    if let Ok(output) = Command::new("lsof").args(["-n", "-P", "-p", &list, "-F", "f"]).output() {
+        let mut current = None;
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            if let Some(pid) = line.strip_prefix('p') {
+                current = pid.parse::<u32>().ok();
+            } else if let (true, Some(pid)) = (line.starts_with('f'), current) {
+                *files.entry(pid).or_default() += 1;
+            }
+        }
+    }
+
+    pids.iter()
+        .map(|&pid| (pid, (threads.get(&pid).copied(), files.get(&pid).copied())))
+        .collect()
+}
+
+#[cfg(windows)]
+fn thread_and_file_counts(_pids: &[u32]) -> HashMap<u32, (Option<u32>, Option<u32>)> {
+    HashMap::new()
+}
+
+/// Fill in thread and open file counts for every member, once the SYSTEM lock is released
+/// (the lookups shell out on macOS)
+fn add_thread_and_file_counts(metrics: &mut [ProcessMetrics]) {
+    let pids: Vec<u32> = metrics
+        .iter()
+        .flat_map(|m| m.processes.iter().map(|p| p.pid))
         .collect();
+    let counts = thread_and_file_counts(&pids);
+    for tree in metrics.iter_mut() {
+        for member in tree.processes.iter_mut() {
+            if let Some(&(threads, files)) = counts.get(&member.pid) {
+                member.threads = threads;
+                member.open_files = files;
+            }
+        }
+        let sum_optional = |f: fn(&ProcessTreeMember) -> Option<u32>| {
+            tree.processes.iter().filter_map(f).reduce(|a, b| a + b)
+        };
+        tree.threads = sum_optional(|p| p.threads);
+        tree.open_files = sum_optional(|p| p.open_files);
+    }
+}
 
-    Ok(loads)
+/// Child PIDs of every process in an already-refreshed System
+fn children_map(sys: &System) -> HashMap<Pid, Vec<Pid>> {
+    let mut children_of: HashMap<Pid, Vec<Pid>> = HashMap::new();
+    for (child, process) in sys.processes() {
+        if let Some(parent) = process.parent() {
+            children_of.entry(parent).or_default().push(*child);
+        }
+    }
+    children_of
 }
 
-/// Get top processes by memory usage
+/// A PID and all of its descendants, breadth-first from the root. PIDs get reused, so stale
+/// parent links can form a cycle; each PID is visited once.
+fn tree_pids(children_of: &HashMap<Pid, Vec<Pid>>, root: Pid) -> Vec<Pid> {
+    let mut seen = HashSet::from([root]);
+    let mut tree = vec![root];
+    let mut i = 0;
+    while i < tree.len() {
+        for child in children_of.get(&tree[i]).into_iter().flatten() {
+            if seen.insert(*child) {
+                tree.push(*child);
+            }
+        }
+        i += 1;
+    }
+    tree
+}
+
+/// Collect metrics for a PID and its descendants from an already-refreshed System. Thread and
+/// file counts are left empty for add_thread_and_file_counts.
+fn collect_tree_metrics(sys: &System, pid: u32, path: Option<String>) -> Option<ProcessMetrics> {
+    sys.process(Pid::from_u32(pid))?;
+    let tree: Vec<u32> = tree_pids(&children_map(sys), Pid::from_u32(pid))
+        .into_iter()
+        .map(|p| p.as_u32())
+        .collect();
+
+    let processes: Vec<ProcessTreeMember> = tree
+        .iter()
+        .filter_map(|&member| {
+            let process = sys.process(Pid::from_u32(member))?;
+            Some(ProcessTreeMember {
+                pid: member,
+                name: process.name().to_string(),
+                cpu_percent: process.cpu_usage(),
+                rss_mb: process.memory() as f32 / 1024.0 / 1024.0,
+                threads: None,
+                open_files: None,
+            })
+        })
+        .collect();
+
+    Some(ProcessMetrics {
+        pid,
+        path,
+        cpu_percent: processes.iter().map(|p| p.cpu_percent).sum(),
+        rss_mb: processes.iter().map(|p| p.rss_mb).sum(),
+        threads: None,
+        open_files: None,
+        processes,
+    })
+}
+
+/// Get CPU, memory, thread and file descriptor usage for a process and its child tree
 #[tauri::command]
-pub async fn get_top_processes(limit: Option<u32>) -> Result<Vec<ProcessInfo>, String> {
-    #[cfg(target_os = "macos")]
-    {
-        get_top_processes_macos(limit.unwrap_or(15))
+pub async fn get_process_metrics(pid: u32) -> Result<ProcessMetrics, String> {
+    let path = crate::process::running_processes()
+        .into_iter()
+        .find(|(p, _)| *p == pid)
+        .map(|(_, path)| path);
+
+    let mut metrics = {
+        let mut sys = SYSTEM.lock().map_err(|e| e.to_string())?;
+        sys.refresh_processes();
+        collect_tree_metrics(&sys, pid, path).ok_or_else(|| format!("Process {} is not running", pid))?
+    };
+    add_thread_and_file_counts(std::slice::from_mut(&mut metrics));
+    Ok(metrics)
+}
+
+/// A process and everything it spawned
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessTreeNode {
+    pub pid: u32,
+    pub parent_pid: Option<u32>,
+    pub name: String,
+    pub command: String,
+    pub status: String,
+    pub cpu_percent: f32,
+    pub rss_mb: f32,
+    pub children: Vec<ProcessTreeNode>,
+}
+
+fn build_tree_node(
+    sys: &System,
+    children_of: &HashMap<Pid, Vec<Pid>>,
+    pid: Pid,
+    seen: &mut HashSet<Pid>,
+) -> Option<ProcessTreeNode> {
+    let process = sys.process(pid)?;
+    seen.insert(pid);
+    let mut children: Vec<ProcessTreeNode> = children_of
+        .get(&pid)
+        .into_iter()
+        .flatten()
+        .filter_map(|child| {
+            // PIDs get reused, so guard against a cycle in stale parent links
+            if seen.contains(child) {
+                None
+            } else {
+                build_tree_node(sys, children_of, *child, seen)
+            }
+        })
+        .collect();
+    children.sort_by_key(|c| c.pid);
+
+    Some(ProcessTreeNode {
+        pid: pid.as_u32(),
+        parent_pid: process.parent().map(|p| p.as_u32()),
+        name: process.name().to_string(),
+        command: if process.cmd().is_empty() {
+            process.name().to_string()
+        } else {
+            process.cmd().join(" ")
+        },
+        status: process.status().to_string(),
+        cpu_percent: process.cpu_usage(),
+        rss_mb: process.memory() as f32 / 1024.0 / 1024.0,
+        children,
+    })
+}
+
+fn process_tree(sys: &System, pid: u32) -> Option<ProcessTreeNode> {
+    build_tree_node(sys, &children_map(sys), Pid::from_u32(pid), &mut HashSet::new())
+}
+
+/// Full child tree of a process (e.g. npm → node → esbuild → workers) with per-node usage
+#[tauri::command]
+pub async fn get_process_tree(pid: u32) -> Result<ProcessTreeNode, String> {
+    let mut sys = SYSTEM.lock().map_err(|e| e.to_string())?;
+    sys.refresh_processes();
+    process_tree(&sys, pid).ok_or_else(|| format!("Process {} is not running", pid))
+}
+
+/// Kill one descendant of a tracked dev server, leaving the rest of its tree running.
+/// Sends SIGTERM unless `force` is set (Windows always terminates immediately).
+#[tauri::command]
+pub async fn kill_tree_member(root_pid: u32, pid: u32, force: Option<bool>) -> Result<bool, String> {
+    if root_pid == pid {
+        return Err("Use kill_process to stop the dev server itself".to_string());
+    }
+    if !crate::process::running_processes().iter().any(|(p, _)| *p == root_pid) {
+        return Err(format!("Process {} is not a running dev server", root_pid));
     }
 
-    #[cfg(not(target_os = "macos"))]
-    {
-        Err("Process monitoring only supported on macOS".to_string())
+    let mut sys = SYSTEM.lock().map_err(|e| e.to_string())?;
+    sys.refresh_processes();
+    // Only PIDs inside the server's tree, so this can't be used to kill arbitrary processes
+    let members = tree_pids(&children_map(&sys), Pid::from_u32(root_pid));
+    if !members.contains(&Pid::from_u32(pid)) {
+        return Err(format!("Process {} is not part of {}'s tree", pid, root_pid));
     }
+
+    let process = sys
+        .process(Pid::from_u32(pid))
+        .ok_or_else(|| format!("Process {} is not running", pid))?;
+    println!("[system] Killing process {} in the tree of {}", pid, root_pid);
+    let killed = if force.unwrap_or(false) {
+        process.kill()
+    } else {
+        process.kill_with(sysinfo::Signal::Term).unwrap_or_else(|| process.kill())
+    };
+    Ok(killed)
 }
 
-#[cfg(target_os = "macos")]
-fn get_top_processes_macos(limit: u32) -> Result<Vec<ProcessInfo>, String> {
-    // Use ps to get detailed process info
-    // Format: pid, cpu%, mem%, rss (KB), vsz (KB), rprvt (private bytes), rshrd (shared bytes), user, state, elapsed time, command with args
-    let output = Command::new("ps")
-        .args(["-A", "-o", "pid=,pcpu=,pmem=,rss=,vsz=,rprvt=,rshrd=,user=,state=,etime=,args=", "-r"])
-        .output()
-        .map_err(|e| e.to_string())?;
+/// Metrics for every running tracked dev server and its children
+pub(crate) fn tracked_process_metrics() -> Vec<ProcessMetrics> {
+    let tracked = crate::process::running_processes();
+    if tracked.is_empty() {
+        return Vec::new();
+    }
+    let mut metrics: Vec<ProcessMetrics> = {
+        let Ok(mut sys) = SYSTEM.lock() else {
+            return Vec::new();
+        };
+        sys.refresh_processes();
+        tracked
+            .into_iter()
+            .filter_map(|(pid, path)| collect_tree_metrics(&sys, pid, Some(path)))
+            .collect()
+    };
+    add_thread_and_file_counts(&mut metrics);
+    metrics
+}
 
-    let ps_str = String::from_utf8_lossy(&output.stdout);
-    let mut processes: Vec<ProcessInfo> = Vec::new();
+/// Periodically emit `process-metrics` for every running tracked dev server
+pub fn start_process_metrics_monitor(app: &AppHandle) {
+    let app = app.clone();
+    std::thread::spawn(move || loop {
+        std::thread::sleep(std::time::Duration::from_secs(PROCESS_METRICS_INTERVAL_SECS));
 
-    for line in ps_str.lines() {
-        let line = line.trim();
-        if line.is_empty() {
+        let metrics = tracked_process_metrics();
+        crate::tray::update_status(&app, &metrics);
+        if metrics.is_empty() {
             continue;
         }
+        let over_cap = crate::limits::check(&app, &metrics);
+        crate::alerts::check(&app, &metrics, &over_cap);
+        let _ = app.emit("process-metrics", metrics);
+    });
+}
 
-        // Use split_whitespace to properly handle multiple consecutive spaces
-        let fields: Vec<&str> = line.split_whitespace().collect();
-
-        if fields.len() >= 11 {
-            let pid: u32 = fields[0].parse().unwrap_or(0);
-            let cpu_percent: f32 = fields[1].parse().unwrap_or(0.0);
-            let memory_percent: f32 = fields[2].parse().unwrap_or(0.0);
-            let rss_kb: f32 = fields[3].parse().unwrap_or(0.0);
-            let vsz_kb: f32 = fields[4].parse().unwrap_or(0.0);
-            // rprvt and rshrd are in bytes, parse with suffix handling
-            let private_bytes = parse_memory_value(fields[5]);
-            let shared_bytes = parse_memory_value(fields[6]);
-            let user = fields[7].to_string();
-            let state = fields[8].to_string();
-            let elapsed = fields[9].to_string();
-            // Command is everything from field 10 onwards, joined back together
-            let command = fields[10..].join(" ");
-
-            let memory_mb = rss_kb / 1024.0;
-            let vsz_mb = vsz_kb / 1024.0;
-            let private_mb = private_bytes / 1024.0 / 1024.0;
-            let shared_mb = shared_bytes / 1024.0 / 1024.0;
-
-            // Extract just the executable name from the full path
-            let name = command
-                .split('/')
-                .last()
-                .unwrap_or(&command)
-                .split_whitespace()
-                .next()
-                .unwrap_or(&command)
-                .to_string();
+#[derive(Debug, Serialize, Clone)]
+pub struct NetworkInterfaceInfo {
+    pub name: String,
+    pub mac_address: String,
+    /// Throughput since the previous sample, bytes per second
+    pub rx_bytes_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+    /// Totals since the interface came up
+    pub total_rx_bytes: u64,
+    pub total_tx_bytes: u64,
+}
 
-            // Skip kernel processes and very small processes
-            if pid > 0 && (cpu_percent > 0.0 || memory_mb > 10.0) {
-                processes.push(ProcessInfo {
-                    pid,
-                    name,
-                    command,
-                    cpu_percent,
-                    memory_mb,
-                    memory_percent,
-                    vsz_mb,
-                    private_mb,
-                    shared_mb,
-                    user,
-                    state,
-                    elapsed,
-                });
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ConnectionCounts {
+    pub established: u32,
+    pub listening: u32,
+    pub time_wait: u32,
+    pub total: u32,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct NetworkInfo {
+    pub interfaces: Vec<NetworkInterfaceInfo>,
+    pub rx_bytes_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+    /// Length of the measurement window in ms
+    pub sample_ms: u64,
+    pub connections: ConnectionCounts,
+}
+
+/// Window used for the first measurement, when there is no previous sample yet
+const NETWORK_PRIME_MS: u64 = 500;
+
+/// Count TCP sockets by state from /proc/net/tcp{,6} (state codes: 01 established, 06 time-wait, 0A listen)
+#[cfg(target_os = "linux")]
+fn connection_counts() -> ConnectionCounts {
+    let mut counts = ConnectionCounts::default();
+    for file in ["/proc/net/tcp", "/proc/net/tcp6"] {
+        let Ok(content) = std::fs::read_to_string(file) else {
+            continue;
+        };
+        for line in content.lines().skip(1) {
+            let Some(state) = line.split_whitespace().nth(3) else {
+                continue;
+            };
+            match state {
+                "01" => counts.established += 1,
+                "06" => counts.time_wait += 1,
+                "0A" => counts.listening += 1,
+                _ => {}
             }
+            counts.total += 1;
         }
+    }
+    counts
+}
 
-        if processes.len() >= (limit * 2) as usize {
-            // Collect more than needed, we'll sort and trim later
-            break;
+/// Count TCP sockets by state from `netstat -an`
+#[cfg(not(target_os = "linux"))]
+fn connection_counts() -> ConnectionCounts {
+    let mut counts = ConnectionCounts::default();
+    let Ok(output) = std::process::Command::new("netstat").args(["-an", "-p", "tcp"]).output() else {
+        return counts;
+    };
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let upper = line.to_uppercase();
+        if !upper.trim_start().starts_with("TCP") {
+            continue;
         }
+        if upper.contains("ESTABLISHED") {
+            counts.established += 1;
+        } else if upper.contains("LISTEN") {
+            counts.listening += 1;
+        } else if upper.contains("TIME_WAIT") {
+            counts.time_wait += 1;
+        }
+        counts.total += 1;
     }
+    counts
+}
 
-    // Sort by memory (descending)
-    processes.sort_by(|a, b| b.memory_mb.partial_cmp(&a.memory_mb).unwrap_or(std::cmp::Ordering::Equal));
+/// Per-interface throughput and totals plus TCP connection counts
+#[tauri::command]
+pub async fn get_network_info() -> Result<NetworkInfo, String> {
+    // Take the sampler out so neither the baseline sleep nor the refresh runs under the lock;
+    // a concurrent call just starts its own baseline
+    let taken = NETWORKS.lock().map_err(|e| e.to_string())?.take();
+    let (mut networks, last) = match taken {
+        Some(sampler) => sampler,
+        None => {
+            // First call: take a short baseline so the rates aren't zero
+            let baseline = (Networks::new_with_refreshed_list(), std::time::Instant::now());
+            tokio::time::sleep(std::time::Duration::from_millis(NETWORK_PRIME_MS)).await;
+            baseline
+        }
+    };
+    networks.refresh();
+    let elapsed = last.elapsed();
 
-    Ok(processes.into_iter().take(limit as usize).collect())
-}
-
-/// Parse memory values that may have suffixes like K, M, G
-fn parse_memory_value(s: &str) -> f32 {
-    let s = s.trim();
-    if s.ends_with('K') || s.ends_with('k') {
-        s[..s.len()-1].parse::<f32>().unwrap_or(0.0) * 1024.0
-    } else if s.ends_with('M') || s.ends_with('m') {
-        s[..s.len()-1].parse::<f32>().unwrap_or(0.0) * 1024.0 * 1024.0
-    } else if s.ends_with('G') || s.ends_with('g') {
-        s[..s.len()-1].parse::<f32>().unwrap_or(0.0) * 1024.0 * 1024.0 * 1024.0
-    } else if s.ends_with('B') || s.ends_with('b') {
-        // Handle "123B" format
-        s[..s.len()-1].parse::<f32>().unwrap_or(0.0)
-    } else {
-        // Assume bytes
-        s.parse::<f32>().unwrap_or(0.0)
+    let secs = elapsed.as_secs_f64().max(0.001);
+    let mut interfaces: Vec<NetworkInterfaceInfo> = networks
+        .iter()
+        .map(|(name, data)| NetworkInterfaceInfo {
+            name: name.clone(),
+            mac_address: data.mac_address().to_string(),
+            rx_bytes_per_sec: data.received() as f64 / secs,
+            tx_bytes_per_sec: data.transmitted() as f64 / secs,
+            total_rx_bytes: data.total_received(),
+            total_tx_bytes: data.total_transmitted(),
+        })
+        .collect();
+    if let Ok(mut guard) = NETWORKS.lock() {
+        *guard = Some((networks, std::time::Instant::now()));
     }
+    // Busiest interfaces first
+    interfaces.sort_by(|a, b| {
+        (b.rx_bytes_per_sec + b.tx_bytes_per_sec)
+            .partial_cmp(&(a.rx_bytes_per_sec + a.tx_bytes_per_sec))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(NetworkInfo {
+        rx_bytes_per_sec: interfaces.iter().map(|i| i.rx_bytes_per_sec).sum(),
+        tx_bytes_per_sec: interfaces.iter().map(|i| i.tx_bytes_per_sec).sum(),
+        sample_ms: elapsed.as_millis() as u64,
+        interfaces,
+        connections: connection_counts(),
+    })
 }
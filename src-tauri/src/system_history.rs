@@ -0,0 +1,114 @@
+use crate::system;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::{Mutex, Once};
+use std::time::Duration;
+use sysinfo::System;
+use tauri::AppHandle;
+
+lazy_static::lazy_static! {
+    // Samples oldest first, bounded by the retention window
+    static ref HISTORY: Mutex<VecDeque<SystemSample>> = Mutex::new(VecDeque::new());
+}
+
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct SystemSample {
+    /// Unix millis (start of the bucket for downsampled results)
+    pub timestamp: i64,
+    pub cpu_percent: f32,
+    pub memory_percent: f32,
+    pub memory_used_gb: f32,
+    pub swap_used_gb: f32,
+    pub disk_percent: f32,
+    pub load_one: f32,
+}
+
+fn take_sample() -> Option<SystemSample> {
+    let cpu = system::get_cpu_info().ok()?;
+    let memory = system::get_memory_info().ok()?;
+    let disk = system::get_disk_info().ok();
+    Some(SystemSample {
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        cpu_percent: cpu.usage_percent,
+        memory_percent: memory.usage_percent,
+        memory_used_gb: memory.used_gb,
+        swap_used_gb: memory.swap_used_gb,
+        disk_percent: disk.map(|d| d.usage_percent).unwrap_or(0.0),
+        load_one: System::load_average().one as f32,
+    })
+}
+
+/// Start recording system samples every `interval_secs` (once per app run)
+pub fn start_sampler(app: &AppHandle) {
+    static SAMPLER: Once = Once::new();
+    let prefs = crate::config::load_config_internal(app)
+        .map(|c| c.preferences.system_history)
+        .unwrap_or_default();
+
+    SAMPLER.call_once(move || {
+        let interval = prefs.interval_secs.max(1);
+        let capacity = (prefs.retention_minutes.max(1) * 60 / interval) as usize;
+        println!("[system_history] Sampling every {}s, keeping {} samples", interval, capacity);
+
+        std::thread::spawn(move || loop {
+            if let Some(sample) = take_sample() {
+                if let Ok(mut history) = HISTORY.lock() {
+                    history.push_back(sample);
+                    while history.len() > capacity {
+                        history.pop_front();
+                    }
+                }
+            }
+            std::thread::sleep(Duration::from_secs(interval));
+        });
+    });
+}
+
+/// Average the samples in one bucket
+fn average(bucket: &[&SystemSample], timestamp: i64) -> SystemSample {
+    let n = bucket.len().max(1) as f32;
+    let mean = |f: fn(&SystemSample) -> f32| bucket.iter().map(|s| f(s)).sum::<f32>() / n;
+    SystemSample {
+        timestamp,
+        cpu_percent: mean(|s| s.cpu_percent),
+        memory_percent: mean(|s| s.memory_percent),
+        memory_used_gb: mean(|s| s.memory_used_gb),
+        swap_used_gb: mean(|s| s.swap_used_gb),
+        disk_percent: mean(|s| s.disk_percent),
+        load_one: mean(|s| s.load_one),
+    }
+}
+
+/// Recorded samples from the last `range_secs` (default 1 hour), averaged into
+/// `resolution_secs` buckets when given (raw samples otherwise)
+#[tauri::command]
+pub async fn get_system_history(
+    range_secs: Option<u64>,
+    resolution_secs: Option<u64>,
+) -> Result<Vec<SystemSample>, String> {
+    let history = HISTORY.lock().map_err(|e| e.to_string())?;
+    let since = chrono::Utc::now().timestamp_millis() - (range_secs.unwrap_or(3600) * 1000) as i64;
+    let samples: Vec<&SystemSample> = history.iter().filter(|s| s.timestamp >= since).collect();
+
+    let Some(resolution) = resolution_secs.filter(|r| *r > 0) else {
+        return Ok(samples.into_iter().cloned().collect());
+    };
+    let bucket_ms = (resolution * 1000) as i64;
+
+    let mut result = Vec::new();
+    let mut bucket: Vec<&SystemSample> = Vec::new();
+    let mut bucket_start = None;
+    for sample in samples {
+        let start = sample.timestamp - sample.timestamp.rem_euclid(bucket_ms);
+        if bucket_start.is_some_and(|b| b != start) {
+            result.push(average(&bucket, bucket_start.unwrap_or(start)));
+            bucket.clear();
+        }
+        bucket_start = Some(start);
+        bucket.push(sample);
+    }
+    if let Some(start) = bucket_start {
+        result.push(average(&bucket, start));
+    }
+    Ok(result)
+}
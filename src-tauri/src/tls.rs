@@ -0,0 +1,230 @@
+use axum::Router;
+use axum_server::tls_rustls::RustlsConfig;
+use chrono::{Datelike, Duration, Utc};
+use rcgen::{
+    BasicConstraints, Certificate, CertificateParams, CidrSubnet, DistinguishedName, DnType, ExtendedKeyUsagePurpose,
+    GeneralSubtree, IsCa, KeyPair, KeyUsagePurpose, NameConstraints,
+};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+use tokio::sync::mpsc;
+
+/// Browsers reject leaf certificates valid for longer than this
+const LEAF_VALIDITY_DAYS: i64 = 397;
+const CA_VALIDITY_YEARS: i32 = 10;
+
+/// Hostnames and addresses the local certificate covers
+const LEAF_NAMES: &[&str] = &["localhost", "*.localhost", "127.0.0.1", "::1"];
+
+// Ports currently served over HTTPS by spawn_server, so tunnels know to speak TLS upstream
+static HTTPS_PORTS: Mutex<BTreeSet<u16>> = Mutex::new(BTreeSet::new());
+
+/// Whether one of DevLaunch's own servers is serving HTTPS on `port`
+pub fn is_https_port(port: u16) -> bool {
+    HTTPS_PORTS.lock().map(|ports| ports.contains(&port)).unwrap_or(false)
+}
+
+/// Names the local CA may sign for, so a leaked CA key can't be used for real domains
+fn ca_name_constraints() -> NameConstraints {
+    NameConstraints {
+        permitted_subtrees: vec![
+            GeneralSubtree::DnsName("localhost".to_string()),
+            GeneralSubtree::IpAddress(CidrSubnet::V4([127, 0, 0, 0], [255, 0, 0, 0])),
+            GeneralSubtree::IpAddress(CidrSubnet::V6(
+                std::net::Ipv6Addr::LOCALHOST.octets(),
+                [0xff; 16],
+            )),
+        ],
+        excluded_subtrees: Vec::new(),
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LocalCaInfo {
+    /// Add this to the system/browser trust store to trust DevLaunch's HTTPS servers
+    pub ca_cert_path: String,
+    pub cert_path: String,
+    pub key_path: String,
+    /// Command that trusts the CA on this platform
+    pub trust_command: Option<String>,
+}
+
+struct TlsPaths {
+    ca_cert: PathBuf,
+    ca_key: PathBuf,
+    cert: PathBuf,
+    key: PathBuf,
+}
+
+fn tls_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?
+        .join("tls");
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create TLS dir: {}", e))?;
+    Ok(dir)
+}
+
+fn paths(app: &AppHandle) -> Result<TlsPaths, String> {
+    let dir = tls_dir(app)?;
+    Ok(TlsPaths {
+        ca_cert: dir.join("ca.pem"),
+        ca_key: dir.join("ca-key.pem"),
+        cert: dir.join("localhost.pem"),
+        key: dir.join("localhost-key.pem"),
+    })
+}
+
+fn generate_ca(paths: &TlsPaths) -> Result<Certificate, String> {
+    let mut params = CertificateParams::default();
+    let mut name = DistinguishedName::new();
+    name.push(DnType::CommonName, "DevLaunch Local CA");
+    name.push(DnType::OrganizationName, "DevLaunch");
+    params.distinguished_name = name;
+    params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    params.name_constraints = Some(ca_name_constraints());
+    params.key_usages = vec![KeyUsagePurpose::KeyCertSign, KeyUsagePurpose::CrlSign, KeyUsagePurpose::DigitalSignature];
+    let now = Utc::now() - Duration::days(1);
+    params.not_before = rcgen::date_time_ymd(now.year(), now.month() as u8, now.day() as u8);
+    params.not_after = rcgen::date_time_ymd(now.year() + CA_VALIDITY_YEARS, now.month() as u8, 1);
+
+    let ca = Certificate::from_params(params).map_err(|e| format!("Failed to create local CA: {}", e))?;
+    std::fs::write(&paths.ca_cert, ca.serialize_pem().map_err(|e| e.to_string())?)
+        .map_err(|e| format!("Failed to write CA certificate: {}", e))?;
+    crate::secrets::write_private_file(&paths.ca_key, &ca.serialize_private_key_pem())?;
+    println!("[tls] Created local CA at {}", paths.ca_cert.display());
+    Ok(ca)
+}
+
+/// Load the CA from disk, creating it the first time
+fn load_or_create_ca(paths: &TlsPaths) -> Result<Certificate, String> {
+    let existing = std::fs::read_to_string(&paths.ca_cert)
+        .ok()
+        .zip(std::fs::read_to_string(&paths.ca_key).ok());
+    let Some((cert_pem, key_pem)) = existing else {
+        return generate_ca(paths);
+    };
+    let key = KeyPair::from_pem(&key_pem).map_err(|e| format!("Invalid local CA key: {}", e))?;
+    let params = CertificateParams::from_ca_cert_pem(&cert_pem, key).map_err(|e| format!("Invalid local CA: {}", e))?;
+    // CAs from older builds could sign for any domain; replace them
+    if params.name_constraints.is_none() {
+        println!("[tls] Replacing unconstrained local CA; trust the new one and remove the old \"DevLaunch Local CA\"");
+        return generate_ca(paths);
+    }
+    Certificate::from_params(params).map_err(|e| format!("Invalid local CA: {}", e))
+}
+
+/// Issue a fresh localhost certificate signed by the local CA (cheap, so done on every start
+/// instead of tracking expiry)
+fn issue_leaf(paths: &TlsPaths) -> Result<(), String> {
+    let ca = load_or_create_ca(paths)?;
+
+    let mut params = CertificateParams::new(LEAF_NAMES.iter().map(|n| n.to_string()).collect::<Vec<_>>());
+    params.distinguished_name = DistinguishedName::new();
+    params.distinguished_name.push(DnType::CommonName, "localhost");
+    params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
+    let ymd = |d: chrono::DateTime<Utc>| rcgen::date_time_ymd(d.year(), d.month() as u8, d.day() as u8);
+    params.not_before = ymd(Utc::now() - Duration::days(1));
+    params.not_after = ymd(Utc::now() + Duration::days(LEAF_VALIDITY_DAYS));
+
+    let leaf = Certificate::from_params(params).map_err(|e| format!("Failed to create certificate: {}", e))?;
+    let cert_pem = leaf
+        .serialize_pem_with_signer(&ca)
+        .map_err(|e| format!("Failed to sign certificate: {}", e))?;
+    std::fs::write(&paths.cert, cert_pem).map_err(|e| format!("Failed to write certificate: {}", e))?;
+    crate::secrets::write_private_file(&paths.key, &leaf.serialize_private_key_pem())
+}
+
+#[cfg(target_os = "macos")]
+fn trust_command(ca_cert: &str) -> Option<String> {
+    Some(format!(
+        "security add-trusted-cert -r trustRoot -k ~/Library/Keychains/login.keychain-db \"{}\"",
+        ca_cert
+    ))
+}
+
+#[cfg(target_os = "linux")]
+fn trust_command(ca_cert: &str) -> Option<String> {
+    Some(format!(
+        "sudo cp \"{}\" /usr/local/share/ca-certificates/devlaunch.crt && sudo update-ca-certificates",
+        ca_cert
+    ))
+}
+
+#[cfg(windows)]
+fn trust_command(ca_cert: &str) -> Option<String> {
+    Some(format!("certutil -user -addstore Root \"{}\"", ca_cert))
+}
+
+/// Certificate and key for an HTTPS server, issuing them (and the CA) as needed
+pub async fn rustls_config(app: &AppHandle) -> Result<RustlsConfig, String> {
+    let paths = paths(app)?;
+    issue_leaf(&paths)?;
+    RustlsConfig::from_pem_file(&paths.cert, &paths.key)
+        .await
+        .map_err(|e| format!("Failed to load TLS certificate: {}", e))
+}
+
+/// Serve `router` on `listener` until `shutdown_rx` fires, over HTTPS when `tls` is given
+pub fn spawn_server(
+    listener: tokio::net::TcpListener,
+    router: Router,
+    tls: Option<RustlsConfig>,
+    mut shutdown_rx: mpsc::Receiver<()>,
+) -> Result<(), String> {
+    let Some(tls) = tls else {
+        tokio::spawn(async move {
+            axum::serve(listener, router)
+                .with_graceful_shutdown(async move {
+                    shutdown_rx.recv().await;
+                })
+                .await
+                .ok();
+        });
+        return Ok(());
+    };
+
+    let listener = listener.into_std().map_err(|e| e.to_string())?;
+    let port = listener.local_addr().map_err(|e| e.to_string())?.port();
+    if let Ok(mut ports) = HTTPS_PORTS.lock() {
+        ports.insert(port);
+    }
+    let handle = axum_server::Handle::new();
+    let shutdown_handle = handle.clone();
+    tokio::spawn(async move {
+        shutdown_rx.recv().await;
+        shutdown_handle.graceful_shutdown(Some(std::time::Duration::from_secs(2)));
+    });
+    tokio::spawn(async move {
+        axum_server::from_tcp_rustls(listener, tls)
+            .handle(handle)
+            .serve(router.into_make_service())
+            .await
+            .ok();
+        if let Ok(mut ports) = HTTPS_PORTS.lock() {
+            ports.remove(&port);
+        }
+    });
+    Ok(())
+}
+
+/// Paths of the local CA and localhost certificate (created on first call), for trusting
+/// the CA or reusing the certificate in a dev server
+#[tauri::command]
+pub async fn get_local_ca(app: AppHandle) -> Result<LocalCaInfo, String> {
+    let paths = paths(&app)?;
+    if !paths.cert.exists() || !paths.ca_cert.exists() {
+        issue_leaf(&paths)?;
+    }
+    let ca_cert = paths.ca_cert.to_string_lossy().to_string();
+    Ok(LocalCaInfo {
+        trust_command: trust_command(&ca_cert),
+        ca_cert_path: ca_cert,
+        cert_path: paths.cert.to_string_lossy().to_string(),
+        key_path: paths.key.to_string_lossy().to_string(),
+    })
+}
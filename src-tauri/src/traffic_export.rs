@@ -0,0 +1,284 @@
+use crate::proxy::ProxyExchange;
+use crate::webhook_filter::WebhookEventFilter;
+use crate::webhook_server::WebhookEvent;
+use serde_json::json;
+use std::collections::HashMap;
+use tauri::AppHandle;
+use tauri_plugin_dialog::DialogExt;
+
+/// A captured request/response in a source-neutral shape
+struct Exchange {
+    timestamp: i64,
+    method: String,
+    url: String,
+    query: String,
+    request_headers: HashMap<String, String>,
+    request_body: String,
+    status: Option<u16>,
+    response_headers: HashMap<String, String>,
+    response_body: String,
+    latency_ms: u64,
+}
+
+impl Exchange {
+    fn from_webhook(event: &WebhookEvent) -> Self {
+        // The Host header reflects how the sender reached us (e.g. a tunnel domain)
+        let host = event
+            .headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case("host"))
+            .map(|(_, v)| v.clone())
+            .unwrap_or_else(|| "localhost".to_string());
+        let forward = event.forward.as_ref();
+        Exchange {
+            timestamp: event.timestamp,
+            method: event.method.clone(),
+            url: with_query(&format!("http://{}{}", host, event.path), &event.query),
+            query: event.query.clone(),
+            request_headers: event.headers.clone(),
+            request_body: event.body.clone(),
+            status: Some(event.response_status).filter(|s| *s > 0),
+            response_headers: forward.map(|f| f.headers.clone()).unwrap_or_default(),
+            response_body: forward.map(|f| f.body.clone()).unwrap_or_default(),
+            latency_ms: forward.map(|f| f.latency_ms).unwrap_or(0),
+        }
+    }
+
+    fn from_proxy(exchange: &ProxyExchange) -> Self {
+        Exchange {
+            timestamp: exchange.timestamp,
+            method: exchange.method.clone(),
+            url: with_query(
+                &format!("http://localhost:{}{}", exchange.target_port, exchange.path),
+                &exchange.query,
+            ),
+            query: exchange.query.clone(),
+            request_headers: exchange.request_headers.clone(),
+            request_body: exchange.request_body.clone(),
+            status: exchange.status,
+            response_headers: exchange.response_headers.clone(),
+            response_body: exchange.response_body.clone(),
+            latency_ms: exchange.latency_ms,
+        }
+    }
+}
+
+fn with_query(url: &str, query: &str) -> String {
+    if query.is_empty() {
+        url.to_string()
+    } else {
+        format!("{}?{}", url, query)
+    }
+}
+
+fn header(headers: &HashMap<String, String>, name: &str) -> Option<String> {
+    headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.clone())
+}
+
+/// HAR name/value list, sorted so exports are stable
+fn har_pairs(headers: &HashMap<String, String>) -> Vec<serde_json::Value> {
+    let mut pairs: Vec<(&String, &String)> = headers.iter().collect();
+    pairs.sort();
+    pairs
+        .into_iter()
+        .map(|(name, value)| json!({ "name": name, "value": value }))
+        .collect()
+}
+
+fn har_entry(e: &Exchange) -> serde_json::Value {
+    let started = chrono::DateTime::from_timestamp_millis(e.timestamp)
+        .unwrap_or_default()
+        .to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+    let query_string: Vec<serde_json::Value> = e
+        .query
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            json!({ "name": name, "value": value })
+        })
+        .collect();
+
+    let mut request = json!({
+        "method": e.method,
+        "url": e.url,
+        "httpVersion": "HTTP/1.1",
+        "cookies": [],
+        "headers": har_pairs(&e.request_headers),
+        "queryString": query_string,
+        "headersSize": -1,
+        "bodySize": e.request_body.len(),
+    });
+    if !e.request_body.is_empty() {
+        request["postData"] = json!({
+            "mimeType": header(&e.request_headers, "content-type").unwrap_or_default(),
+            "text": e.request_body,
+        });
+    }
+
+    let status = e.status.unwrap_or(0);
+    json!({
+        "startedDateTime": started,
+        "time": e.latency_ms,
+        "request": request,
+        "response": {
+            "status": status,
+            "statusText": axum::http::StatusCode::from_u16(status)
+                .ok()
+                .and_then(|s| s.canonical_reason())
+                .unwrap_or(""),
+            "httpVersion": "HTTP/1.1",
+            "cookies": [],
+            "headers": har_pairs(&e.response_headers),
+            "content": {
+                "size": e.response_body.len(),
+                "mimeType": header(&e.response_headers, "content-type").unwrap_or_default(),
+                "text": e.response_body,
+            },
+            "redirectURL": header(&e.response_headers, "location").unwrap_or_default(),
+            "headersSize": -1,
+            "bodySize": e.response_body.len(),
+        },
+        "cache": {},
+        "timings": { "send": 0, "wait": e.latency_ms, "receive": 0 },
+    })
+}
+
+fn to_har(exchanges: &[Exchange]) -> String {
+    let har = json!({
+        "log": {
+            "version": "1.2",
+            "creator": { "name": "DevLaunch", "version": env!("CARGO_PKG_VERSION") },
+            "entries": exchanges.iter().map(har_entry).collect::<Vec<_>>(),
+        }
+    });
+    serde_json::to_string_pretty(&har).unwrap_or_default()
+}
+
+/// Single-quote for POSIX shells
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+fn to_curl(e: &Exchange) -> String {
+    let mut parts = vec!["curl".to_string(), "-X".to_string(), e.method.clone(), shell_quote(&e.url)];
+    let mut headers: Vec<(&String, &String)> = e
+        .request_headers
+        .iter()
+        .filter(|(k, _)| !crate::webhook_server::is_hop_by_hop(k))
+        .collect();
+    headers.sort();
+    for (name, value) in headers {
+        parts.push(format!("-H {}", shell_quote(&format!("{}: {}", name, value))));
+    }
+    if !e.request_body.is_empty() {
+        parts.push(format!("--data-raw {}", shell_quote(&e.request_body)));
+    }
+    parts.join(" \\\n  ")
+}
+
+/// Captured traffic from webhooks, proxies or both, oldest first
+async fn collect(app: &AppHandle, source: &str, filter: &WebhookEventFilter) -> Vec<Exchange> {
+    let mut exchanges = Vec::new();
+    if source == "webhook" || source == "all" {
+        exchanges.extend(
+            crate::webhook_server::all_events(app)
+                .await
+                .iter()
+                .filter(|e| filter.matches(e))
+                .map(Exchange::from_webhook),
+        );
+    }
+    if source == "proxy" || source == "all" {
+        exchanges.extend(
+            crate::proxy::exchanges_internal(None)
+                .await
+                .iter()
+                .filter(|e| filter.matches_exchange(e))
+                .map(Exchange::from_proxy),
+        );
+    }
+    exchanges.sort_by_key(|e| e.timestamp);
+    exchanges
+}
+
+/// Ask where to save, returning None if the user cancels
+async fn pick_save_path(app: &AppHandle, default_name: &str, label: &str, extension: &str) -> Option<std::path::PathBuf> {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    app.dialog()
+        .file()
+        .set_file_name(default_name)
+        .add_filter(label, &[extension])
+        .save_file(move |path| {
+            let _ = tx.send(path);
+        });
+    rx.await.ok().flatten().and_then(|p| p.into_path().ok())
+}
+
+/// Export captured traffic matching `filter` as "har" (HAR 1.2) or "curl" (a shell script),
+/// from `source` "webhook" (default), "proxy" or "all". Writes to `path`, or asks with a save
+/// dialog when omitted; returns the written path, or None if the dialog was cancelled.
+#[tauri::command]
+pub async fn export_webhook_events(
+    app: AppHandle,
+    format: String,
+    filter: Option<WebhookEventFilter>,
+    source: Option<String>,
+    path: Option<String>,
+) -> Result<Option<String>, String> {
+    let source = source.unwrap_or_else(|| "webhook".to_string());
+    let exchanges = collect(&app, &source, &filter.unwrap_or_default()).await;
+    if exchanges.is_empty() {
+        return Err("No captured requests match the filter".to_string());
+    }
+
+    let (content, label, extension) = match format.as_str() {
+        "har" => (to_har(&exchanges), "HTTP Archive", "har"),
+        "curl" => {
+            let commands: Vec<String> = exchanges.iter().map(to_curl).collect();
+            (format!("#!/bin/sh\n\n{}\n", commands.join("\n\n")), "Shell script", "sh")
+        }
+        other => return Err(format!("Unsupported export format: {}", other)),
+    };
+
+    let default_name = format!("devlaunch-{}-{}.{}", source, chrono::Local::now().format("%Y%m%d-%H%M%S"), extension);
+    let target = match path {
+        Some(path) => std::path::PathBuf::from(path),
+        None => match pick_save_path(&app, &default_name, label, extension).await {
+            Some(path) => path,
+            None => return Ok(None),
+        },
+    };
+
+    std::fs::write(&target, content).map_err(|e| format!("Failed to write {}: {}", target.display(), e))?;
+    println!("[traffic_export] Wrote {} requests to {}", exchanges.len(), target.display());
+    Ok(Some(target.to_string_lossy().to_string()))
+}
+
+/// `curl` command reproducing one captured webhook event or proxy exchange, for the clipboard.
+/// `base_url` (e.g. http://localhost:3000) replaces the scheme and host when given.
+#[tauri::command]
+pub async fn copy_event_as_curl(app: AppHandle, id: String, base_url: Option<String>) -> Result<String, String> {
+    let mut exchange = match crate::webhook_server::all_events(&app).await.iter().find(|e| e.id == id) {
+        Some(event) => Exchange::from_webhook(event),
+        None => crate::proxy::exchanges_internal(None)
+            .await
+            .iter()
+            .find(|e| e.id == id)
+            .map(Exchange::from_proxy)
+            .ok_or_else(|| format!("Captured request not found: {}", id))?,
+    };
+
+    if let Some(base) = base_url {
+        let mut url = reqwest::Url::parse(&exchange.url).map_err(|e| e.to_string())?;
+        let base = reqwest::Url::parse(&base).map_err(|e| format!("Invalid base URL: {}", e))?;
+        url.set_scheme(base.scheme()).map_err(|_| "Invalid base URL scheme".to_string())?;
+        url.set_host(base.host_str()).map_err(|e| e.to_string())?;
+        url.set_port(base.port()).map_err(|_| "Invalid base URL port".to_string())?;
+        exchange.url = url.to_string();
+    }
+    Ok(to_curl(&exchange))
+}
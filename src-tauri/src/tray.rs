@@ -0,0 +1,206 @@
+use crate::process::{self, ProcessRecord};
+use crate::projects::project_name;
+use std::sync::Mutex;
+use tauri::{
+    image::Image,
+    menu::{IsMenuItem, Menu, MenuItem, Submenu},
+    AppHandle, Wry,
+};
+use tauri_plugin_opener::OpenerExt;
+
+/// Id of the tray icon created in lib.rs
+pub const TRAY_ID: &str = "main";
+
+/// The app icon, used for the tray
+pub const ICON: &[u8] = include_bytes!("../icons/32x32.png");
+
+lazy_static::lazy_static! {
+    // CPU of all tracked process trees from the latest metrics sample
+    static ref LAST_CPU: Mutex<Option<f32>> = Mutex::new(None);
+    // Title and warning state last applied, so the icon is only swapped on changes
+    static ref APPLIED: Mutex<Option<(String, bool)>> = Mutex::new(None);
+}
+
+// Menu item ids for per-project actions look like "project:<action>:<managed id>"
+const PROJECT_PREFIX: &str = "project:";
+
+/// Folder name of a project path, for menu labels
+fn project_label(record: &ProcessRecord) -> String {
+    // Parsed rather than split on ':', which also matches the scheme and anything in the path
+    let port = record
+        .url
+        .as_deref()
+        .and_then(|u| tauri::Url::parse(u).ok())
+        .and_then(|u| u.port_or_known_default());
+    match port {
+        Some(port) => format!("{} (:{})", project_name(&record.cwd), port),
+        None => project_name(&record.cwd),
+    }
+}
+
+fn project_submenu(app: &AppHandle, record: &ProcessRecord) -> tauri::Result<Submenu<Wry>> {
+    let id = |action: &str| format!("{}{}:{}", PROJECT_PREFIX, action, record.id);
+    let stop = MenuItem::with_id(app, id("stop"), "Stop", true, None::<&str>)?;
+    let restart = MenuItem::with_id(app, id("restart"), "Restart", true, None::<&str>)?;
+    let open = MenuItem::with_id(app, id("open"), "Open in Browser", record.url.is_some(), None::<&str>)?;
+    Submenu::with_items(app, project_label(record), true, &[&open, &restart, &stop])
+}
+
+/// Build the tray menu: running projects first, then the window/quit items
+pub fn build_menu(app: &AppHandle) -> tauri::Result<Menu<Wry>> {
+    let running: Vec<ProcessRecord> = process::list_processes_internal()
+        .into_iter()
+        .filter(|r| r.running)
+        .collect();
+
+    let project_menus = running
+        .iter()
+        .map(|record| project_submenu(app, record))
+        .collect::<tauri::Result<Vec<_>>>()?;
+    let no_projects = MenuItem::with_id(app, "no-projects", "No running projects", false, None::<&str>)?;
+
+    let tray_quit = MenuItem::with_id(app, "quit", "Quit DevLaunch", true, None::<&str>)?;
+    let tray_show = MenuItem::with_id(app, "show", "Show Window", true, None::<&str>)?;
+    let tray_hide = MenuItem::with_id(app, "hide", "Hide Window", true, None::<&str>)?;
+    let tray_sep = MenuItem::with_id(app, "sep", "─────────────", false, None::<&str>)?;
+    let tray_sep2 = MenuItem::with_id(app, "sep2", "─────────────", false, None::<&str>)?;
+
+    let mut items: Vec<&dyn IsMenuItem<Wry>> = Vec::new();
+    if project_menus.is_empty() {
+        items.push(&no_projects);
+    } else {
+        items.extend(project_menus.iter().map(|m| m as &dyn IsMenuItem<Wry>));
+    }
+    items.extend([
+        &tray_sep2 as &dyn IsMenuItem<Wry>,
+        &tray_show,
+        &tray_hide,
+        &tray_sep,
+        &tray_quit,
+    ]);
+
+    Menu::with_items(app, &items)
+}
+
+/// Rebuild the tray menu after a process starts, exits or reports its URL
+pub fn refresh(app: &AppHandle) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return;
+    };
+    match build_menu(app) {
+        Ok(menu) => {
+            let _ = tray.set_menu(Some(menu));
+        }
+        Err(e) => println!("[tray] Failed to rebuild menu: {}", e),
+    }
+    apply_status(app);
+}
+
+/// The tray icon with an orange dot in the bottom-right corner
+fn warning_icon() -> tauri::Result<Image<'static>> {
+    let icon = Image::from_bytes(ICON)?;
+    let (width, height) = (icon.width(), icon.height());
+    let mut rgba = icon.rgba().to_vec();
+    let radius = width.min(height) as f32 * 0.28;
+    let (cx, cy) = (width as f32 - radius, height as f32 - radius);
+    for y in 0..height {
+        for x in 0..width {
+            let (dx, dy) = (x as f32 + 0.5 - cx, y as f32 + 0.5 - cy);
+            if dx * dx + dy * dy <= radius * radius {
+                let i = ((y * width + x) * 4) as usize;
+                rgba[i..i + 4].copy_from_slice(&[0xf5, 0x9e, 0x0b, 0xff]);
+            }
+        }
+    }
+    Ok(Image::new_owned(rgba, width, height))
+}
+
+/// Update the tray title (macOS menu bar) and tooltip with the running count and CPU,
+/// and badge the icon while any tracked process has crashed
+fn apply_status(app: &AppHandle) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return;
+    };
+    let preferences = crate::config::load_config_internal(app)
+        .map(|c| c.preferences.tray)
+        .unwrap_or_default();
+    let records = process::list_processes_internal();
+    let running = records.iter().filter(|r| r.running).count();
+    // Exited entries that weren't stopped on purpose stay in the list until relaunched
+    let crashed = records.iter().any(|r| r.crashed);
+
+    let mut parts = Vec::new();
+    if preferences.show_running_count && running > 0 {
+        parts.push(running.to_string());
+    }
+    if let Some(cpu) = LAST_CPU.lock().ok().and_then(|c| *c).filter(|_| preferences.show_cpu && running > 0) {
+        parts.push(format!("{:.0}%", cpu));
+    }
+    let title = parts.join(" · ");
+
+    let Ok(mut applied) = APPLIED.lock() else {
+        return;
+    };
+    if applied.as_ref() == Some(&(title.clone(), crashed)) {
+        return;
+    }
+    let _ = tray.set_title(Some(&title));
+    let tooltip = match (running, crashed) {
+        (0, false) => "DevLaunch".to_string(),
+        (n, false) => format!("DevLaunch: {} running", n),
+        (n, true) => format!("DevLaunch: {} running, a process crashed", n),
+    };
+    let _ = tray.set_tooltip(Some(&tooltip));
+    let icon = if crashed { warning_icon() } else { Image::from_bytes(ICON) };
+    match icon {
+        Ok(icon) => {
+            let _ = tray.set_icon(Some(icon));
+        }
+        Err(e) => println!("[tray] Failed to load tray icon: {}", e),
+    }
+    *applied = Some((title, crashed));
+}
+
+/// Record the latest process metrics sample and update the tray status
+pub fn update_status(app: &AppHandle, metrics: &[crate::system::ProcessMetrics]) {
+    if let Ok(mut cpu) = LAST_CPU.lock() {
+        *cpu = Some(metrics.iter().map(|m| m.cpu_percent).sum());
+    }
+    apply_status(app);
+}
+
+/// Handle a per-project tray action; returns false for ids that aren't project items
+pub fn handle_project_menu_event(app: &AppHandle, id: &str) -> bool {
+    let Some((action, managed_id)) = id
+        .strip_prefix(PROJECT_PREFIX)
+        .and_then(|rest| rest.split_once(':'))
+    else {
+        return false;
+    };
+    let Some(record) = process::find_process(managed_id) else {
+        return true;
+    };
+
+    match action {
+        "stop" => {
+            if let Err(e) = process::kill_with_hooks(app, record.pid) {
+                println!("[tray] Failed to stop {}: {}", record.cwd, e);
+            }
+        }
+        "restart" => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = process::restart_process_internal(&app, record.pid).await {
+                    println!("[tray] Failed to restart {}: {}", record.cwd, e);
+                }
+            });
+        }
+        "open" => {
+            if let Some(url) = &record.url {
+                let _ = app.opener().open_url(url, None::<&str>);
+            }
+        }
+        _ => {}
+    }
+    true
+}
@@ -0,0 +1,419 @@
+use crate::config;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::AppHandle;
+
+/// Local API of the ngrok agent (tunnels are added and removed here at runtime)
+const NGROK_API: &str = "http://127.0.0.1:4040/api/tunnels";
+
+/// How long to wait for a freshly started agent to serve its API
+const AGENT_STARTUP_TIMEOUT_SECS: u64 = 10;
+
+/// How long cloudflared/localtunnel get to print their public URL
+const URL_TIMEOUT_SECS: u64 = 30;
+
+lazy_static! {
+    // A single ngrok agent session hosts every named tunnel
+    static ref NGROK_AGENT: Mutex<Option<Child>> = Mutex::new(None);
+    // cloudflared/localtunnel run one process per tunnel, keyed by tunnel name
+    static ref PROCESS_TUNNELS: Mutex<HashMap<String, ProcessTunnel>> = Mutex::new(HashMap::new());
+    // Public URLs printed by cloudflared quick tunnels and localtunnel
+    static ref PUBLIC_URL_REGEX: Regex =
+        Regex::new(r"https://[a-zA-Z0-9.-]+\.(?:trycloudflare\.com|loca\.lt|localtunnel\.me)").unwrap();
+}
+
+/// A tunnel backed by its own provider process
+struct ProcessTunnel {
+    child: Child,
+    info: TunnelInfo,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TunnelInfo {
+    pub name: String,
+    pub provider: String,
+    pub port: u16,
+    pub proto: String,
+    pub public_url: String,
+    pub request_count: u64,
+    pub connection_count: u64,
+}
+
+/// Tunnel names end up in the agent API path, so keep them URL-safe
+fn validate_name(name: &str) -> Result<(), String> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!(
+            "Invalid tunnel name '{}': use letters, digits, '-' or '_'",
+            name
+        ));
+    }
+    Ok(())
+}
+
+fn agent_running() -> bool {
+    NGROK_AGENT
+        .lock()
+        .map(|mut agent| match agent.as_mut() {
+            Some(child) => matches!(child.try_wait(), Ok(None)),
+            None => false,
+        })
+        .unwrap_or(false)
+}
+
+/// Start the ngrok agent (with no tunnels) if it isn't already running
+async fn ensure_agent() -> Result<(), String> {
+    if agent_running() {
+        return Ok(());
+    }
+
+    {
+        let mut agent = NGROK_AGENT.lock().map_err(|e| e.to_string())?;
+        if let Some(mut child) = agent.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+
+        let child = Command::new("ngrok")
+            .args(["start", "--none"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Failed to start ngrok: {}. Make sure ngrok is installed.", e))?;
+        println!("[tunnels] Started ngrok agent with PID {:?}", child.id());
+        *agent = Some(child);
+    }
+
+    let client = reqwest::Client::new();
+    let deadline = std::time::Instant::now() + Duration::from_secs(AGENT_STARTUP_TIMEOUT_SECS);
+    while std::time::Instant::now() < deadline {
+        if client.get(NGROK_API).send().await.is_ok() {
+            return Ok(());
+        }
+        if !agent_running() {
+            return Err("ngrok exited on startup. Check your auth token in Preferences.".to_string());
+        }
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+
+    Err("Timed out waiting for the ngrok agent to start".to_string())
+}
+
+fn stop_agent() {
+    if let Ok(mut agent) = NGROK_AGENT.lock() {
+        if let Some(mut child) = agent.take() {
+            println!("[tunnels] Stopping ngrok agent");
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// Parse one entry of the agent's tunnel list
+fn parse_tunnel(tunnel: &serde_json::Value) -> Option<TunnelInfo> {
+    let name = tunnel.get("name")?.as_str()?.to_string();
+    let public_url = tunnel.get("public_url")?.as_str()?.to_string();
+    let proto = tunnel
+        .get("proto")
+        .and_then(|p| p.as_str())
+        .unwrap_or("http")
+        .to_string();
+    // addr looks like "http://localhost:3000" or "localhost:3000"
+    let port = tunnel
+        .get("config")
+        .and_then(|c| c.get("addr"))
+        .and_then(|a| a.as_str())
+        .and_then(|a| a.rsplit(':').next())
+        .and_then(|p| p.trim_end_matches('/').parse().ok())
+        .unwrap_or(0);
+
+    let metrics = tunnel.get("metrics");
+    let request_count = metrics
+        .and_then(|m| m.get("http"))
+        .and_then(|h| h.get("count"))
+        .and_then(|c| c.as_u64())
+        .unwrap_or(0);
+    let connection_count = metrics
+        .and_then(|m| m.get("conns"))
+        .and_then(|c| c.get("count"))
+        .and_then(|c| c.as_u64())
+        .unwrap_or(0);
+
+    Some(TunnelInfo {
+        name,
+        provider: "ngrok".to_string(),
+        port,
+        proto,
+        public_url,
+        request_count,
+        connection_count,
+    })
+}
+
+async fn fetch_ngrok_tunnels() -> Result<Vec<TunnelInfo>, String> {
+    if !agent_running() {
+        return Ok(Vec::new());
+    }
+
+    let response = reqwest::Client::new()
+        .get(NGROK_API)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to query ngrok: {}", e))?;
+    let data: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse ngrok response: {}", e))?;
+
+    let mut tunnels: Vec<TunnelInfo> = data
+        .get("tunnels")
+        .and_then(|t| t.as_array())
+        .map(|t| t.iter().filter_map(parse_tunnel).collect())
+        .unwrap_or_default();
+
+    // http tunnels may show up twice (http + https) under "name" and "name (http)"
+    tunnels.retain(|t| !t.name.ends_with(" (http)"));
+    Ok(tunnels)
+}
+
+/// Tunnels run by cloudflared/localtunnel processes, dropping any whose process exited
+fn process_tunnels() -> Vec<TunnelInfo> {
+    let Ok(mut tunnels) = PROCESS_TUNNELS.lock() else {
+        return Vec::new();
+    };
+    tunnels.retain(|_, t| matches!(t.child.try_wait(), Ok(None)));
+    tunnels.values().map(|t| t.info.clone()).collect()
+}
+
+async fn fetch_tunnels() -> Result<Vec<TunnelInfo>, String> {
+    let mut tunnels = fetch_ngrok_tunnels().await?;
+    tunnels.extend(process_tunnels());
+    tunnels.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(tunnels)
+}
+
+/// Tunnel provider selected in preferences
+pub fn preferred_provider(app: &AppHandle) -> String {
+    config::load_config_internal(app)
+        .map(|c| c.preferences.tunnel_provider)
+        .unwrap_or_else(|_| "ngrok".to_string())
+}
+
+/// Upstream URL for a local port, over HTTPS when one of DevLaunch's TLS servers listens there
+fn local_url(port: u16) -> String {
+    let scheme = if crate::tls::is_https_port(port) { "https" } else { "http" };
+    format!("{}://localhost:{}", scheme, port)
+}
+
+/// Spawn a cloudflared quick tunnel or localtunnel and wait for it to print its public URL
+async fn start_process_tunnel(provider: &str, name: &str, port: u16) -> Result<TunnelInfo, String> {
+    let https = crate::tls::is_https_port(port);
+    let (program, mut args): (&str, Vec<String>) = match provider {
+        // Quick tunnels need no account; cloudflared logs the URL to stderr
+        "cloudflared" => (
+            "cloudflared",
+            vec!["tunnel".to_string(), "--no-autoupdate".to_string(), "--url".to_string(), local_url(port)],
+        ),
+        "localtunnel" => (
+            if cfg!(windows) { "npx.cmd" } else { "npx" },
+            vec!["--yes".to_string(), "localtunnel".to_string(), "--port".to_string(), port.to_string()],
+        ),
+        other => return Err(format!("Unknown tunnel provider: {}", other)),
+    };
+    // The local certificate is signed by DevLaunch's own CA, which the providers don't trust
+    if https {
+        match provider {
+            "cloudflared" => args.push("--no-tls-verify".to_string()),
+            _ => args.extend(["--local-https".to_string(), "--allow-invalid-cert".to_string()]),
+        }
+    }
+
+    let mut child = Command::new(program)
+        .args(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to start {}: {}. Make sure it is installed.", provider, e))?;
+    println!("[tunnels] Started {} for '{}' with PID {}", provider, name, child.id());
+
+    // Watch both pipes for the URL, then keep draining them so the provider never blocks on output
+    let (url_tx, url_rx) = std::sync::mpsc::channel::<String>();
+    if let Some(stdout) = child.stdout.take() {
+        let tx = url_tx.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if let Some(m) = PUBLIC_URL_REGEX.find(&line) {
+                    let _ = tx.send(m.as_str().to_string());
+                }
+            }
+        });
+    }
+    if let Some(stderr) = child.stderr.take() {
+        let tx = url_tx;
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                if let Some(m) = PUBLIC_URL_REGEX.find(&line) {
+                    let _ = tx.send(m.as_str().to_string());
+                }
+            }
+        });
+    }
+
+    let public_url = tokio::task::spawn_blocking(move || url_rx.recv_timeout(Duration::from_secs(URL_TIMEOUT_SECS)))
+        .await
+        .map_err(|e| e.to_string())?;
+    let public_url = match public_url {
+        Ok(url) => url,
+        Err(_) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(format!("{} did not report a public URL", provider));
+        }
+    };
+
+    let info = TunnelInfo {
+        name: name.to_string(),
+        provider: provider.to_string(),
+        port,
+        proto: "http".to_string(),
+        public_url,
+        request_count: 0,
+        connection_count: 0,
+    };
+    PROCESS_TUNNELS
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(name.to_string(), ProcessTunnel { child, info: info.clone() });
+    Ok(info)
+}
+
+/// Open a named tunnel to a local port with the given provider
+/// (internal version, also used by the webhook server and log shares)
+pub async fn start_tunnel_internal(provider: &str, name: &str, port: u16, proto: &str) -> Result<TunnelInfo, String> {
+    validate_name(name)?;
+    if !matches!(proto, "http" | "tcp" | "tls") {
+        return Err(format!("Unsupported tunnel protocol: {}", proto));
+    }
+    if provider != "ngrok" && proto != "http" {
+        return Err(format!("{} only supports http tunnels", provider));
+    }
+
+    // Re-using a name replaces that tunnel, whichever provider it was on
+    if fetch_tunnels().await?.iter().any(|t| t.name == name) {
+        stop_tunnel_internal(name).await?;
+    }
+
+    if provider != "ngrok" {
+        println!("[tunnels] Opening {} tunnel '{}' to port {}", provider, name, port);
+        return start_process_tunnel(provider, name, port).await;
+    }
+
+    ensure_agent().await?;
+
+    println!("[tunnels] Opening {} tunnel '{}' to port {}", proto, name, port);
+    let response = reqwest::Client::new()
+        .post(NGROK_API)
+        .json(&serde_json::json!({
+            "name": name,
+            "proto": proto,
+            "addr": if proto == "http" { local_url(port) } else { port.to_string() },
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to create tunnel: {}", e))?;
+
+    let status = response.status();
+    let data: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse ngrok response: {}", e))?;
+
+    if !status.is_success() {
+        let msg = data
+            .get("msg")
+            .or_else(|| data.get("details").and_then(|d| d.get("err")))
+            .and_then(|m| m.as_str())
+            .unwrap_or("unknown error");
+        return Err(format!("ngrok refused the tunnel: {}", msg));
+    }
+
+    parse_tunnel(&data).ok_or_else(|| "ngrok returned no public URL".to_string())
+}
+
+/// Close a named tunnel; the ngrok agent exits once no ngrok tunnels remain
+pub async fn stop_tunnel_internal(name: &str) -> Result<(), String> {
+    validate_name(name)?;
+
+    let process_tunnel = PROCESS_TUNNELS.lock().map_err(|e| e.to_string())?.remove(name);
+    if let Some(mut tunnel) = process_tunnel {
+        let _ = tunnel.child.kill();
+        let _ = tunnel.child.wait();
+        println!("[tunnels] Closed {} tunnel '{}'", tunnel.info.provider, name);
+        return Ok(());
+    }
+
+    if !agent_running() {
+        return Err(format!("Tunnel '{}' is not running", name));
+    }
+
+    let response = reqwest::Client::new()
+        .delete(format!("{}/{}", NGROK_API, name))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to close tunnel: {}", e))?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(format!("Tunnel '{}' is not running", name));
+    }
+
+    // Also drop the plain-http twin of an http tunnel, if the agent created one
+    let _ = reqwest::Client::new()
+        .delete(format!("{}/{}%20(http)", NGROK_API, name))
+        .send()
+        .await;
+
+    println!("[tunnels] Closed tunnel '{}'", name);
+    if fetch_ngrok_tunnels().await.map(|t| t.is_empty()).unwrap_or(false) {
+        stop_agent();
+    }
+    Ok(())
+}
+
+/// Find one running tunnel by name
+pub async fn find_tunnel(name: &str) -> Result<Option<TunnelInfo>, String> {
+    Ok(fetch_tunnels().await?.into_iter().find(|t| t.name == name))
+}
+
+/// Expose a local port through a named tunnel. `provider` defaults to the one in preferences;
+/// `proto` is "http" (default), or "tcp"/"tls" with ngrok.
+#[tauri::command]
+pub async fn start_tunnel(
+    app: AppHandle,
+    name: String,
+    port: u16,
+    proto: Option<String>,
+    provider: Option<String>,
+) -> Result<TunnelInfo, String> {
+    let provider = provider.unwrap_or_else(|| preferred_provider(&app));
+    start_tunnel_internal(&provider, &name, port, proto.as_deref().unwrap_or("http")).await
+}
+
+#[tauri::command]
+pub async fn stop_tunnel(name: String) -> Result<(), String> {
+    stop_tunnel_internal(&name).await
+}
+
+/// List every open tunnel with its public URL
+#[tauri::command]
+pub async fn list_tunnels() -> Result<Vec<TunnelInfo>, String> {
+    fetch_tunnels().await
+}
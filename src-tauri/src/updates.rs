@@ -0,0 +1,143 @@
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+/// Update manifests per channel, attached to GitHub releases; "beta" is a rolling prerelease tag
+const STABLE_ENDPOINT: &str = "https://github.com/thisisvillegas/dev-launch/releases/latest/download/latest.json";
+const BETA_ENDPOINT: &str = "https://github.com/thisisvillegas/dev-launch/releases/download/beta/latest.json";
+
+lazy_static::lazy_static! {
+    // Update found by the last check, ready to download
+    static ref AVAILABLE: tokio::sync::Mutex<Option<Update>> = tokio::sync::Mutex::new(None);
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateInfo {
+    pub version: String,
+    pub current_version: String,
+    pub date: Option<String>,
+    /// Release notes
+    pub notes: Option<String>,
+    pub channel: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DownloadProgress {
+    downloaded: u64,
+    total: Option<u64>,
+}
+
+/// Updates are verified against `plugins.updater.pubkey`, which release builds merge into
+/// tauri.conf.json (see README); builds without one don't ship the updater
+fn has_signing_key(app: &AppHandle) -> bool {
+    app.config()
+        .plugins
+        .0
+        .get("updater")
+        .and_then(|u| u.get("pubkey"))
+        .and_then(|k| k.as_str())
+        .map(|k| !k.trim().is_empty())
+        .unwrap_or(false)
+}
+
+async fn check(app: &AppHandle) -> Result<Option<UpdateInfo>, String> {
+    if !has_signing_key(app) {
+        return Err("Updates are disabled: this build has no updater signing key".to_string());
+    }
+    let channel = crate::config::load_config_internal(app)
+        .map(|c| c.preferences.updates.channel)
+        .unwrap_or_else(|_| "stable".to_string());
+    let endpoint = if channel == "beta" { BETA_ENDPOINT } else { STABLE_ENDPOINT };
+    let url = endpoint.parse::<tauri::Url>().map_err(|e| e.to_string())?;
+
+    let update = app
+        .updater_builder()
+        .endpoints(vec![url])
+        .map_err(|e| e.to_string())?
+        .build()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| format!("Failed to check for updates: {}", e))?;
+
+    let info = update.as_ref().map(|u| UpdateInfo {
+        version: u.version.clone(),
+        current_version: u.current_version.clone(),
+        date: u.date.map(|d| d.to_string()),
+        notes: u.body.clone(),
+        channel: channel.clone(),
+    });
+    *AVAILABLE.lock().await = update;
+
+    match &info {
+        Some(info) => {
+            println!("[updates] {} is available ({} channel)", info.version, channel);
+            let _ = app.emit("update-available", info.clone());
+        }
+        None => println!("[updates] Up to date ({} channel)", channel),
+    }
+    Ok(info)
+}
+
+/// Check the configured channel for a newer version; emits `update-available` when there is one
+#[tauri::command]
+pub async fn check_for_updates(app: AppHandle) -> Result<Option<UpdateInfo>, String> {
+    check(&app).await
+}
+
+/// Download and install the update found by the last check, emitting `update-download-progress`
+/// and then `update-installed`; the new version runs after the next restart
+#[tauri::command]
+pub async fn download_and_install(app: AppHandle) -> Result<(), String> {
+    let update = AVAILABLE
+        .lock()
+        .await
+        .take()
+        .ok_or_else(|| "No update available; check for updates first".to_string())?;
+    let version = update.version.clone();
+
+    let mut downloaded = 0u64;
+    let progress_app = app.clone();
+    update
+        .download_and_install(
+            move |chunk, total| {
+                downloaded += chunk as u64;
+                let _ = progress_app.emit("update-download-progress", DownloadProgress { downloaded, total });
+            },
+            || println!("[updates] Download finished"),
+        )
+        .await
+        .map_err(|e| format!("Failed to install update: {}", e))?;
+
+    println!("[updates] Installed {}", version);
+    let _ = app.emit("update-installed", version);
+    Ok(())
+}
+
+/// Register the updater plugin when this build has a signing key, then check in the
+/// background when the preference is on. A failed check is emitted as `update-check-failed`.
+pub fn init(app: &AppHandle) {
+    if !has_signing_key(app) {
+        println!("[updates] No updater signing key in this build; updates are disabled");
+        return;
+    }
+    if let Err(e) = app.plugin(tauri_plugin_updater::Builder::new().build()) {
+        println!("[updates] Failed to start updater: {}", e);
+        return;
+    }
+    let enabled = crate::config::load_config_internal(app)
+        .map(|c| c.preferences.updates.check_on_launch)
+        .unwrap_or(true);
+    if !enabled {
+        return;
+    }
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = check(&app).await {
+            println!("[updates] {}", e);
+            let _ = app.emit("update-check-failed", e);
+        }
+    });
+}
@@ -0,0 +1,315 @@
+use crate::config::WatchRestart;
+use crate::process::{self, LogEvent};
+use crate::webhook_server::glob_regex;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+/// Directories that churn on every build or install and never need a restart
+const ALWAYS_IGNORED: &[&str] = &[".git", "node_modules", "target", "__pycache__", ".venv", "venv", ".devlaunch"];
+
+lazy_static::lazy_static! {
+    // Active watches keyed by project path
+    static ref WATCHES: Mutex<HashMap<String, ProjectWatch>> = Mutex::new(HashMap::new());
+}
+
+struct ProjectWatch {
+    // Dropping the watcher stops the watch
+    watcher: Arc<Mutex<RecommendedWatcher>>,
+    /// Folders watched one level deep because ignored folders sit somewhere below them
+    shallow: HashSet<PathBuf>,
+    settings: WatchRestart,
+    globs: Globs,
+    /// Process to restart; updated as restarts hand out new PIDs
+    pid: u32,
+    command: String,
+    /// Last matching change, while a restart is waiting out the debounce
+    pending: Option<Instant>,
+}
+
+/// A project's watch globs, compiled once per watch
+struct Globs {
+    paths: Vec<Regex>,
+    ignore: Vec<Regex>,
+}
+
+impl Globs {
+    fn new(settings: &WatchRestart) -> Self {
+        // "**/*.go" should also match files at the project root
+        let compile = |patterns: &[String]| -> Vec<Regex> {
+            patterns
+                .iter()
+                .flat_map(|p| std::iter::once(p.as_str()).chain(p.strip_prefix("**/")))
+                .filter_map(glob_regex)
+                .collect()
+        };
+        Globs {
+            paths: compile(&settings.paths),
+            ignore: compile(&settings.ignore),
+        }
+    }
+}
+
+fn is_ignored_dir(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| ALWAYS_IGNORED.contains(&name))
+}
+
+/// Whether a changed path (relative to the project) should trigger a restart
+fn is_relevant(globs: &Globs, relative: &str) -> bool {
+    if relative
+        .split('/')
+        .any(|segment| ALWAYS_IGNORED.contains(&segment))
+    {
+        return false;
+    }
+    globs.paths.iter().any(|r| r.is_match(relative)) && !globs.ignore.iter().any(|r| r.is_match(relative))
+}
+
+/// Plan watches for `dir` that never descend into ignored folders: a folder with none
+/// anywhere below it is watched recursively, the rest one level at a time. Returns whether
+/// `dir` itself is clean, leaving it to the caller to watch it.
+fn plan_watches(dir: &Path, plan: &mut Vec<(PathBuf, RecursiveMode)>) -> bool {
+    let subdirs: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                // Symlinked folders aren't followed
+                .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+                .map(|e| e.path())
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut clean = true;
+    let mut clean_subdirs = Vec::new();
+    for subdir in subdirs {
+        if is_ignored_dir(&subdir) {
+            clean = false;
+        } else if plan_watches(&subdir, plan) {
+            clean_subdirs.push(subdir);
+        } else {
+            clean = false;
+        }
+    }
+    if !clean {
+        plan.push((dir.to_path_buf(), RecursiveMode::NonRecursive));
+        plan.extend(clean_subdirs.into_iter().map(|d| (d, RecursiveMode::Recursive)));
+    }
+    clean
+}
+
+fn relative_path(cwd: &str, changed: &Path) -> Option<String> {
+    let relative = changed.strip_prefix(cwd).ok()?;
+    Some(relative.to_string_lossy().replace('\\', "/"))
+}
+
+/// Restart the project on file changes matching its watch_and_restart globs. Called for
+/// user launches; does nothing when the project has no (enabled) watch configured.
+pub(crate) fn watch(app: &AppHandle, cwd: &str, pid: u32) {
+    let Some(settings) = crate::config::load_config_internal(app)
+        .ok()
+        .and_then(|c| c.project_settings(cwd).watch_and_restart)
+        .filter(|w| w.enabled && !w.paths.is_empty())
+    else {
+        return;
+    };
+    let command = process::list_processes_internal()
+        .into_iter()
+        .find(|r| r.pid == pid)
+        .map(|r| r.command)
+        .unwrap_or_default();
+
+    let Ok(mut watches) = WATCHES.lock() else {
+        return;
+    };
+    // Relaunching an already-watched project only needs the new PID
+    if let Some(existing) = watches.get_mut(cwd) {
+        existing.pid = pid;
+        existing.command = command;
+        existing.globs = Globs::new(&settings);
+        existing.settings = settings;
+        return;
+    }
+
+    let (handler_app, handler_cwd) = (app.clone(), cwd.to_string());
+    let watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+        if let Ok(event) = result {
+            handle_event(&handler_app, &handler_cwd, event);
+        }
+    });
+    let mut watcher = match watcher {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            println!("[watch_restart] Failed to start file watcher: {}", e);
+            return;
+        }
+    };
+    let mut plan = Vec::new();
+    if plan_watches(Path::new(cwd), &mut plan) {
+        plan.push((PathBuf::from(cwd), RecursiveMode::Recursive));
+    }
+    let mut shallow = HashSet::new();
+    for (dir, mode) in plan {
+        if let Err(e) = watcher.watch(&dir, mode) {
+            println!("[watch_restart] Failed to watch {}: {}", dir.display(), e);
+            return;
+        }
+        if mode == RecursiveMode::NonRecursive {
+            shallow.insert(dir);
+        }
+    }
+
+    println!("[watch_restart] Watching {} for {:?}", cwd, settings.paths);
+    watches.insert(
+        cwd.to_string(),
+        ProjectWatch {
+            watcher: Arc::new(Mutex::new(watcher)),
+            shallow,
+            globs: Globs::new(&settings),
+            settings,
+            pid,
+            command,
+            pending: None,
+        },
+    );
+}
+
+/// Stop watching a project (it was stopped on purpose)
+pub(crate) fn unwatch(cwd: &str) {
+    // Drop the watcher outside the lock: its event thread may be waiting on it
+    let removed = WATCHES.lock().ok().and_then(|mut w| w.remove(cwd));
+    if removed.is_some() {
+        println!("[watch_restart] Stopped watching {}", cwd);
+    }
+}
+
+fn handle_event(app: &AppHandle, cwd: &str, event: notify::Event) {
+    if matches!(event.kind, EventKind::Access(_) | EventKind::Other) {
+        return;
+    }
+    let Ok(mut watches) = WATCHES.lock() else {
+        return;
+    };
+    let Some(entry) = watches.get_mut(cwd) else {
+        return;
+    };
+    if matches!(event.kind, EventKind::Create(_)) {
+        watch_new_dirs(entry, &event.paths);
+    }
+    let Some(changed) = event
+        .paths
+        .iter()
+        .filter_map(|p| relative_path(cwd, p))
+        .find(|relative| is_relevant(&entry.globs, relative))
+    else {
+        return;
+    };
+
+    let first = entry.pending.is_none();
+    entry.pending = Some(Instant::now());
+    if !first {
+        return;
+    }
+
+    let debounce = Duration::from_millis(entry.settings.debounce_ms);
+    let (app, cwd) = (app.clone(), cwd.to_string());
+    std::thread::spawn(move || {
+        // Wait until the files have been quiet for the debounce period
+        loop {
+            std::thread::sleep(debounce);
+            let quiet = WATCHES
+                .lock()
+                .ok()
+                .and_then(|w| w.get(&cwd).and_then(|e| e.pending))
+                .map(|last| last.elapsed() >= debounce);
+            match quiet {
+                Some(true) => break,
+                Some(false) => continue,
+                // Unwatched in the meantime
+                None => return,
+            }
+        }
+        restart(&app, &cwd, PathBuf::from(changed));
+    });
+}
+
+/// Folders created under a one-level watch aren't covered yet; watch them too (unless ignored)
+fn watch_new_dirs(entry: &ProjectWatch, paths: &[PathBuf]) {
+    let new_dirs: Vec<PathBuf> = paths
+        .iter()
+        .filter(|p| p.is_dir() && !is_ignored_dir(p))
+        .filter(|p| p.parent().is_some_and(|parent| entry.shallow.contains(parent)))
+        .cloned()
+        .collect();
+    if new_dirs.is_empty() {
+        return;
+    }
+    // Adding a watch waits on the watcher's event thread, which is the one calling us
+    let watcher = entry.watcher.clone();
+    std::thread::spawn(move || {
+        let Ok(mut watcher) = watcher.lock() else {
+            return;
+        };
+        for dir in new_dirs {
+            if let Err(e) = watcher.watch(&dir, RecursiveMode::Recursive) {
+                println!("[watch_restart] Failed to watch {}: {}", dir.display(), e);
+            }
+        }
+    });
+}
+
+/// Restart the watched process, or drop the watch when it's no longer running
+fn restart(app: &AppHandle, cwd: &str, changed: PathBuf) {
+    let target = {
+        let Ok(mut watches) = WATCHES.lock() else {
+            return;
+        };
+        let Some(entry) = watches.get_mut(cwd) else {
+            return;
+        };
+        entry.pending = None;
+        // Auto-restarts after a crash hand out new PIDs too; fall back to the same command
+        let running = process::list_processes_internal();
+        running
+            .iter()
+            .find(|r| r.running && r.pid == entry.pid)
+            .or_else(|| {
+                running
+                    .iter()
+                    .find(|r| r.running && r.cwd == cwd && r.command == entry.command)
+            })
+            .map(|r| r.pid)
+    };
+    let Some(pid) = target else {
+        unwatch(cwd);
+        return;
+    };
+
+    process::emit_log(
+        app,
+        LogEvent {
+            path: cwd.to_string(),
+            level: "info".to_string(),
+            message: format!("[watch] {} changed, restarting", changed.display()),
+            ..Default::default()
+        },
+    );
+    let (app, cwd) = (app.clone(), cwd.to_string());
+    tauri::async_runtime::spawn(async move {
+        match process::restart_process_internal(&app, pid).await {
+            Ok(new_pid) => {
+                if let Ok(mut watches) = WATCHES.lock() {
+                    if let Some(entry) = watches.get_mut(&cwd) {
+                        entry.pid = new_pid;
+                    }
+                }
+            }
+            Err(e) => println!("[watch_restart] Failed to restart {}: {}", cwd, e),
+        }
+    });
+}
@@ -0,0 +1,108 @@
+use crate::proxy::ProxyExchange;
+use crate::webhook_server::{glob_matches, WebhookEvent};
+use std::collections::HashMap;
+use serde::Deserialize;
+
+/// Criteria for narrowing captured webhooks; every set field must match
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEventFilter {
+    /// HTTP methods, case-insensitive (any if empty)
+    #[serde(default)]
+    pub methods: Vec<String>,
+    /// Path glob like "/stripe/*" or "/hooks/**"
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Response statuses: exact ("404") or a class ("4xx") (any if empty)
+    #[serde(default)]
+    pub statuses: Vec<String>,
+    /// Case-insensitive text searched in the path, query, headers and body
+    #[serde(default)]
+    pub search: Option<String>,
+    /// Timestamp range in ms, inclusive
+    #[serde(default)]
+    pub since: Option<i64>,
+    #[serde(default)]
+    pub until: Option<i64>,
+}
+
+fn status_matches(pattern: &str, status: u16) -> bool {
+    let pattern = pattern.trim().to_lowercase();
+    match pattern.strip_suffix("xx") {
+        Some(class) => class.parse::<u16>().map(|c| status / 100 == c).unwrap_or(false),
+        None => pattern.parse::<u16>().map(|s| s == status).unwrap_or(false),
+    }
+}
+
+/// The parts of a captured request a filter looks at
+struct Candidate<'a> {
+    method: &'a str,
+    path: &'a str,
+    query: &'a str,
+    status: u16,
+    timestamp: i64,
+    bodies: &'a [&'a str],
+    headers: &'a [&'a HashMap<String, String>],
+}
+
+impl WebhookEventFilter {
+    pub fn matches(&self, event: &WebhookEvent) -> bool {
+        self.matches_candidate(&Candidate {
+            method: &event.method,
+            path: &event.path,
+            query: &event.query,
+            status: event.response_status,
+            timestamp: event.timestamp,
+            bodies: &[&event.body],
+            headers: &[&event.headers],
+        })
+    }
+
+    /// Same criteria for recorded proxy traffic; search also covers the response
+    pub fn matches_exchange(&self, exchange: &ProxyExchange) -> bool {
+        self.matches_candidate(&Candidate {
+            method: &exchange.method,
+            path: &exchange.path,
+            query: &exchange.query,
+            status: exchange.status.unwrap_or(0),
+            timestamp: exchange.timestamp,
+            bodies: &[&exchange.request_body, &exchange.response_body],
+            headers: &[&exchange.request_headers, &exchange.response_headers],
+        })
+    }
+
+    fn matches_candidate(&self, c: &Candidate) -> bool {
+        if !self.methods.is_empty() && !self.methods.iter().any(|m| m.eq_ignore_ascii_case(c.method)) {
+            return false;
+        }
+        if let Some(path) = self.path.as_deref().filter(|p| !p.is_empty()) {
+            if !glob_matches(path, c.path) {
+                return false;
+            }
+        }
+        if !self.statuses.is_empty() && !self.statuses.iter().any(|s| status_matches(s, c.status)) {
+            return false;
+        }
+        if self.since.is_some_and(|since| c.timestamp < since) {
+            return false;
+        }
+        if self.until.is_some_and(|until| c.timestamp > until) {
+            return false;
+        }
+        if let Some(search) = self.search.as_deref().filter(|s| !s.is_empty()) {
+            let needle = search.to_lowercase();
+            let found = c.path.to_lowercase().contains(&needle)
+                || c.query.to_lowercase().contains(&needle)
+                || c.bodies.iter().any(|b| b.to_lowercase().contains(&needle))
+                || c.headers.iter().any(|headers| {
+                    headers
+                        .iter()
+                        .any(|(k, v)| k.to_lowercase().contains(&needle) || v.to_lowercase().contains(&needle))
+                });
+            if !found {
+                return false;
+            }
+        }
+        true
+    }
+}
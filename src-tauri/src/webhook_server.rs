@@ -1,16 +1,19 @@
 use axum::{
     body::Body,
     extract::{Request, State},
-    http::StatusCode,
-    response::IntoResponse,
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
     routing::any,
     Router,
 };
+use crate::config::{self, WebhookResponseRule, WebhookSigningSecrets};
+use crate::webhook_filter::WebhookEventFilter;
 use chrono::Utc;
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, net::SocketAddr, sync::Arc};
-use std::process::{Child, Command, Stdio};
+use std::{collections::HashMap, net::SocketAddr, path::PathBuf, sync::Arc};
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
 use tauri::{AppHandle, Emitter};
 use tokio::sync::{mpsc, Mutex};
 use tower_http::cors::CorsLayer;
@@ -23,11 +26,55 @@ pub struct WebhookEvent {
     pub method: String,
     pub headers: HashMap<String, String>,
     pub body: String,
+    /// Exact body bytes (hex) when they aren't valid UTF-8 and `body` is only a lossy rendering
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_hex: Option<String>,
     pub query: String,
+    /// Response from the forward target when forwarding is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forward: Option<ForwardResult>,
+    /// Id of the response rule that answered this request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matched_rule: Option<String>,
+    #[serde(default)]
+    pub response_status: u16,
+    /// Provider whose signature header was present ("github", "stripe", "slack")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_provider: Option<String>,
+    /// Whether the signature verified; None if unsigned or no secret is configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_valid: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_error: Option<String>,
+}
+
+impl WebhookEvent {
+    /// The body exactly as received, so replays keep provider signatures valid
+    pub fn raw_body(&self) -> Vec<u8> {
+        self.body_hex
+            .as_deref()
+            .and_then(|h| hex::decode(h).ok())
+            .unwrap_or_else(|| self.body.clone().into_bytes())
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ForwardResult {
+    pub target_url: String,
+    pub status: Option<u16>,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+    pub latency_ms: u64,
+    pub error: Option<String>,
 }
 
 struct ServerState {
     events: Arc<Mutex<Vec<WebhookEvent>>>,
+    forward_port: Arc<Mutex<Option<u16>>>,
+    rules: Arc<Mutex<Vec<WebhookResponseRule>>>,
+    secrets: Arc<Mutex<WebhookSigningSecrets>>,
+    // On-disk history (path, retention) when persistence is enabled
+    store: Option<(PathBuf, usize)>,
     app_handle: AppHandle,
 }
 
@@ -35,17 +82,242 @@ struct WebhookServer {
     shutdown_tx: Option<mpsc::Sender<()>>,
     port: u16,
     events: Arc<Mutex<Vec<WebhookEvent>>>,
+    forward_port: Arc<Mutex<Option<u16>>>,
+    rules: Arc<Mutex<Vec<WebhookResponseRule>>>,
+    secrets: Arc<Mutex<WebhookSigningSecrets>>,
+}
+
+/// Events kept in memory for the live view (persisted history can go further back)
+const MAX_MEMORY_EVENTS: usize = 100;
+
+// Requests received since launch, for the metrics endpoint
+static EVENTS_RECEIVED: AtomicU64 = AtomicU64::new(0);
+
+/// Total webhook requests received since DevLaunch started
+pub fn events_received_total() -> u64 {
+    EVENTS_RECEIVED.load(Ordering::Relaxed)
 }
 
 lazy_static! {
     static ref SERVER: Arc<Mutex<Option<WebhookServer>>> = Arc::new(Mutex::new(None));
-    static ref NGROK_PROCESS: std::sync::Mutex<Option<Child>> = std::sync::Mutex::new(None);
+    // Live-tail subscriptions by id, each owned by the window that subscribed
+    static ref STREAMS: Mutex<HashMap<String, StreamSubscription>> = Mutex::new(HashMap::new());
+    // {{placeholder}} in response rule body templates
+    static ref TEMPLATE_REGEX: regex::Regex = regex::Regex::new(r"\{\{\s*([\w.\-]+)\s*\}\}").unwrap();
+    // Compiled globs by pattern (None if the pattern doesn't compile), so matching doesn't rebuild them
+    static ref GLOB_CACHE: std::sync::Mutex<HashMap<String, Option<regex::Regex>>> =
+        std::sync::Mutex::new(HashMap::new());
+}
+
+/// Patterns come from user filters too, so drop the cache rather than let it grow without bound
+const MAX_CACHED_GLOBS: usize = 256;
+
+/// Compile a glob: `*` matches within a segment, `**` across segments, `?` one char
+pub(crate) fn glob_regex(pattern: &str) -> Option<regex::Regex> {
+    let mut regex = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                regex.push_str(".*");
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            _ => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    regex::Regex::new(&regex).ok()
+}
+
+/// Match a path against a glob (see glob_regex), compiling each pattern once
+pub(crate) fn glob_matches(pattern: &str, text: &str) -> bool {
+    let Ok(mut cache) = GLOB_CACHE.lock() else {
+        return glob_regex(pattern).is_some_and(|r| r.is_match(text));
+    };
+    if !cache.contains_key(pattern) {
+        if cache.len() >= MAX_CACHED_GLOBS {
+            cache.clear();
+        }
+        cache.insert(pattern.to_string(), glob_regex(pattern));
+    }
+    cache.get(pattern).and_then(|r| r.as_ref()).is_some_and(|r| r.is_match(text))
+}
+
+/// Find the first enabled rule matching a request
+fn find_rule<'a>(rules: &'a [WebhookResponseRule], method: &str, path: &str) -> Option<&'a WebhookResponseRule> {
+    rules.iter().find(|rule| {
+        rule.enabled
+            && rule
+                .method
+                .as_deref()
+                .map(|m| m == "*" || m.eq_ignore_ascii_case(method))
+                .unwrap_or(true)
+            && glob_matches(&rule.path, path)
+    })
+}
+
+/// Fill {{placeholders}} in a rule body from the incoming request
+fn render_template(template: &str, event: &WebhookEvent) -> String {
+    TEMPLATE_REGEX
+        .replace_all(template, |caps: &regex::Captures| {
+            let key = &caps[1];
+            match key {
+                "id" => event.id.clone(),
+                "timestamp" => event.timestamp.to_string(),
+                "method" => event.method.clone(),
+                "path" => event.path.clone(),
+                "query" => event.query.clone(),
+                "body" => event.body.clone(),
+                _ => key
+                    .strip_prefix("header.")
+                    .and_then(|name| event.headers.get(&name.to_ascii_lowercase()))
+                    .cloned()
+                    .unwrap_or_default(),
+            }
+        })
+        .to_string()
+}
+
+/// Build the configured response for a matched rule
+fn rule_response(rule: &WebhookResponseRule, event: &WebhookEvent) -> Response {
+    let status = StatusCode::from_u16(rule.status).unwrap_or(StatusCode::OK);
+    let mut response = (status, render_template(&rule.body, event)).into_response();
+    let headers = response.headers_mut();
+    if let Some(value) = rule
+        .content_type
+        .as_deref()
+        .and_then(|ct| HeaderValue::from_str(ct).ok())
+    {
+        headers.insert(header::CONTENT_TYPE, value);
+    }
+    for (name, value) in &rule.headers {
+        if let (Ok(name), Ok(value)) = (
+            header::HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            headers.insert(name, value);
+        }
+    }
+    response
+}
+
+/// Replace the response rules of the running server (no-op if it isn't running)
+pub async fn set_response_rules(rules: Vec<WebhookResponseRule>) {
+    let server = SERVER.lock().await;
+    if let Some(s) = server.as_ref() {
+        *s.rules.lock().await = rules;
+    }
+}
+
+/// Replace the signing secrets of the running server (no-op if it isn't running)
+pub async fn set_signing_secrets(secrets: WebhookSigningSecrets) {
+    let server = SERVER.lock().await;
+    if let Some(s) = server.as_ref() {
+        *s.secrets.lock().await = secrets;
+    }
+}
+
+/// Headers that describe the original connection and must not be re-sent
+pub(crate) fn is_hop_by_hop(name: &str) -> bool {
+    matches!(
+        name.to_ascii_lowercase().as_str(),
+        "host" | "content-length" | "connection" | "transfer-encoding" | "keep-alive" | "upgrade"
+    )
+}
+
+/// Send a captured request (method, headers, body) to a target URL and capture the response
+async fn send_captured(
+    method: &str,
+    target_url: &str,
+    headers: &HashMap<String, String>,
+    body: &[u8],
+) -> ForwardResult {
+    let started = std::time::Instant::now();
+    let failed = |e: String| ForwardResult {
+        target_url: target_url.to_string(),
+        status: None,
+        headers: HashMap::new(),
+        body: String::new(),
+        latency_ms: started.elapsed().as_millis() as u64,
+        error: Some(e),
+    };
+
+    let method = match reqwest::Method::from_bytes(method.as_bytes()) {
+        Ok(m) => m,
+        Err(e) => return failed(format!("Invalid method: {}", e)),
+    };
+
+    let client = reqwest::Client::new();
+    let mut request = client.request(method, target_url).body(body.to_vec());
+    for (name, value) in headers.iter().filter(|(name, _)| !is_hop_by_hop(name)) {
+        request = request.header(name.as_str(), value.as_str());
+    }
+
+    match request.send().await {
+        Ok(response) => {
+            let status = response.status().as_u16();
+            let mut response_headers = HashMap::new();
+            for (name, value) in response.headers() {
+                if let Ok(v) = value.to_str() {
+                    response_headers.insert(name.to_string(), v.to_string());
+                }
+            }
+            let response_body = response.text().await.unwrap_or_default();
+            ForwardResult {
+                target_url: target_url.to_string(),
+                status: Some(status),
+                headers: response_headers,
+                body: response_body,
+                latency_ms: started.elapsed().as_millis() as u64,
+                error: None,
+            }
+        }
+        Err(e) => failed(format!("Request failed: {}", e)),
+    }
+}
+
+/// Build the URL an event is sent to: base target plus the event's path and query
+/// (if the target doesn't already specify a path)
+fn event_target_url(target: &str, event_path: &str, query: &str) -> Result<String, String> {
+    let mut url = reqwest::Url::parse(target).map_err(|e| format!("Invalid target URL: {}", e))?;
+    if url.path() == "/" || url.path().is_empty() {
+        url.set_path(event_path);
+        if !query.is_empty() {
+            url.set_query(Some(query));
+        }
+    }
+    Ok(url.to_string())
+}
+
+/// Turn a forward result into the response returned to the webhook sender
+fn forward_response(result: &ForwardResult) -> Response {
+    let Some(status) = result.status else {
+        return (StatusCode::BAD_GATEWAY, result.error.clone().unwrap_or_default()).into_response();
+    };
+
+    let mut response = (
+        StatusCode::from_u16(status).unwrap_or(StatusCode::BAD_GATEWAY),
+        result.body.clone(),
+    )
+        .into_response();
+    if let Some(content_type) = result
+        .headers
+        .get("content-type")
+        .and_then(|v| HeaderValue::from_str(v).ok())
+    {
+        response.headers_mut().insert(header::CONTENT_TYPE, content_type);
+    }
+    response
 }
 
 async fn handle_webhook(
     State(state): State<Arc<ServerState>>,
     request: Request<Body>,
-) -> impl IntoResponse {
+) -> Response {
+    EVENTS_RECEIVED.fetch_add(1, Ordering::Relaxed);
+
     // Extract request info
     let method = request.method().to_string();
     let path = request.uri().path().to_string();
@@ -63,38 +335,179 @@ async fn handle_webhook(
     let body_bytes = axum::body::to_bytes(request.into_body(), 1024 * 1024) // 1MB limit
         .await
         .unwrap_or_default();
-    let body = String::from_utf8_lossy(&body_bytes).to_string();
+    let (body, body_hex) = match std::str::from_utf8(&body_bytes) {
+        Ok(text) => (text.to_string(), None),
+        Err(_) => (String::from_utf8_lossy(&body_bytes).to_string(), Some(hex::encode(&body_bytes))),
+    };
+
+    // Verify provider signatures against the raw bytes before anything else touches the body
+    let signature = {
+        let secrets = state.secrets.lock().await;
+        crate::webhook_signature::verify(&secrets, &headers, &body_bytes, Utc::now().timestamp())
+    };
+
+    // A matching response rule answers instead of forwarding
+    let rule = {
+        let rules = state.rules.lock().await;
+        find_rule(&rules, &method, &path).cloned()
+    };
+
+    // Proxy to the configured local port when always-forward is on
+    let forward_port = if rule.is_some() {
+        None
+    } else {
+        *state.forward_port.lock().await
+    };
+    let forward = match forward_port {
+        Some(port) => {
+            let target = format!("http://localhost:{}", port);
+            Some(match event_target_url(&target, &path, &query) {
+                Ok(url) => send_captured(&method, &url, &headers, &body_bytes).await,
+                Err(e) => ForwardResult {
+                    target_url: target,
+                    status: None,
+                    headers: HashMap::new(),
+                    body: String::new(),
+                    latency_ms: 0,
+                    error: Some(e),
+                },
+            })
+        }
+        None => None,
+    };
 
     // Create event
-    let event = WebhookEvent {
+    let mut event = WebhookEvent {
         id: uuid::Uuid::new_v4().to_string(),
         timestamp: Utc::now().timestamp_millis(),
         path,
         method,
         headers,
         body,
+        body_hex,
         query,
+        forward,
+        matched_rule: rule.as_ref().map(|r| r.id.clone()),
+        response_status: StatusCode::OK.as_u16(),
+        signature_provider: signature.as_ref().map(|c| c.provider.clone()),
+        signature_valid: signature.as_ref().and_then(|c| c.valid),
+        signature_error: signature.and_then(|c| c.error),
+    };
+    let response = match (&rule, &event.forward) {
+        (Some(rule), _) => {
+            if rule.delay_ms > 0 {
+                tokio::time::sleep(std::time::Duration::from_millis(rule.delay_ms)).await;
+            }
+            rule_response(rule, &event)
+        }
+        (None, Some(result)) => forward_response(result),
+        (None, None) => StatusCode::OK.into_response(),
     };
+    event.response_status = response.status().as_u16();
 
     // Store event
     {
         let mut events = state.events.lock().await;
         events.push(event.clone());
-        // Keep only last 100 events
-        if events.len() > 100 {
+        // Keep only the most recent events in memory
+        if events.len() > MAX_MEMORY_EVENTS {
             events.remove(0);
         }
     }
+    if let Some((path, max_events)) = &state.store {
+        if let Err(e) = crate::webhook_store::append(path, &event, *max_events) {
+            println!("[webhook_server] Failed to persist event: {}", e);
+        }
+    }
 
-    // Emit to frontend
-    let _ = state.app_handle.emit("webhook-received", event);
+    emit_event(&state.app_handle, event).await;
 
-    StatusCode::OK
+    response
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WebhookStreamEvent {
+    subscription_id: String,
+    event: WebhookEvent,
+}
+
+struct StreamSubscription {
+    /// Label of the subscribing window, which gets the webhook-stream events
+    window: String,
+    filter: WebhookEventFilter,
+}
+
+/// Emit webhook-received to everyone, plus webhook-stream to each subscription whose filter matches
+async fn emit_event(app: &AppHandle, event: WebhookEvent) {
+    let _ = app.emit("webhook-received", event.clone());
+    let streams = STREAMS.lock().await;
+    for (id, stream) in streams.iter() {
+        if stream.filter.matches(&event) {
+            let _ = app.emit_to(
+                stream.window.as_str(),
+                "webhook-stream",
+                WebhookStreamEvent {
+                    subscription_id: id.clone(),
+                    event: event.clone(),
+                },
+            );
+        }
+    }
 }
 
+/// Live-tail only events matching `filter` (as webhook-stream events to the calling window).
+/// Returns the subscription id.
 #[tauri::command]
-pub async fn start_webhook_server(app: AppHandle, port: u16) -> Result<String, String> {
+pub async fn subscribe_webhook_stream(
+    window: tauri::WebviewWindow,
+    filter: Option<WebhookEventFilter>,
+) -> Result<String, String> {
+    let id = uuid::Uuid::new_v4().to_string();
+    STREAMS.lock().await.insert(
+        id.clone(),
+        StreamSubscription {
+            window: window.label().to_string(),
+            filter: filter.unwrap_or_default(),
+        },
+    );
+    Ok(id)
+}
+
+/// Change the filter of an existing subscription
+#[tauri::command]
+pub async fn update_webhook_stream(subscription_id: String, filter: WebhookEventFilter) -> Result<(), String> {
+    let mut streams = STREAMS.lock().await;
+    let existing = streams
+        .get_mut(&subscription_id)
+        .ok_or_else(|| "Unknown webhook stream subscription".to_string())?;
+    existing.filter = filter;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn unsubscribe_webhook_stream(subscription_id: String) -> Result<(), String> {
+    STREAMS.lock().await.remove(&subscription_id);
+    Ok(())
+}
+
+/// Drop a window's subscriptions when it's destroyed or reloads (its listeners are gone)
+pub fn drop_window_streams(label: &str) {
+    let label = label.to_string();
+    tauri::async_runtime::spawn(async move {
+        STREAMS.lock().await.retain(|_, s| s.window != label);
+    });
+}
+
+#[tauri::command]
+pub async fn start_webhook_server(
+    app: AppHandle,
+    port: u16,
+    forward_port: Option<u16>,
+    tls: Option<bool>,
+) -> Result<String, String> {
     println!("[webhook_server] start_webhook_server called with port: {}", port);
+    let tls = tls.unwrap_or(false);
 
     let mut server = SERVER.lock().await;
     println!("[webhook_server] Got server lock");
@@ -104,11 +517,42 @@ pub async fn start_webhook_server(app: AppHandle, port: u16) -> Result<String, S
         return Err("Server is already running".to_string());
     }
 
-    let events: Arc<Mutex<Vec<WebhookEvent>>> = Arc::new(Mutex::new(Vec::new()));
-    let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
+    // Seed the live view from disk so events survive restarts
+    let store = persistent_store(&app);
+    let initial_events = match &store {
+        Some((path, _)) => {
+            let mut stored = crate::webhook_store::load(path);
+            stored.drain(..stored.len().saturating_sub(MAX_MEMORY_EVENTS));
+            stored
+        }
+        None => Vec::new(),
+    };
+    let events: Arc<Mutex<Vec<WebhookEvent>>> = Arc::new(Mutex::new(initial_events));
+    let forward_port = Arc::new(Mutex::new(forward_port));
+    let rules = Arc::new(Mutex::new(
+        config::load_config_internal(&app)
+            .map(|c| c.preferences.webhook_response_rules)
+            .unwrap_or_default(),
+    ));
+    let secrets = Arc::new(Mutex::new(
+        config::load_config_internal(&app)
+            .map(|c| c.preferences.webhook_signing_secrets)
+            .unwrap_or_default(),
+    ));
+    let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>(1);
+    // Providers that insist on HTTPS callbacks get a certificate from the local CA
+    let tls_config = if tls {
+        Some(crate::tls::rustls_config(&app).await?)
+    } else {
+        None
+    };
 
     let state = Arc::new(ServerState {
         events: events.clone(),
+        forward_port: forward_port.clone(),
+        rules: rules.clone(),
+        secrets: secrets.clone(),
+        store,
         app_handle: app,
     });
 
@@ -130,22 +574,18 @@ pub async fn start_webhook_server(app: AppHandle, port: u16) -> Result<String, S
     let actual_port = listener.local_addr().map_err(|e| e.to_string())?.port();
     println!("[webhook_server] Bound to port {}", actual_port);
 
-    tokio::spawn(async move {
-        axum::serve(listener, app_router)
-            .with_graceful_shutdown(async move {
-                shutdown_rx.recv().await;
-            })
-            .await
-            .ok();
-    });
+    crate::tls::spawn_server(listener, app_router, tls_config, shutdown_rx)?;
 
     *server = Some(WebhookServer {
         shutdown_tx: Some(shutdown_tx),
         port: actual_port,
         events,
+        forward_port,
+        rules,
+        secrets,
     });
 
-    let url = format!("http://localhost:{}", actual_port);
+    let url = format!("{}://localhost:{}", if tls { "https" } else { "http" }, actual_port);
     println!("[webhook_server] Server started at {}", url);
     Ok(url)
 }
@@ -164,20 +604,65 @@ pub async fn stop_webhook_server() -> Result<(), String> {
     }
 }
 
-#[tauri::command]
-pub async fn get_webhook_events() -> Result<Vec<WebhookEvent>, String> {
-    let server = SERVER.lock().await;
+/// Persisted store location and retention, if persistence is enabled in preferences
+fn persistent_store(app: &AppHandle) -> Option<(PathBuf, usize)> {
+    let prefs = config::load_config_internal(app).ok()?.preferences.webhook_store;
+    if !prefs.enabled {
+        return None;
+    }
+    crate::webhook_store::store_path(app)
+        .ok()
+        .map(|path| (path, prefs.max_events))
+}
 
-    if let Some(s) = server.as_ref() {
-        let events = s.events.lock().await;
-        Ok(events.clone())
-    } else {
-        Ok(Vec::new())
+/// All known events, oldest first: the on-disk history when persistence is enabled,
+/// otherwise the running server's in-memory list
+pub(crate) async fn all_events(app: &AppHandle) -> Vec<WebhookEvent> {
+    if let Some((path, _)) = persistent_store(app) {
+        return crate::webhook_store::load(&path);
+    }
+
+    let server = SERVER.lock().await;
+    match server.as_ref() {
+        Some(s) => s.events.lock().await.clone(),
+        None => Vec::new(),
     }
 }
 
+/// Get captured events (oldest first) matching `filter`; `since`/`until` narrow the time range (ms, inclusive).
+/// `offset`/`limit` page backwards from the newest match: offset 0 is the latest page.
 #[tauri::command]
-pub async fn clear_webhook_events() -> Result<(), String> {
+pub async fn get_webhook_events(
+    app: AppHandle,
+    offset: Option<usize>,
+    limit: Option<usize>,
+    since: Option<i64>,
+    until: Option<i64>,
+    filter: Option<WebhookEventFilter>,
+) -> Result<Vec<WebhookEvent>, String> {
+    let mut filter = filter.unwrap_or_default();
+    filter.since = filter.since.or(since);
+    filter.until = filter.until.or(until);
+
+    let mut events: Vec<WebhookEvent> = all_events(&app)
+        .await
+        .into_iter()
+        .filter(|e| filter.matches(e))
+        .collect();
+
+    let end = events.len().saturating_sub(offset.unwrap_or(0));
+    let start = limit.map(|l| end.saturating_sub(l)).unwrap_or(0);
+    events.truncate(end);
+    events.drain(..start);
+    Ok(events)
+}
+
+#[tauri::command]
+pub async fn clear_webhook_events(app: AppHandle) -> Result<(), String> {
+    if let Some((path, _)) = persistent_store(&app) {
+        crate::webhook_store::clear(&path)?;
+    }
+
     let server = SERVER.lock().await;
 
     if let Some(s) = server.as_ref() {
@@ -189,54 +674,60 @@ pub async fn clear_webhook_events() -> Result<(), String> {
     }
 }
 
+/// Enable (Some(port)) or disable (None) proxying every incoming webhook to a local port
 #[tauri::command]
-pub async fn get_webhook_server_status() -> Result<Option<u16>, String> {
+pub async fn set_webhook_forward_port(port: Option<u16>) -> Result<(), String> {
     let server = SERVER.lock().await;
-    Ok(server.as_ref().map(|s| s.port))
+    let s = server.as_ref().ok_or_else(|| "No server is running".to_string())?;
+    *s.forward_port.lock().await = port;
+    println!("[webhook_server] Forwarding set to {:?}", port);
+    Ok(())
 }
 
+/// Re-send a captured event to a target (e.g. http://localhost:3000) and return the response.
+/// If the target has no path, the event's original path and query are used.
 #[tauri::command]
-pub fn start_ngrok(port: u16) -> Result<(), String> {
-    println!("[ngrok] Starting ngrok for port {}", port);
-
-    let mut ngrok = NGROK_PROCESS.lock().map_err(|e| e.to_string())?;
+pub async fn replay_webhook_event(
+    app: AppHandle,
+    id: String,
+    target_url: String,
+) -> Result<ForwardResult, String> {
+    let event = all_events(&app)
+        .await
+        .into_iter()
+        .find(|e| e.id == id)
+        .ok_or_else(|| format!("Webhook event not found: {}", id))?;
 
-    // Kill existing ngrok process if any
-    if let Some(mut child) = ngrok.take() {
-        println!("[ngrok] Killing existing ngrok process");
-        let _ = child.kill();
-        let _ = child.wait();
-    }
+    let url = event_target_url(&target_url, &event.path, &event.query)?;
+    println!("[webhook_server] Replaying {} {} to {}", event.method, event.path, url);
+    Ok(send_captured(&event.method, &url, &event.headers, &event.raw_body()).await)
+}
 
-    // Start new ngrok process
-    let child = Command::new("ngrok")
-        .args(["http", &port.to_string()])
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .spawn()
-        .map_err(|e| format!("Failed to start ngrok: {}. Make sure ngrok is installed.", e))?;
+#[tauri::command]
+pub async fn get_webhook_server_status() -> Result<Option<u16>, String> {
+    let server = SERVER.lock().await;
+    Ok(server.as_ref().map(|s| s.port))
+}
 
-    println!("[ngrok] Started ngrok process with PID {:?}", child.id());
-    *ngrok = Some(child);
+/// Tunnel name used for the webhook receiver's public URL
+const WEBHOOK_TUNNEL: &str = "webhook";
 
+/// Expose the webhook server through the preferred tunnel provider
+/// (other ports use the named tunnels in tunnels.rs)
+#[tauri::command]
+pub async fn start_ngrok(app: AppHandle, port: u16) -> Result<(), String> {
+    let provider = crate::tunnels::preferred_provider(&app);
+    println!("[ngrok] Starting {} tunnel for port {}", provider, port);
+    crate::tunnels::start_tunnel_internal(&provider, WEBHOOK_TUNNEL, port, "http").await?;
     Ok(())
 }
 
 #[tauri::command]
-pub fn stop_ngrok() -> Result<(), String> {
+pub async fn stop_ngrok() -> Result<(), String> {
     println!("[ngrok] Stopping ngrok");
-
-    let mut ngrok = NGROK_PROCESS.lock().map_err(|e| e.to_string())?;
-
-    if let Some(mut child) = ngrok.take() {
-        println!("[ngrok] Killing ngrok process");
-        child.kill().map_err(|e| format!("Failed to kill ngrok: {}", e))?;
-        child.wait().map_err(|e| format!("Failed to wait for ngrok: {}", e))?;
-        println!("[ngrok] ngrok stopped");
-        Ok(())
-    } else {
-        Err("ngrok is not running".to_string())
-    }
+    crate::tunnels::stop_tunnel_internal(WEBHOOK_TUNNEL)
+        .await
+        .map_err(|_| "Tunnel is not running".to_string())
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -249,7 +740,9 @@ pub struct NgrokTunnelInfo {
 #[tauri::command]
 pub fn set_ngrok_auth_token(token: String) -> Result<(), String> {
     println!("[ngrok] Setting auth token");
-    
+
+    // The preferences modal may pass back the keychain reference it loaded
+    let token = crate::secrets::resolve(&token);
     let output = Command::new("ngrok")
         .args(["config", "add-authtoken", &token])
         .output()
@@ -266,62 +759,10 @@ pub fn set_ngrok_auth_token(token: String) -> Result<(), String> {
 
 #[tauri::command]
 pub async fn get_ngrok_status() -> Result<Option<NgrokTunnelInfo>, String> {
-    // Check if ngrok process is running
-    {
-        let ngrok = NGROK_PROCESS.lock().map_err(|e| e.to_string())?;
-        if ngrok.is_none() {
-            return Ok(None);
-        }
-    }
-
-    // Query ngrok API
-    let client = reqwest::Client::new();
-    let response = client
-        .get("http://localhost:4040/api/tunnels")
-        .send()
-        .await
-        .map_err(|e| format!("Failed to query ngrok: {}", e))?;
-
-    if !response.status().is_success() {
-        return Ok(None);
-    }
-
-    let data: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse ngrok response: {}", e))?;
-
-    if let Some(tunnels) = data.get("tunnels").and_then(|t| t.as_array()) {
-        if let Some(tunnel) = tunnels.first() {
-            let public_url = tunnel
-                .get("public_url")
-                .and_then(|u| u.as_str())
-                .unwrap_or("")
-                .to_string();
-
-            let request_count = tunnel
-                .get("metrics")
-                .and_then(|m| m.get("http"))
-                .and_then(|h| h.get("count"))
-                .and_then(|c| c.as_u64())
-                .unwrap_or(0);
-
-            let connection_count = tunnel
-                .get("metrics")
-                .and_then(|m| m.get("conns"))
-                .and_then(|c| c.get("count"))
-                .and_then(|c| c.as_u64())
-                .unwrap_or(0);
-
-            if !public_url.is_empty() {
-                return Ok(Some(NgrokTunnelInfo {
-                    public_url,
-                    request_count,
-                    connection_count,
-                }));
-            }
-        }
-    }
-
-    Ok(None)
+    let tunnel = crate::tunnels::find_tunnel(WEBHOOK_TUNNEL).await?;
+    Ok(tunnel.map(|t| NgrokTunnelInfo {
+        public_url: t.public_url,
+        request_count: t.request_count,
+        connection_count: t.connection_count,
+    }))
 }
@@ -0,0 +1,249 @@
+use crate::config::WebhookSigningSecrets;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::collections::HashMap;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Outcome of checking a provider signature on one request
+#[derive(Debug, Clone)]
+pub struct SignatureCheck {
+    pub provider: String,
+    /// None when the provider's headers are present but no secret is configured
+    pub valid: Option<bool>,
+    pub error: Option<String>,
+}
+
+impl SignatureCheck {
+    fn ok(provider: &str) -> Self {
+        Self {
+            provider: provider.to_string(),
+            valid: Some(true),
+            error: None,
+        }
+    }
+
+    fn invalid(provider: &str, error: impl Into<String>) -> Self {
+        Self {
+            provider: provider.to_string(),
+            valid: Some(false),
+            error: Some(error.into()),
+        }
+    }
+
+    fn unchecked(provider: &str) -> Self {
+        Self {
+            provider: provider.to_string(),
+            valid: None,
+            error: Some(format!("No {} signing secret configured", provider)),
+        }
+    }
+}
+
+/// Constant-time check of a hex HMAC-SHA256 signature over `message`
+fn hmac_matches(secret: &str, message: &[u8], signature_hex: &str) -> bool {
+    let Ok(expected) = hex::decode(signature_hex.trim()) else {
+        return false;
+    };
+    let Ok(mut mac) = HmacSha256::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(message);
+    mac.verify_slice(&expected).is_ok()
+}
+
+fn within_tolerance(timestamp: &str, now_secs: i64, tolerance_secs: u64) -> bool {
+    timestamp
+        .trim()
+        .parse::<i64>()
+        .map(|ts| (now_secs - ts).unsigned_abs() <= tolerance_secs)
+        .unwrap_or(false)
+}
+
+/// GitHub: `X-Hub-Signature-256: sha256=<hex>` over the raw body
+fn verify_github(secret: &str, header: &str, body: &[u8]) -> SignatureCheck {
+    match header.strip_prefix("sha256=") {
+        Some(sig) if hmac_matches(secret, body, sig) => SignatureCheck::ok("github"),
+        Some(_) => SignatureCheck::invalid("github", "Signature does not match"),
+        None => SignatureCheck::invalid("github", "Malformed X-Hub-Signature-256 header"),
+    }
+}
+
+/// Stripe: `Stripe-Signature: t=<ts>,v1=<hex>[,v1=...]` over `<ts>.<body>`
+fn verify_stripe(secret: &str, header: &str, body: &[u8], now_secs: i64, tolerance_secs: u64) -> SignatureCheck {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", v)) => timestamp = Some(v),
+            Some(("v1", v)) => signatures.push(v),
+            _ => {}
+        }
+    }
+
+    let Some(timestamp) = timestamp else {
+        return SignatureCheck::invalid("stripe", "Stripe-Signature has no timestamp");
+    };
+    if signatures.is_empty() {
+        return SignatureCheck::invalid("stripe", "Stripe-Signature has no v1 signature");
+    }
+
+    let mut payload = format!("{}.", timestamp).into_bytes();
+    payload.extend_from_slice(body);
+    if !signatures.iter().any(|sig| hmac_matches(secret, &payload, sig)) {
+        return SignatureCheck::invalid("stripe", "Signature does not match");
+    }
+    if !within_tolerance(timestamp, now_secs, tolerance_secs) {
+        return SignatureCheck::invalid("stripe", "Signature timestamp is outside the tolerance window");
+    }
+    SignatureCheck::ok("stripe")
+}
+
+/// Slack: `X-Slack-Signature: v0=<hex>` over `v0:<X-Slack-Request-Timestamp>:<body>`
+fn verify_slack(
+    secret: &str,
+    header: &str,
+    timestamp: Option<&String>,
+    body: &[u8],
+    now_secs: i64,
+    tolerance_secs: u64,
+) -> SignatureCheck {
+    let Some(timestamp) = timestamp else {
+        return SignatureCheck::invalid("slack", "Missing X-Slack-Request-Timestamp header");
+    };
+    let Some(sig) = header.strip_prefix("v0=") else {
+        return SignatureCheck::invalid("slack", "Malformed X-Slack-Signature header");
+    };
+
+    let mut payload = format!("v0:{}:", timestamp.trim()).into_bytes();
+    payload.extend_from_slice(body);
+    if !hmac_matches(secret, &payload, sig) {
+        return SignatureCheck::invalid("slack", "Signature does not match");
+    }
+    if !within_tolerance(timestamp, now_secs, tolerance_secs) {
+        return SignatureCheck::invalid("slack", "Signature timestamp is outside the tolerance window");
+    }
+    SignatureCheck::ok("slack")
+}
+
+/// Detect which provider signed a request (by its signature header) and verify it.
+/// Returns None for unsigned requests. `headers` keys are lowercase, as captured by axum.
+pub fn verify(
+    secrets: &WebhookSigningSecrets,
+    headers: &HashMap<String, String>,
+    body: &[u8],
+    now_secs: i64,
+) -> Option<SignatureCheck> {
+    let secret = |s: &Option<String>| s.clone().filter(|s| !s.is_empty());
+
+    if let Some(header) = headers.get("x-hub-signature-256") {
+        return Some(match secret(&secrets.github) {
+            Some(secret) => verify_github(&secret, header, body),
+            None => SignatureCheck::unchecked("github"),
+        });
+    }
+
+    if let Some(header) = headers.get("stripe-signature") {
+        return Some(match secret(&secrets.stripe) {
+            Some(secret) => verify_stripe(&secret, header, body, now_secs, secrets.tolerance_secs),
+            None => SignatureCheck::unchecked("stripe"),
+        });
+    }
+
+    if let Some(header) = headers.get("x-slack-signature") {
+        return Some(match secret(&secrets.slack) {
+            Some(secret) => verify_slack(
+                &secret,
+                header,
+                headers.get("x-slack-request-timestamp"),
+                body,
+                now_secs,
+                secrets.tolerance_secs,
+            ),
+            None => SignatureCheck::unchecked("slack"),
+        });
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn github_secrets(secret: &str) -> WebhookSigningSecrets {
+        WebhookSigningSecrets {
+            github: Some(secret.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn headers(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    // Example from GitHub's "Validating webhook deliveries" docs
+    const GITHUB_SECRET: &str = "It's a Secret to Everybody";
+    const GITHUB_BODY: &[u8] = b"Hello, World!";
+    const GITHUB_SIGNATURE: &str = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+    #[test]
+    fn github_known_signature_verifies() {
+        let check = verify(
+            &github_secrets(GITHUB_SECRET),
+            &headers(&[("x-hub-signature-256", GITHUB_SIGNATURE)]),
+            GITHUB_BODY,
+            0,
+        )
+        .unwrap();
+        assert_eq!(check.provider, "github");
+        assert_eq!(check.valid, Some(true));
+        assert!(check.error.is_none());
+    }
+
+    #[test]
+    fn github_rejects_tampered_body_and_wrong_secret() {
+        let header = headers(&[("x-hub-signature-256", GITHUB_SIGNATURE)]);
+        let tampered = verify(&github_secrets(GITHUB_SECRET), &header, b"Hello, World?", 0).unwrap();
+        assert_eq!(tampered.valid, Some(false));
+        let wrong_secret = verify(&github_secrets("not the secret"), &header, GITHUB_BODY, 0).unwrap();
+        assert_eq!(wrong_secret.valid, Some(false));
+    }
+
+    #[test]
+    fn github_without_secret_is_unchecked() {
+        let check = verify(
+            &WebhookSigningSecrets::default(),
+            &headers(&[("x-hub-signature-256", GITHUB_SIGNATURE)]),
+            GITHUB_BODY,
+            0,
+        )
+        .unwrap();
+        assert_eq!(check.valid, None);
+    }
+
+    #[test]
+    fn unsigned_request_is_not_checked() {
+        assert!(verify(&github_secrets(GITHUB_SECRET), &headers(&[]), GITHUB_BODY, 0).is_none());
+    }
+
+    // Example from Slack's "Verifying requests from Slack" docs
+    #[test]
+    fn slack_known_signature_verifies_within_tolerance() {
+        let body = b"token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V\
+&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=\
+&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN\
+&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+        let secrets = WebhookSigningSecrets {
+            slack: Some("8f742231b10e8888abcd99yyyzzz85a5".to_string()),
+            ..Default::default()
+        };
+        let request = headers(&[
+            ("x-slack-signature", "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503"),
+            ("x-slack-request-timestamp", "1531420618"),
+        ]);
+        assert_eq!(verify(&secrets, &request, body, 1531420618).unwrap().valid, Some(true));
+        let stale = verify(&secrets, &request, body, 1531420618 + 3600).unwrap();
+        assert_eq!(stale.valid, Some(false));
+    }
+}
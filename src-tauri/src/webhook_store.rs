@@ -0,0 +1,91 @@
+use crate::webhook_server::WebhookEvent;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+lazy_static::lazy_static! {
+    // Serializes file access and caches the number of stored lines (None until first counted)
+    static ref STORE: Mutex<Option<usize>> = Mutex::new(None);
+}
+
+/// JSON-lines file holding persisted webhook events (one event per line, oldest first)
+pub fn store_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    Ok(app_data_dir.join("webhook-events.jsonl"))
+}
+
+fn read_events(path: &Path) -> Vec<WebhookEvent> {
+    let Ok(file) = fs::File::open(path) else {
+        return Vec::new();
+    };
+    BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        // A torn last line (crash mid-write) is skipped rather than failing the whole load
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect()
+}
+
+fn write_events(path: &Path, events: &[WebhookEvent]) -> Result<(), String> {
+    let mut content = String::new();
+    for event in events {
+        let line = serde_json::to_string(event).map_err(|e| e.to_string())?;
+        content.push_str(&line);
+        content.push('\n');
+    }
+    let tmp = path.with_extension("jsonl.tmp");
+    fs::write(&tmp, content).map_err(|e| format!("Failed to write webhook events: {}", e))?;
+    fs::rename(&tmp, path).map_err(|e| format!("Failed to write webhook events: {}", e))
+}
+
+/// Load every persisted event, oldest first
+pub fn load(path: &Path) -> Vec<WebhookEvent> {
+    let _guard = STORE.lock();
+    read_events(path)
+}
+
+/// Append an event, compacting the file once it grows ~10% past `max_events`
+pub fn append(path: &Path, event: &WebhookEvent, max_events: usize) -> Result<(), String> {
+    let mut count = STORE.lock().map_err(|e| e.to_string())?;
+    let line = serde_json::to_string(event).map_err(|e| e.to_string())?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open webhook store: {}", e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("Failed to write webhook event: {}", e))?;
+
+    let stored = match *count {
+        Some(n) => n + 1,
+        None => read_events(path).len(),
+    };
+
+    let max_events = max_events.max(1);
+    if stored > max_events + max_events / 10 {
+        let events = read_events(path);
+        let keep = &events[events.len().saturating_sub(max_events)..];
+        write_events(path, keep)?;
+        *count = Some(keep.len());
+    } else {
+        *count = Some(stored);
+    }
+    Ok(())
+}
+
+/// Delete every persisted event
+pub fn clear(path: &Path) -> Result<(), String> {
+    let mut count = STORE.lock().map_err(|e| e.to_string())?;
+    if path.exists() {
+        fs::remove_file(path).map_err(|e| format!("Failed to clear webhook store: {}", e))?;
+    }
+    *count = Some(0);
+    Ok(())
+}
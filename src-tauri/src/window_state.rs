@@ -0,0 +1,89 @@
+use crate::config::{self, WindowState};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, PhysicalPosition, PhysicalSize, WebviewWindow};
+
+/// Wait for resizing/moving to settle before writing config
+const SAVE_DEBOUNCE_MS: u64 = 500;
+
+// Bumped on every window event; a pending save only runs if it's still the latest
+static SAVE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Whether a saved position is still on one of the connected monitors
+fn is_on_screen(window: &WebviewWindow, state: &WindowState) -> bool {
+    window
+        .available_monitors()
+        .map(|monitors| {
+            monitors.iter().any(|m| {
+                let pos = m.position();
+                let size = m.size();
+                state.x >= pos.x
+                    && state.y >= pos.y
+                    && state.x < pos.x + size.width as i32
+                    && state.y < pos.y + size.height as i32
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Apply the saved geometry to the main window (call before showing it)
+pub fn restore(app: &AppHandle, window: &WebviewWindow) {
+    let Some(state) = config::load_config_internal(app).ok().and_then(|c| c.window_state) else {
+        return;
+    };
+
+    if state.width > 0 && state.height > 0 {
+        let _ = window.set_size(PhysicalSize::new(state.width, state.height));
+    }
+    // Skip the position if that monitor is gone, so the window can't open off-screen
+    if is_on_screen(window, &state) {
+        let _ = window.set_position(PhysicalPosition::new(state.x, state.y));
+    }
+    if state.maximized {
+        let _ = window.maximize();
+    }
+}
+
+fn save_now(app: &AppHandle, window: &WebviewWindow) -> Result<(), String> {
+    if window.is_minimized().unwrap_or(false) {
+        return Ok(());
+    }
+
+    let maximized = window.is_maximized().unwrap_or(false);
+    let size = window.inner_size().map_err(|e| e.to_string())?;
+    let position = window.outer_position().map_err(|e| e.to_string())?;
+
+    // One load-modify-save step, so a frontend save at the same moment isn't overwritten
+    config::update_config(app, |config| {
+        // Keep the restored (un-maximized) geometry so un-maximizing after restart looks right
+        let state = match (maximized, config.window_state.take()) {
+            (true, Some(previous)) => WindowState { maximized: true, ..previous },
+            _ => WindowState {
+                width: size.width,
+                height: size.height,
+                x: position.x,
+                y: position.y,
+                maximized,
+            },
+        };
+        config.window_state = Some(state);
+        Ok(())
+    })
+    .map(|_| ())
+}
+
+/// Save the window geometry once it has stopped changing for a moment
+pub fn schedule_save(app: &AppHandle, window: &WebviewWindow) {
+    let generation = SAVE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let app = app.clone();
+    let window = window.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(SAVE_DEBOUNCE_MS));
+        if SAVE_GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        if let Err(e) = save_now(&app, &window) {
+            println!("[window_state] Failed to save window state: {}", e);
+        }
+    });
+}
@@ -1,11 +1,11 @@
 import { useEffect, useState, useCallback, useMemo } from "react";
-import { getCurrentWindow } from "@tauri-apps/api/window";
 import { listen } from "@tauri-apps/api/event";
 import { invoke } from "@tauri-apps/api/core";
 import { useAppStore } from "./stores/app-store";
 import { useLogStream } from "./hooks/useLogStream";
 import { useUrlStream } from "./hooks/useUrlStream";
 import { useConfig } from "./hooks/useConfig";
+import { QuitRequest } from "./types/project";
 import { useGitStatus } from "./hooks/useGitStatus";
 import { ProjectListItem } from "./components/ProjectListItem";
 import { MultiLogViewer } from "./components/MultiLogViewer";
@@ -30,6 +30,7 @@ function App() {
   const [projectsCollapsed, setProjectsCollapsed] = useState(false);
   const [showPreferences, setShowPreferences] = useState(false);
   const [collapsedSubdirs, setCollapsedSubdirs] = useState<Set<string>>(new Set());
+  const [updateError, setUpdateError] = useState<string | null>(null);
 
   const toggleSubdir = (key: string) => {
     setCollapsedSubdirs(prev => {
@@ -66,7 +67,6 @@ function App() {
   // Git status polling
   const { pullProject } = useGitStatus({
     enabled: preferences.git?.enabled ?? true,
-    pollingIntervalMinutes: preferences.git?.pollingIntervalMinutes ?? 10,
     tokens: preferences.git?.tokens ?? [],
   });
 
@@ -121,52 +121,53 @@ function App() {
     };
   }, []);
 
+  // The background update check on launch failed
+  useEffect(() => {
+    const unlisten = listen<string>("update-check-failed", (event) => {
+      setUpdateError(event.payload);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   useEffect(() => {
     // Dark mode by default
     document.documentElement.classList.add("dark");
   }, []);
 
-  // Handle window close event
+  // The backend asks before quitting (window close, ⌘Q, tray) while servers are running
   useEffect(() => {
-    const appWindow = getCurrentWindow();
-
-    const unlisten = appWindow.onCloseRequested(async (event) => {
-      // Check how many processes are running
-      try {
-        const count = await invoke<number>("get_running_count");
-        if (count > 0) {
-          // Prevent default close and show dialog
-          event.preventDefault();
-          setRunningCount(count);
-          setShowQuitDialog(true);
-        }
-        // If no processes running, let the window close normally
-      } catch {
-        // If we can't check, just close
-      }
+    const unlisten = listen<QuitRequest>("quit-requested", (event) => {
+      setRunningCount(event.payload.runningCount);
+      setShowQuitDialog(true);
+    });
+    // Unanswered for too long; the backend asks with a native dialog instead
+    const unlistenExpired = listen("quit-prompt-expired", () => {
+      setShowQuitDialog(false);
     });
 
     return () => {
       unlisten.then((fn) => fn());
+      unlistenExpired.then((fn) => fn());
     };
   }, []);
 
-  const handleStopAllAndQuit = async () => {
+  const resolveQuit = async (choice: "stop-all" | "leave-running" | "cancel") => {
+    setShowQuitDialog(false);
     try {
-      await invoke("kill_all_processes");
+      await invoke("resolve_quit", { choice });
     } catch (err) {
-      console.error("Failed to kill processes:", err);
+      console.error("Failed to resolve quit:", err);
     }
-    getCurrentWindow().close();
   };
 
-  const handleKeepRunningAndQuit = () => {
-    getCurrentWindow().destroy();
-  };
+  const handleStopAllAndQuit = () => resolveQuit("stop-all");
 
-  const handleCancelQuit = () => {
-    setShowQuitDialog(false);
-  };
+  const handleKeepRunningAndQuit = () => resolveQuit("leave-running");
+
+  const handleCancelQuit = () => resolveQuit("cancel");
 
   // Handle resize drag
   const handleMouseDown = useCallback((e: React.MouseEvent) => {
@@ -208,6 +209,15 @@ function App() {
         <SystemStats />
       </header>
 
+      {updateError && (
+        <div className="flex items-center justify-between px-4 py-1.5 text-xs bg-yellow-500/10 text-yellow-400 border-b border-border">
+          <span className="truncate" title={updateError}>{updateError}</span>
+          <button onClick={() => setUpdateError(null)} className="ml-4 hover:text-yellow-300">
+            Dismiss
+          </button>
+        </div>
+      )}
+
       {/* Preset Bar */}
       <PresetBar />
 
@@ -74,7 +74,7 @@ export function LogViewer({ project, showHeader = true, compact = false }: LogVi
   );
 }
 
-function LogLine({ entry }: { entry: LogEntry }) {
+export function LogLine({ entry }: { entry: LogEntry }) {
   const levelConfig = {
     info: {
       text: "text-sky-300",
@@ -0,0 +1,103 @@
+import { useEffect, useRef, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
+import { LogEntry } from "../types/project";
+import { LogLine } from "./LogViewer";
+
+// Set by open_log_window's initialization script
+declare global {
+  interface Window {
+    __DEVLAUNCH_LOG_WINDOW__?: { projectPath: string };
+  }
+}
+
+interface LogEvent {
+  path: string;
+  level: LogEntry["level"];
+  message: string;
+  fields?: Record<string, unknown>;
+  fingerprint?: string;
+  timestamp?: number;
+}
+
+interface BufferedLogLine {
+  timestamp: number;
+  level: LogEntry["level"];
+  message: string;
+  fields?: Record<string, unknown>;
+  fingerprint?: string;
+}
+
+interface LogPage {
+  lines: BufferedLogLine[];
+}
+
+// Keep the pop-out as light as the main log view
+const MAX_LINES = 5000;
+
+// Detached window streaming one project's logs (opened with open_log_window)
+export function LogWindow() {
+  const projectPath = window.__DEVLAUNCH_LOG_WINDOW__?.projectPath ?? "";
+  const name = projectPath.split(/[\\/]/).pop() || projectPath;
+  const [logs, setLogs] = useState<LogEntry[]>([]);
+  const scrollRef = useRef<HTMLDivElement>(null);
+
+  useEffect(() => {
+    document.documentElement.classList.add("dark");
+    if (!projectPath) return;
+
+    invoke<LogPage>("get_process_logs", { projectPath })
+      .then((page) =>
+        setLogs((live) => [
+          ...page.lines.map((l) => ({
+            timestamp: new Date(l.timestamp),
+            level: l.level,
+            message: l.message,
+            fields: l.fields,
+            fingerprint: l.fingerprint,
+          })),
+          ...live,
+        ])
+      )
+      .catch((err) => console.error("Failed to load logs:", err));
+
+    const unlisten = getCurrentWebviewWindow().listen<LogEvent>("log-window-log", (event) => {
+      const entry: LogEntry = {
+        timestamp: event.payload.timestamp ? new Date(event.payload.timestamp) : new Date(),
+        level: event.payload.level,
+        message: event.payload.message,
+        fields: event.payload.fields,
+        fingerprint: event.payload.fingerprint,
+      };
+      setLogs((prev) => [...prev, entry].slice(-MAX_LINES));
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [projectPath]);
+
+  // Auto-scroll to bottom on new logs
+  useEffect(() => {
+    if (scrollRef.current) {
+      scrollRef.current.scrollTop = scrollRef.current.scrollHeight;
+    }
+  }, [logs.length]);
+
+  return (
+    <div className="h-screen flex flex-col bg-background text-foreground">
+      <div className="flex items-center px-3 py-2 border-b border-border bg-muted/30">
+        <span className="font-medium text-sm truncate">Logs: {name}</span>
+      </div>
+      <div ref={scrollRef} className="flex-1 overflow-auto font-mono text-xs log-scroll p-4">
+        {logs.length === 0 ? (
+          <div className="text-muted-foreground text-center py-8">
+            No logs yet. Start the project to see output.
+          </div>
+        ) : (
+          logs.map((entry, i) => <LogLine key={i} entry={entry} />)
+        )}
+      </div>
+    </div>
+  );
+}
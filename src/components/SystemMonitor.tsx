@@ -32,15 +32,38 @@ interface SystemInfo {
   gpu: GpuInfo | null;
   uptime: string;
   load_average: number[];
+  battery: BatteryInfo | null;
+  thermal: ThermalInfo | null;
+}
+
+interface BatteryInfo {
+  percent: number;
+  state: "charging" | "discharging" | "charged" | "unknown";
+  on_battery: boolean;
+  minutes_remaining: number | null;
+}
+
+interface ThermalInfo {
+  pressure: "nominal" | "fair" | "serious" | "critical";
+  temperature_c: number | null;
+  cpu_speed_limit: number | null;
 }
 
 interface CpuInfo {
   usage_percent: number;
-  user_percent: number;
-  system_percent: number;
+  user_percent: number | null;    // null where the platform has no user/system split
+  system_percent: number | null;
   idle_percent: number;
   core_count: number;
   model: string;
+  cores: CoreUsage[];
+  max_core_percent: number;
+}
+
+interface CoreUsage {
+  index: number;
+  usage_percent: number;
+  frequency_mhz: number | null;
 }
 
 interface MemoryInfo {
@@ -68,6 +91,10 @@ interface GpuInfo {
   name: string;
   vendor: string;
   vram_mb: number | null;
+  utilization_percent: number | null;
+  renderer_utilization_percent: number | null;
+  tiler_utilization_percent: number | null;
+  in_use_memory_mb: number | null;
 }
 
 interface ProcessInfo {
@@ -78,8 +105,8 @@ interface ProcessInfo {
   memory_mb: number;
   memory_percent: number;
   vsz_mb: number;
-  private_mb: number;
-  shared_mb: number;
+  private_mb: number | null;   // null where the platform has no private/shared split
+  shared_mb: number | null;
   user: string;
   state: string;
   elapsed: string;
@@ -91,8 +118,8 @@ interface HistoryPoint {
   cpu: number;
   memory: number;
   disk: number;
-  cpuUser: number;
-  cpuSystem: number;
+  cpuUser: number | null;
+  cpuSystem: number | null;
 }
 
 type SortBy = "memory" | "cpu" | "name";
@@ -338,14 +365,18 @@ function ProcessDetailModal({
           <div className="bg-muted/20 rounded-lg p-3">
             <h3 className="text-xs font-semibold mb-2 text-muted-foreground">Memory Breakdown</h3>
             <div className="grid grid-cols-2 gap-2 text-sm">
-              <div className="flex justify-between">
-                <span className="text-muted-foreground">Private</span>
-                <span className="font-medium text-blue-400">{formatMemory(process.private_mb)}</span>
-              </div>
-              <div className="flex justify-between">
-                <span className="text-muted-foreground">Shared</span>
-                <span className="font-medium text-cyan-400">{formatMemory(process.shared_mb)}</span>
-              </div>
+              {process.private_mb !== null && (
+                <div className="flex justify-between">
+                  <span className="text-muted-foreground">Private</span>
+                  <span className="font-medium text-blue-400">{formatMemory(process.private_mb)}</span>
+                </div>
+              )}
+              {process.shared_mb !== null && (
+                <div className="flex justify-between">
+                  <span className="text-muted-foreground">Shared</span>
+                  <span className="font-medium text-cyan-400">{formatMemory(process.shared_mb)}</span>
+                </div>
+              )}
               <div className="flex justify-between">
                 <span className="text-muted-foreground">Virtual</span>
                 <span className="font-medium">{formatMemory(process.vsz_mb)}</span>
@@ -813,26 +844,40 @@ export function SystemMonitor() {
                     wrapperStyle={{ fontSize: "12px" }}
                     iconType="circle"
                   />
-                  <Area
-                    type="monotone"
-                    dataKey="cpuUser"
-                    name="User"
-                    stroke="#3b82f6"
-                    strokeWidth={2}
-                    fillOpacity={1}
-                    fill="url(#colorUser)"
-                    stackId="1"
-                  />
-                  <Area
-                    type="monotone"
-                    dataKey="cpuSystem"
-                    name="System"
-                    stroke="#ef4444"
-                    strokeWidth={2}
-                    fillOpacity={1}
-                    fill="url(#colorSystem)"
-                    stackId="1"
-                  />
+                  {systemInfo.cpu.system_percent !== null ? (
+                    <>
+                      <Area
+                        type="monotone"
+                        dataKey="cpuUser"
+                        name="User"
+                        stroke="#3b82f6"
+                        strokeWidth={2}
+                        fillOpacity={1}
+                        fill="url(#colorUser)"
+                        stackId="1"
+                      />
+                      <Area
+                        type="monotone"
+                        dataKey="cpuSystem"
+                        name="System"
+                        stroke="#ef4444"
+                        strokeWidth={2}
+                        fillOpacity={1}
+                        fill="url(#colorSystem)"
+                        stackId="1"
+                      />
+                    </>
+                  ) : (
+                    <Area
+                      type="monotone"
+                      dataKey="cpu"
+                      name="Total"
+                      stroke="#3b82f6"
+                      strokeWidth={2}
+                      fillOpacity={1}
+                      fill="url(#colorUser)"
+                    />
+                  )}
                 </AreaChart>
               </ResponsiveContainer>
             </div>
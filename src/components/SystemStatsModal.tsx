@@ -11,8 +11,8 @@ interface SystemInfo {
 
 interface CpuInfo {
   usage_percent: number;
-  user_percent: number;
-  system_percent: number;
+  user_percent: number | null;    // null where the platform has no user/system split
+  system_percent: number | null;
   idle_percent: number;
   core_count: number;
   model: string;
@@ -161,20 +161,24 @@ export function SystemStatsModal({
                 </span>
               </div>
               <div className="space-y-2">
-                <ProgressBar
-                  value={stats.cpu.user_percent}
-                  max={100}
-                  color="#3b82f6"
-                  label="User"
-                  rightLabel={`${stats.cpu.user_percent.toFixed(1)}%`}
-                />
-                <ProgressBar
-                  value={stats.cpu.system_percent}
-                  max={100}
-                  color="#8b5cf6"
-                  label="System"
-                  rightLabel={`${stats.cpu.system_percent.toFixed(1)}%`}
-                />
+                {stats.cpu.user_percent !== null && (
+                  <ProgressBar
+                    value={stats.cpu.user_percent}
+                    max={100}
+                    color="#3b82f6"
+                    label="User"
+                    rightLabel={`${stats.cpu.user_percent.toFixed(1)}%`}
+                  />
+                )}
+                {stats.cpu.system_percent !== null && (
+                  <ProgressBar
+                    value={stats.cpu.system_percent}
+                    max={100}
+                    color="#8b5cf6"
+                    label="System"
+                    rightLabel={`${stats.cpu.system_percent.toFixed(1)}%`}
+                  />
+                )}
                 <ProgressBar
                   value={stats.cpu.idle_percent}
                   max={100}
@@ -1,5 +1,6 @@
 import { useEffect, useRef, useCallback, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { useAppStore } from "../stores/app-store";
 import { AppConfig, Preferences, GitPreferences } from "../types/project";
 
@@ -35,6 +36,8 @@ export function useConfig() {
   const saveTimeoutRef = useRef<ReturnType<typeof setTimeout> | null>(null);
   const isLoadedRef = useRef(false);
   const isLoadingRef = useRef(false);
+  // Set while applying a config the backend reported, so it isn't saved straight back
+  const applyingExternalRef = useRef(false);
   const [preferences, setPreferencesState] = useState<Preferences>(DEFAULT_PREFERENCES);
 
   // Subscribe to store changes for watchedDirs and presets
@@ -82,6 +85,41 @@ export function useConfig() {
     loadConfig();
   }, []); // Empty deps - run once on mount
 
+  // config.json was edited outside DevLaunch; take the new contents instead of overwriting them
+  useEffect(() => {
+    const unlisten = listen<AppConfig>("config-changed", async (event) => {
+      if (!isLoadedRef.current) return;
+      const config = event.payload;
+      console.log("[Config] Changed on disk:", config);
+
+      // A pending save would write the stale state over the edit
+      if (saveTimeoutRef.current) {
+        clearTimeout(saveTimeoutRef.current);
+        saveTimeoutRef.current = null;
+      }
+      applyingExternalRef.current = true;
+
+      const { watchedDirs, scanDirectory, removeDirectory, setPresets } = useAppStore.getState();
+      const nextDirs = config.watchedDirs ?? [];
+      for (const dir of watchedDirs.filter((d) => !nextDirs.includes(d))) {
+        removeDirectory(dir);
+      }
+      for (const dir of nextDirs.filter((d) => !watchedDirs.includes(d))) {
+        await scanDirectory(dir);
+      }
+      setPresets(config.presets ?? []);
+      setPreferencesState(mergePreferences(DEFAULT_PREFERENCES, config.preferences));
+      // Let the state updates above render before saving resumes
+      setTimeout(() => {
+        applyingExternalRef.current = false;
+      }, 0);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   // Debounced save function - always gets fresh state
   const saveConfig = useCallback((currentPreferences: Preferences) => {
     if (saveTimeoutRef.current) {
@@ -117,6 +155,7 @@ export function useConfig() {
   // Save whenever watchedDirs, presets, or preferences change
   useEffect(() => {
     if (!isLoadedRef.current) return;
+    if (applyingExternalRef.current) return;
     saveConfig(preferences);
   }, [watchedDirs, presets, preferences, saveConfig]);
 
@@ -1,33 +1,67 @@
-import { useEffect, useRef, useCallback } from "react";
+import { useEffect, useCallback } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { useAppStore } from "../stores/app-store";
-import { GitToken } from "../types/project";
+import { GitCommitInfo, GitPullResult, GitToken, SubmoduleStatus } from "../types/project";
 
 interface GitStatusResult {
   isGitRepo: boolean;
   branch: string | null;
   remote: string | null;
   behindCount: number;
+  aheadCount: number;
+  stagedCount: number;
+  modifiedCount: number;
+  untrackedCount: number;
+  conflictedCount: number;
+  insertions: number;
+  deletions: number;
+  lastCommit: GitCommitInfo | null;
+  submodules: SubmoduleStatus[];
   error: string | null;
 }
 
-interface GitPullResult {
-  success: boolean;
-  message: string;
-  commitsPulled: number;
+// Backend poll result (git-status-updated event, get_git_statuses)
+interface CachedGitStatus {
+  path: string;
+  status: GitStatusResult;
+  checkedAt: number;
 }
 
+// Polling runs in the backend on preferences.git.pollingIntervalMinutes
 interface UseGitStatusOptions {
   enabled: boolean;
-  pollingIntervalMinutes: number;
   tokens: GitToken[];
 }
 
 export function useGitStatus(options: UseGitStatusOptions) {
-  const { enabled, pollingIntervalMinutes, tokens } = options;
-  const intervalRef = useRef<ReturnType<typeof setInterval> | null>(null);
+  const { enabled, tokens } = options;
   const { projects, updateGitStatus } = useAppStore();
 
+  const applyStatus = useCallback(
+    (path: string, result: GitStatusResult, checkedAt: Date) => {
+      updateGitStatus(path, {
+        isGitRepo: result.isGitRepo,
+        branch: result.branch,
+        remote: result.remote,
+        behindCount: result.behindCount,
+        aheadCount: result.aheadCount,
+        stagedCount: result.stagedCount,
+        modifiedCount: result.modifiedCount,
+        untrackedCount: result.untrackedCount,
+        conflictedCount: result.conflictedCount,
+        insertions: result.insertions,
+        deletions: result.deletions,
+        lastCommit: result.lastCommit,
+        submodules: result.submodules,
+        lastChecked: checkedAt,
+        fetchError: result.error,
+        isPulling: false,
+      });
+    },
+    [updateGitStatus]
+  );
+
   // Check git status for a single project
   const checkProjectStatus = useCallback(
     async (path: string) => {
@@ -36,16 +70,7 @@ export function useGitStatus(options: UseGitStatusOptions) {
           path,
           tokens,
         });
-
-        updateGitStatus(path, {
-          isGitRepo: result.isGitRepo,
-          branch: result.branch,
-          remote: result.remote,
-          behindCount: result.behindCount,
-          lastChecked: new Date(),
-          fetchError: result.error,
-          isPulling: false,
-        });
+        applyStatus(path, result, new Date());
       } catch (err) {
         console.error(`[Git] Failed to check status for ${path}:`, err);
         updateGitStatus(path, {
@@ -54,7 +79,7 @@ export function useGitStatus(options: UseGitStatusOptions) {
         });
       }
     },
-    [tokens, updateGitStatus]
+    [tokens, applyStatus, updateGitStatus]
   );
 
   // Check all projects
@@ -121,42 +146,20 @@ export function useGitStatus(options: UseGitStatusOptions) {
     [tokens, updateGitStatus, checkProjectStatus]
   );
 
-  // Initial check on mount
+  // Results from the backend poller, including any from before the window loaded
   useEffect(() => {
-    if (enabled && projects.length > 0) {
-      // Small delay to let the app settle
-      const timeout = setTimeout(() => {
-        checkAllProjects();
-      }, 2000);
-      return () => clearTimeout(timeout);
-    }
-  }, [enabled]); // Only run on mount/enabled change, not when projects change
-
-  // Set up polling interval
-  useEffect(() => {
-    if (intervalRef.current) {
-      clearInterval(intervalRef.current);
-      intervalRef.current = null;
-    }
+    const apply = (cached: CachedGitStatus) =>
+      applyStatus(cached.path, cached.status, new Date(cached.checkedAt));
 
-    if (!enabled || pollingIntervalMinutes <= 0) {
-      return;
-    }
-
-    const intervalMs = pollingIntervalMinutes * 60 * 1000;
-    console.log(`[Git] Setting up polling every ${pollingIntervalMinutes} minutes`);
-
-    intervalRef.current = setInterval(() => {
-      checkAllProjects();
-    }, intervalMs);
+    invoke<CachedGitStatus[]>("get_git_statuses")
+      .then((statuses) => statuses.forEach(apply))
+      .catch((err) => console.error("[Git] Failed to load cached statuses:", err));
+    const unlisten = listen<CachedGitStatus>("git-status-updated", (event) => apply(event.payload));
 
     return () => {
-      if (intervalRef.current) {
-        clearInterval(intervalRef.current);
-        intervalRef.current = null;
-      }
+      unlisten.then((fn) => fn());
     };
-  }, [enabled, pollingIntervalMinutes, checkAllProjects]);
+  }, [applyStatus]);
 
   return {
     refreshProject,
@@ -1,11 +1,16 @@
 import { useEffect } from "react";
 import { listen } from "@tauri-apps/api/event";
 import { useAppStore } from "../stores/app-store";
+import { AnsiSpan } from "../types/project";
 
 interface LogEvent {
   path: string;
   level: "info" | "warn" | "error" | "debug";
   message: string;
+  fields?: Record<string, unknown>;
+  fingerprint?: string;
+  timestamp?: number;  // set for JSON logs that carry their own time
+  spans?: AnsiSpan[];
 }
 
 export function useLogStream() {
@@ -14,9 +19,12 @@ export function useLogStream() {
   useEffect(() => {
     const unlisten = listen<LogEvent>("process-log", (event) => {
       appendLog(event.payload.path, {
-        timestamp: new Date(),
+        timestamp: event.payload.timestamp ? new Date(event.payload.timestamp) : new Date(),
         level: event.payload.level as "info" | "warn" | "error" | "debug",
         message: event.payload.message,
+        fields: event.payload.fields,
+        fingerprint: event.payload.fingerprint,
+        spans: event.payload.spans,
       });
     });
 
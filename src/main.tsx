@@ -1,10 +1,14 @@
 import React from "react";
 import ReactDOM from "react-dom/client";
 import App from "./App";
+import { LogWindow } from "./components/LogWindow";
 import "./index.css";
 
+// Log pop-out windows load index.html#/logs
+const isLogWindow = window.location.hash.startsWith("#/logs");
+
 ReactDOM.createRoot(document.getElementById("root") as HTMLElement).render(
   <React.StrictMode>
-    <App />
+    {isLogWindow ? <LogWindow /> : <App />}
   </React.StrictMode>
 );
@@ -7,17 +7,229 @@ export interface ProjectScript {
   command: string;
 }
 
+// Backend scan result (scan_projects)
+export interface ProjectInfo {
+  path: string;
+  name: string;
+  projectType: Exclude<ProjectType, "unknown">;
+  framework: string | null;  // "vite", "next", "poetry", "django", "compose", ...
+  packageManager: string | null;
+  scripts: { name: string; source: string; command: string; args: string[]; description: string | null }[];
+  defaultScript: string | null;
+  defaultPort: number | null;
+}
+
+// Monorepo packages (detect_workspaces)
+export interface WorkspacePackage extends ProjectInfo {
+  relativePath: string;  // e.g. "apps/web"
+}
+
+export interface WorkspaceInfo {
+  root: string;
+  tools: string[];  // "pnpm", "yarn", "npm", "nx", "turbo"
+  packageManager: string;
+  packages: WorkspacePackage[];
+}
+
+export interface GitCommitInfo {
+  hash: string;
+  author: string;
+  subject: string;
+  timestamp: number;     // unix seconds
+  relativeTime: string;  // e.g. "3 hours ago"
+}
+
+// git_log entry
+export interface LogCommit extends GitCommitInfo {
+  fullHash: string;
+  pushed: boolean;  // reachable from a remote-tracking branch
+}
+
+export interface CommitFileStat {
+  path: string;
+  insertions: number | null;  // null for binary files
+  deletions: number | null;
+}
+
+// git_show result
+export interface CommitDetails extends GitCommitInfo {
+  fullHash: string;
+  body: string;
+  files: CommitFileStat[];
+  insertions: number;
+  deletions: number;
+}
+
+// Install/migration hints after git_pull
+export interface DependencyChanges {
+  needsInstall: boolean;
+  needsMigration: boolean;
+  manifestFiles: string[];    // changed manifests/lockfiles
+  migrationFiles: string[];
+  ecosystems: string[];       // "node", "rust", "python", "go"
+  installCommands: string[];  // what run_install would run
+}
+
+export interface SubmoduleStatus {
+  path: string;
+  state: "uninitialized" | "out-of-date" | "conflict" | "ok";
+  dirty: boolean;
+}
+
+export interface OutdatedPackage {
+  name: string;
+  current: string | null;
+  wanted: string | null;  // newest version the manifest's range allows
+  latest: string | null;
+  major: boolean;         // latest is a new major version
+}
+
+export interface VulnerabilityCounts {
+  info: number;
+  low: number;
+  moderate: number;
+  high: number;
+  critical: number;
+  total: number;
+}
+
+// check_dependencies result
+export interface DependencyReport {
+  path: string;
+  packageManager: string;
+  outdatedCount: number;
+  outdated: OutdatedPackage[];
+  vulnerabilities: VulnerabilityCounts;
+  checkedAt: number;
+  cached: boolean;
+  errors: string[];
+}
+
+export interface RuntimeCheck {
+  runtime: "node" | "python" | "go";
+  required: string;  // version or range, e.g. "20.11.0", ">=18"
+  source: string;    // e.g. ".nvmrc", "package.json engines"
+  installed: string | null;
+  binary: string | null;
+  status: "ok" | "mismatch" | "missing" | "unknown";
+}
+
+// detect_runtime result
+export interface RuntimeReport {
+  path: string;
+  runtimes: RuntimeCheck[];
+  versionManagers: string[];  // "nvm", "fnm", "asdf", "volta", "pyenv", "mise"
+  mismatches: number;         // mismatched or missing runtimes
+}
+
 export interface GitStatus {
   isGitRepo: boolean;
   branch: string | null;
   remote: string | null;
   behindCount: number;
+  aheadCount?: number;
+  stagedCount?: number;
+  modifiedCount?: number;   // tracked files with unstaged changes
+  untrackedCount?: number;
+  conflictedCount?: number;
+  insertions?: number;      // lines changed against HEAD
+  deletions?: number;
+  lastCommit?: GitCommitInfo | null;
+  submodules?: SubmoduleStatus[];  // only the ones needing attention
   lastChecked: Date | null;
   fetchError: string | null;
   isPulling: boolean;
   pullError: string | null;  // Error from last pull attempt
 }
 
+// git_pull result
+export interface GitPullResult {
+  success: boolean;
+  message: string;
+  commitsPulled: number;
+  dependencyChanges: DependencyChanges | null;
+}
+
+// Per-repo outcome of git_status_all / git_pull_all
+export interface RepoResult<T> {
+  path: string;
+  result: T | null;
+  error: string | null;
+}
+
+export interface PullAllFilter {
+  paths?: string[];      // default: every watched git repo
+  onlyBehind?: boolean;  // skip repos the last check didn't find behind
+}
+
+// git_pull_all result
+export interface PullAllResult {
+  pulled: number;
+  upToDate: number;
+  failed: number;
+  repos: RepoResult<GitPullResult>[];
+}
+
+// git_clone result
+export interface GitCloneResult {
+  success: boolean;
+  message: string;
+  path: string;
+  registered: boolean;  // parent directory was added to watchedDirs
+}
+
+// git-clone-progress event payload
+export interface GitCloneProgress {
+  url: string;
+  dest: string;
+  phase: string;  // e.g. "Receiving objects", "Resolving deltas"
+  percent: number;
+  current: number;
+  total: number;
+}
+
+// git_repo_size result
+export interface RepoSizeReport {
+  gitDirBytes: number;
+  packBytes: number;
+  looseBytes: number;
+  objectCount: number;
+  largestObjects: { hash: string; path: string | null; sizeBytes: number }[];
+  lfsInstalled: boolean;
+  lfsTracked: boolean;
+  lfsFileCount: number;
+  isPartialClone: boolean;
+  suggestion: string | null;
+}
+
+// git_hygiene result
+export interface HygieneReport {
+  defaultBranch: string | null;
+  mergedBranches: string[];  // merged into defaultBranch, safe to delete
+  noUpstream: string[];
+  upstreamGone: string[];
+  size: RepoSizeReport;
+  pruned: string[];          // deleted by the prune action
+}
+
+export type CiStatus = "success" | "failure" | "pending" | "none" | "unknown";
+
+export interface GitRemoteInfo {
+  provider: "github" | "gitlab";
+  host: string;
+  repo: string;  // owner/repo
+  webUrl: string | null;
+  defaultBranch: string | null;
+  branch: string | null;
+  openPullRequests: number;  // Open PRs/MRs from the current branch
+  pullRequestUrl: string | null;
+  ciStatus: CiStatus;
+  ciUrl: string | null;
+  defaultBranchCiStatus: CiStatus;
+  error: string | null;
+  checkedAt: number;
+}
+
 export interface Project {
   path: string;
   name: string;
@@ -37,6 +249,148 @@ export interface LogEntry {
   timestamp: Date;
   level: "info" | "warn" | "error" | "debug";
   message: string;
+  fields?: Record<string, unknown>; // JSON log keys and named groups captured by log rules
+  fingerprint?: string;             // groups repeated errors
+  spans?: AnsiSpan[];               // styled runs of the message (ansiMode "spans")
+}
+
+export interface AnsiSpan {
+  text: string;
+  fg?: string;            // color name ("red", "brightBlue") or "#rrggbb"
+  bg?: string;
+  bold?: boolean;
+  dim?: boolean;
+  italic?: boolean;
+  underline?: boolean;
+}
+
+// search_logs result
+export interface LogSearchMatch {
+  path: string;
+  source: string;         // "buffer" or the saved log file
+  line: number;
+  timestamp: number;
+  level: string;
+  message: string;
+  before: string[];       // context lines
+  after: string[];
+}
+
+export interface LogSearchResult {
+  matches: LogSearchMatch[];  // oldest first
+  totalMatches: number;
+  truncated: boolean;
+}
+
+// Repeated error lines grouped by fingerprint
+export interface Problem {
+  fingerprint: string;
+  message: string;        // first occurrence
+  lastMessage: string;
+  count: number;
+  firstSeen: number;      // ms since epoch
+  lastSeen: number;
+}
+
+// get_project_problems result; also the problems-updated event payload
+export interface ProjectProblems {
+  path: string;
+  distinct: number;
+  occurrences: number;
+  problems: Problem[];    // most recently seen first
+}
+
+// list_browsers result
+export interface BrowserInfo {
+  id: string;             // "chrome", "firefox", "safari", "arc", "edge", ...
+  name: string;
+  path: string;
+  supportsPrivate: boolean;
+  supportsCleanProfile: boolean;  // can open in a throwaway profile
+}
+
+// Local SSH port forward; also the ssh-tunnel-status event payload
+export interface SshTunnelInfo {
+  id: string;
+  host: string;           // "user@host" or an ~/.ssh/config alias
+  remoteHost: string;
+  remotePort: number;
+  localPort: number;
+  key: string | null;     // identity file
+  status: "connecting" | "connected" | "reconnecting";
+  reconnects: number;
+  lastError: string | null;
+  pid: number | null;
+  startedAt: number;
+}
+
+// check_database result
+export interface DatabaseStatus {
+  kind: "postgres" | "mysql" | "redis" | "mongodb";
+  host: string;
+  port: number;
+  reachable: boolean;     // answered in its own protocol, not just an open port
+  latencyMs: number | null;
+  version: string | null;
+  authRequired: boolean;
+  error: string | null;
+}
+
+// Locally installed datastore managed by brew services or systemd
+export interface DatabaseService {
+  name: string;
+  kind: "postgres" | "mysql" | "redis" | "mongodb";
+  manager: "brew" | "systemd";
+  running: boolean;
+  status: string;
+}
+
+// Line in the system hosts file (read_hosts)
+export interface HostEntry {
+  ip: string;
+  hostnames: string[];
+  comment: string | null;
+  managed: boolean;       // added by DevLaunch
+  project: string | null; // project it was added for
+  line: number;
+}
+
+// get_lan_urls result
+export interface LanUrl {
+  interface: string;
+  ip: string;
+  url: string;
+}
+
+export interface LanUrls {
+  port: number;
+  urls: LanUrl[];         // best candidate first
+  qrPayload: string | null;  // text to encode as a QR code
+  bindAddresses: string[];   // where the server on this port listens
+  loopbackOnly: boolean;  // unreachable from other devices until rebound
+  bindHint: string | null;
+}
+
+// config-invalid event payload: config.json was edited outside the app and doesn't parse
+export interface ConfigInvalidEvent {
+  path: string;
+  error: string;
+}
+
+// quit-requested event payload; answer with resolve_quit
+export interface QuitRequest {
+  runningCount: number;
+  projects: string[];     // paths of the running servers
+}
+
+// Dev server left running by a previous session (list_orphans, orphans-detected event)
+export interface OrphanProcess {
+  pid: number;
+  cwd: string;
+  command: string;
+  args: string[];
+  startedAt: number;
+  ports: number[];        // listening, including child processes
 }
 
 export interface Preset {
@@ -48,6 +402,19 @@ export interface Preset {
 export interface PresetProject {
   path: string;
   script: string;
+  dependsOn?: string[];  // paths of projects that must be ready first
+  startupDelayMs?: number;  // wait before spawning
+  readyWhen?: ReadinessCondition;
+  command?: string;  // exact command to run instead of resolving script (session restore)
+  args?: string[];
+}
+
+// Every condition that is set must pass before dependents start
+export interface ReadinessCondition {
+  port?: number;        // localhost TCP port accepting connections
+  url?: string;         // answers with 2xx/3xx
+  logPattern?: string;  // regex matched by a log line
+  timeoutMs?: number;   // default 60000
 }
 
 export interface WindowState {
@@ -63,6 +430,7 @@ export interface GitToken {
   pattern: string;  // e.g., "github.com/routefusion/*", "*" for fallback
   token: string;
   label?: string;   // optional friendly name for display
+  sshKey?: string;  // private key for matching SSH remotes (default: SSH agent)
 }
 
 export interface GitPreferences {
@@ -71,13 +439,340 @@ export interface GitPreferences {
   tokens: GitToken[];
 }
 
+export interface RestartPolicy {
+  enabled: boolean;
+  maxRetries: number;
+  initialBackoffMs: number;
+  maxBackoffMs: number;
+  onlyOnNonzeroExit: boolean;
+  resetAfterSecs: number;       // uptime after which the retry count resets
+}
+
+export interface HealthCheckConfig {
+  url?: string;               // path or full URL; defaults to the detected base URL
+  intervalMs?: number;        // default 5000
+  timeoutMs?: number;         // default 3000
+  expectedStatus?: number;    // any 2xx/3xx when unset
+  expectedBody?: string;      // substring the body must contain
+  failureThreshold?: number;  // consecutive failures before "unhealthy" (default 3)
+  startupTimeoutMs?: number;  // max time in "starting" (default 60000)
+}
+
+export type HealthStatus = "starting" | "healthy" | "unhealthy";
+
+// Startup timings of one launch, in ms from the spawn
+export interface LaunchRecord {
+  startedAt: number;
+  command: string;
+  firstLogMs?: number;
+  urlDetectedMs?: number;
+  healthCheckPassMs?: number;
+  exitedEarly: boolean;       // exited before it became ready
+}
+
+export interface DurationSummary {
+  count: number;
+  latestMs?: number;
+  medianMs?: number;
+  p90Ms?: number;
+  minMs?: number;
+  maxMs?: number;
+}
+
+export interface LaunchStats {
+  path: string;
+  launches: LaunchRecord[];   // oldest first, up to 100
+  current?: LaunchRecord;     // launch that hasn't become ready yet
+  firstLog: DurationSummary;
+  urlDetected: DurationSummary;
+  healthCheckPass: DurationSummary;
+}
+
+// Non-zero or signal exit of a project's process; get_crash_history returns up to 20, oldest first
+export interface CrashRecord {
+  pid: number;
+  command: string;
+  startedAt: number;
+  exitedAt: number;
+  code?: number;
+  signal?: number;
+  reason: string;         // e.g. "Exited with code 1", "Killed by SIGKILL (9) - possibly out of memory"
+  lastOutput: string[];   // last 50 lines of the run, oldest first
+}
+
+// Also the shape of a project's .devlaunch.json, which overrides these per field
+export interface ProjectSettings {
+  warmupRequests?: string[];  // e.g. ["/", "/api/health"], requested once the server is ready
+  autoRestart?: RestartPolicy;
+  healthCheck?: HealthCheckConfig;
+  ports?: number[];           // ports checked for conflicts before launching
+  script?: string;            // script launched by default
+  env?: Record<string, string>;
+  hooks?: ProjectHooks;
+  limits?: ResourceLimits;
+  resourceAlerts?: ResourceAlertRule[];  // checked on every metrics sample
+  watchAndRestart?: WatchRestart;
+  idleShutdown?: IdleRule;
+  logPresets?: ("rails" | "django" | "spring")[];  // built-in rules, applied after logRules
+  logRules?: LogRule[];
+  ansiMode?: "strip" | "spans" | "keep";  // color codes in log lines (default "strip")
+  versionManager?: "auto" | "nvm" | "fnm" | "asdf" | "mise" | "volta" | "login-shell";  // run commands under it
+}
+
+// Regex rule for classifying log lines; the first match wins
+export interface LogRule {
+  id: string;
+  name?: string;
+  enabled: boolean;
+  pattern: string;        // named groups become fields; "url"/"port" groups feed URL detection
+  level?: string;         // "error", "warn", "info", "debug", or a placeholder like "{{severity}}"
+  fingerprint?: string;   // e.g. "{{exception}} at {{file}}"
+}
+
+// Restart on file changes, for servers without their own reloader
+export interface WatchRestart {
+  enabled: boolean;
+  paths: string[];        // globs relative to the project, e.g. ["**/*.go", "templates/**"]
+  ignore?: string[];      // .git, node_modules, target and virtualenvs are always skipped
+  debounceMs?: number;    // quiet period before restarting (default 500)
+}
+
+// Flag a server with no log output and no connections to its port
+export interface IdleRule {
+  enabled: boolean;
+  minutes?: number;           // idle time before acting (default 30)
+  action?: "warn" | "stop";   // default "warn"
+}
+
+// project-idle event payload
+export interface IdleEvent {
+  pid: number;
+  path: string;
+  idleMinutes: number;
+  action: "warn" | "stop";
+}
+
+// process-waiting-for-input event payload; answer with send_stdin (e.g. "y\n")
+export interface WaitingForInputEvent {
+  pid: number;
+  path: string;
+  prompt: string;   // the question, with escape codes removed
+}
+
+// Caps on a project's process tree
+export interface ResourceLimits {
+  maxMemoryMb?: number;         // resident memory of the whole tree (checked by the monitor)
+  nice?: number;                // -20 (highest) to 19 (lowest)
+  onExceed?: "warn" | "kill" | "restart";  // default "warn"
+  hardMemoryLimit?: boolean;    // also enforce maxMemoryMb in the OS: per process on Linux (RLIMIT_DATA), per tree on Windows (Job Object)
+}
+
+// resource-limit-exceeded event payload
+export interface ResourceLimitEvent {
+  pid: number;
+  path: string;
+  rssMb: number;
+  maxMemoryMb: number;
+  action: "warn" | "kill" | "restart";
+}
+
+// Sustained CPU or memory usage worth flagging, e.g. { metric: "memory", threshold: 2048, durationSecs: 60 }
+export interface ResourceAlertRule {
+  metric: "memory" | "cpu";     // resident MB of the tree, or percent (100 per fully used core)
+  threshold: number;
+  durationSecs?: number;        // how long usage stays over the threshold (default 60)
+  notify?: boolean;             // also show a native notification
+  action?: "warn" | "restart";  // default "warn"
+}
+
+// resource-alert event payload
+export interface ResourceAlertEvent {
+  pid: number;
+  path: string;
+  metric: "memory" | "cpu";
+  value: number;
+  threshold: number;
+  durationSecs: number;
+  action: "warn" | "restart";
+}
+
+// Shell commands run around launches, in the project directory
+export interface ProjectHooks {
+  preLaunch?: string[];   // e.g. ["docker compose up -d db"]; a failure aborts the launch
+  postStop?: string[];    // e.g. ["docker compose stop"]
+  timeoutSecs?: number;   // per command (default 300)
+}
+
+// load_project_config result; also the project-config-changed event payload
+export interface ProjectConfigInfo {
+  path: string;
+  filePath: string;
+  exists: boolean;
+  file: ProjectSettings | null;   // .devlaunch.json alone
+  effective: ProjectSettings;     // app config with .devlaunch.json on top
+  error: string | null;
+  trusted: boolean;               // env, hooks and versionManager from the file apply only once trusted
+  hash: string | null;            // SHA-256 of the file; pass to trust_project_config
+}
+
+export interface WebhookResponseRule {
+  id: string;
+  name?: string;
+  enabled: boolean;
+  path: string;           // glob, e.g. "/stripe/*" or "/hooks/**"
+  method?: string;        // omit or "*" for any
+  status: number;
+  contentType?: string;
+  body: string;           // supports {{method}}, {{path}}, {{body}}, {{header.name}}, ...
+  headers?: Record<string, string>;
+  delayMs: number;
+}
+
+export interface WebhookSigningSecrets {
+  github?: string;          // verifies X-Hub-Signature-256
+  stripe?: string;          // endpoint secret (whsec_...) for Stripe-Signature
+  slack?: string;           // signing secret for X-Slack-Signature
+  toleranceSecs?: number;   // max timestamp age for Stripe/Slack (default 300)
+}
+
+export interface WebhookStorePreferences {
+  enabled: boolean;     // keep received webhooks on disk across restarts
+  maxEvents?: number;   // retention limit (default 5000)
+}
+
+export interface LogFilePreferences {
+  enabled: boolean;         // tee process output to logs/<project>/<timestamp>.log under app data
+  maxFileSizeMb?: number;   // rotate to a new file at this size (default 10)
+  maxFiles?: number;        // files kept per project (default 10)
+}
+
+export interface MetricsServerPreferences {
+  enabled: boolean;   // serve Prometheus metrics at http://127.0.0.1:<port>/metrics on launch
+  port?: number;      // default 9464
+}
+
+export interface MockResponse {
+  status?: number;                    // default 200
+  body?: unknown;                     // JSON; strings support {{params.id}}, {{query.q}}, {{method}}, {{path}}, {{timestamp}}
+  headers?: Record<string, string>;
+  delayMs?: number;                   // simulated latency
+}
+
+export interface MockRoute {
+  id: string;
+  name?: string;
+  enabled: boolean;
+  method?: string;                    // omit or "*" for any
+  path: string;                       // "/users/:id", "/files/*", "/api/**"
+  responses: MockResponse[];          // returned in order on successive hits
+  repeat?: "last" | "loop";           // after the last response (default "last")
+}
+
+export interface MockApiPreferences {
+  port?: number;      // default 4010
+  routes?: MockRoute[];
+}
+
+export interface UpdatePreferences {
+  channel: "stable" | "beta";  // default "stable"
+  checkOnLaunch: boolean;      // default true
+}
+
+// update-available event payload, also returned by check_for_updates
+export interface UpdateInfo {
+  version: string;
+  currentVersion: string;
+  date?: string;
+  notes?: string;              // release notes
+  channel: string;
+}
+
+// Shown next to the tray icon (macOS menu bar title)
+export interface TrayPreferences {
+  showRunningCount: boolean;   // default true
+  showCpu: boolean;            // combined CPU of running servers, 100% per core
+}
+
+// System-wide keyboard shortcuts
+export interface ShortcutPreferences {
+  enabled: boolean;            // default false
+  bindings: ShortcutBinding[];
+}
+
+export interface ShortcutBinding {
+  accelerator: string;         // e.g. "CommandOrControl+Shift+D"
+  action: string;              // "toggle-window", "kill-all" or "launch-preset:<n>" (1-based)
+}
+
+// Registration result per binding, from get_shortcuts
+export interface ShortcutStatus {
+  accelerator: string;
+  action: string;
+  registered: boolean;
+  error?: string;              // invalid accelerator, unknown action, or taken by another app
+}
+
+export interface ControlServerPreferences {
+  enabled: boolean;   // serve the CLI control API on 127.0.0.1:<port> (token in control.json)
+  port?: number;      // default 47820
+}
+
+export interface SystemHistoryPreferences {
+  intervalSecs?: number;      // sampling interval (default 5)
+  retentionMinutes?: number;  // history kept in memory (default 1440)
+}
+
+export interface ScheduledTask {
+  id: string;
+  name: string;
+  enabled: boolean;
+  command: string;
+  args?: string[];
+  cwd?: string;             // default: home directory
+  schedule?: string;        // cron "min hour day month weekday" or @hourly/@daily/@weekly/@monthly (local time)
+  runAtStartup?: boolean;
+}
+
+export interface ShellEnvPreferences {
+  useLoginShell: boolean;       // resolve PATH etc. from the login shell for spawned commands
+  shell?: string;               // override $SHELL
+  env?: Record<string, string>; // extra variables applied on top
+}
+
+// resolve_command result
+export interface ResolvedCommand {
+  command: string;
+  path: string | null;       // full path of the executable, if found
+  searched: string[];        // PATH directories, in order
+  fromLoginShell: boolean;
+}
+
 export interface Preferences {
   ngrokAuthToken?: string;
   defaultWebhookPort: number;
   git: GitPreferences;
+  projects?: Record<string, ProjectSettings>;  // keyed by project path
+  shellEnv?: ShellEnvPreferences;
+  logBufferSize?: number;  // lines of log history kept per project (default 5000)
+  webhookResponseRules?: WebhookResponseRule[];
+  webhookSigningSecrets?: WebhookSigningSecrets;
+  webhookStore?: WebhookStorePreferences;
+  tunnelProvider?: "ngrok" | "cloudflared" | "localtunnel";  // default "ngrok"
+  logFiles?: LogFilePreferences;
+  metricsServer?: MetricsServerPreferences;
+  systemHistory?: SystemHistoryPreferences;
+  controlServer?: ControlServerPreferences;
+  mockApi?: MockApiPreferences;
+  scheduledTasks?: ScheduledTask[];
+  restoreSessionOnLaunch?: boolean;  // relaunch last session's projects on startup
+  trustedProjectFiles?: Record<string, string>;  // project path -> SHA-256 of the trusted .devlaunch.json
+  shortcuts?: ShortcutPreferences;
+  tray?: TrayPreferences;
+  updates?: UpdatePreferences;
 }
 
 export interface AppConfig {
+  configVersion?: number;  // schema version; older files are migrated (with a backup) on load
   watchedDirs: string[];
   presets: Preset[];
   lastSession?: {